@@ -24,8 +24,8 @@ use node_runtime::{
     CouncilConfig, CouncilElectionConfig, DataObjectStorageRegistryConfig,
     DataObjectTypeRegistryConfig, ElectionParameters, GrandpaConfig, ImOnlineConfig, IndicesConfig,
     MembersConfig, MigrationConfig, Perbill, ProposalsCodexConfig, SessionConfig, SessionKeys,
-    Signature, StakerStatus, StakingConfig, SudoConfig, SystemConfig, VersionedStoreConfig, DAYS,
-    WASM_BINARY,
+    Signature, StakerStatus, StakingConfig, SudoConfig, SystemConfig, TallyMethod,
+    VersionedStoreConfig, DAYS, WASM_BINARY,
 };
 pub use node_runtime::{AccountId, GenesisConfig};
 use primitives::{sr25519, Pair, Public};
@@ -249,6 +249,8 @@ pub fn testnet_genesis(
                 min_council_stake: 10 * DOLLARS,
                 new_term_duration: 14 * DAYS,
                 min_voting_stake: 1 * DOLLARS,
+                tally_method: TallyMethod::FirstPastThePost,
+                max_ranked_candidates: 0,
             },
         }),
         members: Some(MembersConfig {
@@ -332,6 +334,30 @@ pub fn testnet_genesis(
                 .set_storage_role_parameters_proposal_voting_period,
             set_storage_role_parameters_proposal_grace_period: cpcp
                 .set_storage_role_parameters_proposal_grace_period,
+            set_proposal_parameters_proposal_voting_period: cpcp
+                .set_proposal_parameters_proposal_voting_period,
+            set_proposal_parameters_proposal_grace_period: cpcp
+                .set_proposal_parameters_proposal_grace_period,
+            add_content_working_group_leader_opening_proposal_voting_period: cpcp
+                .add_content_working_group_leader_opening_proposal_voting_period,
+            add_content_working_group_leader_opening_proposal_grace_period: cpcp
+                .add_content_working_group_leader_opening_proposal_grace_period,
+            begin_review_content_working_group_leader_applications_proposal_voting_period: cpcp
+                .begin_review_content_working_group_leader_applications_proposal_voting_period,
+            begin_review_content_working_group_leader_applications_proposal_grace_period: cpcp
+                .begin_review_content_working_group_leader_applications_proposal_grace_period,
+            fill_content_working_group_leader_opening_proposal_voting_period: cpcp
+                .fill_content_working_group_leader_opening_proposal_voting_period,
+            fill_content_working_group_leader_opening_proposal_grace_period: cpcp
+                .fill_content_working_group_leader_opening_proposal_grace_period,
+            slash_content_working_group_leader_stake_proposal_voting_period: cpcp
+                .slash_content_working_group_leader_stake_proposal_voting_period,
+            slash_content_working_group_leader_stake_proposal_grace_period: cpcp
+                .slash_content_working_group_leader_stake_proposal_grace_period,
+            terminate_content_working_group_leader_role_proposal_voting_period: cpcp
+                .terminate_content_working_group_leader_role_proposal_voting_period,
+            terminate_content_working_group_leader_role_proposal_grace_period: cpcp
+                .terminate_content_working_group_leader_role_proposal_grace_period,
         }),
     }
 }
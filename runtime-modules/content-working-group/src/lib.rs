@@ -15,6 +15,7 @@ mod tests;
 mod mock;
 
 pub mod genesis;
+mod migration;
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -241,6 +242,15 @@ pub static MSG_ORIGIN_IS_NIETHER_MEMBER_CONTROLLER_OR_ROOT: &str =
     "Origin must be controller or root account of member";
 pub static MSG_MEMBER_HAS_ACTIVE_APPLICATION_ON_OPENING: &str =
     "Member already has an active application on the opening";
+pub static MSG_OPENING_IS_NOT_CURATOR_OPENING: &str = "Opening is not a curator opening";
+pub static MSG_OPENING_IS_NOT_LEADER_OPENING: &str = "Opening is not a leader opening";
+pub static MSG_MEMBER_NO_LONGER_REGISTRABLE_AS_LEAD: &str = "Member no longer registrable as lead";
+pub static MSG_FILL_LEAD_OPENING_APPLICATION_FOR_WRONG_OPENING: &str =
+    "Lead application is for a different opening";
+pub static MSG_LEAD_HAS_NO_ROLE_STAKE: &str = "Lead has no role stake";
+pub static MSG_SLASH_LEAD_STAKE_STAKE_DOES_NOT_EXIST: &str = "Lead role stake does not exist";
+pub static MSG_SLASH_LEAD_STAKE_NOT_STAKED: &str = "Lead role stake is not staked";
+pub static MSG_SLASH_LEAD_STAKE_ZERO_AMOUNT: &str = "Slash amount should be greater than zero";
 
 /// The exit stage of a lead involvement in the working group.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -248,6 +258,9 @@ pub static MSG_MEMBER_HAS_ACTIVE_APPLICATION_ON_OPENING: &str =
 pub struct ExitedLeadRole<BlockNumber> {
     /// When exit was initiated.
     pub initiated_at_block_number: BlockNumber,
+
+    /// Explainer for why exit was initiated.
+    pub rationale_text: Vec<u8>,
 }
 
 /// The stage of the involvement of a lead in the working group.
@@ -270,17 +283,20 @@ impl<BlockNumber> Default for LeadRoleState<BlockNumber> {
 }
 
 /// Working group lead: curator lead
-/// For now this role is not staked or inducted through an structured process, like the hiring module,
-/// hence information about this is missing. Recurring rewards is included, somewhat arbitrarily!
+/// The lead is either set directly by `replace_lead`, or hired through a leader opening
+/// in the hiring module, in which case the role may be staked and rewarded.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
-pub struct Lead<AccountId, RewardRelationshipId, BlockNumber> {
+pub struct Lead<AccountId, RewardRelationshipId, StakeId, BlockNumber> {
     /// Account used to authenticate in this role,
     pub role_account: AccountId,
 
     /// Whether the role has recurring reward, and if so an identifier for this.
     pub reward_relationship: Option<RewardRelationshipId>,
 
+    /// When set, describes role stake of lead.
+    pub role_stake_profile: Option<CuratorRoleStakeProfile<StakeId, BlockNumber>>,
+
     /// When was inducted
     /// TODO: Add richer information about circumstances of induction, like referencing a council proposal?
     pub inducted: BlockNumber,
@@ -483,6 +499,25 @@ impl<
     }
 }
 
+/// The role an opening is hiring for.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpeningType {
+    /// Opening for the curator lead role, managed by root (the council).
+    Leader,
+
+    /// Opening for a curator role, managed by the lead.
+    Curator,
+}
+
+/// Must be default constructible because it indirectly is a value in a storage map.
+/// ***SHOULD NEVER ACTUALLY GET CALLED, IS REQUIRED TO DUE BAD STORAGE MODEL IN SUBSTRATE***
+impl Default for OpeningType {
+    fn default() -> Self {
+        OpeningType::Curator
+    }
+}
+
 /// An opening for a curator role.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
@@ -490,6 +525,9 @@ pub struct CuratorOpening<OpeningId, BlockNumber, Balance, CuratorApplicationId:
     /// Identifer for underlying opening in the hiring module.
     pub opening_id: OpeningId,
 
+    /// Role the opening is hiring for.
+    pub opening_type: OpeningType,
+
     /// Set of identifiers for all curator applications ever added
     pub curator_applications: BTreeSet<CuratorApplicationId>,
 
@@ -906,6 +944,22 @@ impl<T: hiring::Trait> rstd::convert::From<WrappedError<hiring::FillOpeningError
     }
 }
 
+impl rstd::convert::From<WrappedError<stake::StakeActionError<stake::ImmediateSlashingError>>>
+    for &str
+{
+    fn from(wrapper: WrappedError<stake::StakeActionError<stake::ImmediateSlashingError>>) -> Self {
+        match wrapper.error {
+            stake::StakeActionError::StakeNotFound => MSG_SLASH_LEAD_STAKE_STAKE_DOES_NOT_EXIST,
+            stake::StakeActionError::Error(stake::ImmediateSlashingError::NotStaked) => {
+                MSG_SLASH_LEAD_STAKE_NOT_STAKED
+            }
+            stake::StakeActionError::Error(
+                stake::ImmediateSlashingError::SlashAmountShouldBeGreaterThanZero,
+            ) => MSG_SLASH_LEAD_STAKE_ZERO_AMOUNT,
+        }
+    }
+}
+
 impl rstd::convert::From<WrappedError<hiring::DeactivateApplicationError>> for &str {
     fn from(wrapper: WrappedError<hiring::DeactivateApplicationError>) -> Self {
         match wrapper.error {
@@ -983,11 +1037,17 @@ impl rstd::convert::From<WrappedError<members::MemberControllerAccountDidNotSign
 }
 
 /// The recurring reward if any to be assigned to an actor when filling in the position.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, Eq, PartialEq, Debug)]
 pub struct RewardPolicy<Balance, BlockNumber> {
-    amount_per_payout: Balance,
-    next_payment_at_block: BlockNumber,
-    payout_interval: Option<BlockNumber>,
+    /// Amount paid out in each payout.
+    pub amount_per_payout: Balance,
+
+    /// Block of the first payout.
+    pub next_payment_at_block: BlockNumber,
+
+    /// Blocks between payouts, if recurring.
+    pub payout_interval: Option<BlockNumber>,
 }
 
 // ======================================================================== //
@@ -1004,7 +1064,7 @@ decl_storage! {
         pub CurrentLeadId get(current_lead_id) : Option<LeadId<T>>;
 
         /// Maps identifier to corresponding lead.
-        pub LeadById get(lead_by_id): linked_map LeadId<T> => Lead<T::AccountId, T::RewardRelationshipId, T::BlockNumber>;
+        pub LeadById get(lead_by_id): linked_map LeadId<T> => Lead<T::AccountId, T::RewardRelationshipId, T::StakeId, T::BlockNumber>;
 
         /// Next identifier for new current lead.
         pub NextLeadId get(next_lead_id): LeadId<T>;
@@ -1089,6 +1149,7 @@ decl_event! {
         CuratorId = CuratorId<T>,
         CuratorApplicationIdToCuratorIdMap = CuratorApplicationIdToCuratorIdMap<T>,
         MintBalanceOf = minting::BalanceOf<T>,
+        StakeBalanceOf = BalanceOf<T>,
         <T as system::Trait>::AccountId,
         <T as minting::Trait>::MintId,
     {
@@ -1112,6 +1173,10 @@ decl_event! {
         ChannelCreationEnabledUpdated(bool),
         MintCapacityIncreased(MintId, MintBalanceOf, MintBalanceOf),
         MintCapacityDecreased(MintId, MintBalanceOf, MintBalanceOf),
+        LeadOpeningAdded(CuratorOpeningId),
+        BeganLeadApplicationReview(CuratorOpeningId),
+        LeadOpeningFilled(CuratorOpeningId, CuratorApplicationId, LeadId),
+        LeadStakeSlashed(LeadId, StakeBalanceOf),
     }
 }
 
@@ -1362,42 +1427,12 @@ decl_module! {
             // Ensure lead is set and is origin signer
             Self::ensure_origin_is_set_lead(origin)?;
 
-            // Ensure human radable text is valid
-            Self::ensure_opening_human_readable_text_is_valid(&human_readable_text)?;
-
-            // Add opening
-            // NB: This call can in principle fail, because the staking policies
-            // may not respect the minimum currency requirement.
-
-            let policy_commitment = commitment.clone();
-
-            let opening_id = ensure_on_wrapped_error!(
-                hiring::Module::<T>::add_opening(
-                    activate_at,
-                    commitment.max_review_period_length,
-                    commitment.application_rationing_policy,
-                    commitment.application_staking_policy,
-                    commitment.role_staking_policy,
-                    human_readable_text,
-                ))?;
-
-            //
-            // == MUTATION SAFE ==
-            //
-
-            let new_curator_opening_id = NextCuratorOpeningId::<T>::get();
-
-            // Create and add curator opening.
-            let new_opening_by_id = CuratorOpening {
-                opening_id,
-                curator_applications: BTreeSet::new(),
-                policy_commitment,
-            };
-
-            CuratorOpeningById::<T>::insert(new_curator_opening_id, new_opening_by_id);
-
-            // Update NextCuratorOpeningId
-            NextCuratorOpeningId::<T>::mutate(|id| *id += <CuratorOpeningId<T> as One>::one());
+            let new_curator_opening_id = Self::add_opening(
+                activate_at,
+                commitment,
+                human_readable_text,
+                OpeningType::Curator
+            )?;
 
             // Trigger event
             Self::deposit_event(RawEvent::CuratorOpeningAdded(new_curator_opening_id));
@@ -1415,6 +1450,9 @@ decl_module! {
             // this working group, not something else.
             let (curator_opening, _opening) = Self::ensure_curator_opening_exists(&curator_opening_id)?;
 
+            // Ensure opening is managed by the lead
+            Self::ensure_opening_type(&curator_opening, OpeningType::Curator)?;
+
             // Attempt to begin accepting applicationsa
             // NB: Combined ensure check and mutation in hiring module
             ensure_on_wrapped_error!(
@@ -1441,6 +1479,9 @@ decl_module! {
             // this working group, not something else.
             let (curator_opening, _opening) = Self::ensure_curator_opening_exists(&curator_opening_id)?;
 
+            // Ensure opening is managed by the lead
+            Self::ensure_opening_type(&curator_opening, OpeningType::Curator)?;
+
             // Attempt to begin review of applications
             // NB: Combined ensure check and mutation in hiring module
             ensure_on_wrapped_error!(
@@ -1468,6 +1509,9 @@ decl_module! {
             // Ensure curator opening exists
             let (curator_opening, _) = Self::ensure_curator_opening_exists(&curator_opening_id)?;
 
+            // Ensure opening is managed by the lead
+            Self::ensure_opening_type(&curator_opening, OpeningType::Curator)?;
+
             // Make iterator over successful curator application
             let successful_iter = successful_curator_application_ids
                                     .iter()
@@ -1518,20 +1562,7 @@ decl_module! {
                 )
             )?;
 
-            let create_reward_settings = if let Some(policy) = reward_policy {
-                // A reward will need to be created so ensure our configured mint exists
-                let mint_id = Self::mint();
-
-                ensure!(<minting::Mints<T>>::exists(mint_id), MSG_FILL_CURATOR_OPENING_MINT_DOES_NOT_EXIST);
-
-                // Make sure valid parameters are selected for next payment at block number
-                ensure!(policy.next_payment_at_block > <system::Module<T>>::block_number(), MSG_FILL_CURATOR_OPENING_INVALID_NEXT_PAYMENT_BLOCK);
-
-                // The verified reward settings to use
-                Some((mint_id, policy))
-            } else {
-                None
-            };
+            let create_reward_settings = Self::ensure_reward_policy_is_valid(reward_policy)?;
 
             //
             // == MUTATION SAFE ==
@@ -1550,31 +1581,9 @@ decl_module! {
             .for_each(|(successful_curator_application, id, _)| {
 
                 // Create a reward relationship
-                let reward_relationship = if let Some((mint_id, checked_policy)) = create_reward_settings.clone() {
-
-                    // Create a new recipient for the new relationship
-                    let recipient = <recurringrewards::Module<T>>::add_recipient();
-
-                    // member must exist, since it was checked that it can enter the role
-                    let member_profile = <members::Module<T>>::member_profile(successful_curator_application.member_id).unwrap();
-
-                    // rewards are deposited in the member's root account
-                    let reward_destination_account = member_profile.root_account;
-
-                    // values have been checked so this should not fail!
-                    let relationship_id = <recurringrewards::Module<T>>::add_reward_relationship(
-                        mint_id,
-                        recipient,
-                        reward_destination_account,
-                        checked_policy.amount_per_payout,
-                        checked_policy.next_payment_at_block,
-                        checked_policy.payout_interval,
-                    ).expect("Failed to create reward relationship!");
-
-                    Some(relationship_id)
-                } else {
-                    None
-                };
+                let reward_relationship = create_reward_settings.clone().map(|(mint_id, checked_policy)| {
+                    Self::add_reward_relationship(successful_curator_application.member_id, mint_id, &checked_policy)
+                });
 
                 // Get possible stake for role
                 let application = hiring::ApplicationById::<T>::get(successful_curator_application.application_id);
@@ -1694,6 +1703,9 @@ decl_module! {
             // Ensuring curator application actually exists
             let (curator_application, _, curator_opening) = Self::ensure_curator_application_exists(&curator_application_id)?;
 
+            // Ensure opening is managed by the lead
+            Self::ensure_opening_type(&curator_opening, OpeningType::Curator)?;
+
             // Attempt to deactivate application
             // NB: Combined ensure check and mutation in hiring module
             ensure_on_wrapped_error!(
@@ -1738,8 +1750,15 @@ decl_module! {
             // Ensure curator opening exists
             let (curator_opening, _opening) = Self::ensure_curator_opening_exists(&curator_opening_id)?;
 
-            // Ensure new owner can actually become a curator
-            let (_member_as_curator, _new_curator_id) = Self::ensure_can_register_curator_role_on_member(&member_id)?;
+            // Ensure new owner can actually step into the role the opening is for
+            match curator_opening.opening_type {
+                OpeningType::Curator => {
+                    Self::ensure_can_register_curator_role_on_member(&member_id)?;
+                }
+                OpeningType::Leader => {
+                    Self::ensure_can_register_lead_role_on_member(&member_id)?;
+                }
+            }
 
             // Ensure that there is sufficient balance to cover stake proposed
             Self::ensure_can_make_stake_imbalance(
@@ -1927,7 +1946,7 @@ decl_module! {
 
             // Unset current lead first
             if Self::ensure_lead_is_set().is_ok() {
-                Self::unset_lead()?;
+                Self::unset_lead(Vec::new())?;
             }
 
             // Try to set new lead
@@ -1936,6 +1955,185 @@ decl_module! {
             }
         }
 
+        /// Add an opening for the lead role. Applications are made with `apply_on_curator_opening`.
+        pub fn add_lead_opening(origin, activate_at: hiring::ActivateOpeningAt<T::BlockNumber>, commitment: OpeningPolicyCommitment<T::BlockNumber, BalanceOf<T>>, human_readable_text: Vec<u8>)  {
+            // Ensure root is origin
+            ensure_root(origin)?;
+
+            let new_curator_opening_id = Self::add_opening(
+                activate_at,
+                commitment,
+                human_readable_text,
+                OpeningType::Leader
+            )?;
+
+            // Trigger event
+            Self::deposit_event(RawEvent::LeadOpeningAdded(new_curator_opening_id));
+        }
+
+        /// Begin reviewing applications to the lead opening, and therefore not accepting new applications.
+        pub fn begin_lead_applicant_review(origin, curator_opening_id: CuratorOpeningId<T>) {
+            // Ensure root is origin
+            ensure_root(origin)?;
+
+            // Ensure opening exists
+            let (curator_opening, _opening) = Self::ensure_curator_opening_exists(&curator_opening_id)?;
+
+            // Ensure opening is for the lead role
+            Self::ensure_opening_type(&curator_opening, OpeningType::Leader)?;
+
+            // Attempt to begin review of applications
+            // NB: Combined ensure check and mutation in hiring module
+            ensure_on_wrapped_error!(
+                hiring::Module::<T>::begin_review(curator_opening.opening_id)
+                )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // Trigger event
+            Self::deposit_event(RawEvent::BeganLeadApplicationReview(curator_opening_id));
+        }
+
+        /// Fill the lead opening with the chosen application. The lead role must be vacant.
+        pub fn fill_lead_opening(
+            origin,
+            curator_opening_id: CuratorOpeningId<T>,
+            successful_curator_application_id: CuratorApplicationId<T>,
+            reward_policy: Option<RewardPolicy<minting::BalanceOf<T>, T::BlockNumber>>
+        ) {
+            // Ensure root is origin
+            ensure_root(origin)?;
+
+            // Ensure there is no current lead
+            ensure!(
+                <CurrentLeadId<T>>::get().is_none(),
+                MSG_CURRENT_LEAD_ALREADY_SET
+            );
+
+            // Ensure curator opening exists
+            let (curator_opening, _) = Self::ensure_curator_opening_exists(&curator_opening_id)?;
+
+            // Ensure opening is for the lead role
+            Self::ensure_opening_type(&curator_opening, OpeningType::Leader)?;
+
+            // Ensure application exists and was made on this opening
+            let (successful_curator_application, _, _) =
+                Self::ensure_curator_application_exists(&successful_curator_application_id)?;
+
+            ensure!(
+                successful_curator_application.curator_opening_id == curator_opening_id,
+                MSG_FILL_LEAD_OPENING_APPLICATION_FOR_WRONG_OPENING
+            );
+
+            // Ensure applicant _still_ can step into the lead role
+            let (lead_in_role, new_lead_id) =
+                Self::ensure_can_register_lead_role_on_member(&successful_curator_application.member_id)
+                .map_err(|_| MSG_MEMBER_NO_LONGER_REGISTRABLE_AS_LEAD)?;
+
+            // Ensure reward settings are valid
+            let create_reward_settings = Self::ensure_reward_policy_is_valid(reward_policy)?;
+
+            let successful_application_ids = vec![successful_curator_application.application_id]
+                .into_iter()
+                .collect::<BTreeSet<_>>();
+
+            // NB: Combined ensure check and mutation in hiring module
+            ensure_on_wrapped_error!(
+                hiring::Module::<T>::fill_opening(
+                    curator_opening.opening_id,
+                    successful_application_ids,
+                    curator_opening.policy_commitment.fill_opening_successful_applicant_application_stake_unstaking_period,
+                    curator_opening.policy_commitment.fill_opening_failed_applicant_application_stake_unstaking_period,
+                    curator_opening.policy_commitment.fill_opening_failed_applicant_role_stake_unstaking_period
+                )
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // Create a reward relationship
+            let reward_relationship = create_reward_settings.map(|(mint_id, checked_policy)| {
+                Self::add_reward_relationship(successful_curator_application.member_id, mint_id, &checked_policy)
+            });
+
+            // Get possible stake for role
+            let application = hiring::ApplicationById::<T>::get(successful_curator_application.application_id);
+
+            // Staking profile for lead
+            let role_stake_profile = application.active_role_staking_id.map(|stake_id| {
+                CuratorRoleStakeProfile::new(
+                    &stake_id,
+                    &curator_opening.policy_commitment.terminate_curator_role_stake_unstaking_period,
+                    &curator_opening.policy_commitment.exit_curator_role_stake_unstaking_period
+                )
+            });
+
+            // Register role on member
+            let registered_role = members::Module::<T>::register_role_on_member(
+                successful_curator_application.member_id,
+                &lead_in_role
+            ).is_ok();
+
+            assert!(registered_role);
+
+            Self::induct_lead(
+                new_lead_id,
+                successful_curator_application.role_account,
+                reward_relationship,
+                role_stake_profile
+            );
+
+            // Trigger event
+            Self::deposit_event(RawEvent::LeadOpeningFilled(curator_opening_id, successful_curator_application_id, new_lead_id));
+        }
+
+        /// Slash the role stake of the current lead.
+        pub fn slash_lead_stake(origin, slash_amount: BalanceOf<T>) {
+            // Ensure root is origin
+            ensure_root(origin)?;
+
+            // Ensure lead is set
+            let (lead_id, lead) = Self::ensure_lead_is_set()?;
+
+            // Ensure lead is staked
+            let stake_profile = lead.role_stake_profile.ok_or(MSG_LEAD_HAS_NO_ROLE_STAKE)?;
+
+            // Attempt to slash the stake, slashed funds are burned
+            // NB: Combined ensure check and mutation in stake module
+            let slash_outcome = ensure_on_wrapped_error!(
+                stake::Module::<T>::slash_immediate(&stake_profile.stake_id, slash_amount, false)
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // Trigger event
+            Self::deposit_event(RawEvent::LeadStakeSlashed(lead_id, slash_outcome.actually_slashed));
+        }
+
+        /// Terminate the current lead. Recurring reward is stopped and the role stake, if any,
+        /// starts unstaking.
+        pub fn terminate_lead_role(origin, rationale_text: Vec<u8>) {
+            // Ensure root is origin
+            ensure_root(origin)?;
+
+            // Ensure lead is set
+            Self::ensure_lead_is_set()?;
+
+            // Ensure rationale text is valid
+            Self::ensure_curator_exit_rationale_text_is_valid(&rationale_text)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::unset_lead(rationale_text)?;
+        }
+
         /// Add an opening for a curator role.
         pub fn set_channel_creation_enabled(origin, enabled: bool)  {
 
@@ -2069,10 +2267,24 @@ impl<T: Trait> Module<T> {
         // Register in role - will fail if member cannot become lead
         members::Module::<T>::register_role_on_member(member, &new_lead_role)?;
 
+        Self::induct_lead(new_lead_id, role_account, None, None);
+
+        Ok(())
+    }
+
+    /// Store the new lead under the given identifier and make it the current lead.
+    /// The lead role must already be registered on the member.
+    fn induct_lead(
+        new_lead_id: LeadId<T>,
+        role_account: T::AccountId,
+        reward_relationship: Option<T::RewardRelationshipId>,
+        role_stake_profile: Option<CuratorRoleStakeProfile<T::StakeId, T::BlockNumber>>,
+    ) {
         // Construct lead
         let new_lead = Lead {
             role_account,
-            reward_relationship: None,
+            reward_relationship,
+            role_stake_profile,
             inducted: <system::Module<T>>::block_number(),
             stage: LeadRoleState::Active,
         };
@@ -2088,12 +2300,10 @@ impl<T: Trait> Module<T> {
 
         // Trigger event
         Self::deposit_event(RawEvent::LeadSet(new_lead_id));
-
-        Ok(())
     }
 
     /// Evict the currently set lead
-    fn unset_lead() -> dispatch::Result {
+    fn unset_lead(rationale_text: Vec<u8>) -> dispatch::Result {
         // Ensure there is a lead set
         let (lead_id, lead) = Self::ensure_lead_is_set()?;
 
@@ -2109,12 +2319,21 @@ impl<T: Trait> Module<T> {
 
         <members::Module<T>>::unregister_role(current_lead_role)?;
 
+        // Stop any possible recurring rewards
+        if let Some(reward_relationship_id) = lead.reward_relationship {
+            recurringrewards::Module::<T>::try_to_deactivate_relationship(reward_relationship_id)
+                .expect("Relationship must exist");
+        }
+
         // Update lead stage as exited
         let current_block = <system::Module<T>>::block_number();
 
+        let role_stake_profile = lead.role_stake_profile.clone();
+
         let new_lead = Lead {
             stage: LeadRoleState::Exited(ExitedLeadRole {
                 initiated_at_block_number: current_block,
+                rationale_text,
             }),
             ..lead
         };
@@ -2124,6 +2343,20 @@ impl<T: Trait> Module<T> {
         // Update current lead
         <CurrentLeadId<T>>::take(); // None
 
+        // Unstake if lead is staked
+        if let Some(stake_profile) = role_stake_profile {
+            // Keep track of lead unstaking
+            let unstaker = WorkingGroupUnstaker::Lead(lead_id);
+            UnstakerByStakeId::<T>::insert(stake_profile.stake_id, unstaker);
+
+            // Unstake
+            stake::Module::<T>::initiate_unstaking(
+                &stake_profile.stake_id,
+                stake_profile.termination_unstaking_period,
+            )
+            .expect("Unstaking must be possible at this time");
+        }
+
         // Trigger event
         Self::deposit_event(RawEvent::LeadUnset(lead_id));
 
@@ -2177,6 +2410,15 @@ impl<T: Trait> Module<T> {
             .map(|curator_in_role| (curator_in_role, next_id))
     }
 
+    fn ensure_can_register_lead_role_on_member(
+        member_id: &T::MemberId,
+    ) -> Result<(members::ActorInRole<ActorIdInMembersModule<T>>, LeadId<T>), &'static str> {
+        let next_id = <NextLeadId<T>>::get();
+
+        Self::ensure_can_register_role_on_member(member_id, role_types::Role::CuratorLead, &next_id)
+            .map(|lead_in_role| (lead_in_role, next_id))
+    }
+
     fn ensure_can_register_channel_owner_role_on_member(
         member_id: &T::MemberId,
         opt_channel_id: Option<ChannelId<T>>,
@@ -2303,7 +2545,7 @@ impl<T: Trait> Module<T> {
     pub fn ensure_lead_is_set() -> Result<
         (
             LeadId<T>,
-            Lead<T::AccountId, T::RewardRelationshipId, T::BlockNumber>,
+            Lead<T::AccountId, T::RewardRelationshipId, T::StakeId, T::BlockNumber>,
         ),
         &'static str,
     > {
@@ -2332,7 +2574,7 @@ impl<T: Trait> Module<T> {
     ) -> Result<
         (
             LeadId<T>,
-            Lead<T::AccountId, T::RewardRelationshipId, T::BlockNumber>,
+            Lead<T::AccountId, T::RewardRelationshipId, T::StakeId, T::BlockNumber>,
         ),
         &'static str,
     > {
@@ -2387,6 +2629,29 @@ impl<T: Trait> Module<T> {
         Ok((curator_opening, opening))
     }
 
+    fn ensure_opening_type(
+        curator_opening: &CuratorOpening<
+            T::OpeningId,
+            T::BlockNumber,
+            BalanceOf<T>,
+            CuratorApplicationId<T>,
+        >,
+        opening_type: OpeningType,
+    ) -> dispatch::Result {
+        match opening_type {
+            OpeningType::Leader => ensure!(
+                curator_opening.opening_type == OpeningType::Leader,
+                MSG_OPENING_IS_NOT_LEADER_OPENING
+            ),
+            OpeningType::Curator => ensure!(
+                curator_opening.opening_type == OpeningType::Curator,
+                MSG_OPENING_IS_NOT_CURATOR_OPENING
+            ),
+        }
+
+        Ok(())
+    }
+
     fn ensure_curator_exists(
         curator_id: &CuratorId<T>,
     ) -> Result<
@@ -2718,6 +2983,114 @@ impl<T: Trait> Module<T> {
         Self::deposit_event(event);
     }
 
+    /// Ensures a reward can be created with the given policy. Returns the verified reward
+    /// settings to use, if a policy was provided.
+    fn ensure_reward_policy_is_valid(
+        reward_policy: Option<RewardPolicy<minting::BalanceOf<T>, T::BlockNumber>>,
+    ) -> Result<
+        Option<(
+            T::MintId,
+            RewardPolicy<minting::BalanceOf<T>, T::BlockNumber>,
+        )>,
+        &'static str,
+    > {
+        if let Some(policy) = reward_policy {
+            // A reward will need to be created so ensure our configured mint exists
+            let mint_id = Self::mint();
+
+            ensure!(
+                <minting::Mints<T>>::exists(mint_id),
+                MSG_FILL_CURATOR_OPENING_MINT_DOES_NOT_EXIST
+            );
+
+            // Make sure valid parameters are selected for next payment at block number
+            ensure!(
+                policy.next_payment_at_block > <system::Module<T>>::block_number(),
+                MSG_FILL_CURATOR_OPENING_INVALID_NEXT_PAYMENT_BLOCK
+            );
+
+            // The verified reward settings to use
+            Ok(Some((mint_id, policy)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Creates a reward relationship paying to the root account of the member.
+    /// Reward settings must have been verified with `ensure_reward_policy_is_valid`.
+    fn add_reward_relationship(
+        member_id: T::MemberId,
+        mint_id: T::MintId,
+        checked_policy: &RewardPolicy<minting::BalanceOf<T>, T::BlockNumber>,
+    ) -> T::RewardRelationshipId {
+        // Create a new recipient for the new relationship
+        let recipient = <recurringrewards::Module<T>>::add_recipient();
+
+        // member must exist, since it was checked that it can enter the role
+        let member_profile = <members::Module<T>>::member_profile(member_id).unwrap();
+
+        // rewards are deposited in the member's root account
+        let reward_destination_account = member_profile.root_account;
+
+        // values have been checked so this should not fail!
+        <recurringrewards::Module<T>>::add_reward_relationship(
+            mint_id,
+            recipient,
+            reward_destination_account,
+            checked_policy.amount_per_payout,
+            checked_policy.next_payment_at_block,
+            checked_policy.payout_interval,
+        )
+        .expect("Failed to create reward relationship!")
+    }
+
+    /// Adds the opening of the given type to the hiring module and this working group.
+    fn add_opening(
+        activate_at: hiring::ActivateOpeningAt<T::BlockNumber>,
+        commitment: OpeningPolicyCommitment<T::BlockNumber, BalanceOf<T>>,
+        human_readable_text: Vec<u8>,
+        opening_type: OpeningType,
+    ) -> Result<CuratorOpeningId<T>, &'static str> {
+        // Ensure human radable text is valid
+        Self::ensure_opening_human_readable_text_is_valid(&human_readable_text)?;
+
+        // Add opening
+        // NB: This call can in principle fail, because the staking policies
+        // may not respect the minimum currency requirement.
+
+        let policy_commitment = commitment.clone();
+
+        let opening_id = ensure_on_wrapped_error!(hiring::Module::<T>::add_opening(
+            activate_at,
+            commitment.max_review_period_length,
+            commitment.application_rationing_policy,
+            commitment.application_staking_policy,
+            commitment.role_staking_policy,
+            human_readable_text,
+        ))?;
+
+        //
+        // == MUTATION SAFE ==
+        //
+
+        let new_curator_opening_id = NextCuratorOpeningId::<T>::get();
+
+        // Create and add curator opening.
+        let new_opening_by_id = CuratorOpening {
+            opening_id,
+            opening_type,
+            curator_applications: BTreeSet::new(),
+            policy_commitment,
+        };
+
+        CuratorOpeningById::<T>::insert(new_curator_opening_id, new_opening_by_id);
+
+        // Update NextCuratorOpeningId
+        NextCuratorOpeningId::<T>::mutate(|id| *id += <CuratorOpeningId<T> as One>::one());
+
+        Ok(new_curator_opening_id)
+    }
+
     /// Adds the given principal to storage under the returned identifier.
     fn add_new_principal(principal: &Principal<CuratorId<T>, ChannelId<T>>) -> PrincipalId<T> {
         // Get principal id for curator
@@ -2804,12 +3177,15 @@ impl<T: Trait> Module<T> {
         // Unstaker must be in this group
         let unstaker = Self::ensure_unstaker_exists(&stake_id).unwrap();
 
-        // Get curator doing the unstaking,
-        // urrently the only possible unstaker in this module.
-        let curator_id = if let WorkingGroupUnstaker::Curator(curator_id) = unstaker {
-            curator_id
-        } else {
-            panic!("Should not be possible, only curators unstake in this module currently.");
+        // Get curator doing the unstaking. A lead has already exited when unstaking
+        // was initiated, so only the unstaker record is removed.
+        let curator_id = match unstaker {
+            WorkingGroupUnstaker::Curator(curator_id) => curator_id,
+            WorkingGroupUnstaker::Lead(_) => {
+                UnstakerByStakeId::<T>::remove(stake_id);
+
+                return;
+            }
         };
 
         // Grab curator from id, unwrap, because this curator _must_ exist.
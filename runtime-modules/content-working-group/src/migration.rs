@@ -0,0 +1,117 @@
+//! Migration of the leads and curator openings saved by the previous runtime version.
+//! The previous lead layout lacks the role stake profile and the exit rationale.
+//! The previous curator opening layout lacks the opening type.
+
+use codec::{Decode, Encode};
+use rstd::collections::btree_set::BTreeSet;
+use rstd::prelude::*;
+use srml_support::{print, StorageLinkedMap};
+
+use crate::{
+    CuratorApplicationId, CuratorOpening, CuratorOpeningById, ExitedLeadRole, Lead, LeadById,
+    LeadRoleState, Module, OpeningPolicyCommitment, OpeningType, Trait,
+};
+
+// The exit stage of a lead of the previous runtime version.
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub(crate) struct LegacyExitedLeadRole<BlockNumber> {
+    pub initiated_at_block_number: BlockNumber,
+}
+
+// The stage of the lead involvement of the previous runtime version.
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub(crate) enum LegacyLeadRoleState<BlockNumber> {
+    Active,
+    Exited(LegacyExitedLeadRole<BlockNumber>),
+}
+
+// Working group lead of the previous runtime version.
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub(crate) struct LegacyLead<AccountId, RewardRelationshipId, BlockNumber> {
+    pub role_account: AccountId,
+    pub reward_relationship: Option<RewardRelationshipId>,
+    pub inducted: BlockNumber,
+    pub stage: LegacyLeadRoleState<BlockNumber>,
+}
+
+// Curator opening of the previous runtime version.
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub(crate) struct LegacyCuratorOpening<
+    OpeningId,
+    BlockNumber,
+    Balance,
+    CuratorApplicationId: core::cmp::Ord,
+> {
+    pub opening_id: OpeningId,
+    pub curator_applications: BTreeSet<CuratorApplicationId>,
+    pub policy_commitment: OpeningPolicyCommitment<BlockNumber, Balance>,
+}
+
+impl<AccountId, RewardRelationshipId, StakeId, BlockNumber>
+    From<LegacyLead<AccountId, RewardRelationshipId, BlockNumber>>
+    for Lead<AccountId, RewardRelationshipId, StakeId, BlockNumber>
+{
+    fn from(lead: LegacyLead<AccountId, RewardRelationshipId, BlockNumber>) -> Self {
+        let stage = match lead.stage {
+            LegacyLeadRoleState::Active => LeadRoleState::Active,
+            LegacyLeadRoleState::Exited(exited_role) => LeadRoleState::Exited(ExitedLeadRole {
+                initiated_at_block_number: exited_role.initiated_at_block_number,
+                rationale_text: Vec::new(),
+            }),
+        };
+
+        Lead {
+            role_account: lead.role_account,
+            reward_relationship: lead.reward_relationship,
+            role_stake_profile: None,
+            inducted: lead.inducted,
+            stage,
+        }
+    }
+}
+
+impl<OpeningId, BlockNumber, Balance, CuratorApplicationId: core::cmp::Ord>
+    From<LegacyCuratorOpening<OpeningId, BlockNumber, Balance, CuratorApplicationId>>
+    for CuratorOpening<OpeningId, BlockNumber, Balance, CuratorApplicationId>
+{
+    fn from(
+        opening: LegacyCuratorOpening<OpeningId, BlockNumber, Balance, CuratorApplicationId>,
+    ) -> Self {
+        CuratorOpening {
+            opening_id: opening.opening_id,
+            opening_type: OpeningType::Curator,
+            curator_applications: opening.curator_applications,
+            policy_commitment: opening.policy_commitment,
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// Migrates the leads and curator openings saved by the previous runtime version to
+    /// the current layout. Leads get no role stake, existing openings are curator openings.
+    /// Should be called on the migration to the new runtime version only once.
+    pub fn migrate_leads_and_openings() {
+        let leads_translation_result = <LeadById<T>>::translate(
+            |lead_id| lead_id,
+            |lead: LegacyLead<T::AccountId, T::RewardRelationshipId, T::BlockNumber>| lead.into(),
+        );
+
+        if leads_translation_result.is_err() {
+            print("Broken invariant: cannot migrate leads");
+        }
+
+        let openings_translation_result = <CuratorOpeningById<T>>::translate(
+            |opening_id| opening_id,
+            |opening: LegacyCuratorOpening<
+                T::OpeningId,
+                T::BlockNumber,
+                crate::BalanceOf<T>,
+                CuratorApplicationId<T>,
+            >| opening.into(),
+        );
+
+        if openings_translation_result.is_err() {
+            print("Broken invariant: cannot migrate curator openings");
+        }
+    }
+}
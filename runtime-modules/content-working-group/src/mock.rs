@@ -148,6 +148,7 @@ type TestRecipientId = u64;
 type TestRewardRelationshipId = u64;
 impl recurringrewards::Trait for Test {
     type PayoutStatusHandler = ();
+    type PayoutPenaltyPolicy = ();
     type RecipientId = TestRecipientId;
     type RewardRelationshipId = TestRewardRelationshipId;
 }
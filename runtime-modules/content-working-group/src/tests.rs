@@ -4,10 +4,11 @@ use super::genesis;
 use super::mock::{self, *};
 //use crate::membership;
 use hiring;
+use membership::role_types;
 use rstd::collections::btree_map::BTreeMap;
 use rstd::collections::btree_set::BTreeSet;
 use sr_primitives::traits::One;
-use srml_support::{assert_err, assert_ok, StorageLinkedMap, StorageValue};
+use srml_support::{assert_err, assert_ok, StorageLinkedMap, StorageMap, StorageValue};
 
 /// DIRTY IMPORT BECAUSE
 /// InputValidationLengthConstraint has not been factored out yet!!!
@@ -406,6 +407,7 @@ fn add_curator_opening_success() {
 
             let expected_curator_opening = CuratorOpening {
                 opening_id: expected_opening_id,
+                opening_type: OpeningType::Curator,
                 curator_applications: BTreeSet::new(),
                 policy_commitment: get_baseline_opening_policy(),
             };
@@ -1188,6 +1190,7 @@ impl SetLeadFixture {
         let expected_new_lead = Lead {
             role_account: self.new_role_account,
             reward_relationship: None,
+            role_stake_profile: None,
             inducted: 1, // make dynamic later
             stage: LeadRoleState::Active,
         };
@@ -1242,6 +1245,7 @@ impl UnsetLeadFixture {
         let expected_updated_lead = Lead {
             stage: LeadRoleState::Exited(ExitedLeadRole {
                 initiated_at_block_number: 1,
+                rationale_text: Vec::new(),
             }),
             ..original_lead
         };
@@ -1349,6 +1353,312 @@ fn unstaked_curator_success() {
         });
 }
 
+struct HiredLead {
+    pub lead_id: LeadId<Test>,
+    pub curator_opening_id: CuratorOpeningId<Test>,
+    pub applicant: NewMemberAppliedResult,
+}
+
+fn setup_lead_opening() -> CuratorOpeningId<Test> {
+    let expected_curator_opening_id = NextCuratorOpeningId::<Test>::get();
+
+    assert_ok!(ContentWorkingGroup::add_lead_opening(
+        Origin::system(system::RawOrigin::Root),
+        hiring::ActivateOpeningAt::CurrentBlock,
+        get_baseline_opening_policy(),
+        generate_valid_length_buffer(&OpeningHumanReadableText::get())
+    ));
+
+    assert_eq!(
+        get_last_event_or_panic(),
+        crate::RawEvent::LeadOpeningAdded(expected_curator_opening_id)
+    );
+
+    expected_curator_opening_id
+}
+
+fn setup_and_hire_lead() -> HiredLead {
+    let curator_opening_id = setup_lead_opening();
+
+    let params = make_generic_add_member_params();
+
+    let applicant = add_member_and_apply_on_opening(
+        curator_opening_id,
+        params.curator_applicant_root_and_controller_account,
+        params.handle,
+        params.curator_applicant_role_account,
+        params.human_readable_text,
+    );
+
+    assert_ok!(ContentWorkingGroup::begin_lead_applicant_review(
+        Origin::system(system::RawOrigin::Root),
+        curator_opening_id
+    ));
+
+    assert_eq!(
+        get_last_event_or_panic(),
+        crate::RawEvent::BeganLeadApplicationReview(curator_opening_id)
+    );
+
+    let lead_id = NextLeadId::<Test>::get();
+
+    assert_ok!(ContentWorkingGroup::fill_lead_opening(
+        Origin::system(system::RawOrigin::Root),
+        curator_opening_id,
+        applicant.curator_application_id,
+        None
+    ));
+
+    assert_eq!(
+        get_last_event_or_panic(),
+        crate::RawEvent::LeadOpeningFilled(
+            curator_opening_id,
+            applicant.curator_application_id,
+            lead_id
+        )
+    );
+
+    HiredLead {
+        lead_id,
+        curator_opening_id,
+        applicant,
+    }
+}
+
+fn get_staked_amount(stake_id: StakeId<Test>) -> BalanceOf<Test> {
+    if let stake::StakingStatus::Staked(staked_state) =
+        stake::Module::<Test>::stakes(stake_id).staking_status
+    {
+        staked_state.staked_amount
+    } else {
+        panic!("Stake not staked")
+    }
+}
+
+#[test]
+fn fill_lead_opening_success() {
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            let hired_lead = setup_and_hire_lead();
+
+            assert_eq!(CurrentLeadId::<Test>::get(), Some(hired_lead.lead_id));
+
+            let lead = LeadById::<Test>::get(hired_lead.lead_id);
+
+            assert_eq!(
+                lead.role_account,
+                make_generic_add_member_params().curator_applicant_role_account
+            );
+            assert_eq!(lead.stage, LeadRoleState::Active);
+
+            let role_stake_id = lead.role_stake_profile.unwrap().stake_id;
+
+            assert_eq!(
+                get_staked_amount(role_stake_id),
+                get_baseline_opening_policy()
+                    .role_staking_policy
+                    .unwrap()
+                    .amount
+            );
+
+            assert!(members::MembershipIdByActorInRole::<Test>::exists(
+                role_types::ActorInRole::new(role_types::Role::CuratorLead, hired_lead.lead_id)
+            ));
+
+            assert_eq!(
+                members::MembershipIdByActorInRole::<Test>::get(role_types::ActorInRole::new(
+                    role_types::Role::CuratorLead,
+                    hired_lead.lead_id
+                )),
+                hired_lead.applicant.member_id
+            );
+        });
+}
+
+#[test]
+fn fill_lead_opening_fails_with_lead_already_set() {
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            let curator_opening_id = setup_lead_opening();
+
+            let params = make_generic_add_member_params();
+
+            let applicant = add_member_and_apply_on_opening(
+                curator_opening_id,
+                params.curator_applicant_root_and_controller_account,
+                params.handle,
+                params.curator_applicant_role_account,
+                params.human_readable_text,
+            );
+
+            assert_ok!(ContentWorkingGroup::begin_lead_applicant_review(
+                Origin::system(system::RawOrigin::Root),
+                curator_opening_id
+            ));
+
+            add_member_and_set_as_lead();
+
+            assert_err!(
+                ContentWorkingGroup::fill_lead_opening(
+                    Origin::system(system::RawOrigin::Root),
+                    curator_opening_id,
+                    applicant.curator_application_id,
+                    None
+                ),
+                MSG_CURRENT_LEAD_ALREADY_SET
+            );
+        });
+}
+
+#[test]
+fn opening_extrinsics_fail_with_wrong_opening_type() {
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            add_member_and_set_as_lead();
+
+            let lead_opening_id = setup_lead_opening();
+            let curator_opening_id = add_curator_opening();
+
+            assert_err!(
+                ContentWorkingGroup::begin_curator_applicant_review(
+                    Origin::signed(LEAD_ROLE_ACCOUNT),
+                    lead_opening_id
+                ),
+                MSG_OPENING_IS_NOT_CURATOR_OPENING
+            );
+
+            assert_err!(
+                ContentWorkingGroup::begin_lead_applicant_review(
+                    Origin::system(system::RawOrigin::Root),
+                    curator_opening_id
+                ),
+                MSG_OPENING_IS_NOT_LEADER_OPENING
+            );
+        });
+}
+
+#[test]
+fn slash_lead_stake_success() {
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            let hired_lead = setup_and_hire_lead();
+
+            let role_stake_id = LeadById::<Test>::get(hired_lead.lead_id)
+                .role_stake_profile
+                .unwrap()
+                .stake_id;
+
+            let original_staked_amount = get_staked_amount(role_stake_id);
+
+            assert_ok!(ContentWorkingGroup::slash_lead_stake(
+                Origin::system(system::RawOrigin::Root),
+                1000
+            ));
+
+            assert_eq!(
+                get_last_event_or_panic(),
+                crate::RawEvent::LeadStakeSlashed(hired_lead.lead_id, 1000)
+            );
+
+            assert_eq!(
+                get_staked_amount(role_stake_id),
+                original_staked_amount - 1000
+            );
+
+            assert_err!(
+                ContentWorkingGroup::slash_lead_stake(Origin::system(system::RawOrigin::Root), 0),
+                MSG_SLASH_LEAD_STAKE_ZERO_AMOUNT
+            );
+        });
+}
+
+#[test]
+fn slash_lead_stake_fails_with_unstaked_lead() {
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            add_member_and_set_as_lead();
+
+            assert_err!(
+                ContentWorkingGroup::slash_lead_stake(
+                    Origin::system(system::RawOrigin::Root),
+                    1000
+                ),
+                MSG_LEAD_HAS_NO_ROLE_STAKE
+            );
+        });
+}
+
+#[test]
+fn terminate_lead_role_success() {
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            let hired_lead = setup_and_hire_lead();
+
+            let rationale_text = generate_valid_length_buffer(&CuratorExitRationaleText::get());
+
+            assert_ok!(ContentWorkingGroup::terminate_lead_role(
+                Origin::system(system::RawOrigin::Root),
+                rationale_text.clone()
+            ));
+
+            assert_eq!(
+                get_last_event_or_panic(),
+                crate::RawEvent::LeadUnset(hired_lead.lead_id)
+            );
+
+            assert!(CurrentLeadId::<Test>::get().is_none());
+
+            let lead = LeadById::<Test>::get(hired_lead.lead_id);
+
+            assert_eq!(
+                lead.stage,
+                LeadRoleState::Exited(ExitedLeadRole {
+                    initiated_at_block_number: 1,
+                    rationale_text,
+                })
+            );
+
+            assert!(!members::MembershipIdByActorInRole::<Test>::exists(
+                role_types::ActorInRole::new(role_types::Role::CuratorLead, hired_lead.lead_id)
+            ));
+
+            // Lead role stake is unstaking
+            let role_stake_id = lead.role_stake_profile.unwrap().stake_id;
+
+            assert_eq!(
+                UnstakerByStakeId::<Test>::get(role_stake_id),
+                WorkingGroupUnstaker::Lead(hired_lead.lead_id)
+            );
+
+            ContentWorkingGroup::unstaked(role_stake_id);
+
+            assert!(!UnstakerByStakeId::<Test>::exists(role_stake_id));
+        });
+}
+
+#[test]
+fn terminate_lead_role_fails_with_invalid_rationale() {
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            setup_and_hire_lead();
+
+            assert_err!(
+                ContentWorkingGroup::terminate_lead_role(
+                    Origin::system(system::RawOrigin::Root),
+                    generate_too_long_length_buffer(&CuratorExitRationaleText::get())
+                ),
+                MSG_CURATOR_EXIT_RATIONALE_TEXT_TOO_LONG
+            );
+        });
+}
+
 #[test]
 fn account_can_act_as_principal_success() {}
 
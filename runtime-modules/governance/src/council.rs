@@ -1,9 +1,14 @@
+use codec::{Decode, Encode};
 use rstd::prelude::*;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sr_primitives::traits::{One, Zero};
+use sr_primitives::Perbill;
+use srml_support::traits::{Currency, Get, ReservableCurrency};
 use srml_support::{debug, decl_event, decl_module, decl_storage, ensure};
-use system::{self, ensure_root};
+use system::{self, ensure_root, ensure_signed};
 
-pub use super::election::{self, CouncilElected, Seat, Seats};
+pub use super::election::{self, Backer, CouncilElected, Seat, Seats};
 pub use common::currency::{BalanceOf, GovernanceCurrency};
 
 // Hook For announcing that council term has ended
@@ -21,15 +26,75 @@ impl<X: CouncilTermEnded> CouncilTermEnded for (X,) {
     }
 }
 
-pub trait Trait: system::Trait + recurringrewards::Trait + GovernanceCurrency {
+// Hook for announcing that a council seat was vacated by the recall. The hook takes ownership
+// of the vacated seat stakes.
+pub trait CouncilSeatVacated<Seat> {
+    fn council_seat_vacated(seat: Seat);
+}
+
+impl<Seat> CouncilSeatVacated<Seat> for () {
+    fn council_seat_vacated(_: Seat) {}
+}
+
+impl<Seat, X: CouncilSeatVacated<Seat>> CouncilSeatVacated<Seat> for (X,) {
+    fn council_seat_vacated(seat: Seat) {
+        X::council_seat_vacated(seat);
+    }
+}
+
+// Hook for reading the proposal voting participation of the councilor. Returns the running
+// totals of the proposal voting rounds the councilor voted in and of all ended voting rounds.
+pub trait VotingParticipation<AccountId> {
+    fn voting_participation(councilor: &AccountId) -> (u32, u32);
+}
+
+impl<AccountId> VotingParticipation<AccountId> for () {
+    fn voting_participation(_: &AccountId) -> (u32, u32) {
+        (0, 0)
+    }
+}
+
+/// Council reward mode.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq)]
+pub enum CouncilRewardMode {
+    /// Every councilor gets the full payout.
+    Flat,
+
+    /// The payout scales with the part of the proposal voting rounds ended during the reward
+    /// period the councilor voted in. Canceled and vetoed proposals and proposals expired waiting
+    /// for sponsors are not counted. The rest of the payout is withheld as the penalty. The full
+    /// payout is made when no voting rounds ended.
+    ParticipationWeighted,
+}
+
+impl Default for CouncilRewardMode {
+    fn default() -> Self {
+        CouncilRewardMode::Flat
+    }
+}
+
+pub trait Trait:
+    system::Trait + recurringrewards::Trait + GovernanceCurrency + membership::members::Trait
+{
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 
     type CouncilTermEnded: CouncilTermEnded;
+
+    type CouncilSeatVacated: CouncilSeatVacated<
+        Seat<Self::AccountId, BalanceOf<Self>, Self::BlockNumber>,
+    >;
+
+    type VotingParticipation: VotingParticipation<Self::AccountId>;
+
+    /// Minimum backing stake of the no-confidence motion required to vacate the seat
+    /// regardless of the councilor seat stake (eg.: zero staked runner-up seats).
+    type MinRecallMotionStake: Get<BalanceOf<Self>>;
 }
 
 decl_storage! {
     trait Store for Module<T: Trait> as Council {
-        pub ActiveCouncil get(active_council) config(): Seats<T::AccountId, BalanceOf<T>>;
+        pub ActiveCouncil get(active_council) config(): Seats<T::AccountId, BalanceOf<T>, T::BlockNumber>;
 
         pub TermEndsAt get(term_ends_at) config() : T::BlockNumber = T::BlockNumber::from(1);
 
@@ -51,20 +116,65 @@ decl_storage! {
 
         /// How many blocks after the reward is created, the first payout will be made
         pub FirstPayoutAfterRewardCreated get(first_payout_after_reward_created): T::BlockNumber;
+
+        /// Reward mode applied to the council reward payouts.
+        pub RewardMode get(reward_mode): CouncilRewardMode;
+
+        /// Voting participation totals of the councilor at the start of the current reward
+        /// period: the reward creation or the last payout.
+        pub RewardPeriodParticipation get(reward_period_participation): map T::AccountId => (u32, u32);
+
+        /// Open no-confidence motions by the councilor: members backing the motion with their
+        /// reserved stakes.
+        pub RecallMotions get(recall_motions): map T::AccountId => Vec<Backer<T::AccountId, BalanceOf<T>>>;
+
+        /// Part of the councilor total seat stake the backing stake of the no-confidence motion
+        /// must exceed to vacate the seat.
+        pub RecallThreshold get(recall_threshold): Perbill = Perbill::one();
+
+        /// Max number of the council terms served by the elected seats. Every election opens
+        /// the seats with the term ending with the council term: about the council size divided
+        /// by the terms per seat seats.
+        pub TermsPerSeat get(terms_per_seat): u32 = 1;
     }
 }
 
 // Event for this module.
 decl_event!(
-    pub enum Event<T> where <T as system::Trait>::BlockNumber {
+    pub enum Event<T> where
+        <T as system::Trait>::BlockNumber,
+        <T as system::Trait>::AccountId,
+        Balance = BalanceOf<T>
+    {
         CouncilTermEnded(BlockNumber),
         NewCouncilTermStarted(BlockNumber),
+        /// Params: backer, councilor, stake
+        RecallMotionBacked(AccountId, AccountId, Balance),
+        CouncilorRecalled(AccountId),
     }
 );
 
-impl<T: Trait> CouncilElected<Seats<T::AccountId, BalanceOf<T>>, T::BlockNumber> for Module<T> {
-    fn council_elected(seats: Seats<T::AccountId, BalanceOf<T>>, term: T::BlockNumber) {
-        <ActiveCouncil<T>>::put(seats.clone());
+impl<T: Trait> CouncilElected<Seats<T::AccountId, BalanceOf<T>, T::BlockNumber>, T::BlockNumber>
+    for Module<T>
+{
+    fn council_elected(
+        seats: Seats<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+        term: T::BlockNumber,
+    ) {
+        Self::close_recall_motions();
+
+        let (expired_seats, mut new_council): (Vec<_>, Vec<_>) = Self::active_council()
+            .into_iter()
+            .partition(Self::is_seat_expiring);
+
+        for seat in expired_seats.iter() {
+            Self::remove_reward_relationship(&seat.member);
+        }
+
+        let seats = Self::assign_seat_terms(&new_council, seats, term);
+
+        new_council.extend(seats.iter().cloned());
+        <ActiveCouncil<T>>::put(new_council);
 
         let next_term_ends_at = <system::Module<T>>::block_number() + term;
 
@@ -94,6 +204,86 @@ impl<T: Trait> Module<T> {
         Self::active_council().iter().any(|c| c.member == *sender)
     }
 
+    /// Returns true if the seat term ends with the current council term.
+    pub fn is_seat_expiring(seat: &Seat<T::AccountId, BalanceOf<T>, T::BlockNumber>) -> bool {
+        seat.term_ends_at <= Self::term_ends_at()
+    }
+
+    /// Seats opened by the next election.
+    pub fn expiring_seats() -> Seats<T::AccountId, BalanceOf<T>, T::BlockNumber> {
+        Self::active_council()
+            .into_iter()
+            .filter(Self::is_seat_expiring)
+            .collect()
+    }
+
+    /// Seats carried over to the next council.
+    pub fn carried_over_seats() -> Seats<T::AccountId, BalanceOf<T>, T::BlockNumber> {
+        Self::active_council()
+            .into_iter()
+            .filter(|seat| !Self::is_seat_expiring(seat))
+            .collect()
+    }
+
+    /// Returns true if the councilor seat carries over to the next council.
+    pub fn is_carried_over_councilor(sender: &T::AccountId) -> bool {
+        Self::active_council()
+            .iter()
+            .any(|seat| seat.member == *sender && !Self::is_seat_expiring(seat))
+    }
+
+    /// Block of the seat term end for the seat elected for the council term starting now.
+    pub fn seat_term_ends_at(term: T::BlockNumber) -> T::BlockNumber {
+        <system::Module<T>>::block_number() + term * T::BlockNumber::from(Self::terms_per_seat())
+    }
+
+    /// Sets the term ends of the seats elected for the council term starting now. Every council
+    /// term end expires at most the council size divided by the terms per seat seats. Top staked
+    /// seats serve all the terms, other seats serve fewer terms to stagger the council.
+    fn assign_seat_terms(
+        carried_over_seats: &[Seat<T::AccountId, BalanceOf<T>, T::BlockNumber>],
+        mut seats: Seats<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+        term: T::BlockNumber,
+    ) -> Seats<T::AccountId, BalanceOf<T>, T::BlockNumber> {
+        let terms_per_seat = Self::terms_per_seat();
+        let council_size = carried_over_seats.len() + seats.len();
+        let seats_per_term_end =
+            (council_size + terms_per_seat as usize - 1) / terms_per_seat as usize;
+
+        let now = <system::Module<T>>::block_number();
+        let term_ends_at = |terms: u32| now + term * T::BlockNumber::from(terms);
+
+        seats.sort_by(|a, b| b.calc_total_stake().cmp(&a.calc_total_stake()));
+
+        let mut assigned_seats: Seats<T::AccountId, BalanceOf<T>, T::BlockNumber> =
+            Vec::with_capacity(seats.len());
+
+        for seat in seats.into_iter() {
+            let terms = (1..=terms_per_seat)
+                .rev()
+                .find(|terms| {
+                    let seats_expiring_with_term = carried_over_seats
+                        .iter()
+                        .chain(assigned_seats.iter())
+                        .filter(|seat| {
+                            seat.term_ends_at > term_ends_at(*terms - 1)
+                                && seat.term_ends_at <= term_ends_at(*terms)
+                        })
+                        .count();
+
+                    seats_expiring_with_term < seats_per_term_end
+                })
+                .unwrap_or(1);
+
+            assigned_seats.push(Seat {
+                term_ends_at: term_ends_at(terms),
+                ..seat
+            });
+        }
+
+        assigned_seats
+    }
+
     /// Initializes a new mint, discarding previous mint if it existed.
     pub fn create_new_council_mint(
         capacity: minting::BalanceOf<T>,
@@ -121,18 +311,128 @@ impl<T: Trait> Module<T> {
             Self::payout_interval(),
         ) {
             RewardRelationships::<T>::insert(destination, relationship_id);
+            <RewardPeriodParticipation<T>>::insert(
+                destination,
+                T::VotingParticipation::voting_participation(destination),
+            );
         } else {
             debug::warn!("Failed to create a reward relationship for council seat");
         }
     }
 
+    fn remove_reward_relationship(account: &T::AccountId) {
+        if RewardRelationships::<T>::exists(account) {
+            let relationship_id = Self::reward_relationships(account);
+            <recurringrewards::Module<T>>::remove_reward_relationship(relationship_id);
+            <RewardPeriodParticipation<T>>::remove(account);
+        }
+    }
+
+    fn is_council_reward_relationship(id: T::RewardRelationshipId, account: &T::AccountId) -> bool {
+        RewardRelationships::<T>::exists(account) && Self::reward_relationships(account) == id
+    }
+
     fn remove_reward_relationships() {
-        for seat in Self::active_council().into_iter() {
-            if RewardRelationships::<T>::exists(&seat.member) {
-                let id = Self::reward_relationships(&seat.member);
-                <recurringrewards::Module<T>>::remove_reward_relationship(id);
+        for seat in Self::active_council().iter() {
+            Self::remove_reward_relationship(&seat.member);
+        }
+    }
+
+    /// Extends the term of the current council by the duration starting from the current block.
+    /// Council rewards removed on the term end are restored.
+    pub fn extend_term(duration: T::BlockNumber) {
+        let next_term_ends_at = <system::Module<T>>::block_number() + duration;
+
+        <TermEndsAt<T>>::put(next_term_ends_at);
+
+        if let Some(reward_source) = Self::council_mint() {
+            Self::remove_reward_relationships();
+
+            for seat in Self::active_council().iter() {
+                Self::add_reward_relationship(&seat.member, reward_source);
             }
         }
+
+        Self::deposit_event(RawEvent::NewCouncilTermStarted(next_term_ends_at));
+    }
+
+    /// Seats the member in the place of the vacated seat. The seat keeps the term end of
+    /// the vacated seat. The new councilor gets the recurring reward if the council mint exists.
+    pub fn fill_vacated_seat(
+        seat: Seat<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+    ) -> Result<(), &'static str> {
+        ensure!(
+            !Self::is_councilor(&seat.member),
+            "cannot add same account multiple times"
+        );
+        ensure!(
+            <membership::members::Module<T>>::is_member_account(&seat.member),
+            "only members can fill the vacated seat"
+        );
+
+        if let Some(reward_source) = Self::council_mint() {
+            Self::add_reward_relationship(&seat.member, reward_source);
+        }
+
+        <ActiveCouncil<T>>::mutate(|council| council.push(seat));
+
+        Ok(())
+    }
+
+    fn remove_councilor(account: &T::AccountId) {
+        Self::remove_reward_relationship(account);
+
+        Self::close_recall_motion(account);
+
+        let filtered_council: Seats<T::AccountId, BalanceOf<T>, T::BlockNumber> =
+            Self::active_council()
+                .into_iter()
+                .filter(|c| c.member != *account)
+                .collect();
+
+        <ActiveCouncil<T>>::put(filtered_council);
+    }
+
+    /// Vacates the seat of the councilor. Seat stakes are passed to the seat vacated hook.
+    fn recall_councilor(councilor: &T::AccountId) {
+        let vacated_seat = Self::active_council()
+            .into_iter()
+            .find(|c| c.member == *councilor);
+
+        Self::remove_councilor(councilor);
+
+        Self::deposit_event(RawEvent::CouncilorRecalled(councilor.clone()));
+
+        if let Some(seat) = vacated_seat {
+            T::CouncilSeatVacated::council_seat_vacated(seat);
+        }
+    }
+
+    fn is_recall_threshold_exceeded(councilor: &T::AccountId) -> bool {
+        let motion_stake = Self::recall_motions(councilor)
+            .iter()
+            .fold(BalanceOf::<T>::zero(), |acc, backer| acc + backer.stake);
+
+        let seat_stake = Self::active_council()
+            .iter()
+            .find(|c| c.member == *councilor)
+            .map_or(BalanceOf::<T>::zero(), |seat| seat.calc_total_stake());
+
+        motion_stake > Self::recall_threshold() * seat_stake
+            && motion_stake >= T::MinRecallMotionStake::get()
+    }
+
+    // Returns the stakes of the motion backers and removes the motion.
+    fn close_recall_motion(councilor: &T::AccountId) {
+        for backer in <RecallMotions<T>>::take(councilor).iter() {
+            <T as GovernanceCurrency>::Currency::unreserve(&backer.member, backer.stake);
+        }
+    }
+
+    fn close_recall_motions() {
+        for seat in Self::active_council().iter() {
+            Self::close_recall_motion(&seat.member);
+        }
     }
 
     fn on_term_ended(now: T::BlockNumber) {
@@ -141,7 +441,13 @@ impl<T: Trait> Module<T> {
         // During the term the recurring reward module could unfairly pay some but not all council members
         // If there is insufficient mint capacity.. so doing it at this point offers more control
         // and a potentially more fair outcome in such a case.
-        Self::remove_reward_relationships();
+        // Councilors with carried over seats keep their rewards.
+        for seat in Self::expiring_seats().iter() {
+            Self::remove_reward_relationship(&seat.member);
+        }
+
+        // Councilors can't be recalled after the term end.
+        Self::close_recall_motions();
 
         Self::deposit_event(RawEvent::CouncilTermEnded(now));
 
@@ -149,10 +455,51 @@ impl<T: Trait> Module<T> {
     }
 }
 
+impl<T: Trait> recurringrewards::PayoutPenaltyPolicy<T> for Module<T> {
+    /// Withholds the part of the council reward payout not backed by the voting participation
+    /// of the councilor during the reward period. Other reward relationships are not penalized.
+    fn payout_penalty(
+        id: T::RewardRelationshipId,
+        destination_account: &T::AccountId,
+        amount: minting::BalanceOf<T>,
+    ) -> minting::BalanceOf<T> {
+        if !Self::is_council_reward_relationship(id, destination_account) {
+            return Zero::zero();
+        }
+
+        // the next reward period starts with the payout
+        let (period_voted, period_voting_rounds) =
+            <RewardPeriodParticipation<T>>::get(destination_account);
+        let (voted, voting_rounds) =
+            T::VotingParticipation::voting_participation(destination_account);
+        <RewardPeriodParticipation<T>>::insert(destination_account, (voted, voting_rounds));
+
+        if Self::reward_mode() == CouncilRewardMode::Flat {
+            return Zero::zero();
+        }
+
+        let voting_rounds = voting_rounds.saturating_sub(period_voting_rounds);
+
+        if voting_rounds == 0 {
+            return Zero::zero();
+        }
+
+        // several member ids of the councilor can vote in the same round
+        let voted = rstd::cmp::min(voted.saturating_sub(period_voted), voting_rounds);
+        let participation = Perbill::from_rational_approximation(voted, voting_rounds);
+
+        amount - participation * amount
+    }
+}
+
 decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
         fn deposit_event() = default;
 
+        /// Exports const - minimum backing stake of the no-confidence motion required to vacate
+        /// the seat.
+        const MinRecallMotionStake: BalanceOf<T> = T::MinRecallMotionStake::get();
+
         fn on_finalize(now: T::BlockNumber) {
             if now == Self::term_ends_at() {
                 Self::on_term_ended(now);
@@ -164,12 +511,13 @@ decl_module! {
         /// Force set a zero staked council. Stakes in existing council seats are not returned.
         /// Existing council rewards are removed and new council members do NOT get any rewards.
         /// Avoid using this call if possible, will be deprecated. The term of the new council is
-        /// not extended.
+        /// not extended: new seats expire with the current council term.
         pub fn set_council(origin, accounts: Vec<T::AccountId>) {
             ensure_root(origin)?;
 
             // Council is being replaced so remove existing reward relationships if they exist
             Self::remove_reward_relationships();
+            Self::close_recall_motions();
 
             if let Some(reward_source) = Self::council_mint() {
                 for account in accounts.clone() {
@@ -177,11 +525,12 @@ decl_module! {
                 }
             }
 
-            let new_council: Seats<T::AccountId, BalanceOf<T>> = accounts.into_iter().map(|account| {
+            let new_council: Seats<T::AccountId, BalanceOf<T>, T::BlockNumber> = accounts.into_iter().map(|account| {
                 Seat {
                     member: account,
                     stake: BalanceOf::<T>::zero(),
-                    backers: vec![]
+                    backers: vec![],
+                    term_ends_at: Self::term_ends_at(),
                 }
             }).collect();
 
@@ -201,7 +550,8 @@ decl_module! {
             let seat = Seat {
                 member: account,
                 stake: BalanceOf::<T>::zero(),
-                backers: vec![]
+                backers: vec![],
+                term_ends_at: Self::term_ends_at(),
             };
 
             // add member to existing council
@@ -214,17 +564,62 @@ decl_module! {
 
             ensure!(Self::is_councilor(&account_to_remove), "account is not a councilor");
 
-            if RewardRelationships::<T>::exists(&account_to_remove) {
-                let relationship_id = Self::reward_relationships(&account_to_remove);
-                <recurringrewards::Module<T>>::remove_reward_relationship(relationship_id);
+            Self::remove_councilor(&account_to_remove);
+        }
+
+        /// Backs the no-confidence motion against the councilor with the reserved stake. Opens
+        /// the motion if it doesn't exist. The seat is vacated when the motion backing stake
+        /// exceeds the recall threshold of the councilor total seat stake and reaches
+        /// the minimum recall motion stake.
+        fn back_recall_motion(origin, councilor: T::AccountId, stake: BalanceOf<T>) {
+            let backer = ensure_signed(origin)?;
+
+            ensure!(
+                <membership::members::Module<T>>::is_member_account(&backer),
+                "only members can back recall motions"
+            );
+            ensure!(Self::is_councilor(&councilor), "account is not a councilor");
+            ensure!(!Self::is_term_ended(), "council term ended");
+            ensure!(!stake.is_zero(), "recall stake cannot be zero");
+            ensure!(
+                <T as GovernanceCurrency>::Currency::can_reserve(&backer, stake),
+                "not enough free balance to reserve"
+            );
+
+            // mutation
+
+            <T as GovernanceCurrency>::Currency::reserve(&backer, stake)?;
+
+            <RecallMotions<T>>::mutate(&councilor, |motion| {
+                if let Some(existing_backer) = motion.iter_mut().find(|b| b.member == backer) {
+                    existing_backer.stake += stake;
+                } else {
+                    motion.push(Backer { member: backer.clone(), stake });
+                }
+            });
+
+            Self::deposit_event(RawEvent::RecallMotionBacked(backer, councilor.clone(), stake));
+
+            if Self::is_recall_threshold_exceeded(&councilor) {
+                Self::recall_councilor(&councilor);
             }
+        }
 
-            let filtered_council: Seats<T::AccountId, BalanceOf<T>> = Self::active_council()
-                .into_iter()
-                .filter(|c| c.member != account_to_remove)
-                .collect();
+        /// Sets the part of the councilor total seat stake the no-confidence motion backing
+        /// stake must exceed to vacate the seat.
+        fn set_recall_threshold(origin, threshold: Perbill) {
+            ensure_root(origin)?;
 
-            <ActiveCouncil<T>>::put(filtered_council);
+            RecallThreshold::put(threshold);
+        }
+
+        /// Sets the number of the council terms served by the elected seats. Applies to the seats
+        /// elected after the change.
+        fn set_terms_per_seat(origin, terms: u32) {
+            ensure_root(origin)?;
+            ensure!(terms > 0, "terms per seat cannot be zero");
+
+            TermsPerSeat::put(terms);
         }
 
         /// Set blocknumber when council term will end
@@ -257,6 +652,13 @@ decl_module! {
             }
         }
 
+        /// Sets the mode of the council reward payouts. Applies to the next payouts.
+        fn set_council_reward_mode(origin, mode: CouncilRewardMode) {
+            ensure_root(origin)?;
+
+            RewardMode::put(mode);
+        }
+
         /// Sets the council rewards which is only applied on new council being elected.
         fn set_council_rewards(
             origin,
@@ -348,16 +750,19 @@ mod tests {
                         member: 5,
                         stake: 0,
                         backers: vec![],
+                        term_ends_at: 0,
                     },
                     Seat {
                         member: 6,
                         stake: 0,
                         backers: vec![],
+                        term_ends_at: 0,
                     },
                     Seat {
                         member: 7,
                         stake: 0,
                         backers: vec![],
+                        term_ends_at: 0,
                     },
                 ],
                 50 as u64, // <Test as system::Trait>::BlockNumber::from(50)
@@ -372,4 +777,308 @@ mod tests {
             assert!(RewardRelationships::<Test>::exists(&7));
         });
     }
+
+    fn elect_staked_councilor(account: <Test as system::Trait>::AccountId, stake: u64) {
+        let _ = Balances::deposit_creating(&account, stake);
+        assert_ok!(Balances::reserve(&account, stake));
+
+        Council::council_elected(
+            vec![Seat {
+                member: account,
+                stake,
+                backers: vec![],
+                term_ends_at: 0,
+            }],
+            50 as u64,
+        );
+    }
+
+    #[test]
+    fn back_recall_motion_works() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            elect_staked_councilor(5, 100);
+            let _ = Balances::deposit_creating(&1, 1000);
+            let _ = Balances::deposit_creating(&2, 1000);
+
+            assert_ok!(Council::back_recall_motion(Origin::signed(1), 5, 60));
+            assert_ok!(Council::back_recall_motion(Origin::signed(1), 5, 20));
+
+            assert!(Council::is_councilor(&5));
+            assert_eq!(Balances::reserved_balance(&1), 80);
+            assert_eq!(
+                Council::recall_motions(&5),
+                vec![Backer {
+                    member: 1,
+                    stake: 80,
+                }]
+            );
+
+            // the motion backing stake exceeds the councilor stake
+            assert_ok!(Council::back_recall_motion(Origin::signed(2), 5, 30));
+
+            assert!(!Council::is_councilor(&5));
+            assert!(!RecallMotions::<Test>::exists(&5));
+            assert_eq!(Balances::reserved_balance(&1), 0);
+            assert_eq!(Balances::reserved_balance(&2), 0);
+        });
+    }
+
+    #[test]
+    fn vacated_seat_is_filled_by_members_only() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let seat = |member| Seat {
+                member,
+                stake: 0,
+                backers: vec![],
+                term_ends_at: 50,
+            };
+
+            assert_err!(
+                Council::fill_vacated_seat(seat(100)),
+                "only members can fill the vacated seat"
+            );
+
+            assert_ok!(Council::fill_vacated_seat(seat(7)));
+            assert_err!(
+                Council::fill_vacated_seat(seat(7)),
+                "cannot add same account multiple times"
+            );
+            assert_eq!(Council::active_council(), vec![seat(7)]);
+        });
+    }
+
+    #[test]
+    fn zero_staked_seat_recall_requires_min_recall_motion_stake() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(Council::fill_vacated_seat(Seat {
+                member: 7,
+                stake: 0,
+                backers: vec![],
+                term_ends_at: 50,
+            }));
+            let _ = Balances::deposit_creating(&1, 1000);
+
+            let min_recall_motion_stake = <Test as Trait>::MinRecallMotionStake::get();
+
+            assert_ok!(Council::back_recall_motion(Origin::signed(1), 7, 1));
+            assert!(Council::is_councilor(&7));
+
+            assert_ok!(Council::back_recall_motion(
+                Origin::signed(1),
+                7,
+                min_recall_motion_stake - 1
+            ));
+            assert!(!Council::is_councilor(&7));
+        });
+    }
+
+    #[test]
+    fn recall_threshold_is_applied() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            elect_staked_councilor(5, 100);
+            let _ = Balances::deposit_creating(&1, 1000);
+
+            assert_ok!(Council::set_recall_threshold(
+                system::RawOrigin::Root.into(),
+                Perbill::from_percent(50)
+            ));
+
+            assert_ok!(Council::back_recall_motion(Origin::signed(1), 5, 50));
+            assert!(Council::is_councilor(&5));
+
+            assert_ok!(Council::back_recall_motion(Origin::signed(1), 5, 1));
+            assert!(!Council::is_councilor(&5));
+        });
+    }
+
+    #[test]
+    fn back_recall_motion_fails_with_invalid_params() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            elect_staked_councilor(5, 100);
+            let _ = Balances::deposit_creating(&1, 10);
+            let _ = Balances::deposit_creating(&100, 1000);
+
+            assert_err!(
+                Council::back_recall_motion(Origin::signed(100), 5, 10),
+                "only members can back recall motions"
+            );
+            assert_err!(
+                Council::back_recall_motion(Origin::signed(1), 6, 10),
+                "account is not a councilor"
+            );
+            assert_err!(
+                Council::back_recall_motion(Origin::signed(1), 5, 0),
+                "recall stake cannot be zero"
+            );
+            assert_err!(
+                Council::back_recall_motion(Origin::signed(1), 5, 20),
+                "not enough free balance to reserve"
+            );
+
+            System::set_block_number(Council::term_ends_at());
+            assert_err!(
+                Council::back_recall_motion(Origin::signed(1), 5, 10),
+                "council term ended"
+            );
+        });
+    }
+
+    #[test]
+    fn staggered_seats_carry_over() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(Council::set_terms_per_seat(
+                system::RawOrigin::Root.into(),
+                2
+            ));
+
+            elect_staked_councilor(5, 100);
+            assert_eq!(Council::active_council()[0].term_ends_at, 101);
+            assert!(Council::is_carried_over_councilor(&5));
+            assert!(Council::expiring_seats().is_empty());
+
+            System::set_block_number(60);
+            elect_staked_councilor(6, 100);
+
+            assert!(Council::is_councilor(&5));
+            assert!(Council::is_councilor(&6));
+            assert_eq!(Council::term_ends_at(), 110);
+
+            // the seat of the councilor 5 expires with the current council term
+            assert!(!Council::is_carried_over_councilor(&5));
+            assert_eq!(Council::expiring_seats()[0].member, 5);
+
+            System::set_block_number(120);
+            elect_staked_councilor(7, 100);
+
+            assert!(!Council::is_councilor(&5));
+            assert!(Council::is_councilor(&6));
+            assert!(Council::is_councilor(&7));
+        });
+    }
+
+    #[test]
+    fn elected_seats_are_staggered_by_terms_per_seat() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(Council::set_terms_per_seat(
+                system::RawOrigin::Root.into(),
+                2
+            ));
+
+            let seats = (1..=4)
+                .map(|member| Seat {
+                    member,
+                    stake: member * 10,
+                    backers: vec![],
+                    term_ends_at: 0,
+                })
+                .collect();
+            Council::council_elected(seats, 50);
+
+            // top staked seats serve two terms, half of the council is open with every term end
+            let term_ends_at = |member| {
+                Council::active_council()
+                    .into_iter()
+                    .find(|seat| seat.member == member)
+                    .unwrap()
+                    .term_ends_at
+            };
+            assert_eq!(term_ends_at(4), 101);
+            assert_eq!(term_ends_at(3), 101);
+            assert_eq!(term_ends_at(2), 51);
+            assert_eq!(term_ends_at(1), 51);
+            assert_eq!(Council::expiring_seats().len(), 2);
+            assert_eq!(Council::carried_over_seats().len(), 2);
+
+            System::set_block_number(51);
+            Council::council_elected(
+                vec![Seat {
+                    member: 5,
+                    stake: 10,
+                    backers: vec![],
+                    term_ends_at: 0,
+                }],
+                50,
+            );
+
+            assert_eq!(term_ends_at(5), 151);
+            assert_eq!(Council::active_council().len(), 3);
+        });
+    }
+
+    #[test]
+    fn set_terms_per_seat_fails_with_zero_terms() {
+        initial_test_ext().execute_with(|| {
+            assert_err!(
+                Council::set_terms_per_seat(system::RawOrigin::Root.into(), 0),
+                "terms per seat cannot be zero"
+            );
+        });
+    }
+
+    #[test]
+    fn participation_weighted_rewards_withhold_penalty() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(Council::set_council_mint_capacity(
+                system::RawOrigin::Root.into(),
+                1000
+            ));
+            assert_ok!(Council::set_council_rewards(
+                system::RawOrigin::Root.into(),
+                100,
+                None,
+                10
+            ));
+            assert_ok!(Council::set_council_reward_mode(
+                system::RawOrigin::Root.into(),
+                CouncilRewardMode::ParticipationWeighted
+            ));
+
+            // the voting rounds ended before the reward period are not counted
+            MockVotingParticipation::set(2, 2);
+            elect_staked_councilor(5, 100);
+
+            // the councilor voted in 1 of 4 voting rounds ended during the reward period
+            MockVotingParticipation::set(3, 6);
+
+            let payout_at = 12;
+            System::set_block_number(payout_at);
+            <recurringrewards::Module<Test> as OnFinalize<u64>>::on_finalize(payout_at);
+
+            assert_eq!(Balances::free_balance(&5), 25);
+            assert_eq!(Council::reward_period_participation(&5), (3, 6));
+
+            let relationship = recurringrewards::Module::<Test>::reward_relationships(
+                Council::reward_relationships(&5),
+            );
+            assert_eq!(relationship.total_reward_received(), 25);
+            assert_eq!(relationship.total_reward_penalized(), 75);
+        });
+    }
+
+    #[test]
+    fn recall_motions_are_closed_with_new_council() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            elect_staked_councilor(5, 100);
+            let _ = Balances::deposit_creating(&1, 1000);
+
+            assert_ok!(Council::back_recall_motion(Origin::signed(1), 5, 60));
+
+            assert_ok!(Council::set_council(
+                system::RawOrigin::Root.into(),
+                vec![5, 6]
+            ));
+
+            assert!(!RecallMotions::<Test>::exists(&5));
+            assert_eq!(Balances::reserved_balance(&1), 0);
+        });
+    }
 }
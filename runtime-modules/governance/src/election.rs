@@ -4,8 +4,7 @@
 //! We don't currently handle zero periods, zero council term, zero council size and candidacy
 //! limit in any special way. The behaviour in such cases:
 //!
-//! - Setting any period to 0 will mean the election getting stuck in that stage, until force changing
-//! the state.
+//! - Setting any period to 0 will mean the stage ending at the next block.
 //!
 //! - Council Size of 0 - no limit to size of council, all applicants that move beyond
 //! announcing stage would become council members, so effectively the candidacy limit will
@@ -19,7 +18,67 @@
 //!
 //! We only guard against these edge cases in the [`set_election_parameters`] call.
 //!
+//! # Failed elections:
+//! Announcing is restarted while there are not enough applicants. After the max number of failed
+//! announcing rounds set by [`set_failed_election_policy`] the election fails: the term of the
+//! current council is extended or the reduced council is elected from the announced applicants.
+//! The term of the current council is extended by the new term duration when the reduced
+//! council can't be elected without applicants. A zero max number of failed announcing rounds
+//! restarts announcing until enough applicants announce candidacy.
+//!
+//! # Ranked ballots:
+//! The tally method is set by the election parameters. With the ranked tally methods voters
+//! reveal an ordered list of up to `max_ranked_candidates` applicants with [`reveal_ranked`].
+//! The commitment is the salted hash of the serialized list. Ballots are tallied using
+//! the weighted single transferable vote or the sequential Phragmén method. The ballot stake
+//! backs its highest ranked elected applicant. Ballots without elected applicants are refunded.
+//! Seats not filled by the tally are taken by the top staked applicants.
+//!
+//! # Recall:
+//! Unelected applicants are kept as the runners-up ranked by their own stake and the stake of
+//! the votes ranking them first. Runners-up are cleared when the next election starts. A council
+//! seat vacated by the recall is filled by the next runner-up who is still a member, without
+//! stake. When no runners-up are left, the by-election fills the vacated seat until the seat term
+//! end. Expiring seats are filled by the next regular election, which also replaces the running
+//! by-election.
+//!
+//! # Staggered terms:
+//! Elected seats serve up to the number of council terms set by the council `terms_per_seat`:
+//! every election opens about the council size divided by the terms per seat seats. Only
+//! the seats with the term ending with the council term are open and the election takes
+//! ownership of their stakes. Other councilors carry over to the next council and can't apply.
+//! The council size is the size of the whole council: the election fills the council size less
+//! the carried over seats. The candidacy limit is scaled to the open seats share of the council.
+//! No election is started when the carried over seats fill the council: the expiring seats are
+//! closed and the next council term starts with the carried over seats.
+//!
+//! # Vote delegation:
+//! Members can delegate their votes to another member with [`delegate_vote`]. Delegation
+//! persists across the election rounds until revoked. At the revealing stage end the delegated
+//! stake is reserved and backs the applicant of the largest staked revealed vote of the delegate.
+//! Voting directly overrides the delegation for the election round.
+//!
+//! # Vote escrow:
+//! The public key of the x25519 election key pair is set for the next election with
+//! [`set_next_election_public_key`]. During the election voters can escrow their votes
+//! encrypted to the election public key with [`vote_encrypted`]: votes stay secret until
+//! the secret key is disclosed. A councilor discloses the secret key at the revealing stage and
+//! the escrowed votes not revealed by the voters are revealed at the revealing stage end.
+//! Only `MaxEscrowedVotes` votes can be escrowed in the election round.
+//! The encrypted payload is the serialized vote and salt: the applicant or the ranked applicants
+//! for the ranked tally methods.
+//!
+//! # Election archive:
+//! Applicants, revealed votes, unrevealed commitments and the elected seats of the finished
+//! election are archived by the election round. Only the latest `MaxArchivedElections` elections
+//! are kept.
+//!
 //! [`set_election_parameters`]: struct.Module.html#method.set_election_parameters
+//! [`set_failed_election_policy`]: struct.Module.html#method.set_failed_election_policy
+//! [`delegate_vote`]: struct.Module.html#method.delegate_vote
+//! [`set_next_election_public_key`]: struct.Module.html#method.set_next_election_public_key
+//! [`vote_encrypted`]: struct.Module.html#method.vote_encrypted
+//! [`reveal_ranked`]: struct.Module.html#method.reveal_ranked
 
 // Clippy linter warning
 #![allow(clippy::type_complexity)]
@@ -30,22 +89,25 @@
 #![allow(clippy::redundant_closure_call)] // disable it because of the substrate lib design
 
 use rstd::prelude::*;
-use srml_support::traits::{Currency, ReservableCurrency};
+use srml_support::traits::{Currency, Get, ReservableCurrency};
 use srml_support::{decl_event, decl_module, decl_storage, dispatch::Result, ensure};
 use system::{self, ensure_root, ensure_signed};
 
 use codec::{Decode, Encode};
 use rstd::collections::btree_map::BTreeMap;
+use rstd::collections::btree_set::BTreeSet;
 use rstd::ops::Add;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
-use sr_primitives::traits::{Hash, Zero};
+use sr_primitives::traits::{Hash, UniqueSaturatedInto, Zero};
 
+use super::ranked_tally::{self, RankedBallot};
 use super::sealed_vote::SealedVote;
 use super::stake::Stake;
+use super::vote_escrow::{self, EncryptedVote, PublicKey, SecretKey, MAX_ENCRYPTED_VOTE_LENGTH};
 
 use super::council;
-use crate::election_params::ElectionParameters;
+use crate::election_params::{ElectionParameters, TallyMethod};
 pub use common::currency::{BalanceOf, GovernanceCurrency};
 
 pub trait Trait:
@@ -53,7 +115,20 @@ pub trait Trait:
 {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 
-    type CouncilElected: CouncilElected<Seats<Self::AccountId, BalanceOf<Self>>, Self::BlockNumber>;
+    type CouncilElected: CouncilElected<
+        Seats<Self::AccountId, BalanceOf<Self>, Self::BlockNumber>,
+        Self::BlockNumber,
+    >;
+
+    /// Max number of the archived elections.
+    type MaxArchivedElections: Get<u32>;
+
+    /// Max number of the delegators of the member.
+    type MaxDelegatorsPerDelegate: Get<u32>;
+
+    /// Max number of the escrowed votes of the election round. Bounds the escrowed votes
+    /// revealed at the revealing stage end.
+    type MaxEscrowedVotes: Get<u32>;
 }
 
 pub static MSG_CANNOT_CHANGE_PARAMS_DURING_ELECTION: &str = "CannotChangeParamsDuringElection";
@@ -65,15 +140,46 @@ pub enum ElectionStage<BlockNumber> {
     Revealing(BlockNumber),
 }
 
+/// Action taken after the max number of failed announcing rounds.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq)]
+pub enum FailedElectionPolicy<BlockNumber> {
+    /// Extends the term of the current council by the duration.
+    ExtendCouncilTerm(BlockNumber),
+
+    /// Moves the announced applicants to the voting stage to elect the reduced council.
+    ElectReducedCouncil,
+}
+
+impl<BlockNumber> Default for FailedElectionPolicy<BlockNumber> {
+    fn default() -> Self {
+        FailedElectionPolicy::ElectReducedCouncil
+    }
+}
+
+/// Reason of the election failure.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq)]
+pub enum ElectionFailureReason {
+    /// Less applicants than the council size announced candidacy.
+    NotEnoughApplicants,
+
+    /// No applicants announced candidacy.
+    NoApplicants,
+}
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
-pub struct Seat<AccountId, Balance> {
+pub struct Seat<AccountId, Balance, BlockNumber> {
     pub member: AccountId,
     pub stake: Balance,
     pub backers: Vec<Backer<AccountId, Balance>>,
+    /// Block of the seat term end. Seats with the term ending after the council term carry over
+    /// to the next council.
+    pub term_ends_at: BlockNumber,
 }
 
-impl<AccountId, Balance> Seat<AccountId, Balance>
+impl<AccountId, Balance, BlockNumber> Seat<AccountId, Balance, BlockNumber>
 where
     Balance: Add<Output = Balance> + Copy,
 {
@@ -91,7 +197,41 @@ pub struct Backer<AccountId, Balance> {
     pub stake: Balance,
 }
 
-pub type Seats<AccountId, Balance> = Vec<Seat<AccountId, Balance>>;
+/// Vote delegation: the delegate member and the delegated voting stake.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct VoteDelegation<MemberId, Balance> {
+    pub delegate: MemberId,
+    pub stake: Balance,
+}
+
+/// Revealed vote of the archived election.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct ArchivedVote<AccountId, Balance, Hash> {
+    pub voter: AccountId,
+    pub commitment: Hash,
+    pub stake: Balance,
+    /// Applicants in the order of preference. The vote for a single applicant ranks this
+    /// applicant only.
+    pub ranking: Vec<AccountId>,
+}
+
+/// Results of the finished election.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct ElectionRecord<AccountId, Balance, Hash, BlockNumber> {
+    /// Block of the revealing stage end.
+    pub ended_at: BlockNumber,
+    /// Applicants with their total stakes.
+    pub applicants: Vec<(AccountId, Balance)>,
+    pub revealed_votes: Vec<ArchivedVote<AccountId, Balance, Hash>>,
+    pub unrevealed_commitments: Vec<Hash>,
+    /// Elected seats with their backers.
+    pub seats: Seats<AccountId, Balance, BlockNumber>,
+}
+
+pub type Seats<AccountId, Balance, BlockNumber> = Vec<Seat<AccountId, Balance, BlockNumber>>;
 
 // Hook for setting a new council when it is elected
 pub trait CouncilElected<Elected, Term> {
@@ -142,6 +282,15 @@ decl_storage! {
         // The election round
         Round get(round): u32;
 
+        // Max number of the failed announcing rounds before the election fails, zero for no limit
+        MaxFailedAnnouncingRounds get(max_failed_announcing_rounds): u32;
+
+        // Action taken after the max number of failed announcing rounds
+        FailedElectionAction get(failed_election_action): FailedElectionPolicy<T::BlockNumber>;
+
+        // Number of the failed announcing rounds of the running election
+        FailedAnnouncingRounds get(failed_announcing_rounds): u32;
+
         ExistingStakeHolders get(existing_stake_holders): Vec<T::AccountId>;
         TransferableStakes get(transferable_stakes): map T::AccountId => TransferableStake<BalanceOf<T>>;
 
@@ -150,9 +299,49 @@ decl_storage! {
 
         Commitments get(commitments): Vec<T::Hash>;
 
+        // Unelected applicants of the last election in the order of their ranking. They fill
+        // the council seats vacated by the recall.
+        RunnersUp get(runners_up): Vec<T::AccountId>;
+
+        // Term ends of the vacated seats filled by the running by-election. Empty unless
+        // the by-election is running.
+        ByElectionSeatTerms get(by_election_seat_terms): Vec<T::BlockNumber>;
+
+        // Results of the finished elections by round.
+        ElectionArchive get(election_archive):
+            map u32 => Option<ElectionRecord<T::AccountId, BalanceOf<T>, T::Hash, T::BlockNumber>>;
+
+        // Rounds of the archived elections, the oldest first.
+        ArchivedRounds get(archived_rounds): Vec<u32>;
+
         // TODO value type of this map looks scary, is there any way to simplify the notation?
         Votes get(votes): map T::Hash => SealedVote<T::AccountId, ElectionStake<T>, T::Hash, T::AccountId>;
 
+        // Revealed ranked ballots by commitment: applicants in the order of preference.
+        RankedBallots get(ranked_ballots): map T::Hash => Vec<T::AccountId>;
+
+        // Votes encrypted to the election public key by commitment.
+        EncryptedVotes get(encrypted_votes): map T::Hash => EncryptedVote;
+
+        // Commitments of the escrowed votes of the running election.
+        EscrowedCommitments get(escrowed_commitments): Vec<T::Hash>;
+
+        // Election public key set for the next election.
+        NextElectionPublicKey get(next_election_public_key): Option<PublicKey>;
+
+        // Election public key of the running election.
+        ElectionPublicKey get(election_public_key): Option<PublicKey>;
+
+        // Election secret key disclosed at the revealing stage of the running election.
+        ElectionSecretKey get(election_secret_key): Option<SecretKey>;
+
+        // Vote delegations by the delegator account.
+        VoteDelegations get(vote_delegations):
+            map T::AccountId => Option<VoteDelegation<T::MemberId, BalanceOf<T>>>;
+
+        // Delegator accounts by the delegate member.
+        Delegators get(delegators): map T::MemberId => Vec<T::AccountId>;
+
         // Current Election Parameters.
         // Should we replace all the individual values with a single ElectionParameters type?
         // Having them individually makes it more flexible to add and remove new parameters in future
@@ -165,6 +354,8 @@ decl_storage! {
         MinCouncilStake get(min_council_stake): BalanceOf<T>;
         NewTermDuration get(new_term_duration): T::BlockNumber;
         MinVotingStake get(min_voting_stake): BalanceOf<T>;
+        ElectionTallyMethod get(tally_method): TallyMethod;
+        MaxRankedCandidates get(max_ranked_candidates): u32;
     }
     add_extra_genesis {
         config(election_parameters): ElectionParameters<BalanceOf<T>, T::BlockNumber>;
@@ -180,7 +371,9 @@ decl_event!(
     pub enum Event<T> where
     <T as system::Trait>::BlockNumber,
     <T as system::Trait>::AccountId,
-    <T as system::Trait>::Hash  {
+    <T as system::Trait>::Hash,
+    <T as membership::members::Trait>::MemberId,
+    Balance = BalanceOf<T> {
         /// A new election started
         ElectionStarted(),
         AnnouncingStarted(u32),
@@ -193,6 +386,14 @@ decl_event!(
         Applied(AccountId),
         Voted(AccountId, Hash),
         Revealed(AccountId, Hash, AccountId),
+        RevealedRanked(AccountId, Hash, Vec<AccountId>),
+        /// Params: election round, failure reason
+        ElectionFailed(u32, ElectionFailureReason),
+        /// Params: delegator, delegate, stake
+        VoteDelegated(AccountId, MemberId, Balance),
+        VoteDelegationRevoked(AccountId),
+        /// Params: election round
+        ElectionKeyDisclosed(u32),
     }
 );
 
@@ -207,6 +408,30 @@ impl<T: Trait> Module<T> {
         Self::candidacy_limit() as usize
     }
 
+    /// Number of the seats filled by the election: the council size less the carried over seats.
+    /// No seats are open when the carried over seats fill the council. The by-election fills
+    /// the vacated seats only.
+    fn open_seats_usize() -> usize {
+        if Self::is_by_election() {
+            return Self::by_election_seat_terms().len();
+        }
+
+        let carried_over_seats = <council::Module<T>>::carried_over_seats().len();
+
+        Self::council_size_usize().saturating_sub(carried_over_seats)
+    }
+
+    /// Candidacy limit scaled to the open seats share of the council, rounded up.
+    fn open_candidacy_limit_usize() -> usize {
+        let council_size = Self::council_size_usize();
+
+        if council_size == 0 {
+            return Self::candidacy_limit_usize();
+        }
+
+        (Self::candidacy_limit_usize() * Self::open_seats_usize() + council_size - 1) / council_size
+    }
+
     fn current_block_number_plus(length: T::BlockNumber) -> T::BlockNumber {
         <system::Module<T>>::block_number() + length
     }
@@ -223,6 +448,11 @@ impl<T: Trait> Module<T> {
         Self::stage().is_some()
     }
 
+    /// Returns true if the running election is the by-election filling the vacated seats.
+    pub fn is_by_election() -> bool {
+        !Self::by_election_seat_terms().is_empty()
+    }
+
     /// Returns block number at which current stage will end if an election is running.
     pub fn stage_ends_at() -> Option<T::BlockNumber> {
         if let Some(stage) = Self::stage() {
@@ -240,7 +470,9 @@ impl<T: Trait> Module<T> {
 
     /// Starts an election. Will fail if an election is already running
     /// Initializes transferable stakes. Assumes election parameters have already been set.
-    fn start_election(current_council: Seats<T::AccountId, BalanceOf<T>>) -> Result {
+    fn start_election(
+        current_council: Seats<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+    ) -> Result {
         ensure!(!Self::is_election_running(), "election already in progress");
         ensure!(
             Self::existing_stake_holders().is_empty(),
@@ -249,10 +481,19 @@ impl<T: Trait> Module<T> {
         ensure!(Self::applicants().is_empty(), "applicants must be empty");
         ensure!(Self::commitments().is_empty(), "commitments must be empty");
 
-        // Take snapshot of seat and backing stakes of an existing council
+        // Take snapshot of seat and backing stakes of the expiring council seats. Carried over seats
+        // keep their stakes.
         // Its important to note that the election system takes ownership of these stakes, and is responsible
         // to return any unused stake to original owners at the end of the election.
         Self::initialize_transferable_stakes(current_council);
+        FailedAnnouncingRounds::kill();
+
+        // runners up of the previous election don't fill the seats vacated from now on
+        <RunnersUp<T>>::kill();
+
+        if let Some(public_key) = NextElectionPublicKey::take() {
+            ElectionPublicKey::put(public_key);
+        }
 
         Self::deposit_event(RawEvent::ElectionStarted());
 
@@ -260,6 +501,57 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    /// Starts the election of the expiring seats. No election is started when the carried over
+    /// seats fill the council: the expiring seats are closed with their stakes returned and
+    /// the next council term starts with the carried over seats only.
+    fn start_regular_election() -> Result {
+        if Self::open_seats_usize() > 0 {
+            return Self::start_election(<council::Module<T>>::expiring_seats());
+        }
+
+        ensure!(!Self::is_election_running(), "election already in progress");
+
+        for seat in <council::Module<T>>::expiring_seats().iter() {
+            Self::unreserve_seat_stakes(seat);
+        }
+
+        T::CouncilElected::council_elected(Vec::new(), Self::new_term_duration());
+
+        Ok(())
+    }
+
+    /// Starts the by-election filling the vacated seat until the seat term end. The vacated
+    /// seat is added to the running by-election. The running regular election fills the seat
+    /// without the by-election: the vacated seat is no longer carried over.
+    fn start_by_election(seat_term_ends_at: T::BlockNumber) -> Result {
+        if !Self::is_by_election() {
+            // the vacated seat stakes are already returned, no stakes are transferable
+            Self::start_election(Vec::new())?;
+        }
+
+        <ByElectionSeatTerms<T>>::mutate(|seat_terms| seat_terms.push(seat_term_ends_at));
+
+        Ok(())
+    }
+
+    /// Stops the running election: refunds the voting and the applicant stakes.
+    /// No council gets elected.
+    fn stop_election() {
+        let mut votes = Vec::new();
+        for commitment in Self::commitments() {
+            votes.push(Self::votes(commitment));
+        }
+
+        // no council gets elected
+        let empty_council = BTreeMap::new();
+
+        Self::teardown_election(
+            &votes,
+            &empty_council,
+            false, /* do not unlock transferable stakes */
+        );
+    }
+
     /// Sets announcing stage. Can be called from any stage and assumes all preparatory work
     /// for entering the stage has been performed.
     /// Bumps the election round.
@@ -314,25 +606,76 @@ impl<T: Trait> Module<T> {
     fn on_announcing_ended() {
         let mut applicants = Self::applicants();
 
-        if applicants.len() < Self::council_size_usize() {
+        if applicants.len() < Self::open_seats_usize() {
             // Not enough applicants announced candidacy
-            Self::move_to_announcing_stage();
+            let failed_rounds = FailedAnnouncingRounds::mutate(|n| {
+                *n += 1;
+                *n
+            });
+
+            let max_failed_rounds = Self::max_failed_announcing_rounds();
+            if max_failed_rounds == 0 || failed_rounds < max_failed_rounds {
+                Self::move_to_announcing_stage();
+            } else {
+                Self::on_election_failed(applicants.is_empty());
+            }
         } else {
             // upper limit on applicants that will move to voting stage
-            let limit = rstd::cmp::max(Self::council_size_usize(), Self::candidacy_limit_usize());
+            let limit =
+                rstd::cmp::max(Self::open_seats_usize(), Self::open_candidacy_limit_usize());
             let applicants_to_drop = Self::find_least_staked_applicants(&mut applicants, limit);
 
             Self::drop_applicants(applicants_to_drop);
 
+            FailedAnnouncingRounds::kill();
             Self::move_to_voting_stage();
         }
     }
 
+    /// Applies the failed election policy. Announced applicants move to the voting stage to
+    /// elect the reduced council. Otherwise the election is stopped, applicants are refunded
+    /// and the term of the current council is extended. The current council keeps its stakes.
+    /// The failed by-election leaves the seats vacant until the next election.
+    fn on_election_failed(no_applicants: bool) {
+        let reason = if no_applicants {
+            ElectionFailureReason::NoApplicants
+        } else {
+            ElectionFailureReason::NotEnoughApplicants
+        };
+
+        Self::deposit_event(RawEvent::ElectionFailed(Self::round(), reason));
+
+        FailedAnnouncingRounds::kill();
+
+        let term_extension = match Self::failed_election_action() {
+            FailedElectionPolicy::ElectReducedCouncil if !no_applicants => {
+                Self::move_to_voting_stage();
+                return;
+            }
+            FailedElectionPolicy::ExtendCouncilTerm(duration) => duration,
+            FailedElectionPolicy::ElectReducedCouncil => Self::new_term_duration(),
+        };
+
+        let is_by_election = Self::is_by_election();
+
+        Self::teardown_election(
+            &[],
+            &BTreeMap::new(),
+            false, /* do not unlock transferable stakes */
+        );
+
+        if !is_by_election {
+            <council::Module<T>>::extend_term(term_extension);
+        }
+    }
+
     fn on_voting_ended() {
         Self::move_to_revealing_stage();
     }
 
     fn on_revealing_ended() {
+        Self::reveal_escrowed_votes();
+
         // tally the revealed votes
         let mut votes = Vec::new();
 
@@ -340,7 +683,47 @@ impl<T: Trait> Module<T> {
             votes.push(Self::votes(commitment));
         }
 
-        let mut new_council = Self::tally_votes(&votes);
+        let delegated_votes = Self::cast_delegated_votes(&votes);
+        votes.extend(delegated_votes);
+
+        let (new_council, backing_votes) = match Self::tally_method() {
+            TallyMethod::FirstPastThePost => (Self::elect_top_staked(&votes), votes.clone()),
+            tally_method => Self::elect_by_ranked_ballots(&votes, tally_method),
+        };
+
+        // unless we want to add more filtering criteria to what is considered a successful election
+        // other than just the minimum stake for candidacy, we have a new council!
+
+        // runners up and the archive are based on the revealed votes
+        <RunnersUp<T>>::put(Self::rank_runners_up(&votes, &new_council));
+        Self::archive_election(&votes, &new_council);
+
+        let by_election_seat_terms = Self::by_election_seat_terms();
+
+        // refunds are made for the ballots backing no elected applicant
+        Self::teardown_election(
+            &backing_votes,
+            &new_council,
+            true, /* unlock transferable stakes */
+        );
+
+        let new_council = new_council.into_iter().map(|(_, seat)| seat).collect();
+
+        if by_election_seat_terms.is_empty() {
+            T::CouncilElected::council_elected(new_council, Self::new_term_duration());
+        } else {
+            Self::fill_vacated_seats(new_council, by_election_seat_terms);
+        }
+
+        Self::deposit_event(RawEvent::CouncilElected(<system::Module<T>>::block_number()));
+    }
+
+    /// Tallies the votes backing single applicants. Applicants without votes take part in
+    /// the election with their own stake. Top staked applicants get elected.
+    fn elect_top_staked(
+        votes: &[SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, T::AccountId>],
+    ) -> BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>, T::BlockNumber>> {
+        let mut new_council = Self::tally_votes(votes);
 
         // Note here that applicants with zero votes dont appear in the tally.
         // Is an applicant with some votes but less total stake than another applicant with zero votes
@@ -349,25 +732,18 @@ impl<T: Trait> Module<T> {
 
         for applicant in Self::applicants().iter() {
             if !new_council.contains_key(applicant) {
-                new_council.insert(
-                    applicant.clone(),
-                    Seat {
-                        member: applicant.clone(),
-                        stake: Self::applicant_stakes(applicant).total(),
-                        backers: Vec::new(),
-                    },
-                );
+                new_council.insert(applicant.clone(), Self::new_applicant_seat(applicant));
             }
         }
 
         match new_council.len() {
-            ncl if ncl == Self::council_size_usize() => {
+            ncl if ncl == Self::open_seats_usize() => {
                 // all applicants in the tally will form the new council
             }
-            ncl if ncl > Self::council_size_usize() => {
+            ncl if ncl > Self::open_seats_usize() => {
                 // we have more than enough applicants to form the new council.
                 // select top staked
-                Self::filter_top_staked(&mut new_council, Self::council_size_usize());
+                Self::filter_top_staked(&mut new_council, Self::open_seats_usize());
             }
             _ => {
                 // Not enough applicants with votes to form a council.
@@ -377,136 +753,495 @@ impl<T: Trait> Module<T> {
             }
         }
 
-        // unless we want to add more filtering criteria to what is considered a successful election
-        // other than just the minimum stake for candidacy, we have a new council!
-
-        Self::teardown_election(
-            &votes,
-            &new_council,
-            true, /* unlock transferable stakes */
-        );
-
-        let new_council = new_council.into_iter().map(|(_, seat)| seat).collect();
-        T::CouncilElected::council_elected(new_council, Self::new_term_duration());
-
-        Self::deposit_event(RawEvent::CouncilElected(<system::Module<T>>::block_number()));
+        new_council
     }
 
-    fn teardown_election(
+    /// Tallies the ranked ballots using the ranked tally method. Seats not filled by the tally
+    /// are taken by the top staked applicants. Every ballot stake backs its highest ranked
+    /// elected applicant.
+    /// Returns the new council and the votes unsealed for the backed applicants. Votes backing
+    /// no elected applicant are returned sealed.
+    fn elect_by_ranked_ballots(
         votes: &[SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, T::AccountId>],
-        new_council: &BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>>>,
-        unlock_ts: bool,
+        tally_method: TallyMethod,
+    ) -> (
+        BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>, T::BlockNumber>>,
+        Vec<SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, T::AccountId>>,
     ) {
-        Self::refund_voting_stakes(&votes, &new_council);
-        Self::clear_votes();
-
-        Self::drop_unelected_applicants(&new_council);
-        Self::clear_applicants();
+        // top staked applicants come first, later applicants win ties as in `filter_top_staked`
+        let mut candidates = Self::applicants();
+        candidates.reverse();
+        candidates.sort_by(|a, b| Self::applicant_stakes(b).cmp(&Self::applicant_stakes(a)));
 
-        if unlock_ts {
-            Self::unlock_transferable_stakes();
-        }
+        let ballots: Vec<RankedBallot<T::AccountId>> = votes
+            .iter()
+            .filter_map(|sealed_vote| {
+                Self::get_ranked_ballot(sealed_vote)
+                    .map(|ranking| (ranking, sealed_vote.stake.total().unique_saturated_into()))
+            })
+            .collect();
 
-        Self::clear_transferable_stakes();
+        let seats = rstd::cmp::min(Self::open_seats_usize(), candidates.len());
 
-        <Stage<T>>::kill();
-    }
+        let mut elected = match tally_method {
+            TallyMethod::SingleTransferableVote => {
+                ranked_tally::single_transferable_vote(&ballots, &candidates, seats)
+            }
+            _ => ranked_tally::sequential_phragmen(&ballots, &candidates, seats),
+        };
 
-    fn unlock_transferable_stakes() {
-        // move stakes back to account holder's free balance
-        for stakeholder in Self::existing_stake_holders().iter() {
-            let stake = Self::transferable_stakes(stakeholder);
-            if !stake.seat.is_zero() || !stake.backing.is_zero() {
-                <T as GovernanceCurrency>::Currency::unreserve(
-                    stakeholder,
-                    stake.seat + stake.backing,
-                );
+        for candidate in candidates.into_iter() {
+            if elected.len() >= seats {
+                break;
             }
-        }
-    }
 
-    fn clear_transferable_stakes() {
-        for stakeholder in Self::existing_stake_holders() {
-            <TransferableStakes<T>>::remove(stakeholder);
+            if !elected.contains(&candidate) {
+                elected.push(candidate);
+            }
         }
 
-        <ExistingStakeHolders<T>>::kill();
-    }
-
-    fn clear_applicants() {
-        for applicant in Self::applicants() {
-            <ApplicantStakes<T>>::remove(applicant);
-        }
-        <Applicants<T>>::kill();
-    }
+        let mut new_council: BTreeMap<
+            T::AccountId,
+            Seat<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+        > = elected
+            .into_iter()
+            .map(|member| {
+                let seat = Self::new_applicant_seat(&member);
 
-    fn refund_applicant(applicant: &T::AccountId) {
-        let stake = <ApplicantStakes<T>>::get(applicant);
+                (member, seat)
+            })
+            .collect();
 
-        // return new stake to account's free balance
-        if !stake.new.is_zero() {
-            <T as GovernanceCurrency>::Currency::unreserve(applicant, stake.new);
-        }
+        let mut backing_votes = Vec::new();
 
-        // return unused transferable stake
-        if !stake.transferred.is_zero() {
-            <TransferableStakes<T>>::mutate(applicant, |transferable| {
-                (*transferable).seat += stake.transferred
+        for sealed_vote in votes.iter() {
+            let backed_applicant = Self::get_ranked_ballot(sealed_vote).and_then(|ranking| {
+                ranking
+                    .into_iter()
+                    .find(|applicant| new_council.contains_key(applicant))
             });
-        }
-    }
 
-    fn drop_applicants(drop: &[T::AccountId]) {
-        let not_dropped: Vec<T::AccountId> = Self::applicants()
-            .into_iter()
-            .filter(|id| !drop.iter().any(|x| *x == *id))
-            .collect();
+            let SealedVote {
+                voter,
+                stake,
+                commitment,
+                ..
+            } = sealed_vote.clone();
 
-        for applicant in drop {
-            Self::refund_applicant(applicant);
-            <ApplicantStakes<T>>::remove(applicant);
+            if let Some(applicant) = backed_applicant {
+                if let Some(seat) = new_council.get_mut(&applicant) {
+                    seat.backers.push(Backer {
+                        member: voter.clone(),
+                        stake: stake.total(),
+                    });
+                }
+
+                backing_votes.push(SealedVote::new_unsealed(
+                    voter, stake, commitment, applicant,
+                ));
+            } else {
+                backing_votes.push(SealedVote::new(voter, stake, commitment));
+            }
         }
 
-        <Applicants<T>>::put(not_dropped);
+        (new_council, backing_votes)
     }
 
-    fn drop_unelected_applicants(
-        new_council: &BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>>>,
-    ) {
-        let applicants_to_drop: Vec<T::AccountId> = Self::applicants()
-            .into_iter()
-            .filter(|applicant| !new_council.contains_key(&applicant))
-            .collect();
-
-        Self::drop_applicants(&applicants_to_drop[..]);
-    }
+    /// Reveals the escrowed votes with the disclosed election secret key. Votes revealed by
+    /// the voters and votes failing to decrypt or to reveal are skipped.
+    fn reveal_escrowed_votes() {
+        let secret_key = match Self::election_secret_key() {
+            Some(secret_key) => secret_key,
+            None => return,
+        };
 
-    fn refund_voting_stakes(
-        sealed_votes: &[SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, T::AccountId>],
-        new_council: &BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>>>,
-    ) {
-        for sealed_vote in sealed_votes.iter() {
-            // Do a refund if commitment was not revealed, or the vote was for applicant that did
-            // not get elected to the council
-            // TODO critical: shouldn't we slash the stake in such a case? This is the whole idea behid staking on something: people need to decide carefully and be responsible for their bahavior because they can loose their stake
-            // See https://github.com/Joystream/substrate-node-joystream/issues/4
-            let do_refund = match sealed_vote.get_vote() {
-                Some(applicant) => !new_council.contains_key(&applicant),
-                None => true,
-            };
+        for commitment in Self::escrowed_commitments().into_iter() {
+            let voter = Self::votes(&commitment).voter;
+            let payload = vote_escrow::decrypt_vote(
+                secret_key,
+                commitment.as_ref(),
+                &Self::encrypted_votes(&commitment),
+                <T as system::Trait>::Hashing::hash,
+            );
 
-            if do_refund {
-                // return new stake to account's free balance
-                let SealedVote { voter, stake, .. } = sealed_vote;
-                if !stake.new.is_zero() {
-                    <T as GovernanceCurrency>::Currency::unreserve(voter, stake.new);
+            if Self::tally_method().is_ranked() {
+                if let Ok((ranking, salt)) =
+                    <(Vec<T::AccountId>, Vec<u8>)>::decode(&mut &payload[..])
+                {
+                    if salt.len() <= 32
+                        && Self::try_reveal_ranked_vote(
+                            voter.clone(),
+                            commitment,
+                            ranking.clone(),
+                            salt,
+                        )
+                        .is_ok()
+                    {
+                        Self::deposit_event(RawEvent::RevealedRanked(voter, commitment, ranking));
+                    }
                 }
-
-                // return unused transferable stake
-                if !stake.transferred.is_zero() {
-                    <TransferableStakes<T>>::mutate(voter, |transferable| {
-                        (*transferable).backing += stake.transferred
-                    });
+            } else if let Ok((vote, salt)) = <(T::AccountId, Vec<u8>)>::decode(&mut &payload[..]) {
+                if salt.len() <= 32
+                    && Self::try_reveal_vote(voter.clone(), commitment, vote.clone(), salt).is_ok()
+                {
+                    Self::deposit_event(RawEvent::Revealed(voter, commitment, vote));
+                }
+            }
+        }
+    }
+
+    /// Casts the delegated votes following the delegates. Every delegator follows the largest
+    /// staked revealed vote of the delegate member accounts. The delegated stake is reserved
+    /// reusing the transferable backing stake first. Delegators voting in the round and
+    /// delegators unable to reserve the stake are skipped.
+    fn cast_delegated_votes(
+        votes: &[SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, T::AccountId>],
+    ) -> Vec<SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, T::AccountId>> {
+        let mut delegate_votes: BTreeMap<
+            T::MemberId,
+            &SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, T::AccountId>,
+        > = BTreeMap::new();
+
+        for sealed_vote in votes.iter() {
+            if Self::get_ranked_ballot(sealed_vote).is_none() {
+                continue;
+            }
+
+            let mut member_ids =
+                <membership::members::Module<T>>::member_ids_by_root_account_id(&sealed_vote.voter);
+            member_ids.extend(
+                <membership::members::Module<T>>::member_ids_by_controller_account_id(
+                    &sealed_vote.voter,
+                ),
+            );
+
+            for member_id in member_ids {
+                let is_largest_stake = delegate_votes
+                    .get(&member_id)
+                    .map_or(true, |vote| vote.stake.total() < sealed_vote.stake.total());
+
+                if is_largest_stake {
+                    delegate_votes.insert(member_id, sealed_vote);
+                }
+            }
+        }
+
+        let voters: BTreeSet<&T::AccountId> =
+            votes.iter().map(|sealed_vote| &sealed_vote.voter).collect();
+
+        let mut delegated_votes = Vec::new();
+
+        for (delegate, delegate_vote) in delegate_votes.into_iter() {
+            for delegator in Self::delegators(delegate).into_iter() {
+                // voting directly overrides the delegation
+                if voters.contains(&delegator) {
+                    continue;
+                }
+
+                let delegation = match Self::vote_delegations(&delegator) {
+                    Some(delegation) => delegation,
+                    None => continue,
+                };
+
+                if delegation.stake < Self::min_voting_stake() {
+                    continue;
+                }
+
+                let mut transferable_stake = <TransferableStakes<T>>::get(&delegator);
+
+                let stake = Self::new_stake_reusing_transferable(
+                    &mut transferable_stake.backing,
+                    delegation.stake,
+                );
+
+                if <T as GovernanceCurrency>::Currency::reserve(&delegator, stake.new).is_err() {
+                    continue;
+                }
+
+                if <TransferableStakes<T>>::exists(&delegator) {
+                    <TransferableStakes<T>>::insert(&delegator, transferable_stake);
+                }
+
+                // the ranked ballot of the delegate is found by the commitment
+                let delegated_vote = match delegate_vote.get_vote() {
+                    Some(applicant) => SealedVote::new_unsealed(
+                        delegator,
+                        stake,
+                        delegate_vote.commitment,
+                        applicant.clone(),
+                    ),
+                    None => SealedVote::new(delegator, stake, delegate_vote.commitment),
+                };
+
+                delegated_votes.push(delegated_vote);
+            }
+        }
+
+        delegated_votes
+    }
+
+    fn remove_vote_delegation(delegator: &T::AccountId) {
+        if let Some(delegation) = <VoteDelegations<T>>::take(delegator) {
+            <Delegators<T>>::mutate(delegation.delegate, |delegators| {
+                delegators.retain(|account| account != delegator)
+            });
+        }
+    }
+
+    /// Returns the unelected applicants ordered by their own stake and the stake of the votes
+    /// ranking them first. Later applicants win ties as in `filter_top_staked`.
+    /// Seats the councilors elected by the by-election in the vacated seats. Top staked
+    /// councilors get the latest seat term ends. Stakes of the seats which cannot be filled are
+    /// returned.
+    fn fill_vacated_seats(
+        mut seats: Seats<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+        mut seat_terms: Vec<T::BlockNumber>,
+    ) {
+        seats.sort_by(|a, b| b.calc_total_stake().cmp(&a.calc_total_stake()));
+        seat_terms.sort_by(|a, b| b.cmp(a));
+
+        for (seat, term_ends_at) in seats.into_iter().zip(seat_terms.into_iter()) {
+            let seat = Seat {
+                term_ends_at,
+                ..seat
+            };
+
+            if <council::Module<T>>::fill_vacated_seat(seat.clone()).is_err() {
+                Self::unreserve_seat_stakes(&seat);
+            }
+        }
+    }
+
+    /// Returns the stakes of the councilor and the backers of the seat.
+    fn unreserve_seat_stakes(seat: &Seat<T::AccountId, BalanceOf<T>, T::BlockNumber>) {
+        <T as GovernanceCurrency>::Currency::unreserve(&seat.member, seat.stake);
+
+        for backer in seat.backers.iter() {
+            <T as GovernanceCurrency>::Currency::unreserve(&backer.member, backer.stake);
+        }
+    }
+
+    fn rank_runners_up(
+        votes: &[SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, T::AccountId>],
+        new_council: &BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>, T::BlockNumber>>,
+    ) -> Vec<T::AccountId> {
+        let mut runners_up: Vec<(T::AccountId, BalanceOf<T>)> = Self::applicants()
+            .into_iter()
+            .filter(|applicant| !new_council.contains_key(applicant))
+            .map(|applicant| {
+                let stake = votes
+                    .iter()
+                    .filter(|sealed_vote| {
+                        Self::get_ranked_ballot(sealed_vote)
+                            .and_then(|ranking| ranking.into_iter().next())
+                            .map_or(false, |first| first == applicant)
+                    })
+                    .fold(
+                        Self::applicant_stakes(&applicant).total(),
+                        |acc, sealed_vote| acc + sealed_vote.stake.total(),
+                    );
+
+                (applicant, stake)
+            })
+            .collect();
+
+        runners_up.reverse();
+        runners_up.sort_by(|a, b| b.1.cmp(&a.1));
+
+        runners_up
+            .into_iter()
+            .map(|(applicant, _)| applicant)
+            .collect()
+    }
+
+    /// Archives the results of the current election round. Removes the oldest archived
+    /// elections on the `MaxArchivedElections` limit exceeding.
+    fn archive_election(
+        votes: &[SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, T::AccountId>],
+        new_council: &BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>, T::BlockNumber>>,
+    ) {
+        let mut revealed_votes = Vec::new();
+        let mut unrevealed_commitments = Vec::new();
+
+        for sealed_vote in votes.iter() {
+            if let Some(ranking) = Self::get_ranked_ballot(sealed_vote) {
+                revealed_votes.push(ArchivedVote {
+                    voter: sealed_vote.voter.clone(),
+                    commitment: sealed_vote.commitment,
+                    stake: sealed_vote.stake.total(),
+                    ranking,
+                });
+            } else {
+                unrevealed_commitments.push(sealed_vote.commitment);
+            }
+        }
+
+        let record = ElectionRecord {
+            ended_at: <system::Module<T>>::block_number(),
+            applicants: Self::applicants()
+                .into_iter()
+                .map(|applicant| {
+                    let stake = Self::applicant_stakes(&applicant).total();
+                    (applicant, stake)
+                })
+                .collect(),
+            revealed_votes,
+            unrevealed_commitments,
+            seats: new_council.values().cloned().collect(),
+        };
+
+        let round = Self::round();
+        <ElectionArchive<T>>::insert(round, record);
+
+        let mut archived_rounds = Self::archived_rounds();
+        archived_rounds.push(round);
+
+        let max_archived_elections = T::MaxArchivedElections::get() as usize;
+        if archived_rounds.len() > max_archived_elections {
+            let removed_rounds_count = archived_rounds.len() - max_archived_elections;
+
+            for removed_round in archived_rounds.drain(..removed_rounds_count) {
+                <ElectionArchive<T>>::remove(removed_round);
+            }
+        }
+
+        ArchivedRounds::put(archived_rounds);
+    }
+
+    /// Returns the revealed ranked ballot of the vote. The vote revealed for a single applicant
+    /// is the ballot ranking this applicant only.
+    fn get_ranked_ballot(
+        sealed_vote: &SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, T::AccountId>,
+    ) -> Option<Vec<T::AccountId>> {
+        if <RankedBallots<T>>::exists(&sealed_vote.commitment) {
+            Some(Self::ranked_ballots(&sealed_vote.commitment))
+        } else {
+            sealed_vote
+                .get_vote()
+                .clone()
+                .map(|applicant| vec![applicant])
+        }
+    }
+
+    fn teardown_election(
+        votes: &[SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, T::AccountId>],
+        new_council: &BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>, T::BlockNumber>>,
+        unlock_ts: bool,
+    ) {
+        Self::refund_voting_stakes(&votes, &new_council);
+        Self::clear_votes();
+
+        Self::drop_unelected_applicants(&new_council);
+        Self::clear_applicants();
+
+        if unlock_ts {
+            Self::unlock_transferable_stakes();
+        }
+
+        Self::clear_transferable_stakes();
+
+        ElectionPublicKey::kill();
+        ElectionSecretKey::kill();
+        <ByElectionSeatTerms<T>>::kill();
+
+        <Stage<T>>::kill();
+    }
+
+    fn unlock_transferable_stakes() {
+        // move stakes back to account holder's free balance
+        for stakeholder in Self::existing_stake_holders().iter() {
+            let stake = Self::transferable_stakes(stakeholder);
+            if !stake.seat.is_zero() || !stake.backing.is_zero() {
+                <T as GovernanceCurrency>::Currency::unreserve(
+                    stakeholder,
+                    stake.seat + stake.backing,
+                );
+            }
+        }
+    }
+
+    fn clear_transferable_stakes() {
+        for stakeholder in Self::existing_stake_holders() {
+            <TransferableStakes<T>>::remove(stakeholder);
+        }
+
+        <ExistingStakeHolders<T>>::kill();
+    }
+
+    fn clear_applicants() {
+        for applicant in Self::applicants() {
+            <ApplicantStakes<T>>::remove(applicant);
+        }
+        <Applicants<T>>::kill();
+    }
+
+    fn refund_applicant(applicant: &T::AccountId) {
+        let stake = <ApplicantStakes<T>>::get(applicant);
+
+        // return new stake to account's free balance
+        if !stake.new.is_zero() {
+            <T as GovernanceCurrency>::Currency::unreserve(applicant, stake.new);
+        }
+
+        // return unused transferable stake
+        if !stake.transferred.is_zero() {
+            <TransferableStakes<T>>::mutate(applicant, |transferable| {
+                (*transferable).seat += stake.transferred
+            });
+        }
+    }
+
+    fn drop_applicants(drop: &[T::AccountId]) {
+        let not_dropped: Vec<T::AccountId> = Self::applicants()
+            .into_iter()
+            .filter(|id| !drop.iter().any(|x| *x == *id))
+            .collect();
+
+        for applicant in drop {
+            Self::refund_applicant(applicant);
+            <ApplicantStakes<T>>::remove(applicant);
+        }
+
+        <Applicants<T>>::put(not_dropped);
+    }
+
+    fn drop_unelected_applicants(
+        new_council: &BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>, T::BlockNumber>>,
+    ) {
+        let applicants_to_drop: Vec<T::AccountId> = Self::applicants()
+            .into_iter()
+            .filter(|applicant| !new_council.contains_key(&applicant))
+            .collect();
+
+        Self::drop_applicants(&applicants_to_drop[..]);
+    }
+
+    fn refund_voting_stakes(
+        sealed_votes: &[SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, T::AccountId>],
+        new_council: &BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>, T::BlockNumber>>,
+    ) {
+        for sealed_vote in sealed_votes.iter() {
+            // Do a refund if commitment was not revealed, or the vote was for applicant that did
+            // not get elected to the council
+            // TODO critical: shouldn't we slash the stake in such a case? This is the whole idea behid staking on something: people need to decide carefully and be responsible for their bahavior because they can loose their stake
+            // See https://github.com/Joystream/substrate-node-joystream/issues/4
+            let do_refund = match sealed_vote.get_vote() {
+                Some(applicant) => !new_council.contains_key(&applicant),
+                None => true,
+            };
+
+            if do_refund {
+                // return new stake to account's free balance
+                let SealedVote { voter, stake, .. } = sealed_vote;
+                if !stake.new.is_zero() {
+                    <T as GovernanceCurrency>::Currency::unreserve(voter, stake.new);
+                }
+
+                // return unused transferable stake
+                if !stake.transferred.is_zero() {
+                    <TransferableStakes<T>>::mutate(voter, |transferable| {
+                        (*transferable).backing += stake.transferred
+                    });
                 }
             }
         }
@@ -515,27 +1250,37 @@ impl<T: Trait> Module<T> {
     fn clear_votes() {
         for commitment in Self::commitments() {
             <Votes<T>>::remove(commitment);
+            <RankedBallots<T>>::remove(commitment);
+            <EncryptedVotes<T>>::remove(commitment);
         }
         <Commitments<T>>::kill();
+        <EscrowedCommitments<T>>::kill();
+    }
+
+    /// Seat of the elected applicant without backers. The seat term starts with the new council
+    /// term.
+    fn new_applicant_seat(
+        applicant: &T::AccountId,
+    ) -> Seat<T::AccountId, BalanceOf<T>, T::BlockNumber> {
+        Seat {
+            member: applicant.clone(),
+            stake: Self::applicant_stakes(applicant).total(),
+            backers: Vec::new(),
+            term_ends_at: <council::Module<T>>::seat_term_ends_at(Self::new_term_duration()),
+        }
     }
 
     fn tally_votes(
         sealed_votes: &[SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, T::AccountId>],
-    ) -> BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>>> {
-        let mut tally: BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>>> = BTreeMap::new();
+    ) -> BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>, T::BlockNumber>> {
+        let mut tally: BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>, T::BlockNumber>> =
+            BTreeMap::new();
 
         for sealed_vote in sealed_votes.iter() {
             if let Some(applicant) = sealed_vote.get_vote() {
                 if !tally.contains_key(&applicant) {
                     // Add new seat
-                    tally.insert(
-                        applicant.clone(),
-                        Seat {
-                            member: applicant.clone(),
-                            stake: Self::applicant_stakes(applicant).total(),
-                            backers: vec![],
-                        },
-                    );
+                    tally.insert(applicant.clone(), Self::new_applicant_seat(applicant));
                 }
                 if let Some(seat) = tally.get_mut(&applicant) {
                     // Add backer to existing seat
@@ -551,7 +1296,7 @@ impl<T: Trait> Module<T> {
     }
 
     fn filter_top_staked(
-        tally: &mut BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>>>,
+        tally: &mut BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>, T::BlockNumber>>,
         limit: usize,
     ) {
         if limit >= tally.len() {
@@ -592,19 +1337,19 @@ impl<T: Trait> Module<T> {
         if let Some(stage) = Self::stage() {
             match stage {
                 ElectionStage::Announcing(ends) => {
-                    if ends == now {
+                    if ends <= now {
                         Self::deposit_event(RawEvent::AnnouncingEnded());
                         Self::on_announcing_ended();
                     }
                 }
                 ElectionStage::Voting(ends) => {
-                    if ends == now {
+                    if ends <= now {
                         Self::deposit_event(RawEvent::VotingEnded());
                         Self::on_voting_ended();
                     }
                 }
                 ElectionStage::Revealing(ends) => {
-                    if ends == now {
+                    if ends <= now {
                         Self::deposit_event(RawEvent::RevealingEnded());
                         Self::on_revealing_ended();
                     }
@@ -614,7 +1359,9 @@ impl<T: Trait> Module<T> {
     }
 
     /// Takes a snapshot of the stakes from the current council
-    fn initialize_transferable_stakes(current_council: Seats<T::AccountId, BalanceOf<T>>) {
+    fn initialize_transferable_stakes(
+        current_council: Seats<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+    ) {
         let mut stakeholder_accounts: Vec<T::AccountId> = Vec::new();
 
         for seat in current_council.into_iter() {
@@ -759,7 +1506,10 @@ impl<T: Trait> Module<T> {
 
         let mut sealed_vote = <Votes<T>>::get(&commitment);
 
-        ensure!(sealed_vote.is_not_revealed(), "vote already revealed");
+        ensure!(
+            sealed_vote.is_not_revealed() && !<RankedBallots<T>>::exists(&commitment),
+            "vote already revealed"
+        );
         // only voter can reveal their own votes
         ensure!(sealed_vote.is_owned_by(voter), "only voter can reveal vote");
         ensure!(
@@ -778,6 +1528,61 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    fn try_reveal_ranked_vote(
+        voter: T::AccountId,
+        commitment: T::Hash,
+        ranking: Vec<T::AccountId>,
+        salt: Vec<u8>,
+    ) -> Result {
+        ensure!(<Votes<T>>::exists(&commitment), "commitment not found");
+
+        let SealedVote {
+            voter: vote_owner,
+            stake,
+            ..
+        } = <Votes<T>>::get(&commitment);
+
+        // the ranked ballot is unsealed as a separate vote, the stored vote stays sealed
+        let mut ranked_vote: SealedVote<_, _, _, Vec<T::AccountId>> =
+            SealedVote::new(vote_owner, stake, commitment);
+
+        ensure!(
+            Self::votes(&commitment).is_not_revealed() && !<RankedBallots<T>>::exists(&commitment),
+            "vote already revealed"
+        );
+        // only voter can reveal their own votes
+        ensure!(ranked_vote.is_owned_by(voter), "only voter can reveal vote");
+        ensure!(!ranking.is_empty(), "ranked ballot cannot be empty");
+        ensure!(
+            ranking.len() as u32 <= Self::max_ranked_candidates(),
+            "too many ranked applicants"
+        );
+
+        for (index, applicant) in ranking.iter().enumerate() {
+            ensure!(
+                <ApplicantStakes<T>>::exists(applicant),
+                "vote for non-applicant not allowed"
+            );
+            ensure!(
+                !ranking[..index].contains(applicant),
+                "applicant ranked more than once"
+            );
+        }
+
+        let mut salt = salt;
+
+        // Tries to unseal, if salt is invalid will return error
+        ranked_vote.unseal(
+            ranking.clone(),
+            &mut salt,
+            <T as system::Trait>::Hashing::hash,
+        )?;
+
+        <RankedBallots<T>>::insert(commitment, ranking);
+
+        Ok(())
+    }
+
     fn set_verified_election_parameters(params: ElectionParameters<BalanceOf<T>, T::BlockNumber>) {
         <AnnouncingPeriod<T>>::put(params.announcing_period);
         <VotingPeriod<T>>::put(params.voting_period);
@@ -787,6 +1592,8 @@ impl<T: Trait> Module<T> {
         CouncilSize::put(params.council_size);
         CandidacyLimit::put(params.candidacy_limit);
         <MinVotingStake<T>>::put(params.min_voting_stake);
+        ElectionTallyMethod::put(params.tally_method);
+        MaxRankedCandidates::put(params.max_ranked_candidates);
     }
 }
 
@@ -794,6 +1601,15 @@ decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
         fn deposit_event() = default;
 
+        /// Exports const - max number of the archived elections.
+        const MaxArchivedElections: u32 = T::MaxArchivedElections::get();
+
+        /// Exports const - max number of the delegators of the member.
+        const MaxDelegatorsPerDelegate: u32 = T::MaxDelegatorsPerDelegate::get();
+
+        /// Exports const - max number of the escrowed votes of the election round.
+        const MaxEscrowedVotes: u32 = T::MaxEscrowedVotes::get();
+
         // No origin so this is a priviledged call
         fn on_finalize(now: T::BlockNumber) {
             Self::check_if_stage_is_ending(now);
@@ -804,6 +1620,14 @@ decl_module! {
         fn apply(origin, stake: BalanceOf<T>) {
             let sender = ensure_signed(origin)?;
             ensure!(Self::can_participate(&sender), "Only members can apply to be on council");
+            ensure!(
+                !<council::Module<T>>::is_carried_over_councilor(&sender),
+                "councilors with carried over seats cannot apply"
+            );
+            ensure!(
+                !Self::is_by_election() || !<council::Module<T>>::is_councilor(&sender),
+                "councilors cannot apply in the by-election"
+            );
 
             let stage = Self::stage();
             ensure!(Self::stage().is_some(), "election not running");
@@ -842,25 +1666,104 @@ decl_module! {
             Self::deposit_event(RawEvent::Voted(sender, commitment));
         }
 
-        fn reveal(origin, commitment: T::Hash, vote: T::AccountId, salt: Vec<u8>) {
+        /// Votes with the vote encrypted to the election public key of the running election.
+        /// The vote is revealed at the revealing stage end unless revealed by the voter.
+        fn vote_encrypted(origin, commitment: T::Hash, stake: BalanceOf<T>, encrypted_vote: EncryptedVote) {
             let sender = ensure_signed(origin)?;
-
-            ensure!(salt.len() <= 32, "salt too large"); // at most 256 bits salt
+            ensure!(Self::can_participate(&sender), "Only members can vote for an applicant");
 
             let stage = Self::stage();
             ensure!(Self::stage().is_some(), "election not running");
 
-            let is_revealing = match stage.unwrap() {
-                ElectionStage::Revealing(_) => true,
+            let is_voting = match stage.unwrap() {
+                ElectionStage::Voting(_) => true,
                 _ => false
             };
-            ensure!(is_revealing, "election not in revealing stage");
+            ensure!(is_voting, "election not in voting stage");
 
-            Self::try_reveal_vote(sender.clone(), commitment, vote.clone(), salt)?;
-            Self::deposit_event(RawEvent::Revealed(sender, commitment, vote));
-        }
+            ensure!(Self::election_public_key().is_some(), "election public key not set");
+            ensure!(
+                encrypted_vote.payload.len() <= MAX_ENCRYPTED_VOTE_LENGTH,
+                "encrypted vote too large"
+            );
+            ensure!(
+                (Self::escrowed_commitments().len() as u32) < T::MaxEscrowedVotes::get(),
+                "max escrowed votes of the election round reached"
+            );
 
-        fn set_stage_announcing(origin, ends_at: T::BlockNumber) {
+            ensure!(stake >= Self::min_voting_stake(), "voting stake too low");
+            Self::try_add_vote(sender.clone(), stake, commitment)?;
+            <EncryptedVotes<T>>::insert(commitment, encrypted_vote);
+            <EscrowedCommitments<T>>::mutate(|commitments| commitments.push(commitment));
+            Self::deposit_event(RawEvent::Voted(sender, commitment));
+        }
+
+        /// Discloses the election secret key of the running election. Escrowed votes are
+        /// revealed at the revealing stage end.
+        fn disclose_election_key(origin, secret_key: SecretKey) {
+            let sender = ensure_signed(origin)?;
+            ensure!(<council::Module<T>>::is_councilor(&sender), "only councilors can disclose election key");
+
+            let stage = Self::stage();
+            ensure!(Self::stage().is_some(), "election not running");
+
+            let is_revealing = match stage.unwrap() {
+                ElectionStage::Revealing(_) => true,
+                _ => false
+            };
+            ensure!(is_revealing, "election not in revealing stage");
+
+            let public_key = Self::election_public_key().ok_or("election public key not set")?;
+            ensure!(!ElectionSecretKey::exists(), "election key already disclosed");
+            ensure!(
+                vote_escrow::public_key(secret_key) == public_key,
+                "election secret key does not match public key"
+            );
+
+            ElectionSecretKey::put(secret_key);
+            Self::deposit_event(RawEvent::ElectionKeyDisclosed(Self::round()));
+        }
+
+        fn reveal(origin, commitment: T::Hash, vote: T::AccountId, salt: Vec<u8>) {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(salt.len() <= 32, "salt too large"); // at most 256 bits salt
+
+            let stage = Self::stage();
+            ensure!(Self::stage().is_some(), "election not running");
+
+            let is_revealing = match stage.unwrap() {
+                ElectionStage::Revealing(_) => true,
+                _ => false
+            };
+            ensure!(is_revealing, "election not in revealing stage");
+
+            Self::try_reveal_vote(sender.clone(), commitment, vote.clone(), salt)?;
+            Self::deposit_event(RawEvent::Revealed(sender, commitment, vote));
+        }
+
+        // Reveals the ranked ballot: applicants in the order of preference. Available with
+        // the ranked tally methods only.
+        fn reveal_ranked(origin, commitment: T::Hash, ranking: Vec<T::AccountId>, salt: Vec<u8>) {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(salt.len() <= 32, "salt too large"); // at most 256 bits salt
+            ensure!(Self::tally_method().is_ranked(), "ranked ballots are not enabled");
+
+            let stage = Self::stage();
+            ensure!(Self::stage().is_some(), "election not running");
+
+            let is_revealing = match stage.unwrap() {
+                ElectionStage::Revealing(_) => true,
+                _ => false
+            };
+            ensure!(is_revealing, "election not in revealing stage");
+
+            Self::try_reveal_ranked_vote(sender.clone(), commitment, ranking.clone(), salt)?;
+            Self::deposit_event(RawEvent::RevealedRanked(sender, commitment, ranking));
+        }
+
+        fn set_stage_announcing(origin, ends_at: T::BlockNumber) {
             ensure_root(origin)?;
             ensure!(ends_at > <system::Module<T>>::block_number(), "must end at future block number");
             <Stage<T>>::put(ElectionStage::Announcing(ends_at));
@@ -890,28 +1793,56 @@ decl_module! {
             Self::set_verified_election_parameters(params);
         }
 
+        /// Delegates the vote to the member until revoked. Replaces the existing delegation.
+        /// The delegated stake is reserved at the end of every revealing stage.
+        /// The member can have not more than `MaxDelegatorsPerDelegate` delegators.
+        fn delegate_vote(origin, to: T::MemberId, stake: BalanceOf<T>) {
+            let sender = ensure_signed(origin)?;
+            ensure!(Self::can_participate(&sender), "Only members can delegate votes");
+
+            let delegate_profile = <membership::members::Module<T>>::ensure_profile(to)?;
+            ensure!(
+                delegate_profile.root_account != sender && delegate_profile.controller_account != sender,
+                "cannot delegate vote to yourself"
+            );
+            ensure!(stake >= Self::min_voting_stake(), "voting stake too low");
+
+            let delegators = Self::delegators(to);
+            ensure!(
+                delegators.contains(&sender)
+                    || (delegators.len() as u32) < T::MaxDelegatorsPerDelegate::get(),
+                "too many delegators"
+            );
+
+            // mutation
+
+            Self::remove_vote_delegation(&sender);
+
+            <Delegators<T>>::mutate(to, |delegators| delegators.push(sender.clone()));
+            <VoteDelegations<T>>::insert(&sender, VoteDelegation { delegate: to, stake });
+
+            Self::deposit_event(RawEvent::VoteDelegated(sender, to, stake));
+        }
+
+        fn revoke_vote_delegation(origin) {
+            let sender = ensure_signed(origin)?;
+            ensure!(<VoteDelegations<T>>::exists(&sender), "vote is not delegated");
+
+            Self::remove_vote_delegation(&sender);
+
+            Self::deposit_event(RawEvent::VoteDelegationRevoked(sender));
+        }
+
         fn force_stop_election(origin) {
             ensure_root(origin)?;
             ensure!(Self::is_election_running(), "only running election can be stopped");
 
-            let mut votes = Vec::new();
-            for commitment in Self::commitments() {
-                votes.push(Self::votes(commitment));
-            }
-
-            // no council gets elected
-            let empty_council = BTreeMap::new();
-
-            Self::teardown_election (
-                &votes,
-                &empty_council,
-                false /* do not unlock transferable stakes */
-            );
+            Self::stop_election();
         }
 
         fn force_start_election(origin) {
             ensure_root(origin)?;
-            Self::start_election(<council::Module<T>>::active_council())?;
+            Self::start_regular_election()?;
         }
 
         fn set_auto_start (origin, flag: bool) {
@@ -919,14 +1850,90 @@ decl_module! {
             AutoStart::put(flag);
         }
 
+        /// Sets the election public key for the next election. The secret key is kept by
+        /// the council until the revealing stage. None disables the vote escrow for the next
+        /// election.
+        fn set_next_election_public_key(origin, public_key: Option<PublicKey>) {
+            ensure_root(origin)?;
+
+            if let Some(public_key) = public_key {
+                NextElectionPublicKey::put(public_key);
+            } else {
+                NextElectionPublicKey::kill();
+            }
+        }
+
+        /// Sets the max number of the failed announcing rounds before the election fails and
+        /// the action taken on the election failure. Zero max number of the failed announcing
+        /// rounds restarts announcing until enough applicants announce candidacy.
+        fn set_failed_election_policy(
+            origin,
+            max_failed_announcing_rounds: u32,
+            action: FailedElectionPolicy<T::BlockNumber>
+        ) {
+            ensure_root(origin)?;
+
+            if let FailedElectionPolicy::ExtendCouncilTerm(duration) = action {
+                ensure!(!duration.is_zero(), "council term extension cannot be zero");
+            }
+
+            MaxFailedAnnouncingRounds::put(max_failed_announcing_rounds);
+            <FailedElectionAction<T>>::put(action);
+        }
+
     }
 }
 
 impl<T: Trait> council::CouncilTermEnded for Module<T> {
     fn council_term_ended() {
         if Self::auto_start() {
-            let _ = Self::start_election(<council::Module<T>>::active_council());
+            // the regular election fills the seats vacated during the running by-election
+            if Self::is_by_election() {
+                Self::stop_election();
+            }
+
+            let _ = Self::start_regular_election();
+        }
+    }
+}
+
+impl<T: Trait> council::CouncilSeatVacated<Seat<T::AccountId, BalanceOf<T>, T::BlockNumber>>
+    for Module<T>
+{
+    fn council_seat_vacated(seat: Seat<T::AccountId, BalanceOf<T>, T::BlockNumber>) {
+        // the running election owns the stakes of the expiring seats and replaces them
+        if Self::is_election_running() && <council::Module<T>>::is_seat_expiring(&seat) {
+            return;
+        }
+
+        Self::unreserve_seat_stakes(&seat);
+
+        // the next ranked applicant of the last election, who is still a member, fills the seat
+        let mut runners_up = Self::runners_up();
+
+        while !runners_up.is_empty() {
+            let runner_up = Seat {
+                member: runners_up.remove(0),
+                stake: BalanceOf::<T>::zero(),
+                backers: Vec::new(),
+                term_ends_at: seat.term_ends_at,
+            };
+
+            if <council::Module<T>>::fill_vacated_seat(runner_up).is_ok() {
+                <RunnersUp<T>>::put(runners_up);
+                return;
+            }
+        }
+
+        <RunnersUp<T>>::kill();
+
+        // the next regular election fills the expiring seat
+        if <council::Module<T>>::is_seat_expiring(&seat) {
+            return;
         }
+
+        // by-election for the vacated seat only when no applicants are left
+        let _ = Self::start_by_election(seat.term_ends_at);
     }
 }
 
@@ -941,6 +1948,7 @@ mod tests {
     fn election_starts_when_council_term_ends() {
         initial_test_ext().execute_with(|| {
             System::set_block_number(1);
+            CouncilSize::put(1);
 
             assert!(Council::is_term_ended());
             assert!(Election::stage().is_none());
@@ -1050,6 +2058,7 @@ mod tests {
                             stake: 10,
                         },
                     ],
+                    term_ends_at: 0,
                 },
                 Seat {
                     member: 2,
@@ -1068,6 +2077,7 @@ mod tests {
                             stake: 20,
                         },
                     ],
+                    term_ends_at: 0,
                 },
                 Seat {
                     member: 3,
@@ -1082,6 +2092,7 @@ mod tests {
                             stake: 40,
                         },
                     ],
+                    term_ends_at: 0,
                 },
             ];
 
@@ -1634,6 +2645,187 @@ mod tests {
         });
     }
 
+    fn make_commitment_for_ranking(
+        ranking: Vec<<Test as system::Trait>::AccountId>,
+        salt: &mut Vec<u8>,
+    ) -> <Test as system::Trait>::Hash {
+        let mut payload = ranking.encode();
+        payload.append(salt);
+        <Test as system::Trait>::Hashing::hash(&payload[..])
+    }
+
+    fn insert_applicants(applicants: Vec<u64>) {
+        for applicant in applicants.iter() {
+            <ApplicantStakes<Test>>::insert(
+                applicant,
+                Stake {
+                    new: 10,
+                    transferred: 0,
+                },
+            );
+        }
+
+        <Applicants<Test>>::put(applicants);
+    }
+
+    #[test]
+    fn revealing_ranked_vote_works() {
+        initial_test_ext().execute_with(|| {
+            let ranking = vec![30, 20];
+            let salt = vec![128u8];
+            let commitment = make_commitment_for_ranking(ranking.clone(), &mut salt.clone());
+            let voter = 10 as u64;
+
+            insert_applicants(vec![20, 30]);
+            MaxRankedCandidates::put(2);
+
+            <Votes<Test>>::insert(
+                &commitment,
+                SealedVote::new(
+                    voter,
+                    Stake {
+                        new: 100,
+                        transferred: 0,
+                    },
+                    commitment,
+                ),
+            );
+
+            assert_ok!(Election::try_reveal_ranked_vote(
+                voter,
+                commitment,
+                ranking.clone(),
+                salt.clone()
+            ));
+            assert_eq!(Election::ranked_ballots(commitment), ranking);
+
+            assert_err!(
+                Election::try_reveal_ranked_vote(voter, commitment, ranking, salt.clone()),
+                "vote already revealed"
+            );
+            assert_err!(
+                Election::try_reveal_vote(voter, commitment, 20, salt),
+                "vote already revealed"
+            );
+        });
+    }
+
+    #[test]
+    fn revealing_invalid_ranked_vote_should_not_work() {
+        initial_test_ext().execute_with(|| {
+            let voter = 10 as u64;
+
+            insert_applicants(vec![20, 30, 40]);
+            MaxRankedCandidates::put(2);
+
+            let invalid_rankings = vec![
+                (vec![], "ranked ballot cannot be empty"),
+                (vec![20, 30, 40], "too many ranked applicants"),
+                (vec![20, 20], "applicant ranked more than once"),
+                (vec![20, 50], "vote for non-applicant not allowed"),
+            ];
+
+            for (ranking, error) in invalid_rankings {
+                let salt = vec![128u8];
+                let commitment = make_commitment_for_ranking(ranking.clone(), &mut salt.clone());
+
+                <Votes<Test>>::insert(
+                    &commitment,
+                    SealedVote::new(
+                        voter,
+                        Stake {
+                            new: 100,
+                            transferred: 0,
+                        },
+                        commitment,
+                    ),
+                );
+
+                assert_err!(
+                    Election::try_reveal_ranked_vote(voter, commitment, ranking, salt),
+                    error
+                );
+                assert!(!<RankedBallots<Test>>::exists(commitment));
+            }
+        });
+    }
+
+    #[test]
+    fn reveal_ranked_requires_ranked_tally_method() {
+        initial_test_ext().execute_with(|| {
+            <Stage<Test>>::put(ElectionStage::Revealing(10));
+
+            assert_err!(
+                Election::reveal_ranked(
+                    Origin::signed(10),
+                    make_commitment_for_ranking(vec![20], &mut vec![128u8]),
+                    vec![20],
+                    vec![128u8]
+                ),
+                "ranked ballots are not enabled"
+            );
+        });
+    }
+
+    #[test]
+    fn electing_by_ranked_ballots_should_work() {
+        initial_test_ext().execute_with(|| {
+            for tally_method in vec![
+                TallyMethod::SingleTransferableVote,
+                TallyMethod::SequentialPhragmen,
+            ] {
+                insert_applicants(vec![100, 200, 300]);
+                CouncilSize::put(2);
+
+                let votes: Vec<_> = vec![
+                    //  (voter, stake, ranking)
+                    (10, 60, vec![100, 200]),
+                    (20, 25, vec![300]),
+                    (30, 15, vec![200]),
+                ]
+                .into_iter()
+                .map(|(voter, stake, ranking)| {
+                    let commitment = make_commitment_for_ranking(ranking.clone(), &mut vec![0u8]);
+                    <RankedBallots<Test>>::insert(commitment, ranking);
+
+                    SealedVote::new(
+                        voter,
+                        Stake {
+                            new: stake,
+                            transferred: 0,
+                        },
+                        commitment,
+                    )
+                })
+                .collect();
+
+                let (new_council, backing_votes) =
+                    Election::elect_by_ranked_ballots(&votes, tally_method);
+
+                assert_eq!(new_council.len(), 2);
+                assert_eq!(
+                    new_council.get(&100).unwrap().backers,
+                    vec![Backer {
+                        member: 10,
+                        stake: 60,
+                    }]
+                );
+                assert_eq!(
+                    new_council.get(&200).unwrap().backers,
+                    vec![Backer {
+                        member: 30,
+                        stake: 15,
+                    }]
+                );
+
+                // the ballot backing no elected applicant is refunded
+                assert_eq!(backing_votes[0].get_vote(), &Some(100));
+                assert!(backing_votes[1].is_not_revealed());
+                assert_eq!(backing_votes[2].get_vote(), &Some(200));
+            }
+        });
+    }
+
     pub fn mock_votes(
         mock: Vec<(u64, u64, u64, u64)>,
     ) -> Vec<SealedVote<u64, Stake<u64>, primitives::H256, u64>> {
@@ -1786,13 +2978,14 @@ mod tests {
                 },
             );
 
-            let mut new_council: BTreeMap<u64, Seat<u64, u64>> = BTreeMap::new();
+            let mut new_council: BTreeMap<u64, Seat<u64, u64, u64>> = BTreeMap::new();
             new_council.insert(
                 200 as u64,
                 Seat {
                     member: 200 as u64,
                     stake: 0 as u64,
                     backers: vec![],
+                    term_ends_at: 0,
                 },
             );
             new_council.insert(
@@ -1801,6 +2994,7 @@ mod tests {
                     member: 300 as u64,
                     stake: 0 as u64,
                     backers: vec![],
+                    term_ends_at: 0,
                 },
             );
 
@@ -1863,13 +3057,14 @@ mod tests {
                 (30, 1000, 140, 300),
             ]);
 
-            let mut new_council: BTreeMap<u64, Seat<u64, u64>> = BTreeMap::new();
+            let mut new_council: BTreeMap<u64, Seat<u64, u64, u64>> = BTreeMap::new();
             new_council.insert(
                 200 as u64,
                 Seat {
                     member: 200 as u64,
                     stake: 0 as u64,
                     backers: vec![],
+                    term_ends_at: 0,
                 },
             );
             new_council.insert(
@@ -1878,6 +3073,7 @@ mod tests {
                     member: 300 as u64,
                     stake: 0 as u64,
                     backers: vec![],
+                    term_ends_at: 0,
                 },
             );
 
@@ -1940,76 +3136,814 @@ mod tests {
     }
 
     #[test]
-    fn council_elected_hook_should_work() {
+    fn ranking_runners_up_should_work() {
         initial_test_ext().execute_with(|| {
-            let mut new_council: BTreeMap<u64, Seat<u64, u64>> = BTreeMap::new();
-            new_council.insert(
-                200 as u64,
-                Seat {
-                    member: 200 as u64,
-                    stake: 10 as u64,
-                    backers: vec![],
-                },
-            );
+            insert_applicants(vec![100, 200, 300]);
+
+            let votes = mock_votes(vec![
+                //  (voter, stake[new], stake[transferred], applicant)
+                (10, 50, 0, 300),
+                (20, 80, 0, 100),
+            ]);
+
+            let mut new_council = BTreeMap::new();
             new_council.insert(
-                300 as u64,
+                100,
                 Seat {
-                    member: 300 as u64,
-                    stake: 20 as u64,
+                    member: 100,
+                    stake: 10,
                     backers: vec![],
+                    term_ends_at: 0,
                 },
             );
 
-            assert_eq!(Council::active_council().len(), 0);
-
-            let new_council = new_council
-                .into_iter()
-                .map(|(_, seat)| seat.clone())
-                .collect();
-            <Test as election::Trait>::CouncilElected::council_elected(new_council, 10);
-
-            assert_eq!(Council::active_council().len(), 2);
+            assert_eq!(
+                Election::rank_runners_up(&votes, &new_council),
+                vec![300, 200]
+            );
         });
     }
 
+    fn member_id_of(account: u64) -> u32 {
+        membership::members::Module::<Test>::member_ids_by_root_account_id(&account)[0]
+    }
+
     #[test]
-    fn simulation() {
+    fn delegating_vote_should_work() {
         initial_test_ext().execute_with(|| {
-            assert_eq!(Council::active_council().len(), 0);
-            assert!(Election::stage().is_none());
-
-            CouncilSize::put(10);
-            <MinCouncilStake<Test>>::put(50);
-            <AnnouncingPeriod<Test>>::put(10);
-            <VotingPeriod<Test>>::put(10);
-            <RevealingPeriod<Test>>::put(10);
-            CandidacyLimit::put(20);
-            <NewTermDuration<Test>>::put(100);
+            let _ = Balances::deposit_creating(&3, 1000);
             <MinVotingStake<Test>>::put(10);
 
-            for i in 1..30 {
-                let _ = Balances::deposit_creating(&(i as u64), 50000);
-            }
+            assert_ok!(Election::delegate_vote(
+                Origin::signed(3),
+                member_id_of(1),
+                100
+            ));
+            assert_eq!(Election::delegators(member_id_of(1)), vec![3]);
 
-            System::set_block_number(1);
-            assert_ok!(Election::start_election(vec![]));
+            // the delegation is replaced
+            assert_ok!(Election::delegate_vote(
+                Origin::signed(3),
+                member_id_of(2),
+                50
+            ));
+            assert!(Election::delegators(member_id_of(1)).is_empty());
+            assert_eq!(Election::delegators(member_id_of(2)), vec![3]);
+            assert_eq!(
+                Election::vote_delegations(&3),
+                Some(VoteDelegation {
+                    delegate: member_id_of(2),
+                    stake: 50,
+                })
+            );
 
-            for i in 1..20 {
-                if i < 21 {
-                    assert!(Election::apply(Origin::signed(i), 150).is_ok());
-                } else {
-                    assert!(Election::apply(Origin::signed(i + 1000), 150).is_err()); // not enough free balance
-                    assert!(Election::apply(Origin::signed(i), 20).is_err()); // not enough minimum stake
-                }
-            }
+            // the stake is reserved at the revealing stage end only
+            assert_eq!(Balances::reserved_balance(&3), 0);
 
-            let n = 1 + Election::announcing_period();
-            System::set_block_number(n);
-            let _ = Election::on_finalize(n);
+            assert_ok!(Election::revoke_vote_delegation(Origin::signed(3)));
+            assert!(Election::vote_delegations(&3).is_none());
+            assert!(Election::delegators(member_id_of(2)).is_empty());
+        });
+    }
 
-            for i in 1..20 {
-                assert!(Election::vote(
-                    Origin::signed(i),
+    #[test]
+    fn delegating_vote_with_invalid_params_should_not_work() {
+        initial_test_ext().execute_with(|| {
+            let _ = Balances::deposit_creating(&3, 1000);
+            let _ = Balances::deposit_creating(&100, 1000);
+            <MinVotingStake<Test>>::put(10);
+
+            assert_err!(
+                Election::delegate_vote(Origin::signed(100), member_id_of(1), 100),
+                "Only members can delegate votes"
+            );
+            assert_err!(
+                Election::delegate_vote(Origin::signed(3), member_id_of(3), 100),
+                "cannot delegate vote to yourself"
+            );
+            assert_err!(
+                Election::delegate_vote(Origin::signed(3), 1000, 100),
+                "member profile not found"
+            );
+            assert_err!(
+                Election::delegate_vote(Origin::signed(3), member_id_of(1), 5),
+                "voting stake too low"
+            );
+            assert_err!(
+                Election::revoke_vote_delegation(Origin::signed(3)),
+                "vote is not delegated"
+            );
+        });
+    }
+
+    #[test]
+    fn delegating_vote_is_limited_by_max_delegators() {
+        initial_test_ext().execute_with(|| {
+            let max_delegators = <Test as election::Trait>::MaxDelegatorsPerDelegate::get() as u64;
+
+            for delegator in 3..3 + max_delegators {
+                let _ = Balances::deposit_creating(&delegator, 1000);
+                assert_ok!(Election::delegate_vote(
+                    Origin::signed(delegator),
+                    member_id_of(2),
+                    100
+                ));
+            }
+
+            let next_delegator = 3 + max_delegators;
+            let _ = Balances::deposit_creating(&next_delegator, 1000);
+            assert_err!(
+                Election::delegate_vote(Origin::signed(next_delegator), member_id_of(2), 100),
+                "too many delegators"
+            );
+
+            // existing delegator can update the delegation
+            assert_ok!(Election::delegate_vote(
+                Origin::signed(3),
+                member_id_of(2),
+                200
+            ));
+        });
+    }
+
+    #[test]
+    fn delegated_votes_follow_delegate_vote() {
+        initial_test_ext().execute_with(|| {
+            for delegator in 3..6 {
+                let _ = Balances::deposit_creating(&delegator, 1000);
+                assert_ok!(Election::delegate_vote(
+                    Origin::signed(delegator),
+                    member_id_of(2),
+                    100
+                ));
+            }
+
+            // the delegator has not enough balance to reserve the stake
+            let _ = Balances::slash(&5, 950);
+
+            let votes = mock_votes(vec![
+                //  (voter, stake[new], stake[transferred], applicant)
+                (2, 10, 0, 100),
+                (2, 200, 0, 200),
+                (4, 30, 0, 100),
+            ]);
+
+            let delegated_votes = Election::cast_delegated_votes(&votes);
+
+            // the delegator 4 voted directly
+            assert_eq!(delegated_votes.len(), 1);
+            assert_eq!(delegated_votes[0].voter, 3);
+            assert_eq!(delegated_votes[0].stake.total(), 100);
+            assert_eq!(delegated_votes[0].get_vote(), &Some(200));
+            assert_eq!(Balances::reserved_balance(&3), 100);
+
+            let tally = Election::tally_votes(&[votes, delegated_votes].concat());
+            assert_eq!(
+                tally.get(&200).unwrap().backers,
+                vec![
+                    Backer {
+                        member: 2,
+                        stake: 200,
+                    },
+                    Backer {
+                        member: 3,
+                        stake: 100,
+                    },
+                ]
+            );
+        });
+    }
+
+    const ELECTION_SECRET_KEY: SecretKey = [1u8; 32];
+
+    fn start_escrow_election() {
+        System::set_block_number(1);
+        assert_ok!(Election::set_next_election_public_key(
+            Origin::ROOT,
+            Some(vote_escrow::public_key(ELECTION_SECRET_KEY))
+        ));
+        assert_ok!(Council::set_council(
+            system::RawOrigin::Root.into(),
+            vec![1]
+        ));
+
+        assert_ok!(Election::start_election(vec![]));
+        assert!(Election::next_election_public_key().is_none());
+
+        insert_applicants(vec![20]);
+        <Stage<Test>>::put(ElectionStage::Voting(10));
+    }
+
+    fn escrow_vote(voter: u64, applicant: u64, salt: Vec<u8>, public_key: PublicKey) -> H256 {
+        let commitment = make_commitment_for_applicant(applicant, &mut salt.clone());
+        let encrypted_vote = vote_escrow::encrypt_vote(
+            public_key,
+            [voter as u8; 32],
+            commitment.as_ref(),
+            &(applicant, salt).encode(),
+            <Test as system::Trait>::Hashing::hash,
+        );
+
+        let _ = Balances::deposit_creating(&voter, 1000);
+        assert_ok!(Election::vote_encrypted(
+            Origin::signed(voter),
+            commitment,
+            100,
+            encrypted_vote
+        ));
+
+        commitment
+    }
+
+    #[test]
+    fn escrowed_votes_are_revealed_with_disclosed_key() {
+        initial_test_ext().execute_with(|| {
+            start_escrow_election();
+
+            let public_key = vote_escrow::public_key(ELECTION_SECRET_KEY);
+            let other_public_key = vote_escrow::public_key([2u8; 32]);
+            let commitment = escrow_vote(5, 20, vec![1u8], public_key);
+            let revealed_commitment = escrow_vote(6, 20, vec![2u8], public_key);
+            let invalid_commitment = escrow_vote(7, 20, vec![3u8], other_public_key);
+
+            <Stage<Test>>::put(ElectionStage::Revealing(20));
+            assert_ok!(Election::reveal(
+                Origin::signed(6),
+                revealed_commitment,
+                20,
+                vec![2u8]
+            ));
+            assert_ok!(Election::disclose_election_key(
+                Origin::signed(1),
+                ELECTION_SECRET_KEY
+            ));
+
+            Election::reveal_escrowed_votes();
+
+            assert_eq!(Election::votes(commitment).get_vote(), &Some(20));
+            assert_eq!(Election::votes(revealed_commitment).get_vote(), &Some(20));
+            assert!(Election::votes(invalid_commitment).is_not_revealed());
+        });
+    }
+
+    #[test]
+    fn escrowed_votes_are_limited() {
+        initial_test_ext().execute_with(|| {
+            start_escrow_election();
+
+            let public_key = vote_escrow::public_key(ELECTION_SECRET_KEY);
+            let max_escrowed_votes = <Test as election::Trait>::MaxEscrowedVotes::get() as u64;
+
+            for voter in 1..=max_escrowed_votes {
+                escrow_vote(voter, 20, vec![voter as u8], public_key);
+            }
+
+            let voter = max_escrowed_votes + 1;
+            let commitment = make_commitment_for_applicant(20, &mut vec![0u8]);
+            let _ = Balances::deposit_creating(&voter, 1000);
+
+            assert_err!(
+                Election::vote_encrypted(
+                    Origin::signed(voter),
+                    commitment,
+                    100,
+                    EncryptedVote::default()
+                ),
+                "max escrowed votes of the election round reached"
+            );
+            assert_eq!(
+                Election::escrowed_commitments().len() as u64,
+                max_escrowed_votes
+            );
+        });
+    }
+
+    #[test]
+    fn escrowed_votes_are_not_revealed_without_key() {
+        initial_test_ext().execute_with(|| {
+            start_escrow_election();
+
+            let public_key = vote_escrow::public_key(ELECTION_SECRET_KEY);
+            let commitment = escrow_vote(5, 20, vec![1u8], public_key);
+
+            Election::reveal_escrowed_votes();
+
+            assert!(Election::votes(commitment).is_not_revealed());
+        });
+    }
+
+    #[test]
+    fn disclosing_invalid_election_key_should_not_work() {
+        initial_test_ext().execute_with(|| {
+            start_escrow_election();
+
+            assert_err!(
+                Election::disclose_election_key(Origin::signed(1), ELECTION_SECRET_KEY),
+                "election not in revealing stage"
+            );
+
+            <Stage<Test>>::put(ElectionStage::Revealing(20));
+
+            assert_err!(
+                Election::disclose_election_key(Origin::signed(2), ELECTION_SECRET_KEY),
+                "only councilors can disclose election key"
+            );
+            // the public key doesn't disclose the votes
+            assert_err!(
+                Election::disclose_election_key(
+                    Origin::signed(1),
+                    vote_escrow::public_key(ELECTION_SECRET_KEY)
+                ),
+                "election secret key does not match public key"
+            );
+        });
+    }
+
+    #[test]
+    fn voting_encrypted_without_public_key_should_not_work() {
+        initial_test_ext().execute_with(|| {
+            let _ = Balances::deposit_creating(&5, 1000);
+            <Stage<Test>>::put(ElectionStage::Voting(10));
+
+            assert_err!(
+                Election::vote_encrypted(
+                    Origin::signed(5),
+                    make_commitment_for_applicant(20, &mut vec![1u8]),
+                    100,
+                    EncryptedVote::default()
+                ),
+                "election public key not set"
+            );
+        });
+    }
+
+    #[test]
+    fn archiving_election_should_work() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(10);
+            Round::put(1);
+            insert_applicants(vec![100, 200]);
+
+            let unrevealed_commitment = make_commitment_for_applicant(200, &mut vec![1u8]);
+            let mut votes = mock_votes(vec![
+                //  (voter, stake[new], stake[transferred], applicant)
+                (10, 50, 0, 100),
+            ]);
+            votes.push(SealedVote::new(
+                20,
+                Stake {
+                    new: 30,
+                    transferred: 0,
+                },
+                unrevealed_commitment,
+            ));
+
+            let seat = Seat {
+                member: 100,
+                stake: 10,
+                backers: vec![Backer {
+                    member: 10,
+                    stake: 50,
+                }],
+                term_ends_at: 0,
+            };
+            let mut new_council = BTreeMap::new();
+            new_council.insert(100, seat.clone());
+
+            Election::archive_election(&votes, &new_council);
+
+            assert_eq!(Election::archived_rounds(), vec![1]);
+            assert_eq!(
+                Election::election_archive(1),
+                Some(ElectionRecord {
+                    ended_at: 10,
+                    applicants: vec![(100, 10), (200, 10)],
+                    revealed_votes: vec![ArchivedVote {
+                        voter: 10,
+                        commitment: votes[0].commitment,
+                        stake: 50,
+                        ranking: vec![100],
+                    }],
+                    unrevealed_commitments: vec![unrevealed_commitment],
+                    seats: vec![seat],
+                })
+            );
+        });
+    }
+
+    #[test]
+    fn ranked_election_archives_revealed_votes_backing_no_elected_applicant() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(10);
+            insert_applicants(vec![100, 200, 300]);
+            CouncilSize::put(1);
+            ElectionTallyMethod::put(TallyMethod::SequentialPhragmen);
+
+            let ranked_commitment = make_commitment_for_ranking(vec![100], &mut vec![0u8]);
+            <RankedBallots<Test>>::insert(ranked_commitment, vec![100]);
+            <Votes<Test>>::insert(
+                ranked_commitment,
+                SealedVote::new(
+                    10,
+                    Stake {
+                        new: 60,
+                        transferred: 0,
+                    },
+                    ranked_commitment,
+                ),
+            );
+
+            let commitment = make_commitment_for_applicant(300, &mut vec![1u8]);
+            <Votes<Test>>::insert(
+                commitment,
+                SealedVote::new_unsealed(
+                    20,
+                    Stake {
+                        new: 25,
+                        transferred: 0,
+                    },
+                    commitment,
+                    300,
+                ),
+            );
+
+            <Commitments<Test>>::put(vec![ranked_commitment, commitment]);
+
+            Election::on_revealing_ended();
+
+            let record = Election::election_archive(Election::round()).unwrap();
+            assert_eq!(
+                record.revealed_votes,
+                vec![
+                    ArchivedVote {
+                        voter: 10,
+                        commitment: ranked_commitment,
+                        stake: 60,
+                        ranking: vec![100],
+                    },
+                    ArchivedVote {
+                        voter: 20,
+                        commitment,
+                        stake: 25,
+                        ranking: vec![300],
+                    }
+                ]
+            );
+            assert!(record.unrevealed_commitments.is_empty());
+
+            // the vote for the unelected applicant counts for the runners up ranking
+            assert_eq!(Election::runners_up(), vec![300, 200]);
+        });
+    }
+
+    #[test]
+    fn archived_elections_are_limited() {
+        initial_test_ext().execute_with(|| {
+            let max_archived_elections = <Test as election::Trait>::MaxArchivedElections::get();
+
+            for round in 1..=max_archived_elections + 1 {
+                Round::put(round);
+                Election::archive_election(&[], &BTreeMap::new());
+            }
+
+            assert_eq!(
+                Election::archived_rounds(),
+                (2..=max_archived_elections + 1).collect::<Vec<_>>()
+            );
+            assert!(Election::election_archive(1).is_none());
+            assert!(Election::election_archive(2).is_some());
+        });
+    }
+
+    fn recall_councilor(councilor: u64, backing_stake: u64) {
+        let _ = Balances::deposit_creating(&1, backing_stake);
+
+        assert_ok!(Council::back_recall_motion(
+            Origin::signed(1),
+            councilor,
+            backing_stake
+        ));
+        assert!(!Council::is_councilor(&councilor));
+    }
+
+    fn elect_backed_councilor(councilor: u64, stake: u64, backer: u64, backing_stake: u64) {
+        let _ = Balances::deposit_creating(&councilor, stake);
+        let _ = Balances::deposit_creating(&backer, backing_stake);
+        assert_ok!(Balances::reserve(&councilor, stake));
+        assert_ok!(Balances::reserve(&backer, backing_stake));
+
+        <Test as election::Trait>::CouncilElected::council_elected(
+            vec![Seat {
+                member: councilor,
+                stake,
+                backers: vec![Backer {
+                    member: backer,
+                    stake: backing_stake,
+                }],
+                term_ends_at: 0,
+            }],
+            50,
+        );
+    }
+
+    #[test]
+    fn recalled_seat_is_filled_by_runner_up() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            elect_backed_councilor(5, 100, 2, 50);
+            // the runner up who is not a member anymore is skipped
+            <RunnersUp<Test>>::put(vec![100, 7, 8]);
+
+            recall_councilor(5, 151);
+
+            assert_eq!(
+                Council::active_council(),
+                vec![Seat {
+                    member: 7,
+                    stake: 0,
+                    backers: vec![],
+                    term_ends_at: 51,
+                }]
+            );
+            assert_eq!(Election::runners_up(), vec![8]);
+            assert!(!Election::is_election_running());
+
+            // seat stakes are returned
+            assert_eq!(Balances::reserved_balance(&5), 0);
+            assert_eq!(Balances::reserved_balance(&2), 0);
+        });
+    }
+
+    #[test]
+    fn recalled_seat_starts_by_election_without_runners_up() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(Council::set_terms_per_seat(Origin::ROOT, 2));
+            elect_backed_councilor(5, 100, 2, 50);
+            assert_eq!(Council::term_ends_at(), 51);
+
+            recall_councilor(5, 151);
+
+            assert!(Council::active_council().is_empty());
+            assert!(Election::is_election_running());
+            assert_eq!(Balances::reserved_balance(&5), 0);
+
+            // the by-election fills the vacated seat only
+            assert!(Election::is_by_election());
+            assert_eq!(Election::by_election_seat_terms(), vec![101]);
+            assert_eq!(Election::open_seats_usize(), 1);
+
+            insert_applicants(vec![7, 8]);
+            <ApplicantStakes<Test>>::insert(
+                7,
+                Stake {
+                    new: 20,
+                    transferred: 0,
+                },
+            );
+
+            Election::on_revealing_ended();
+
+            // the elected councilor serves until the vacated seat term end, the council term
+            // is not changed
+            assert_eq!(
+                Council::active_council(),
+                vec![Seat {
+                    member: 7,
+                    stake: 20,
+                    backers: vec![],
+                    term_ends_at: 101,
+                }]
+            );
+            assert_eq!(Council::term_ends_at(), 51);
+            assert_eq!(Election::runners_up(), vec![8]);
+            assert!(!Election::is_election_running());
+            assert!(!Election::is_by_election());
+        });
+    }
+
+    #[test]
+    fn recalled_expiring_seat_is_filled_by_next_election() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            elect_backed_councilor(5, 100, 2, 50);
+
+            recall_councilor(5, 151);
+
+            assert!(Council::active_council().is_empty());
+            assert!(!Election::is_election_running());
+            assert_eq!(Balances::reserved_balance(&5), 0);
+        });
+    }
+
+    #[test]
+    fn failed_by_election_leaves_seat_vacant() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(Council::set_terms_per_seat(Origin::ROOT, 2));
+            elect_backed_councilor(5, 100, 2, 50);
+
+            recall_councilor(5, 151);
+            assert!(Election::is_by_election());
+
+            Election::on_election_failed(true);
+
+            assert!(Council::active_council().is_empty());
+            assert!(!Election::is_election_running());
+            assert!(!Election::is_by_election());
+            // the council term is not extended
+            assert_eq!(Council::term_ends_at(), 51);
+        });
+    }
+
+    #[test]
+    fn council_term_end_replaces_by_election_with_regular_election() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(Council::set_terms_per_seat(Origin::ROOT, 2));
+            elect_backed_councilor(5, 100, 2, 50);
+
+            recall_councilor(5, 151);
+            assert!(Election::is_by_election());
+
+            CouncilSize::put(1);
+            <Election as council::CouncilTermEnded>::council_term_ended();
+
+            assert!(Election::is_election_running());
+            assert!(!Election::is_by_election());
+        });
+    }
+
+    #[test]
+    fn recalled_seat_stakes_are_kept_by_running_election() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            elect_backed_councilor(5, 100, 2, 50);
+            <RunnersUp<Test>>::put(vec![7]);
+            assert_ok!(Election::start_election(Council::active_council()));
+
+            // runners up of the previous election are cleared
+            assert!(Election::runners_up().is_empty());
+            <RunnersUp<Test>>::put(vec![7]);
+
+            recall_councilor(5, 151);
+
+            assert!(Council::active_council().is_empty());
+            assert_eq!(Election::runners_up(), vec![7]);
+            assert_eq!(Balances::reserved_balance(&5), 100);
+            assert_eq!(Balances::reserved_balance(&2), 50);
+        });
+    }
+
+    #[test]
+    fn carried_over_seats_are_not_opened() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(Council::set_terms_per_seat(Origin::ROOT, 2));
+            elect_backed_councilor(5, 100, 2, 50);
+            let _ = Balances::deposit_creating(&5, 1000);
+
+            assert_ok!(Election::start_election(Council::expiring_seats()));
+
+            // the carried over councilor keeps the seat stakes
+            assert!(Election::existing_stake_holders().is_empty());
+            assert_eq!(Balances::reserved_balance(&5), 100);
+
+            assert_err!(
+                Election::apply(Origin::signed(5), 1000),
+                "councilors with carried over seats cannot apply"
+            );
+        });
+    }
+
+    #[test]
+    fn election_fills_council_size_less_carried_over_seats() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(Council::set_terms_per_seat(Origin::ROOT, 2));
+            elect_backed_councilor(5, 100, 2, 50);
+
+            CouncilSize::put(3);
+            CandidacyLimit::put(6);
+
+            assert_eq!(Election::open_seats_usize(), 2);
+            assert_eq!(Election::open_candidacy_limit_usize(), 4);
+
+            // the carried over seats fill the council
+            CouncilSize::put(1);
+            assert_eq!(Election::open_seats_usize(), 0);
+            assert_eq!(Election::open_candidacy_limit_usize(), 0);
+
+            CouncilSize::put(0);
+            assert_eq!(Election::open_seats_usize(), 0);
+            assert_eq!(Election::open_candidacy_limit_usize(), 6);
+        });
+    }
+
+    #[test]
+    fn election_is_skipped_when_carried_over_seats_fill_council() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(Council::set_terms_per_seat(Origin::ROOT, 2));
+            elect_backed_councilor(5, 100, 2, 50);
+            elect_backed_councilor(6, 100, 3, 50);
+            CouncilSize::put(1);
+
+            // the seat of the councilor 6 expires with the council term
+            let expiring_seats = Council::expiring_seats();
+            assert_eq!(expiring_seats.len(), 1);
+            assert_eq!(expiring_seats[0].member, 6);
+
+            System::set_block_number(51);
+            <Election as council::CouncilTermEnded>::council_term_ended();
+
+            assert!(!Election::is_election_running());
+
+            // the expiring seat is closed and its stakes are returned
+            assert!(Council::is_councilor(&5));
+            assert!(!Council::is_councilor(&6));
+            assert_eq!(Balances::reserved_balance(&6), 0);
+            assert_eq!(Balances::reserved_balance(&3), 0);
+            assert_eq!(Balances::reserved_balance(&5), 100);
+
+            // the next council term starts with the carried over seats
+            assert_eq!(Council::term_ends_at(), 101);
+        });
+    }
+
+    #[test]
+    fn council_elected_hook_should_work() {
+        initial_test_ext().execute_with(|| {
+            let mut new_council: BTreeMap<u64, Seat<u64, u64, u64>> = BTreeMap::new();
+            new_council.insert(
+                200 as u64,
+                Seat {
+                    member: 200 as u64,
+                    stake: 10 as u64,
+                    backers: vec![],
+                    term_ends_at: 0,
+                },
+            );
+            new_council.insert(
+                300 as u64,
+                Seat {
+                    member: 300 as u64,
+                    stake: 20 as u64,
+                    backers: vec![],
+                    term_ends_at: 0,
+                },
+            );
+
+            assert_eq!(Council::active_council().len(), 0);
+
+            let new_council = new_council
+                .into_iter()
+                .map(|(_, seat)| seat.clone())
+                .collect();
+            <Test as election::Trait>::CouncilElected::council_elected(new_council, 10);
+
+            assert_eq!(Council::active_council().len(), 2);
+        });
+    }
+
+    #[test]
+    fn simulation() {
+        initial_test_ext().execute_with(|| {
+            assert_eq!(Council::active_council().len(), 0);
+            assert!(Election::stage().is_none());
+
+            CouncilSize::put(10);
+            <MinCouncilStake<Test>>::put(50);
+            <AnnouncingPeriod<Test>>::put(10);
+            <VotingPeriod<Test>>::put(10);
+            <RevealingPeriod<Test>>::put(10);
+            CandidacyLimit::put(20);
+            <NewTermDuration<Test>>::put(100);
+            <MinVotingStake<Test>>::put(10);
+
+            for i in 1..30 {
+                let _ = Balances::deposit_creating(&(i as u64), 50000);
+            }
+
+            System::set_block_number(1);
+            assert_ok!(Election::start_election(vec![]));
+
+            for i in 1..20 {
+                if i < 21 {
+                    assert!(Election::apply(Origin::signed(i), 150).is_ok());
+                } else {
+                    assert!(Election::apply(Origin::signed(i + 1000), 150).is_err()); // not enough free balance
+                    assert!(Election::apply(Origin::signed(i), 20).is_err()); // not enough minimum stake
+                }
+            }
+
+            let n = 1 + Election::announcing_period();
+            System::set_block_number(n);
+            let _ = Election::on_finalize(n);
+
+            for i in 1..20 {
+                assert!(Election::vote(
+                    Origin::signed(i),
                     make_commitment_for_applicant(i, &mut vec![40u8]),
                     100
                 )
@@ -2078,6 +4012,129 @@ mod tests {
         });
     }
 
+    fn start_failing_election(
+        max_failed_announcing_rounds: u32,
+        action: FailedElectionPolicy<u64>,
+        applicants: Vec<u64>,
+    ) -> u64 {
+        System::set_block_number(1);
+        CouncilSize::put(3);
+        <AnnouncingPeriod<Test>>::put(10);
+        <VotingPeriod<Test>>::put(10);
+        <RevealingPeriod<Test>>::put(10);
+        <NewTermDuration<Test>>::put(200);
+
+        assert_ok!(Election::set_failed_election_policy(
+            Origin::ROOT,
+            max_failed_announcing_rounds,
+            action
+        ));
+
+        assert_ok!(Election::start_election(vec![]));
+
+        for applicant in applicants.iter() {
+            let _ = Balances::deposit_creating(applicant, 100);
+            assert_ok!(Election::apply(Origin::signed(*applicant), 50));
+        }
+
+        // the block of the announcing end
+        1 + Election::announcing_period()
+    }
+
+    #[test]
+    fn failed_election_extends_council_term() {
+        initial_test_ext().execute_with(|| {
+            let n =
+                start_failing_election(2, FailedElectionPolicy::ExtendCouncilTerm(100), vec![1]);
+
+            System::set_block_number(n);
+            Election::on_finalize(n);
+
+            // announcing is restarted after the first failed round
+            assert_eq!(Election::failed_announcing_rounds(), 1);
+            assert_announcing_period(n + Election::announcing_period());
+
+            let n = n + Election::announcing_period();
+            System::set_block_number(n);
+            Election::on_finalize(n);
+
+            assert!(!Election::is_election_running());
+            assert_eq!(Election::failed_announcing_rounds(), 0);
+            assert!(Election::applicants().is_empty());
+            assert_eq!(Balances::reserved_balance(&1), 0);
+            assert_eq!(Council::term_ends_at(), n + 100);
+        });
+    }
+
+    #[test]
+    fn failed_election_elects_reduced_council() {
+        initial_test_ext().execute_with(|| {
+            let n =
+                start_failing_election(1, FailedElectionPolicy::ElectReducedCouncil, vec![1, 2]);
+
+            System::set_block_number(n);
+            Election::on_finalize(n);
+
+            match Election::stage() {
+                Some(ElectionStage::Voting(_)) => {}
+                _ => assert!(false, "Election Stage was not correctly set to Voting"),
+            }
+
+            let n = n + Election::voting_period() + Election::revealing_period();
+            System::set_block_number(n - Election::revealing_period());
+            Election::on_finalize(n - Election::revealing_period());
+            System::set_block_number(n);
+            Election::on_finalize(n);
+
+            assert!(!Election::is_election_running());
+            assert_eq!(Council::active_council().len(), 2);
+        });
+    }
+
+    #[test]
+    fn failed_election_without_applicants_extends_council_term() {
+        initial_test_ext().execute_with(|| {
+            let n = start_failing_election(1, FailedElectionPolicy::ElectReducedCouncil, vec![]);
+
+            System::set_block_number(n);
+            Election::on_finalize(n);
+
+            assert!(!Election::is_election_running());
+            assert_eq!(Council::term_ends_at(), n + Election::new_term_duration());
+        });
+    }
+
+    #[test]
+    fn zero_period_stage_ends_at_next_block() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(Election::start_election(vec![]));
+            assert_announcing_period(1);
+
+            System::set_block_number(2);
+            Election::on_finalize(2);
+
+            match Election::stage() {
+                Some(ElectionStage::Voting(_)) => {}
+                _ => assert!(false, "Election Stage was not correctly set to Voting"),
+            }
+        });
+    }
+
+    #[test]
+    fn setting_zero_council_term_extension_should_not_work() {
+        initial_test_ext().execute_with(|| {
+            assert_err!(
+                Election::set_failed_election_policy(
+                    Origin::ROOT,
+                    1,
+                    FailedElectionPolicy::ExtendCouncilTerm(0)
+                ),
+                "council term extension cannot be zero"
+            );
+        });
+    }
+
     #[test]
     fn setting_election_parameters() {
         initial_test_ext().execute_with(|| {
@@ -2094,6 +4151,8 @@ mod tests {
                 min_voting_stake: 6,
                 min_council_stake: 7,
                 new_term_duration: 8,
+                tally_method: TallyMethod::SingleTransferableVote,
+                max_ranked_candidates: 9,
             };
 
             assert_ok!(Election::set_election_parameters(
@@ -2124,6 +4183,11 @@ mod tests {
                 <MinVotingStake<Test>>::get(),
                 new_parameters.min_voting_stake
             );
+            assert_eq!(ElectionTallyMethod::get(), new_parameters.tally_method);
+            assert_eq!(
+                MaxRankedCandidates::get(),
+                new_parameters.max_ranked_candidates
+            );
         });
     }
 }
@@ -8,6 +8,36 @@ pub static MSG_PERIOD_CANNOT_BE_ZERO: &str = "PeriodCannotBeZero";
 pub static MSG_COUNCIL_SIZE_CANNOT_BE_ZERO: &str = "CouncilSizeCannotBeZero";
 pub static MSG_CANDIDACY_LIMIT_WAS_LOWER_THAN_COUNCIL_SIZE: &str =
     "CandidacyWasLessThanCouncilSize";
+pub static MSG_MAX_RANKED_CANDIDATES_CANNOT_BE_ZERO: &str = "MaxRankedCandidatesCannotBeZero";
+
+/// Method of the election votes tally.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, Debug)]
+pub enum TallyMethod {
+    /// Every vote backs a single applicant, top staked applicants get elected.
+    FirstPastThePost,
+
+    /// Ranked ballots are tallied using the weighted single transferable vote with
+    /// the Droop quota.
+    SingleTransferableVote,
+
+    /// Ranked ballots are tallied using the sequential Phragmén method. Ranked candidates are
+    /// considered equally approved.
+    SequentialPhragmen,
+}
+
+impl Default for TallyMethod {
+    fn default() -> Self {
+        TallyMethod::FirstPastThePost
+    }
+}
+
+impl TallyMethod {
+    /// Returns true if the votes are revealed as the ranked ballots.
+    pub fn is_ranked(&self) -> bool {
+        *self != TallyMethod::FirstPastThePost
+    }
+}
 
 /// Combined Election parameters, as argument for set_election_parameters
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -21,12 +51,15 @@ pub struct ElectionParameters<Balance, BlockNumber> {
     pub new_term_duration: BlockNumber,
     pub min_council_stake: Balance,
     pub min_voting_stake: Balance,
+    pub tally_method: TallyMethod,
+    pub max_ranked_candidates: u32,
 }
 
 impl<Balance, BlockNumber: PartialOrd + Zero> ElectionParameters<Balance, BlockNumber> {
     pub fn ensure_valid(&self) -> Result {
         self.ensure_periods_are_valid()?;
         self.ensure_council_size_and_candidacy_limit_are_valid()?;
+        self.ensure_max_ranked_candidates_is_valid()?;
         Ok(())
     }
 
@@ -45,4 +78,14 @@ impl<Balance, BlockNumber: PartialOrd + Zero> ElectionParameters<Balance, BlockN
         );
         Ok(())
     }
+
+    fn ensure_max_ranked_candidates_is_valid(&self) -> Result {
+        if self.tally_method.is_ranked() {
+            ensure!(
+                self.max_ranked_candidates > 0,
+                MSG_MAX_RANKED_CANDIDATES_CANNOT_BE_ZERO
+            );
+        }
+        Ok(())
+    }
 }
@@ -4,7 +4,10 @@
 pub mod council;
 pub mod election;
 pub mod election_params;
+pub mod vote_escrow;
 
+mod migration;
+mod ranked_tally;
 mod sealed_vote;
 mod stake;
 
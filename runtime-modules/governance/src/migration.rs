@@ -0,0 +1,90 @@
+//! Migration of the council saved by the previous runtime version.
+//! The previous seat layout lacks the seat term end.
+
+use codec::{Decode, Encode};
+use rstd::prelude::*;
+use srml_support::storage::unhashed;
+use srml_support::StorageValue;
+
+use crate::council::{ActiveCouncil, BalanceOf, Module, Trait};
+use crate::election::{Backer, Seat, Seats};
+
+// Council seat of the previous runtime version.
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub(crate) struct LegacySeat<AccountId, Balance> {
+    pub member: AccountId,
+    pub stake: Balance,
+    pub backers: Vec<Backer<AccountId, Balance>>,
+}
+
+impl<AccountId, Balance> LegacySeat<AccountId, Balance> {
+    fn into_seat<BlockNumber>(
+        self,
+        term_ends_at: BlockNumber,
+    ) -> Seat<AccountId, Balance, BlockNumber> {
+        Seat {
+            member: self.member,
+            stake: self.stake,
+            backers: self.backers,
+            term_ends_at,
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// Migrates the active council saved by the previous runtime version to the current layout.
+    /// Seats expire with the current council term.
+    /// Should be called on the migration to the new runtime version only once.
+    pub fn migrate_active_council() {
+        let key = <ActiveCouncil<T>>::hashed_key();
+
+        if let Some(legacy_seats) =
+            unhashed::get::<Vec<LegacySeat<T::AccountId, BalanceOf<T>>>>(&key)
+        {
+            let seats: Seats<T::AccountId, BalanceOf<T>, T::BlockNumber> = legacy_seats
+                .into_iter()
+                .map(|seat| seat.into_seat(Self::term_ends_at()))
+                .collect();
+
+            <ActiveCouncil<T>>::put(seats);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::*;
+
+    #[test]
+    fn active_council_is_migrated_with_the_council_term_end() {
+        initial_test_ext().execute_with(|| {
+            <crate::council::TermEndsAt<Test>>::put(50);
+
+            let legacy_seats = vec![LegacySeat::<u64, u64> {
+                member: 5,
+                stake: 100,
+                backers: vec![Backer {
+                    member: 2,
+                    stake: 50,
+                }],
+            }];
+            unhashed::put(&<ActiveCouncil<Test>>::hashed_key(), &legacy_seats);
+
+            Council::migrate_active_council();
+
+            assert_eq!(
+                Council::active_council(),
+                vec![Seat {
+                    member: 5,
+                    stake: 100,
+                    backers: vec![Backer {
+                        member: 2,
+                        stake: 50,
+                    }],
+                    term_ends_at: 50,
+                }]
+            );
+        });
+    }
+}
@@ -13,6 +13,7 @@ pub use sr_primitives::{
 };
 
 use srml_support::{impl_outer_origin, parameter_types};
+use std::cell::RefCell;
 
 impl_outer_origin! {
     pub enum Origin for Test {}
@@ -52,15 +53,48 @@ impl timestamp::Trait for Test {
     type OnTimestampSet = ();
     type MinimumPeriod = MinimumPeriod;
 }
+parameter_types! {
+    pub const MinRecallMotionStake: u64 = 50;
+}
 impl council::Trait for Test {
     type Event = ();
 
     type CouncilTermEnded = (Election,);
+    type CouncilSeatVacated = (Election,);
+    type VotingParticipation = MockVotingParticipation;
+    type MinRecallMotionStake = MinRecallMotionStake;
+}
+
+thread_local!(static VOTING_PARTICIPATION: RefCell<(u32, u32)> = RefCell::new((0, 0)));
+
+// Every councilor has the same voting participation.
+pub struct MockVotingParticipation;
+impl MockVotingParticipation {
+    pub fn set(voted: u32, voting_rounds: u32) {
+        VOTING_PARTICIPATION.with(|cell| {
+            *cell.borrow_mut() = (voted, voting_rounds);
+        });
+    }
+}
+impl council::VotingParticipation<u64> for MockVotingParticipation {
+    fn voting_participation(_councilor: &u64) -> (u32, u32) {
+        VOTING_PARTICIPATION.with(|cell| *cell.borrow())
+    }
+}
+
+parameter_types! {
+    pub const MaxArchivedElections: u32 = 2;
+    pub const MaxDelegatorsPerDelegate: u32 = 3;
+    pub const MaxEscrowedVotes: u32 = 3;
 }
 impl election::Trait for Test {
     type Event = ();
 
     type CouncilElected = (Council,);
+
+    type MaxArchivedElections = MaxArchivedElections;
+    type MaxDelegatorsPerDelegate = MaxDelegatorsPerDelegate;
+    type MaxEscrowedVotes = MaxEscrowedVotes;
 }
 impl membership::members::Trait for Test {
     type Event = ();
@@ -76,6 +110,7 @@ impl minting::Trait for Test {
 }
 impl recurringrewards::Trait for Test {
     type PayoutStatusHandler = ();
+    type PayoutPenaltyPolicy = Council;
     type RecipientId = u64;
     type RewardRelationshipId = u64;
 }
@@ -0,0 +1,223 @@
+//! Ranked ballots tally methods for the council election.
+//!
+//! Ballots are weighted by the voting stake. Ties are resolved in favor of the candidate listed
+//! earlier in the candidates list.
+
+use rstd::prelude::*;
+use sr_primitives::Perbill;
+
+/// Candidates in the order of preference and the ballot weight.
+pub type RankedBallot<AccountId> = (Vec<AccountId>, u64);
+
+// Fixed point scale of the sequential Phragmén loads.
+const PHRAGMEN_SCALE: u128 = u64::max_value() as u128;
+
+// Max bits of the sequential Phragmén ballot weight. Keeps the fixed point arithmetic in bounds.
+const PHRAGMEN_WEIGHT_BITS: u32 = 32;
+
+/// Elects up to `seats` candidates using the weighted single transferable vote with the Droop
+/// quota. Surplus of the elected candidate is transferred to the next preferences of its ballots
+/// with the reduced weights. The last candidate is excluded when nobody reaches the quota.
+/// Returns the elected candidates in the election order.
+pub fn single_transferable_vote<AccountId: PartialEq + Clone>(
+    ballots: &[RankedBallot<AccountId>],
+    candidates: &[AccountId],
+    seats: usize,
+) -> Vec<AccountId> {
+    let mut elected = Vec::new();
+    let mut continuing = candidates.to_vec();
+    let mut weights: Vec<u128> = ballots
+        .iter()
+        .map(|(_, weight)| u128::from(*weight))
+        .collect();
+
+    let total_weight: u128 = weights.iter().sum();
+    let quota = total_weight / (seats as u128 + 1) + 1;
+
+    while elected.len() < seats && !continuing.is_empty() {
+        // continuing candidates fill the remaining seats
+        if elected.len() + continuing.len() <= seats {
+            elected.append(&mut continuing);
+            break;
+        }
+
+        // index of the top continuing preference of every ballot
+        let preferences: Vec<Option<usize>> = ballots
+            .iter()
+            .map(|(ranking, _)| {
+                ranking
+                    .iter()
+                    .find_map(|candidate| continuing.iter().position(|c| c == candidate))
+            })
+            .collect();
+
+        let mut tallies = vec![0u128; continuing.len()];
+        for (preference, weight) in preferences.iter().zip(weights.iter()) {
+            if let Some(index) = preference {
+                tallies[*index] += weight;
+            }
+        }
+
+        let (top_index, top_tally) =
+            tallies
+                .iter()
+                .enumerate()
+                .fold((0, 0), |(top_index, top_tally), (index, tally)| {
+                    if *tally > top_tally {
+                        (index, *tally)
+                    } else {
+                        (top_index, top_tally)
+                    }
+                });
+
+        if top_tally >= quota {
+            let surplus_ratio = Perbill::from_rational_approximation(top_tally - quota, top_tally);
+
+            for (preference, weight) in preferences.iter().zip(weights.iter_mut()) {
+                if *preference == Some(top_index) {
+                    *weight = surplus_ratio * *weight;
+                }
+            }
+
+            elected.push(continuing.remove(top_index));
+        } else {
+            let (last_index, _) = tallies.iter().enumerate().fold(
+                (0, u128::max_value()),
+                |(last_index, last_tally), (index, tally)| {
+                    if *tally <= last_tally {
+                        (index, *tally)
+                    } else {
+                        (last_index, last_tally)
+                    }
+                },
+            );
+
+            continuing.remove(last_index);
+        }
+    }
+
+    elected
+}
+
+/// Elects up to `seats` candidates using the sequential Phragmén method. Every ballot approves
+/// all its ranked candidates. Candidates without approvals are not elected.
+/// Returns the elected candidates in the election order.
+pub fn sequential_phragmen<AccountId: PartialEq + Clone>(
+    ballots: &[RankedBallot<AccountId>],
+    candidates: &[AccountId],
+    seats: usize,
+) -> Vec<AccountId> {
+    let max_weight = ballots.iter().map(|(_, weight)| *weight).max().unwrap_or(0);
+    let weight_shift = (64 - max_weight.leading_zeros()).saturating_sub(PHRAGMEN_WEIGHT_BITS);
+
+    let weights: Vec<u128> = ballots
+        .iter()
+        .map(|(_, weight)| {
+            // non-zero weights are kept non-zero after the reduction
+            let reduced_weight = *weight >> weight_shift;
+            if reduced_weight == 0 && *weight > 0 {
+                1
+            } else {
+                u128::from(reduced_weight)
+            }
+        })
+        .collect();
+
+    let mut elected = Vec::new();
+    let mut continuing = candidates.to_vec();
+    let mut loads = vec![0u128; ballots.len()];
+
+    while elected.len() < seats {
+        let mut best: Option<(usize, u128)> = None;
+
+        for (index, candidate) in continuing.iter().enumerate() {
+            let mut approval_weight = 0u128;
+            let mut weighted_load = 0u128;
+
+            for (((ranking, _), weight), load) in
+                ballots.iter().zip(weights.iter()).zip(loads.iter())
+            {
+                if ranking.contains(candidate) {
+                    approval_weight += weight;
+                    weighted_load = weighted_load.saturating_add(weight.saturating_mul(*load));
+                }
+            }
+
+            if approval_weight == 0 {
+                continue;
+            }
+
+            // load of the candidate voters after the candidate election
+            let score = PHRAGMEN_SCALE.saturating_add(weighted_load) / approval_weight;
+
+            match best {
+                Some((_, best_score)) if best_score <= score => {}
+                _ => best = Some((index, score)),
+            }
+        }
+
+        if let Some((index, score)) = best {
+            let candidate = continuing.remove(index);
+
+            for ((ranking, _), load) in ballots.iter().zip(loads.iter_mut()) {
+                if ranking.contains(&candidate) {
+                    *load = score;
+                }
+            }
+
+            elected.push(candidate);
+        } else {
+            break;
+        }
+    }
+
+    elected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_transferable_vote_transfers_surplus() {
+        let ballots = vec![(vec![1, 2], 60), (vec![3], 25), (vec![2], 15)];
+
+        // quota is 34, the surplus of the candidate 1 elects the candidate 2
+        assert_eq!(
+            single_transferable_vote(&ballots, &[1, 2, 3], 2),
+            vec![1, 2]
+        );
+    }
+
+    #[test]
+    fn single_transferable_vote_excludes_last_candidate() {
+        let ballots = vec![(vec![3, 2], 30), (vec![1], 40), (vec![2], 35)];
+
+        // quota is 53, the candidate 3 is excluded and its ballot elects the candidate 2
+        assert_eq!(single_transferable_vote(&ballots, &[1, 2, 3], 1), vec![2]);
+    }
+
+    #[test]
+    fn single_transferable_vote_fills_remaining_seats() {
+        let ballots = vec![(vec![1], 10)];
+
+        assert_eq!(
+            single_transferable_vote(&ballots, &[1, 2, 3], 3),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn sequential_phragmen_elects_proportionally() {
+        let ballots = vec![(vec![1, 2], 60), (vec![3], 40)];
+
+        assert_eq!(sequential_phragmen(&ballots, &[1, 2, 3], 2), vec![1, 3]);
+    }
+
+    #[test]
+    fn sequential_phragmen_skips_not_approved_candidates() {
+        let ballots = vec![(vec![2], u64::max_value()), (vec![1, 2], 1)];
+
+        assert_eq!(sequential_phragmen(&ballots, &[1, 2, 3], 3), vec![2, 1]);
+    }
+}
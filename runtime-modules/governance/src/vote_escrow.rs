@@ -0,0 +1,135 @@
+//! Encryption of the escrowed votes to the election public key.
+//!
+//! The election key is an x25519 key pair: only its public key is known during the voting,
+//! the secret key is disclosed at the revealing stage. A voter encrypts the vote with a fresh
+//! ephemeral key pair. The shared secret of the ephemeral secret key and the election public key
+//! keys the payload keystream: concatenated hashes of the shared secret, the vote commitment
+//! and the keystream block index. The election secret key and the ephemeral public key give
+//! the same shared secret on decryption.
+//!
+//! The decrypted payload is authenticated by the vote commitment: the vote is revealed only
+//! when the decrypted vote and salt match the commitment.
+
+use codec::{Decode, Encode};
+use rstd::prelude::*;
+use x25519_dalek::{x25519, X25519_BASEPOINT_BYTES};
+
+/// Max length of the encrypted vote payload.
+pub const MAX_ENCRYPTED_VOTE_LENGTH: usize = 2048;
+
+/// x25519 public key.
+pub type PublicKey = [u8; 32];
+
+/// x25519 secret key.
+pub type SecretKey = [u8; 32];
+
+/// Vote encrypted to the election public key.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, Debug)]
+pub struct EncryptedVote {
+    /// Public key of the ephemeral key pair of the voter.
+    pub ephemeral_public_key: PublicKey,
+
+    /// Serialized vote and salt XORed with the keystream.
+    pub payload: Vec<u8>,
+}
+
+/// Derives the public key of the secret key.
+pub fn public_key(secret_key: SecretKey) -> PublicKey {
+    x25519(secret_key, X25519_BASEPOINT_BYTES)
+}
+
+/// Encrypts the payload to the election public key with the ephemeral secret key of the voter.
+/// The ephemeral secret key must be random and used for a single vote only.
+pub fn encrypt_vote<Hash: AsRef<[u8]>>(
+    election_public_key: PublicKey,
+    ephemeral_secret_key: SecretKey,
+    commitment: &[u8],
+    payload: &[u8],
+    hasher: fn(&[u8]) -> Hash,
+) -> EncryptedVote {
+    let shared_secret = x25519(ephemeral_secret_key, election_public_key);
+
+    EncryptedVote {
+        ephemeral_public_key: public_key(ephemeral_secret_key),
+        payload: apply_keystream(&shared_secret, commitment, payload, hasher),
+    }
+}
+
+/// Decrypts the payload of the encrypted vote with the election secret key.
+pub fn decrypt_vote<Hash: AsRef<[u8]>>(
+    election_secret_key: SecretKey,
+    commitment: &[u8],
+    encrypted_vote: &EncryptedVote,
+    hasher: fn(&[u8]) -> Hash,
+) -> Vec<u8> {
+    let shared_secret = x25519(election_secret_key, encrypted_vote.ephemeral_public_key);
+
+    apply_keystream(&shared_secret, commitment, &encrypted_vote.payload, hasher)
+}
+
+// Encrypts or decrypts the payload with the keystream of the shared secret and the vote commitment.
+fn apply_keystream<Hash: AsRef<[u8]>>(
+    shared_secret: &[u8],
+    commitment: &[u8],
+    payload: &[u8],
+    hasher: fn(&[u8]) -> Hash,
+) -> Vec<u8> {
+    let mut keystream = Vec::with_capacity(payload.len());
+    let mut block_index = 0u32;
+
+    while keystream.len() < payload.len() {
+        let mut seed = shared_secret.to_vec();
+        seed.extend_from_slice(commitment);
+        seed.extend_from_slice(&block_index.encode());
+
+        keystream.extend_from_slice(hasher(&seed).as_ref());
+        block_index += 1;
+    }
+
+    payload
+        .iter()
+        .zip(keystream.iter())
+        .map(|(byte, key_byte)| byte ^ key_byte)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sr_primitives::traits::{BlakeTwo256, Hash};
+
+    #[test]
+    fn vote_encrypted_to_public_key_is_decrypted_with_secret_key_only() {
+        let election_secret_key = [1u8; 32];
+        let election_public_key = public_key(election_secret_key);
+        let payload = vec![7u8; 100];
+        let commitment = [2u8; 32];
+
+        let encrypted_vote = encrypt_vote(
+            election_public_key,
+            [3u8; 32],
+            &commitment,
+            &payload,
+            BlakeTwo256::hash,
+        );
+        assert_eq!(encrypted_vote.payload.len(), payload.len());
+        assert_ne!(encrypted_vote.payload, payload);
+
+        // the public data known to the voters doesn't decrypt the payload
+        for key in vec![election_public_key, encrypted_vote.ephemeral_public_key] {
+            let decrypted = decrypt_vote(key, &commitment, &encrypted_vote, BlakeTwo256::hash);
+            assert_ne!(decrypted, payload);
+        }
+
+        let decrypted = decrypt_vote([4u8; 32], &commitment, &encrypted_vote, BlakeTwo256::hash);
+        assert_ne!(decrypted, payload);
+
+        let decrypted = decrypt_vote(
+            election_secret_key,
+            &commitment,
+            &encrypted_vote,
+            BlakeTwo256::hash,
+        );
+        assert_eq!(decrypted, payload);
+    }
+}
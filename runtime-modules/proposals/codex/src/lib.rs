@@ -10,7 +10,11 @@
 //! proposals module. `Codex` uses predefined parameters (eg.:`voting_period`) for each proposal and
 //! encodes extrinsic calls from dependency modules in order to create proposals inside the `engine`
 //! module. For each proposal, [its crucial details](./enum.ProposalDetails.html) are saved to the
-//! `ProposalDetailsByProposalId` map.
+//! `ProposalDetailsByProposalId` map. The discussion thread of the proposal is created in the mode
+//! provided by the proposer and gets closed on the proposal finalization. The proposer (as
+//! the thread author) or a council member can change the mode afterwards using the `discussion`
+//! module `change_thread_mode` extrinsic. Proposal details, theirs amendment history and
+//! the discussion thread id are removed when the `engine` prunes the finalized proposal.
 //!
 //! ### Supported extrinsics (proposal types)
 //! - [create_text_proposal](./struct.Module.html#method.create_text_proposal)
@@ -22,10 +26,32 @@
 //! - [create_evict_storage_provider_proposal](./struct.Module.html#method.create_evict_storage_provider_proposal)
 //! - [create_set_validator_count_proposal](./struct.Module.html#method.create_set_validator_count_proposal)
 //! - [create_set_storage_role_parameters_proposal](./struct.Module.html#method.create_set_storage_role_parameters_proposal)
+//! - [create_batch_proposal](./struct.Module.html#method.create_batch_proposal)
+//! - [create_set_proposal_parameters_proposal](./struct.Module.html#method.create_set_proposal_parameters_proposal)
+//! - [create_add_content_working_group_leader_opening_proposal](./struct.Module.html#method.create_add_content_working_group_leader_opening_proposal)
+//! - [create_begin_review_content_working_group_leader_applications_proposal](./struct.Module.html#method.create_begin_review_content_working_group_leader_applications_proposal)
+//! - [create_fill_content_working_group_leader_opening_proposal](./struct.Module.html#method.create_fill_content_working_group_leader_opening_proposal)
+//! - [create_slash_content_working_group_leader_stake_proposal](./struct.Module.html#method.create_slash_content_working_group_leader_stake_proposal)
+//! - [create_terminate_content_working_group_leader_role_proposal](./struct.Module.html#method.create_terminate_content_working_group_leader_role_proposal)
+//!
+//! ### Runtime upgrade
+//! The runtime upgrade proposal is created with the blake2 hash of the wasm code only. After the
+//! proposal approval anyone can [provide](./struct.Module.html#method.provide_runtime_upgrade_code)
+//! the wasm code matching the hash. The code gets executed via `execute_runtime_upgrade_proposal`.
+//! The approved proposal fails if the code wasn't provided during the `engine` deferred code
+//! timeout.
+//!
+//! ### Proposal amendment
+//! - [amend_proposal](./struct.Module.html#method.amend_proposal) - replaces details of the active
+//! proposal and resets its votes. Previous details are kept in the
+//! `ProposalDetailsAmendmentHistory` map. The number of amendments is limited by the `engine`
+//! _MaxProposalAmendments_ parameter.
 //!
 //! ### Proposal implementations of this module
 //! - execute_text_proposal - prints the proposal to the log
 //! - execute_runtime_upgrade_proposal - sets the runtime code
+//! - set_proposal_parameters - sets the parameters for the proposal type. Parameters set this
+//! way override the default parameters of the proposal type.
 //!
 //! ### Dependencies:
 //! - [proposals engine](../substrate_proposals_engine_module/index.html)
@@ -51,27 +77,34 @@
 // Do not delete! Cannot be uncommented by default, because of Parity decl_module! issue.
 // #![warn(missing_docs)]
 
+mod migration;
 mod proposal_types;
 
 #[cfg(test)]
 mod tests;
 
 use common::origin_validator::ActorOriginValidator;
+use content_working_group::{OpeningPolicyCommitment, OpeningType, RewardPolicy};
 use governance::election_params::ElectionParameters;
-use proposal_engine::ProposalParameters;
+use proposal_discussion::ThreadMode;
+use proposal_engine::{
+    ExecutionRetryPolicy, ProposalFinalizationHandler, ProposalParameters, VotingMode,
+};
 use roles::actors::RoleParameters;
 use rstd::clone::Clone;
 use rstd::prelude::*;
 use rstd::str::from_utf8;
 use rstd::vec::Vec;
-use sr_primitives::traits::Zero;
+use sr_primitives::traits::{Hash, Zero};
 use srml_support::dispatch::DispatchResult;
 use srml_support::traits::{Currency, Get};
-use srml_support::{decl_error, decl_module, decl_storage, ensure, print};
-use system::{ensure_root, RawOrigin};
+use srml_support::{
+    decl_error, decl_module, decl_storage, ensure, print, StorageLinkedMap, StorageValue,
+};
+use system::{ensure_root, ensure_signed, RawOrigin};
 
 pub use crate::proposal_types::ProposalsConfigParameters;
-pub use proposal_types::{ProposalDetails, ProposalDetailsOf, ProposalEncoder};
+pub use proposal_types::{ProposalDetails, ProposalDetailsOf, ProposalEncoder, ProposalType};
 
 // 'Set working group mint capacity' proposal limit
 const CONTENT_WORKING_GROUP_MINT_CAPACITY_MAX_VALUE: u32 = 1_000_000;
@@ -79,6 +112,8 @@ const CONTENT_WORKING_GROUP_MINT_CAPACITY_MAX_VALUE: u32 = 1_000_000;
 const MAX_SPENDING_PROPOSAL_VALUE: u32 = 2_000_000_u32;
 // Max validator count for the 'set validator count' proposal
 const MAX_VALIDATOR_COUNT: u32 = 100;
+// Max number of the proposals in the 'batch' proposal
+const MAX_BATCH_PROPOSAL_ITEMS: u32 = 5;
 // min_actors min value for the 'set storage role parameters' proposal
 const ROLE_PARAMETERS_MIN_ACTORS_MAX_VALUE: u32 = 2;
 // max_actors min value for the 'set storage role parameters' proposal
@@ -149,6 +184,10 @@ const ELECTION_PARAMETERS_ANNOUNCING_PERIOD_MAX_VALUE: u32 = 43200;
 const ELECTION_PARAMETERS_MIN_COUNCIL_STAKE_MIN_VALUE: u32 = 1;
 // min_council_stake max value for the 'set election parameters' proposal
 const ELECTION_PARAMETERS_MIN_COUNCIL_STAKE_MAX_VALUE: u32 = 100_000_u32;
+// max_ranked_candidates min value for the ranked ballots of the 'set election parameters' proposal
+const ELECTION_PARAMETERS_MAX_RANKED_CANDIDATES_MIN_VALUE: u32 = 1;
+// max_ranked_candidates max value for the 'set election parameters' proposal
+const ELECTION_PARAMETERS_MAX_RANKED_CANDIDATES_MAX_VALUE: u32 = 20;
 
 /// 'Proposals codex' substrate module Trait
 pub trait Trait:
@@ -270,6 +309,9 @@ decl_error! {
         /// Invalid council election parameter - announcing_period
         InvalidCouncilElectionParameterAnnouncingPeriod,
 
+        /// Invalid council election parameter - max_ranked_candidates
+        InvalidCouncilElectionParameterMaxRankedCandidates,
+
         /// Invalid council election parameter - min_stake
         InvalidStorageRoleParameterMinStake,
 
@@ -283,7 +325,61 @@ decl_error! {
         InvalidStorageWorkingGroupMintCapacity,
 
         /// Invalid 'set lead proposal' parameter - proposed lead cannot be a councilor
-        InvalidSetLeadParameterCannotBeCouncilor
+        InvalidSetLeadParameterCannotBeCouncilor,
+
+        /// Batch proposal should contain at least one proposal
+        BatchProposalIsEmpty,
+
+        /// Number of the batch proposal items exceeded the limit
+        BatchProposalSizeExceeded,
+
+        /// Batch proposal cannot contain another batch proposal
+        NestedBatchProposal,
+
+        /// Proposal type cannot be changed by the amendment
+        AmendedProposalTypeMismatch,
+
+        /// Invalid 'set proposal parameters' parameter - voting_period cannot be zero
+        InvalidProposalParametersVotingPeriod,
+
+        /// Invalid 'set proposal parameters' parameter - quorum and threshold percentages should
+        /// not exceed 100, thresholds cannot be zero
+        InvalidProposalParametersPercentage,
+
+        /// Invalid 'set proposal parameters' parameter - constitutionality cannot be zero
+        InvalidProposalParametersConstitutionality,
+
+        /// Invalid 'set proposal parameters' parameter - execution retry interval cannot be zero
+        InvalidProposalParametersExecutionRetryInterval,
+
+        /// Content working group opening doesn't exist or is not the leader opening
+        InvalidContentWorkingGroupLeaderOpening,
+
+        /// Content working group application doesn't exist or is not for the leader opening
+        InvalidContentWorkingGroupLeaderApplication,
+
+        /// Content working group leader is not set
+        ContentWorkingGroupLeaderIsNotSet,
+
+        /// Invalid 'slash content working group leader stake' parameter - slashing amount cannot
+        /// be zero
+        InvalidSlashContentWorkingGroupLeaderStakeAmount,
+
+        /// The proposal is not the runtime upgrade proposal waiting for the wasm code
+        NotRuntimeUpgradeCodeHashProposal,
+
+        /// Provided WASM code doesn't match the code hash of the runtime upgrade proposal
+        RuntimeUpgradeCodeHashMismatch,
+
+        /// Batch proposal cannot contain the runtime upgrade proposal with the code hash
+        RuntimeUpgradeCodeHashInBatchProposal,
+
+        /// Batch proposal cannot contain the runtime upgrade proposal
+        RuntimeUpgradeInBatchProposal,
+
+        /// Only the first batch proposal item can fail on execution: executed batch items
+        /// cannot be reverted
+        FallibleBatchProposalItemIsNotFirst
     }
 }
 
@@ -326,13 +422,11 @@ decl_storage! {
 
         /// Map proposal id to proposal details
         pub ProposalDetailsByProposalId get(fn proposal_details_by_proposal_id):
-            map T::ProposalId => ProposalDetails<
-                BalanceOfMint<T>,
-                BalanceOfGovernanceCurrency<T>,
-                T::BlockNumber,
-                T::AccountId,
-                T::MemberId
-            >;
+            map T::ProposalId => ProposalDetailsOf<T>;
+
+        /// Previous proposal details of the amended proposals
+        pub ProposalDetailsAmendmentHistory get(fn proposal_details_amendment_history):
+            map T::ProposalId => Vec<ProposalDetailsOf<T>>;
 
         /// Voting period for the 'set validator count' proposal
         pub SetValidatorCountProposalVotingPeriod get(set_validator_count_proposal_voting_period)
@@ -401,6 +495,59 @@ decl_storage! {
         /// Grace period for the 'set storage role parameters' proposal
         pub SetStorageRoleParametersProposalGracePeriod get(set_storage_role_parameters_proposal_grace_period)
             config(): T::BlockNumber;
+
+        /// Voting period for the 'set proposal parameters' proposal
+        pub SetProposalParametersProposalVotingPeriod get(set_proposal_parameters_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'set proposal parameters' proposal
+        pub SetProposalParametersProposalGracePeriod get(set_proposal_parameters_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'add content working group leader opening' proposal
+        pub AddContentWorkingGroupLeaderOpeningProposalVotingPeriod get(add_content_working_group_leader_opening_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'add content working group leader opening' proposal
+        pub AddContentWorkingGroupLeaderOpeningProposalGracePeriod get(add_content_working_group_leader_opening_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'begin review content working group leader applications' proposal
+        pub BeginReviewContentWorkingGroupLeaderApplicationsProposalVotingPeriod get(begin_review_content_working_group_leader_applications_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'begin review content working group leader applications' proposal
+        pub BeginReviewContentWorkingGroupLeaderApplicationsProposalGracePeriod get(begin_review_content_working_group_leader_applications_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'fill content working group leader opening' proposal
+        pub FillContentWorkingGroupLeaderOpeningProposalVotingPeriod get(fill_content_working_group_leader_opening_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'fill content working group leader opening' proposal
+        pub FillContentWorkingGroupLeaderOpeningProposalGracePeriod get(fill_content_working_group_leader_opening_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'slash content working group leader stake' proposal
+        pub SlashContentWorkingGroupLeaderStakeProposalVotingPeriod get(slash_content_working_group_leader_stake_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'slash content working group leader stake' proposal
+        pub SlashContentWorkingGroupLeaderStakeProposalGracePeriod get(slash_content_working_group_leader_stake_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'terminate content working group leader role' proposal
+        pub TerminateContentWorkingGroupLeaderRoleProposalVotingPeriod get(terminate_content_working_group_leader_role_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'terminate content working group leader role' proposal
+        pub TerminateContentWorkingGroupLeaderRoleProposalGracePeriod get(terminate_content_working_group_leader_role_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Proposal parameters set by the 'set proposal parameters' proposal. Override the default
+        /// parameters of the proposal type.
+        pub ProposalParametersByProposalType get(fn proposal_parameters_by_proposal_type):
+            map ProposalType => Option<ProposalParameters<T::BlockNumber, BalanceOf<T>>>;
     }
 }
 
@@ -423,14 +570,21 @@ decl_module! {
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
+            thread_mode: ThreadMode<MemberId<T>>,
             text: Vec<u8>,
         ) {
-            ensure!(!text.is_empty(), Error::TextProposalIsEmpty);
-            ensure!(text.len() as u32 <=  T::TextProposalMaxLength::get(),
-                Error::TextProposalSizeExceeded);
-
             let proposal_parameters = proposal_types::parameters::text_proposal::<T>();
-            let proposal_details = ProposalDetails::<BalanceOfMint<T>, BalanceOfGovernanceCurrency<T>, T::BlockNumber, T::AccountId, MemberId<T>>::Text(text);
+            let proposal_details = ProposalDetails::<
+                BalanceOfMint<T>,
+                BalanceOfGovernanceCurrency<T>,
+                T::BlockNumber,
+                T::AccountId,
+                MemberId<T>,
+                content_working_group::CuratorOpeningId<T>,
+                content_working_group::CuratorApplicationId<T>,
+            >::Text(text);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
             let proposal_code = T::ProposalEncoder::encode_proposal(proposal_details.clone());
 
             Self::create_proposal(
@@ -439,29 +593,32 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                thread_mode,
                 proposal_code,
                 proposal_parameters,
                 proposal_details,
+                None,
             )?;
         }
 
         /// Create 'Runtime upgrade' proposal type. Runtime upgrade can be initiated only by
         /// members from the hardcoded list `RuntimeUpgradeProposalAllowedProposers`
+        /// The proposal contains only the hash of the wasm code. The code should be provided
+        /// after the proposal approval.
+        /// Optional `exactly_execute_at` block schedules the upgrade execution at a known height.
         pub fn create_runtime_upgrade_proposal(
             origin,
             member_id: MemberId<T>,
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
-            wasm: Vec<u8>,
+            thread_mode: ThreadMode<MemberId<T>>,
+            code_hash: T::Hash,
+            exactly_execute_at: Option<T::BlockNumber>,
         ) {
-            ensure!(!wasm.is_empty(), Error::RuntimeProposalIsEmpty);
-            ensure!(wasm.len() as u32 <= T::RuntimeUpgradeWasmProposalMaxLength::get(),
-                Error::RuntimeProposalSizeExceeded);
-
             let proposal_parameters = proposal_types::parameters::runtime_upgrade_proposal::<T>();
-            let proposal_details = ProposalDetails::RuntimeUpgrade(wasm);
-            let proposal_code = T::ProposalEncoder::encode_proposal(proposal_details.clone());
+            let proposal_details = ProposalDetails::RuntimeUpgradeCodeHash(code_hash);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
 
             Self::create_proposal(
                 origin,
@@ -469,12 +626,42 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
-                proposal_code,
+                thread_mode,
+                Vec::new(),
                 proposal_parameters,
                 proposal_details,
+                exactly_execute_at,
             )?;
         }
 
+        /// Provide the wasm code for the approved 'Runtime upgrade' proposal. The code hash
+        /// should match the hash of the proposal. Can be called by any signed origin.
+        pub fn provide_runtime_upgrade_code(
+            origin,
+            proposal_id: T::ProposalId,
+            wasm: Vec<u8>,
+        ) {
+            ensure_signed(origin)?;
+
+            let code_hash = if let ProposalDetails::RuntimeUpgradeCodeHash(code_hash) =
+                Self::proposal_details_by_proposal_id(proposal_id) {
+                code_hash
+            } else {
+                return Err(Error::NotRuntimeUpgradeCodeHashProposal);
+            };
+
+            Self::ensure_runtime_upgrade_code_valid(&wasm)?;
+            ensure!(
+                <T as system::Trait>::Hashing::hash(&wasm) == code_hash,
+                Error::RuntimeUpgradeCodeHashMismatch
+            );
+
+            let proposal_code =
+                T::ProposalEncoder::encode_proposal(ProposalDetails::RuntimeUpgrade(wasm));
+
+            <proposal_engine::Module<T>>::provide_proposal_code(proposal_id, proposal_code)?;
+        }
+
         /// Create 'Set election parameters' proposal type. This proposal uses `set_election_parameters()`
         /// extrinsic from the `governance::election module`.
         pub fn create_set_election_parameters_proposal(
@@ -483,13 +670,12 @@ decl_module! {
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
+            thread_mode: ThreadMode<MemberId<T>>,
             election_parameters: ElectionParameters<BalanceOfGovernanceCurrency<T>, T::BlockNumber>,
         ) {
-            election_parameters.ensure_valid()?;
-
-            Self::ensure_council_election_parameters_valid(&election_parameters)?;
-
             let proposal_details = ProposalDetails::SetElectionParameters(election_parameters);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
             let proposal_code = T::ProposalEncoder::encode_proposal(proposal_details.clone());
             let proposal_parameters =
                 proposal_types::parameters::set_election_parameters_proposal::<T>();
@@ -500,9 +686,11 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                thread_mode,
                 proposal_code,
                 proposal_parameters,
                 proposal_details,
+                None,
             )?;
         }
 
@@ -514,16 +702,14 @@ decl_module! {
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
+            thread_mode: ThreadMode<MemberId<T>>,
             mint_balance: BalanceOfMint<T>,
         ) {
-            ensure!(
-                mint_balance <= <BalanceOfMint<T>>::from(CONTENT_WORKING_GROUP_MINT_CAPACITY_MAX_VALUE),
-                Error::InvalidStorageWorkingGroupMintCapacity
-            );
-
             let proposal_parameters =
                 proposal_types::parameters::set_content_working_group_mint_capacity_proposal::<T>();
             let proposal_details = ProposalDetails::SetContentWorkingGroupMintCapacity(mint_balance);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
             let proposal_code = T::ProposalEncoder::encode_proposal(proposal_details.clone());
 
             Self::create_proposal(
@@ -532,9 +718,11 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                thread_mode,
                 proposal_code,
                 proposal_parameters,
                 proposal_details,
+                None,
             )?;
         }
 
@@ -546,18 +734,15 @@ decl_module! {
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
+            thread_mode: ThreadMode<MemberId<T>>,
             balance: BalanceOfMint<T>,
             destination: T::AccountId,
         ) {
-            ensure!(balance != BalanceOfMint::<T>::zero(), Error::InvalidSpendingProposalBalance);
-            ensure!(
-                balance <= <BalanceOfMint<T>>::from(MAX_SPENDING_PROPOSAL_VALUE),
-                Error::InvalidSpendingProposalBalance
-            );
-
             let proposal_parameters =
                 proposal_types::parameters::spending_proposal::<T>();
             let proposal_details = ProposalDetails::Spending(balance, destination);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
             let proposal_code = T::ProposalEncoder::encode_proposal(proposal_details.clone());
 
             Self::create_proposal(
@@ -566,9 +751,11 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                thread_mode,
                 proposal_code,
                 proposal_parameters,
                 proposal_details,
+                None,
             )?;
         }
 
@@ -580,19 +767,14 @@ decl_module! {
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
+            thread_mode: ThreadMode<MemberId<T>>,
             new_lead: Option<(T::MemberId, T::AccountId)>
         ) {
-            if let Some(lead) = new_lead.clone() {
-                let account_id = lead.1;
-                ensure!(
-                    !<governance::council::Module<T>>::is_councilor(&account_id),
-                    Error::InvalidSetLeadParameterCannotBeCouncilor
-                );
-            }
-
             let proposal_parameters =
                 proposal_types::parameters::set_lead_proposal::<T>();
             let proposal_details = ProposalDetails::SetLead(new_lead);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
             let proposal_code = T::ProposalEncoder::encode_proposal(proposal_details.clone());
 
             Self::create_proposal(
@@ -601,9 +783,11 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                thread_mode,
                 proposal_code,
                 proposal_parameters,
                 proposal_details,
+                None,
             )?;
         }
 
@@ -615,6 +799,7 @@ decl_module! {
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
+            thread_mode: ThreadMode<MemberId<T>>,
             actor_account: T::AccountId,
         ) {
             let proposal_parameters =
@@ -628,9 +813,11 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                thread_mode,
                 proposal_code,
                 proposal_parameters,
                 proposal_details,
+                None,
             )?;
         }
 
@@ -642,21 +829,15 @@ decl_module! {
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
+            thread_mode: ThreadMode<MemberId<T>>,
             new_validator_count: u32,
+            exactly_execute_at: Option<T::BlockNumber>,
         ) {
-            ensure!(
-                new_validator_count >= <staking::Module<T>>::minimum_validator_count(),
-                Error::InvalidValidatorCount
-            );
-
-            ensure!(
-                new_validator_count <= MAX_VALIDATOR_COUNT,
-                Error::InvalidValidatorCount
-            );
-
             let proposal_parameters =
                 proposal_types::parameters::set_validator_count_proposal::<T>();
             let proposal_details = ProposalDetails::SetValidatorCount(new_validator_count);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
             let proposal_code = T::ProposalEncoder::encode_proposal(proposal_details.clone());
 
             Self::create_proposal(
@@ -665,9 +846,11 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                thread_mode,
                 proposal_code,
                 proposal_parameters,
                 proposal_details,
+                exactly_execute_at,
             )?;
         }
 
@@ -679,13 +862,275 @@ decl_module! {
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
+            thread_mode: ThreadMode<MemberId<T>>,
             role_parameters: RoleParameters<BalanceOfGovernanceCurrency<T>, T::BlockNumber>
         ) {
-            Self::ensure_storage_role_parameters_valid(&role_parameters)?;
-
             let proposal_parameters =
                 proposal_types::parameters::set_storage_role_parameters_proposal::<T>();
             let proposal_details =  ProposalDetails::SetStorageRoleParameters(role_parameters);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
+            let proposal_code = T::ProposalEncoder::encode_proposal(proposal_details.clone());
+
+            Self::create_proposal(
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                thread_mode,
+                proposal_code,
+                proposal_parameters,
+                proposal_details,
+                None,
+            )?;
+        }
+
+        /// Create 'Batch' proposal type. All provided proposals are voted on and executed
+        /// together. Proposal parameters are the strictest parameters of the batch items.
+        /// The batch is executed all-or-nothing: only the first item can be of the proposal type
+        /// which execution can fail, other items should be parameter changes which cannot fail.
+        pub fn create_batch_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            thread_mode: ThreadMode<MemberId<T>>,
+            proposals: Vec<ProposalDetailsOf<T>>,
+        ) {
+            let proposal_parameters = proposal_types::parameters::batch_proposal::<T>(&proposals);
+            let proposal_details = ProposalDetails::Batch(proposals);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
+            let proposal_code = T::ProposalEncoder::encode_proposal(proposal_details.clone());
+
+            Self::create_proposal(
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                thread_mode,
+                proposal_code,
+                proposal_parameters,
+                proposal_details,
+                None,
+            )?;
+        }
+
+        /// Amend the active proposal by its author. Replaces the proposal details and the
+        /// executable code, resets the voting results and restarts the voting period.
+        /// The proposal type (and the batch item types) cannot be changed.
+        pub fn amend_proposal(
+            origin,
+            member_id: MemberId<T>,
+            proposal_id: T::ProposalId,
+            proposal_details: ProposalDetailsOf<T>,
+        ) {
+            T::MembershipOriginValidator::ensure_actor_origin(origin, member_id)?;
+
+            <proposal_engine::Module<T>>::ensure_proposal_can_be_amended(member_id, proposal_id)?;
+
+            let previous_proposal_details = Self::proposal_details_by_proposal_id(proposal_id);
+            ensure!(
+                Self::is_same_proposal_type(&previous_proposal_details, &proposal_details),
+                Error::AmendedProposalTypeMismatch
+            );
+
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
+            // checks passed
+            // mutation
+
+            if let ProposalDetails::Batch(ref batch_items) = proposal_details {
+                let encoded_calls = batch_items
+                    .iter()
+                    .cloned()
+                    .map(T::ProposalEncoder::encode_proposal)
+                    .collect();
+
+                <proposal_engine::Module<T>>::amend_batch_proposal(
+                    member_id,
+                    proposal_id,
+                    encoded_calls,
+                )?;
+            } else {
+                // the code of the runtime upgrade proposal is provided after the approval
+                let proposal_code =
+                    if let ProposalDetails::RuntimeUpgradeCodeHash(_) = proposal_details {
+                        Vec::new()
+                    } else {
+                        T::ProposalEncoder::encode_proposal(proposal_details.clone())
+                    };
+
+                <proposal_engine::Module<T>>::amend_proposal(
+                    member_id,
+                    proposal_id,
+                    proposal_code,
+                )?;
+            }
+
+            <ProposalDetailsAmendmentHistory<T>>::mutate(proposal_id, |history| {
+                history.push(previous_proposal_details)
+            });
+            <ProposalDetailsByProposalId<T>>::insert(proposal_id, proposal_details);
+        }
+
+        /// Create 'Set proposal parameters' proposal type. This proposal uses
+        /// `set_proposal_parameters()` extrinsic of this module.
+        pub fn create_set_proposal_parameters_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            thread_mode: ThreadMode<MemberId<T>>,
+            proposal_type: ProposalType,
+            proposal_parameters: ProposalParameters<T::BlockNumber, BalanceOfGovernanceCurrency<T>>,
+        ) {
+            let parameters = proposal_types::parameters::set_proposal_parameters_proposal::<T>();
+            let proposal_details =
+                ProposalDetails::SetProposalParameters(proposal_type, proposal_parameters);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
+            let proposal_code = T::ProposalEncoder::encode_proposal(proposal_details.clone());
+
+            Self::create_proposal(
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                thread_mode,
+                proposal_code,
+                parameters,
+                proposal_details,
+                None,
+            )?;
+        }
+
+        /// Create 'Add content working group leader opening' proposal type.
+        /// This proposal uses `add_lead_opening()` extrinsic from the `content_working_group` module.
+        /// The opening starts accepting applications on the proposal execution.
+        pub fn create_add_content_working_group_leader_opening_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            thread_mode: ThreadMode<MemberId<T>>,
+            commitment: OpeningPolicyCommitment<T::BlockNumber, BalanceOfGovernanceCurrency<T>>,
+            human_readable_text: Vec<u8>,
+        ) {
+            let proposal_parameters =
+                proposal_types::parameters::add_content_working_group_leader_opening_proposal::<T>();
+            let proposal_details = ProposalDetails::AddContentWorkingGroupLeaderOpening(commitment, human_readable_text);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
+            let proposal_code = T::ProposalEncoder::encode_proposal(proposal_details.clone());
+
+            Self::create_proposal(
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                thread_mode,
+                proposal_code,
+                proposal_parameters,
+                proposal_details,
+                None,
+            )?;
+        }
+
+        /// Create 'Begin review content working group leader applications' proposal type.
+        /// This proposal uses `begin_lead_applicant_review()` extrinsic from the
+        /// `content_working_group` module.
+        pub fn create_begin_review_content_working_group_leader_applications_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            thread_mode: ThreadMode<MemberId<T>>,
+            opening_id: content_working_group::CuratorOpeningId<T>,
+        ) {
+            let proposal_parameters =
+                proposal_types::parameters::begin_review_content_working_group_leader_applications_proposal::<T>();
+            let proposal_details = ProposalDetails::BeginReviewContentWorkingGroupLeaderApplications(opening_id);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
+            let proposal_code = T::ProposalEncoder::encode_proposal(proposal_details.clone());
+
+            Self::create_proposal(
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                thread_mode,
+                proposal_code,
+                proposal_parameters,
+                proposal_details,
+                None,
+            )?;
+        }
+
+        /// Create 'Fill content working group leader opening' proposal type.
+        /// This proposal uses `fill_lead_opening()` extrinsic from the `content_working_group` module.
+        pub fn create_fill_content_working_group_leader_opening_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            thread_mode: ThreadMode<MemberId<T>>,
+            opening_id: content_working_group::CuratorOpeningId<T>,
+            successful_application_id: content_working_group::CuratorApplicationId<T>,
+            reward_policy: Option<RewardPolicy<BalanceOfMint<T>, T::BlockNumber>>,
+        ) {
+            let proposal_parameters =
+                proposal_types::parameters::fill_content_working_group_leader_opening_proposal::<T>();
+            let proposal_details = ProposalDetails::FillContentWorkingGroupLeaderOpening(
+                opening_id,
+                successful_application_id,
+                reward_policy,
+            );
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
+            let proposal_code = T::ProposalEncoder::encode_proposal(proposal_details.clone());
+
+            Self::create_proposal(
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                thread_mode,
+                proposal_code,
+                proposal_parameters,
+                proposal_details,
+                None,
+            )?;
+        }
+
+        /// Create 'Slash content working group leader stake' proposal type.
+        /// This proposal uses `slash_lead_stake()` extrinsic from the `content_working_group` module.
+        pub fn create_slash_content_working_group_leader_stake_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            thread_mode: ThreadMode<MemberId<T>>,
+            slashing_amount: BalanceOfGovernanceCurrency<T>,
+        ) {
+            let proposal_parameters =
+                proposal_types::parameters::slash_content_working_group_leader_stake_proposal::<T>();
+            let proposal_details = ProposalDetails::SlashContentWorkingGroupLeaderStake(slashing_amount);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
             let proposal_code = T::ProposalEncoder::encode_proposal(proposal_details.clone());
 
             Self::create_proposal(
@@ -694,13 +1139,47 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                thread_mode,
                 proposal_code,
                 proposal_parameters,
                 proposal_details,
+                None,
+            )?;
+        }
+
+        /// Create 'Terminate content working group leader role' proposal type.
+        /// This proposal uses `terminate_lead_role()` extrinsic from the `content_working_group`
+        /// module.
+        pub fn create_terminate_content_working_group_leader_role_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            thread_mode: ThreadMode<MemberId<T>>,
+            rationale_text: Vec<u8>,
+        ) {
+            let proposal_parameters =
+                proposal_types::parameters::terminate_content_working_group_leader_role_proposal::<T>();
+            let proposal_details = ProposalDetails::TerminateContentWorkingGroupLeaderRole(rationale_text);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
+            let proposal_code = T::ProposalEncoder::encode_proposal(proposal_details.clone());
+
+            Self::create_proposal(
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                thread_mode,
+                proposal_code,
+                proposal_parameters,
+                proposal_details,
+                None,
             )?;
         }
 
-// *************** Extrinsic to execute
 
         /// Text proposal extrinsic. Should be used as callable object to pass to the `engine` module.
         pub fn execute_text_proposal(
@@ -730,6 +1209,22 @@ decl_module! {
 
             print("Runtime upgrade proposal execution finished.");
         }
+
+        /// Set proposal parameters extrinsic. Should be used as callable object to pass to the
+        /// `engine` module.
+        pub fn set_proposal_parameters(
+            origin,
+            proposal_type: ProposalType,
+            proposal_parameters: ProposalParameters<T::BlockNumber, BalanceOf<T>>,
+        ) {
+            ensure_root(origin)?;
+
+            Self::ensure_proposal_parameters_valid(&proposal_parameters)?;
+
+            // mutation
+
+            <ProposalParametersByProposalType<T>>::insert(proposal_type, Some(proposal_parameters));
+        }
     }
 }
 
@@ -753,22 +1248,18 @@ impl<T: Trait> Module<T> {
         (cloned_origin1.into(), cloned_origin2.into())
     }
 
-    // Generic template proposal builder
+    // Generic template proposal builder. Creates the discussion thread in the provided mode.
     fn create_proposal(
         origin: T::Origin,
         member_id: MemberId<T>,
         title: Vec<u8>,
         description: Vec<u8>,
         stake_balance: Option<BalanceOf<T>>,
+        thread_mode: ThreadMode<MemberId<T>>,
         proposal_code: Vec<u8>,
         proposal_parameters: ProposalParameters<T::BlockNumber, BalanceOf<T>>,
-        proposal_details: ProposalDetails<
-            BalanceOfMint<T>,
-            BalanceOfGovernanceCurrency<T>,
-            T::BlockNumber,
-            T::AccountId,
-            T::MemberId,
-        >,
+        proposal_details: ProposalDetailsOf<T>,
+        exactly_execute_at: Option<T::BlockNumber>,
     ) -> DispatchResult<Error> {
         let account_id = T::MembershipOriginValidator::ensure_actor_origin(origin, member_id)?;
 
@@ -777,22 +1268,54 @@ impl<T: Trait> Module<T> {
             &title,
             &description,
             stake_balance,
+            exactly_execute_at,
         )?;
 
         <proposal_discussion::Module<T>>::ensure_can_create_thread(member_id, &title)?;
+        <proposal_discussion::Module<T>>::ensure_thread_mode_is_valid(&thread_mode)?;
 
         let discussion_thread_id =
-            <proposal_discussion::Module<T>>::create_thread(member_id, title.clone())?;
-
-        let proposal_id = <proposal_engine::Module<T>>::create_proposal(
-            account_id,
-            member_id,
-            proposal_parameters,
-            title,
-            description,
-            stake_balance,
-            proposal_code,
-        )?;
+            <proposal_discussion::Module<T>>::create_thread(member_id, title.clone(), thread_mode)?;
+
+        let proposal_id = if let ProposalDetails::Batch(ref batch_items) = proposal_details {
+            let encoded_calls = batch_items
+                .iter()
+                .cloned()
+                .map(T::ProposalEncoder::encode_proposal)
+                .collect();
+
+            <proposal_engine::Module<T>>::create_batch_proposal(
+                account_id,
+                member_id,
+                proposal_parameters,
+                title,
+                description,
+                stake_balance,
+                encoded_calls,
+                exactly_execute_at,
+            )?
+        } else if let ProposalDetails::RuntimeUpgradeCodeHash(_) = proposal_details {
+            <proposal_engine::Module<T>>::create_deferred_code_proposal(
+                account_id,
+                member_id,
+                proposal_parameters,
+                title,
+                description,
+                stake_balance,
+                exactly_execute_at,
+            )?
+        } else {
+            <proposal_engine::Module<T>>::create_proposal(
+                account_id,
+                member_id,
+                proposal_parameters,
+                title,
+                description,
+                stake_balance,
+                proposal_code,
+                exactly_execute_at,
+            )?
+        };
 
         <ThreadIdByProposalId<T>>::insert(proposal_id, discussion_thread_id);
         <ProposalDetailsByProposalId<T>>::insert(proposal_id, proposal_details);
@@ -800,6 +1323,258 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    // Checks whether both proposal details belong to the same proposal type. Batch proposals
+    // should contain the same item types in the same order.
+    fn is_same_proposal_type(
+        proposal_details: &ProposalDetailsOf<T>,
+        other_proposal_details: &ProposalDetailsOf<T>,
+    ) -> bool {
+        match (proposal_details, other_proposal_details) {
+            (ProposalDetails::Batch(batch_items), ProposalDetails::Batch(other_batch_items)) => {
+                batch_items.len() == other_batch_items.len()
+                    && batch_items
+                        .iter()
+                        .zip(other_batch_items.iter())
+                        .all(|(item, other_item)| Self::is_same_proposal_type(item, other_item))
+            }
+            _ => {
+                rstd::mem::discriminant(proposal_details)
+                    == rstd::mem::discriminant(other_proposal_details)
+            }
+        }
+    }
+
+    // validates parameters for the 'set proposal parameters' proposal
+    fn ensure_proposal_parameters_valid<Balance>(
+        proposal_parameters: &ProposalParameters<T::BlockNumber, Balance>,
+    ) -> Result<(), Error> {
+        ensure!(
+            !proposal_parameters.voting_period.is_zero(),
+            Error::InvalidProposalParametersVotingPeriod
+        );
+
+        let percentages = [
+            proposal_parameters.approval_quorum_percentage,
+            proposal_parameters.approval_threshold_percentage,
+            proposal_parameters.slashing_quorum_percentage,
+            proposal_parameters.slashing_threshold_percentage,
+        ];
+        ensure!(
+            percentages.iter().all(|percentage| *percentage <= 100),
+            Error::InvalidProposalParametersPercentage
+        );
+        ensure!(
+            proposal_parameters.approval_threshold_percentage > 0,
+            Error::InvalidProposalParametersPercentage
+        );
+        ensure!(
+            proposal_parameters.slashing_threshold_percentage > 0,
+            Error::InvalidProposalParametersPercentage
+        );
+
+        ensure!(
+            proposal_parameters.constitutionality > 0,
+            Error::InvalidProposalParametersConstitutionality
+        );
+
+        if let Some(retry_policy) = proposal_parameters.execution_retry_policy {
+            ensure!(
+                !retry_policy.retry_interval.is_zero(),
+                Error::InvalidProposalParametersExecutionRetryInterval
+            );
+        }
+
+        Ok(())
+    }
+
+    // validates proposal details for all proposal types, batch items are validated one by one
+    fn ensure_proposal_details_valid(proposal_details: &ProposalDetailsOf<T>) -> Result<(), Error> {
+        match proposal_details {
+            ProposalDetails::Text(text) => {
+                ensure!(!text.is_empty(), Error::TextProposalIsEmpty);
+                ensure!(
+                    text.len() as u32 <= T::TextProposalMaxLength::get(),
+                    Error::TextProposalSizeExceeded
+                );
+            }
+            ProposalDetails::RuntimeUpgrade(wasm) => {
+                Self::ensure_runtime_upgrade_code_valid(wasm)?;
+            }
+            ProposalDetails::RuntimeUpgradeCodeHash(_) => {}
+            ProposalDetails::SetElectionParameters(election_parameters) => {
+                election_parameters.ensure_valid()?;
+
+                Self::ensure_council_election_parameters_valid(election_parameters)?;
+            }
+            ProposalDetails::Spending(balance, _) => {
+                ensure!(
+                    *balance != BalanceOfMint::<T>::zero(),
+                    Error::InvalidSpendingProposalBalance
+                );
+                ensure!(
+                    *balance <= <BalanceOfMint<T>>::from(MAX_SPENDING_PROPOSAL_VALUE),
+                    Error::InvalidSpendingProposalBalance
+                );
+            }
+            ProposalDetails::SetLead(new_lead) => {
+                if let Some((_, account_id)) = new_lead {
+                    ensure!(
+                        !<governance::council::Module<T>>::is_councilor(account_id),
+                        Error::InvalidSetLeadParameterCannotBeCouncilor
+                    );
+                }
+            }
+            ProposalDetails::SetContentWorkingGroupMintCapacity(mint_balance) => {
+                ensure!(
+                    *mint_balance
+                        <= <BalanceOfMint<T>>::from(CONTENT_WORKING_GROUP_MINT_CAPACITY_MAX_VALUE),
+                    Error::InvalidStorageWorkingGroupMintCapacity
+                );
+            }
+            ProposalDetails::EvictStorageProvider(_) => {}
+            ProposalDetails::SetValidatorCount(new_validator_count) => {
+                ensure!(
+                    *new_validator_count >= <staking::Module<T>>::minimum_validator_count(),
+                    Error::InvalidValidatorCount
+                );
+
+                ensure!(
+                    *new_validator_count <= MAX_VALIDATOR_COUNT,
+                    Error::InvalidValidatorCount
+                );
+            }
+            ProposalDetails::SetStorageRoleParameters(role_parameters) => {
+                Self::ensure_storage_role_parameters_valid(role_parameters)?;
+            }
+            ProposalDetails::SetProposalParameters(_, proposal_parameters) => {
+                Self::ensure_proposal_parameters_valid(proposal_parameters)?;
+            }
+            ProposalDetails::AddContentWorkingGroupLeaderOpening(..) => {}
+            ProposalDetails::BeginReviewContentWorkingGroupLeaderApplications(opening_id) => {
+                Self::ensure_content_working_group_leader_opening_exists(opening_id)?;
+            }
+            ProposalDetails::FillContentWorkingGroupLeaderOpening(
+                opening_id,
+                application_id,
+                _,
+            ) => {
+                Self::ensure_content_working_group_leader_opening_exists(opening_id)?;
+
+                ensure!(
+                    <content_working_group::CuratorApplicationById<T>>::exists(application_id),
+                    Error::InvalidContentWorkingGroupLeaderApplication
+                );
+
+                let application =
+                    <content_working_group::CuratorApplicationById<T>>::get(application_id);
+                ensure!(
+                    application.curator_opening_id == *opening_id,
+                    Error::InvalidContentWorkingGroupLeaderApplication
+                );
+            }
+            ProposalDetails::SlashContentWorkingGroupLeaderStake(slashing_amount) => {
+                ensure!(
+                    *slashing_amount != BalanceOfGovernanceCurrency::<T>::zero(),
+                    Error::InvalidSlashContentWorkingGroupLeaderStakeAmount
+                );
+
+                ensure!(
+                    <content_working_group::Module<T>>::ensure_lead_is_set().is_ok(),
+                    Error::ContentWorkingGroupLeaderIsNotSet
+                );
+            }
+            ProposalDetails::TerminateContentWorkingGroupLeaderRole(_) => {
+                ensure!(
+                    <content_working_group::Module<T>>::ensure_lead_is_set().is_ok(),
+                    Error::ContentWorkingGroupLeaderIsNotSet
+                );
+            }
+            ProposalDetails::Batch(batch_items) => {
+                ensure!(!batch_items.is_empty(), Error::BatchProposalIsEmpty);
+                ensure!(
+                    batch_items.len() as u32 <= MAX_BATCH_PROPOSAL_ITEMS,
+                    Error::BatchProposalSizeExceeded
+                );
+
+                for (index, batch_item) in batch_items.iter().enumerate() {
+                    ensure!(
+                        !matches!(batch_item, ProposalDetails::Batch(..)),
+                        Error::NestedBatchProposal
+                    );
+                    ensure!(
+                        !matches!(batch_item, ProposalDetails::RuntimeUpgradeCodeHash(..)),
+                        Error::RuntimeUpgradeCodeHashInBatchProposal
+                    );
+                    ensure!(
+                        !matches!(batch_item, ProposalDetails::RuntimeUpgrade(..)),
+                        Error::RuntimeUpgradeInBatchProposal
+                    );
+                    ensure!(
+                        index == 0 || !Self::can_fail_on_execution(batch_item),
+                        Error::FallibleBatchProposalItemIsNotFirst
+                    );
+
+                    Self::ensure_proposal_details_valid(batch_item)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // Checks whether the proposal execution can fail after the proposal details validation:
+    // it depends on the runtime state at the execution time. Parameter changes cannot fail.
+    fn can_fail_on_execution(proposal_details: &ProposalDetailsOf<T>) -> bool {
+        match proposal_details {
+            ProposalDetails::Text(_)
+            | ProposalDetails::SetContentWorkingGroupMintCapacity(_)
+            | ProposalDetails::SetValidatorCount(_)
+            | ProposalDetails::SetStorageRoleParameters(_)
+            | ProposalDetails::SetProposalParameters(..) => false,
+            ProposalDetails::RuntimeUpgrade(_)
+            | ProposalDetails::RuntimeUpgradeCodeHash(_)
+            | ProposalDetails::SetElectionParameters(_)
+            | ProposalDetails::Spending(..)
+            | ProposalDetails::SetLead(_)
+            | ProposalDetails::EvictStorageProvider(_)
+            | ProposalDetails::Batch(_)
+            | ProposalDetails::AddContentWorkingGroupLeaderOpening(..)
+            | ProposalDetails::BeginReviewContentWorkingGroupLeaderApplications(_)
+            | ProposalDetails::FillContentWorkingGroupLeaderOpening(..)
+            | ProposalDetails::SlashContentWorkingGroupLeaderStake(_)
+            | ProposalDetails::TerminateContentWorkingGroupLeaderRole(_) => true,
+        }
+    }
+
+    // validates the wasm code size of the runtime upgrade proposal
+    fn ensure_runtime_upgrade_code_valid(wasm: &[u8]) -> Result<(), Error> {
+        ensure!(!wasm.is_empty(), Error::RuntimeProposalIsEmpty);
+        ensure!(
+            wasm.len() as u32 <= T::RuntimeUpgradeWasmProposalMaxLength::get(),
+            Error::RuntimeProposalSizeExceeded
+        );
+
+        Ok(())
+    }
+
+    // ensures the content working group opening exists and is the leader opening
+    fn ensure_content_working_group_leader_opening_exists(
+        opening_id: &content_working_group::CuratorOpeningId<T>,
+    ) -> Result<(), Error> {
+        ensure!(
+            <content_working_group::CuratorOpeningById<T>>::exists(opening_id),
+            Error::InvalidContentWorkingGroupLeaderOpening
+        );
+
+        let opening = <content_working_group::CuratorOpeningById<T>>::get(opening_id);
+        ensure!(
+            opening.opening_type == OpeningType::Leader,
+            Error::InvalidContentWorkingGroupLeaderOpening
+        );
+
+        Ok(())
+    }
+
     // validates storage role parameters for the 'Set storage role parameters' proposal
     fn ensure_storage_role_parameters_valid(
         role_parameters: &RoleParameters<BalanceOfGovernanceCurrency<T>, T::BlockNumber>,
@@ -1028,63 +1803,190 @@ impl<T: Trait> Module<T> {
             Error::InvalidCouncilElectionParameterMinCouncilStake
         );
 
+        if election_parameters.tally_method.is_ranked() {
+            ensure!(
+                election_parameters.max_ranked_candidates
+                    >= ELECTION_PARAMETERS_MAX_RANKED_CANDIDATES_MIN_VALUE,
+                Error::InvalidCouncilElectionParameterMaxRankedCandidates
+            );
+        }
+
+        ensure!(
+            election_parameters.max_ranked_candidates
+                <= ELECTION_PARAMETERS_MAX_RANKED_CANDIDATES_MAX_VALUE,
+            Error::InvalidCouncilElectionParameterMaxRankedCandidates
+        );
+
         Ok(())
     }
 
     /// Sets default config values for the proposals.
     /// Should be called on the migration to the new runtime version.
     pub fn set_default_config_values() {
+        Self::init_config_values(true);
+    }
+
+    /// Sets default config values for the proposals which config values were not set yet.
+    /// Keeps the existing values. Should be called on the migration to the runtime version
+    /// introducing new proposals.
+    pub fn set_missing_config_values() {
+        Self::init_config_values(false);
+    }
+
+    // Sets default config values for the proposals. Existing values are replaced only if
+    // overwriting is required.
+    fn init_config_values(overwrite: bool) {
         let p = ProposalsConfigParameters::default();
 
-        <SetValidatorCountProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+        Self::init_config_value::<SetValidatorCountProposalVotingPeriod<T>>(
             p.set_validator_count_proposal_voting_period,
-        ));
-        <SetValidatorCountProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            overwrite,
+        );
+        Self::init_config_value::<SetValidatorCountProposalGracePeriod<T>>(
             p.set_validator_count_proposal_grace_period,
-        ));
-        <RuntimeUpgradeProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            overwrite,
+        );
+        Self::init_config_value::<RuntimeUpgradeProposalVotingPeriod<T>>(
             p.runtime_upgrade_proposal_voting_period,
-        ));
-        <RuntimeUpgradeProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            overwrite,
+        );
+        Self::init_config_value::<RuntimeUpgradeProposalGracePeriod<T>>(
             p.runtime_upgrade_proposal_grace_period,
-        ));
-        <TextProposalVotingPeriod<T>>::put(T::BlockNumber::from(p.text_proposal_voting_period));
-        <TextProposalGracePeriod<T>>::put(T::BlockNumber::from(p.text_proposal_grace_period));
-        <SetElectionParametersProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            overwrite,
+        );
+        Self::init_config_value::<TextProposalVotingPeriod<T>>(
+            p.text_proposal_voting_period,
+            overwrite,
+        );
+        Self::init_config_value::<TextProposalGracePeriod<T>>(
+            p.text_proposal_grace_period,
+            overwrite,
+        );
+        Self::init_config_value::<SetElectionParametersProposalVotingPeriod<T>>(
             p.set_election_parameters_proposal_voting_period,
-        ));
-        <SetElectionParametersProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            overwrite,
+        );
+        Self::init_config_value::<SetElectionParametersProposalGracePeriod<T>>(
             p.set_election_parameters_proposal_grace_period,
-        ));
-        <SetContentWorkingGroupMintCapacityProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            overwrite,
+        );
+        Self::init_config_value::<SetContentWorkingGroupMintCapacityProposalVotingPeriod<T>>(
             p.set_content_working_group_mint_capacity_proposal_voting_period,
-        ));
-        <SetContentWorkingGroupMintCapacityProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            overwrite,
+        );
+        Self::init_config_value::<SetContentWorkingGroupMintCapacityProposalGracePeriod<T>>(
             p.set_content_working_group_mint_capacity_proposal_grace_period,
-        ));
-        <SetLeadProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            overwrite,
+        );
+        Self::init_config_value::<SetLeadProposalVotingPeriod<T>>(
             p.set_lead_proposal_voting_period,
-        ));
-        <SetLeadProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            overwrite,
+        );
+        Self::init_config_value::<SetLeadProposalGracePeriod<T>>(
             p.set_lead_proposal_grace_period,
-        ));
-        <SpendingProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            overwrite,
+        );
+        Self::init_config_value::<SpendingProposalVotingPeriod<T>>(
             p.spending_proposal_voting_period,
-        ));
-        <SpendingProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            overwrite,
+        );
+        Self::init_config_value::<SpendingProposalGracePeriod<T>>(
             p.spending_proposal_grace_period,
-        ));
-        <EvictStorageProviderProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            overwrite,
+        );
+        Self::init_config_value::<EvictStorageProviderProposalVotingPeriod<T>>(
             p.evict_storage_provider_proposal_voting_period,
-        ));
-        <EvictStorageProviderProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            overwrite,
+        );
+        Self::init_config_value::<EvictStorageProviderProposalGracePeriod<T>>(
             p.evict_storage_provider_proposal_grace_period,
-        ));
-        <SetStorageRoleParametersProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            overwrite,
+        );
+        Self::init_config_value::<SetStorageRoleParametersProposalVotingPeriod<T>>(
             p.set_storage_role_parameters_proposal_voting_period,
-        ));
-        <SetStorageRoleParametersProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            overwrite,
+        );
+        Self::init_config_value::<SetStorageRoleParametersProposalGracePeriod<T>>(
             p.set_storage_role_parameters_proposal_grace_period,
-        ));
+            overwrite,
+        );
+        Self::init_config_value::<SetProposalParametersProposalVotingPeriod<T>>(
+            p.set_proposal_parameters_proposal_voting_period,
+            overwrite,
+        );
+        Self::init_config_value::<SetProposalParametersProposalGracePeriod<T>>(
+            p.set_proposal_parameters_proposal_grace_period,
+            overwrite,
+        );
+        Self::init_config_value::<AddContentWorkingGroupLeaderOpeningProposalVotingPeriod<T>>(
+            p.add_content_working_group_leader_opening_proposal_voting_period,
+            overwrite,
+        );
+        Self::init_config_value::<AddContentWorkingGroupLeaderOpeningProposalGracePeriod<T>>(
+            p.add_content_working_group_leader_opening_proposal_grace_period,
+            overwrite,
+        );
+        Self::init_config_value::<
+            BeginReviewContentWorkingGroupLeaderApplicationsProposalVotingPeriod<T>,
+        >(
+            p.begin_review_content_working_group_leader_applications_proposal_voting_period,
+            overwrite,
+        );
+        Self::init_config_value::<
+            BeginReviewContentWorkingGroupLeaderApplicationsProposalGracePeriod<T>,
+        >(
+            p.begin_review_content_working_group_leader_applications_proposal_grace_period,
+            overwrite,
+        );
+        Self::init_config_value::<FillContentWorkingGroupLeaderOpeningProposalVotingPeriod<T>>(
+            p.fill_content_working_group_leader_opening_proposal_voting_period,
+            overwrite,
+        );
+        Self::init_config_value::<FillContentWorkingGroupLeaderOpeningProposalGracePeriod<T>>(
+            p.fill_content_working_group_leader_opening_proposal_grace_period,
+            overwrite,
+        );
+        Self::init_config_value::<SlashContentWorkingGroupLeaderStakeProposalVotingPeriod<T>>(
+            p.slash_content_working_group_leader_stake_proposal_voting_period,
+            overwrite,
+        );
+        Self::init_config_value::<SlashContentWorkingGroupLeaderStakeProposalGracePeriod<T>>(
+            p.slash_content_working_group_leader_stake_proposal_grace_period,
+            overwrite,
+        );
+        Self::init_config_value::<TerminateContentWorkingGroupLeaderRoleProposalVotingPeriod<T>>(
+            p.terminate_content_working_group_leader_role_proposal_voting_period,
+            overwrite,
+        );
+        Self::init_config_value::<TerminateContentWorkingGroupLeaderRoleProposalGracePeriod<T>>(
+            p.terminate_content_working_group_leader_role_proposal_grace_period,
+            overwrite,
+        );
+    }
+
+    // Puts the config value unless the value is set already and cannot be overwritten.
+    fn init_config_value<V: StorageValue<T::BlockNumber>>(value: u32, overwrite: bool) {
+        if overwrite || !V::exists() {
+            V::put(T::BlockNumber::from(value));
+        }
+    }
+}
+
+impl<T: Trait> ProposalFinalizationHandler<T::ProposalId> for Module<T> {
+    // Closes the discussion thread of the finalized proposal.
+    fn proposal_finalized(proposal_id: T::ProposalId) {
+        if <ThreadIdByProposalId<T>>::exists(proposal_id) {
+            let thread_id = Self::thread_id_by_proposal_id(proposal_id);
+
+            <proposal_discussion::Module<T>>::close_thread(thread_id);
+        }
+    }
+
+    // Removes the details, the amendment history and the discussion thread id of the pruned
+    // proposal.
+    fn proposal_pruned(proposal_id: T::ProposalId) {
+        <ProposalDetailsByProposalId<T>>::remove(proposal_id);
+        <ProposalDetailsAmendmentHistory<T>>::remove(proposal_id);
+        <ThreadIdByProposalId<T>>::remove(proposal_id);
     }
 }
@@ -0,0 +1,97 @@
+//! Migration of the proposal details and discussions saved by the previous runtime version.
+//! The previous election parameters of the `set election parameters` proposal details lack
+//! the tally method and the ranked candidates limit. Other details have the same layout.
+//! The previous discussion threads stay open after the proposal finalization.
+
+use codec::{Decode, Encode};
+use governance::election_params::{ElectionParameters, TallyMethod};
+use proposal_engine::ProposalStatus;
+use srml_support::storage::unhashed;
+use srml_support::StorageMap;
+
+use crate::{
+    BalanceOfGovernanceCurrency, Module, ProposalDetails, ProposalDetailsByProposalId,
+    ThreadIdByProposalId, Trait,
+};
+
+// Index of the `set election parameters` variant of the encoded proposal details.
+const SET_ELECTION_PARAMETERS_DETAILS_INDEX: u8 = 2;
+
+// Election parameters of the previous runtime version.
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub(crate) struct LegacyElectionParameters<Balance, BlockNumber> {
+    pub announcing_period: BlockNumber,
+    pub voting_period: BlockNumber,
+    pub revealing_period: BlockNumber,
+    pub council_size: u32,
+    pub candidacy_limit: u32,
+    pub new_term_duration: BlockNumber,
+    pub min_council_stake: Balance,
+    pub min_voting_stake: Balance,
+}
+
+impl<Balance, BlockNumber> From<LegacyElectionParameters<Balance, BlockNumber>>
+    for ElectionParameters<Balance, BlockNumber>
+{
+    fn from(parameters: LegacyElectionParameters<Balance, BlockNumber>) -> Self {
+        ElectionParameters {
+            announcing_period: parameters.announcing_period,
+            voting_period: parameters.voting_period,
+            revealing_period: parameters.revealing_period,
+            council_size: parameters.council_size,
+            candidacy_limit: parameters.candidacy_limit,
+            new_term_duration: parameters.new_term_duration,
+            min_council_stake: parameters.min_council_stake,
+            min_voting_stake: parameters.min_voting_stake,
+            tally_method: TallyMethod::FirstPastThePost,
+            max_ranked_candidates: 0,
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// Migrates the proposal details saved by the previous runtime version to the current
+    /// layout. Should be called on the migration to the new runtime version only once.
+    pub fn migrate_proposal_details() {
+        for id in 1..=<proposal_engine::Module<T>>::proposal_count() {
+            let proposal_id = T::ProposalId::from(id);
+            let key = <ProposalDetailsByProposalId<T>>::hashed_key_for(proposal_id);
+
+            if let Some(encoded_details) = unhashed::get_raw(&key) {
+                if encoded_details.first() == Some(&SET_ELECTION_PARAMETERS_DETAILS_INDEX) {
+                    let legacy_parameters = LegacyElectionParameters::<
+                        BalanceOfGovernanceCurrency<T>,
+                        T::BlockNumber,
+                    >::decode(
+                        &mut &encoded_details[1..]
+                    );
+
+                    if let Ok(legacy_parameters) = legacy_parameters {
+                        <ProposalDetailsByProposalId<T>>::insert(
+                            proposal_id,
+                            ProposalDetails::SetElectionParameters(legacy_parameters.into()),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Closes the discussion threads of the proposals finalized by the previous runtime version.
+    /// Should be called on the migration to the new runtime version only once, after
+    /// the proposals engine and discussion migrations.
+    pub fn close_finalized_proposal_threads() {
+        for id in 1..=<proposal_engine::Module<T>>::proposal_count() {
+            let proposal_id = T::ProposalId::from(id);
+
+            let proposal = <proposal_engine::Module<T>>::proposals(proposal_id);
+            let is_finalized = matches!(proposal.status, ProposalStatus::Finalized(_));
+
+            if is_finalized && <ThreadIdByProposalId<T>>::exists(proposal_id) {
+                let thread_id = Self::thread_id_by_proposal_id(proposal_id);
+
+                <proposal_discussion::Module<T>>::close_thread(thread_id);
+            }
+        }
+    }
+}
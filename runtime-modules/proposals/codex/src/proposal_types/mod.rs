@@ -7,7 +7,8 @@ use rstd::vec::Vec;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
-use crate::ElectionParameters;
+use crate::{ElectionParameters, ProposalParameters};
+use content_working_group::{OpeningPolicyCommitment, RewardPolicy};
 use roles::actors::RoleParameters;
 
 /// Encodes proposal using its details information.
@@ -23,16 +24,29 @@ pub type ProposalDetailsOf<T> = ProposalDetails<
     <T as system::Trait>::BlockNumber,
     <T as system::Trait>::AccountId,
     crate::MemberId<T>,
+    content_working_group::CuratorOpeningId<T>,
+    content_working_group::CuratorApplicationId<T>,
+    <T as system::Trait>::Hash,
 >;
 
 /// Proposal details provide voters the information required for the perceived voting.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Debug)]
-pub enum ProposalDetails<MintedBalance, CurrencyBalance, BlockNumber, AccountId, MemberId> {
+pub enum ProposalDetails<
+    MintedBalance,
+    CurrencyBalance,
+    BlockNumber,
+    AccountId,
+    MemberId,
+    OpeningId,
+    ApplicationId,
+    Hash,
+> {
     /// The text of the `text` proposal
     Text(Vec<u8>),
 
-    /// The wasm code for the `runtime upgrade` proposal
+    /// The wasm code for the `runtime upgrade` proposal. Used to encode the execution of the
+    /// provided code of the approved `runtime upgrade` proposal.
     RuntimeUpgrade(Vec<u8>),
 
     /// Election parameters for the `set election parameters` proposal
@@ -55,16 +69,135 @@ pub enum ProposalDetails<MintedBalance, CurrencyBalance, BlockNumber, AccountId,
 
     /// Role parameters for the `set storage role parameters` proposal
     SetStorageRoleParameters(RoleParameters<CurrencyBalance, BlockNumber>),
+
+    /// Proposals executed together for the `batch` proposal
+    Batch(
+        Vec<
+            ProposalDetails<
+                MintedBalance,
+                CurrencyBalance,
+                BlockNumber,
+                AccountId,
+                MemberId,
+                OpeningId,
+                ApplicationId,
+                Hash,
+            >,
+        >,
+    ),
+
+    /// Proposal type and its new parameters for the `set proposal parameters` proposal
+    SetProposalParameters(
+        ProposalType,
+        ProposalParameters<BlockNumber, CurrencyBalance>,
+    ),
+
+    /// Opening policy and human readable text for the `add content working group leader opening`
+    /// proposal
+    AddContentWorkingGroupLeaderOpening(
+        OpeningPolicyCommitment<BlockNumber, CurrencyBalance>,
+        Vec<u8>,
+    ),
+
+    /// Opening id for the `begin review content working group leader applications` proposal
+    BeginReviewContentWorkingGroupLeaderApplications(OpeningId),
+
+    /// Opening id, chosen application id and optional reward policy for the
+    /// `fill content working group leader opening` proposal
+    FillContentWorkingGroupLeaderOpening(
+        OpeningId,
+        ApplicationId,
+        Option<RewardPolicy<MintedBalance, BlockNumber>>,
+    ),
+
+    /// Slashing amount for the `slash content working group leader stake` proposal
+    SlashContentWorkingGroupLeaderStake(CurrencyBalance),
+
+    /// Rationale text for the `terminate content working group leader role` proposal
+    TerminateContentWorkingGroupLeaderRole(Vec<u8>),
+
+    /// Wasm code hash for the `runtime upgrade` proposal. The code is provided after
+    /// the proposal approval.
+    RuntimeUpgradeCodeHash(Hash),
 }
 
-impl<MintedBalance, CurrencyBalance, BlockNumber, AccountId, MemberId> Default
-    for ProposalDetails<MintedBalance, CurrencyBalance, BlockNumber, AccountId, MemberId>
+impl<
+        MintedBalance,
+        CurrencyBalance,
+        BlockNumber,
+        AccountId,
+        MemberId,
+        OpeningId,
+        ApplicationId,
+        Hash,
+    > Default
+    for ProposalDetails<
+        MintedBalance,
+        CurrencyBalance,
+        BlockNumber,
+        AccountId,
+        MemberId,
+        OpeningId,
+        ApplicationId,
+        Hash,
+    >
 {
     fn default() -> Self {
         ProposalDetails::Text(b"invalid proposal details".to_vec())
     }
 }
 
+/// Proposal types with parameters configurable by the `set proposal parameters` proposal.
+/// Parameters of the `batch` proposal are derived from its items.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProposalType {
+    /// `Text` proposal
+    Text,
+
+    /// `Runtime upgrade` proposal
+    RuntimeUpgrade,
+
+    /// `Set election parameters` proposal
+    SetElectionParameters,
+
+    /// `Spending` proposal
+    Spending,
+
+    /// `Set lead` proposal
+    SetLead,
+
+    /// `Set content working group mint capacity` proposal
+    SetContentWorkingGroupMintCapacity,
+
+    /// `Evict storage provider` proposal
+    EvictStorageProvider,
+
+    /// `Set validator count` proposal
+    SetValidatorCount,
+
+    /// `Set storage role parameters` proposal
+    SetStorageRoleParameters,
+
+    /// `Set proposal parameters` proposal
+    SetProposalParameters,
+
+    /// `Add content working group leader opening` proposal
+    AddContentWorkingGroupLeaderOpening,
+
+    /// `Begin review content working group leader applications` proposal
+    BeginReviewContentWorkingGroupLeaderApplications,
+
+    /// `Fill content working group leader opening` proposal
+    FillContentWorkingGroupLeaderOpening,
+
+    /// `Slash content working group leader stake` proposal
+    SlashContentWorkingGroupLeaderStake,
+
+    /// `Terminate content working group leader role` proposal
+    TerminateContentWorkingGroupLeaderRole,
+}
+
 /// Contains proposal config parameters. Default values are used by migration and genesis config.
 pub struct ProposalsConfigParameters {
     /// 'Set validator count' proposal voting period
@@ -120,6 +253,42 @@ pub struct ProposalsConfigParameters {
 
     /// 'Set storage role parameters' proposal grace period
     pub set_storage_role_parameters_proposal_grace_period: u32,
+
+    /// 'Set proposal parameters' proposal voting period
+    pub set_proposal_parameters_proposal_voting_period: u32,
+
+    /// 'Set proposal parameters' proposal grace period
+    pub set_proposal_parameters_proposal_grace_period: u32,
+
+    /// 'Add content working group leader opening' proposal voting period
+    pub add_content_working_group_leader_opening_proposal_voting_period: u32,
+
+    /// 'Add content working group leader opening' proposal grace period
+    pub add_content_working_group_leader_opening_proposal_grace_period: u32,
+
+    /// 'Begin review content working group leader applications' proposal voting period
+    pub begin_review_content_working_group_leader_applications_proposal_voting_period: u32,
+
+    /// 'Begin review content working group leader applications' proposal grace period
+    pub begin_review_content_working_group_leader_applications_proposal_grace_period: u32,
+
+    /// 'Fill content working group leader opening' proposal voting period
+    pub fill_content_working_group_leader_opening_proposal_voting_period: u32,
+
+    /// 'Fill content working group leader opening' proposal grace period
+    pub fill_content_working_group_leader_opening_proposal_grace_period: u32,
+
+    /// 'Slash content working group leader stake' proposal voting period
+    pub slash_content_working_group_leader_stake_proposal_voting_period: u32,
+
+    /// 'Slash content working group leader stake' proposal grace period
+    pub slash_content_working_group_leader_stake_proposal_grace_period: u32,
+
+    /// 'Terminate content working group leader role' proposal voting period
+    pub terminate_content_working_group_leader_role_proposal_voting_period: u32,
+
+    /// 'Terminate content working group leader role' proposal grace period
+    pub terminate_content_working_group_leader_role_proposal_grace_period: u32,
 }
 
 impl Default for ProposalsConfigParameters {
@@ -143,6 +312,18 @@ impl Default for ProposalsConfigParameters {
             evict_storage_provider_proposal_grace_period: 0u32,
             set_storage_role_parameters_proposal_voting_period: 43200u32,
             set_storage_role_parameters_proposal_grace_period: 14400u32,
+            set_proposal_parameters_proposal_voting_period: 72000u32,
+            set_proposal_parameters_proposal_grace_period: 14400u32,
+            add_content_working_group_leader_opening_proposal_voting_period: 43200u32,
+            add_content_working_group_leader_opening_proposal_grace_period: 0u32,
+            begin_review_content_working_group_leader_applications_proposal_voting_period: 43200u32,
+            begin_review_content_working_group_leader_applications_proposal_grace_period: 0u32,
+            fill_content_working_group_leader_opening_proposal_voting_period: 43200u32,
+            fill_content_working_group_leader_opening_proposal_grace_period: 0u32,
+            slash_content_working_group_leader_stake_proposal_voting_period: 43200u32,
+            slash_content_working_group_leader_stake_proposal_grace_period: 0u32,
+            terminate_content_working_group_leader_role_proposal_voting_period: 72000u32,
+            terminate_content_working_group_leader_role_proposal_grace_period: 0u32,
         }
     }
 }
@@ -1,127 +1,403 @@
-use crate::{BalanceOf, Module, ProposalParameters};
+use crate::{
+    BalanceOf, ExecutionRetryPolicy, Module, ProposalDetails, ProposalDetailsOf,
+    ProposalParameters, ProposalType, VotingMode,
+};
 
 // Proposal parameters for the 'Set validator count' proposal
 pub(crate) fn set_validator_count_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::set_validator_count_proposal_voting_period(),
-        grace_period: <Module<T>>::set_validator_count_proposal_grace_period(),
-        approval_quorum_percentage: 66,
-        approval_threshold_percentage: 80,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(100_000_u32)),
-    }
+    configured_or_default::<T>(
+        ProposalType::SetValidatorCount,
+        ProposalParameters {
+            voting_period: <Module<T>>::set_validator_count_proposal_voting_period(),
+            grace_period: <Module<T>>::set_validator_count_proposal_grace_period(),
+            approval_quorum_percentage: 66,
+            approval_threshold_percentage: 80,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(100_000_u32)),
+            voting_mode: VotingMode::Headcount,
+            constitutionality: 1,
+            execution_retry_policy: None,
+        },
+    )
 }
 
 // Proposal parameters for the upgrade runtime proposal
 pub(crate) fn runtime_upgrade_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::runtime_upgrade_proposal_voting_period(),
-        grace_period: <Module<T>>::runtime_upgrade_proposal_grace_period(),
-        approval_quorum_percentage: 80,
-        approval_threshold_percentage: 100,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(1_000_000_u32)),
-    }
+    configured_or_default::<T>(
+        ProposalType::RuntimeUpgrade,
+        ProposalParameters {
+            voting_period: <Module<T>>::runtime_upgrade_proposal_voting_period(),
+            grace_period: <Module<T>>::runtime_upgrade_proposal_grace_period(),
+            approval_quorum_percentage: 80,
+            approval_threshold_percentage: 100,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(1_000_000_u32)),
+            voting_mode: VotingMode::StakeWeighted,
+            constitutionality: 2,
+            execution_retry_policy: None,
+        },
+    )
 }
 
 // Proposal parameters for the text proposal
 pub(crate) fn text_proposal<T: crate::Trait>() -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::text_proposal_voting_period(),
-        grace_period: <Module<T>>::text_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 80,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(25000u32)),
-    }
+    configured_or_default::<T>(
+        ProposalType::Text,
+        ProposalParameters {
+            voting_period: <Module<T>>::text_proposal_voting_period(),
+            grace_period: <Module<T>>::text_proposal_grace_period(),
+            approval_quorum_percentage: 60,
+            approval_threshold_percentage: 80,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(25000u32)),
+            voting_mode: VotingMode::Headcount,
+            constitutionality: 1,
+            execution_retry_policy: None,
+        },
+    )
 }
 
 // Proposal parameters for the 'Set Election Parameters' proposal
 pub(crate) fn set_election_parameters_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::set_election_parameters_proposal_voting_period(),
-        grace_period: <Module<T>>::set_election_parameters_proposal_grace_period(),
-        approval_quorum_percentage: 66,
-        approval_threshold_percentage: 80,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(200_000_u32)),
-    }
+    configured_or_default::<T>(
+        ProposalType::SetElectionParameters,
+        ProposalParameters {
+            voting_period: <Module<T>>::set_election_parameters_proposal_voting_period(),
+            grace_period: <Module<T>>::set_election_parameters_proposal_grace_period(),
+            approval_quorum_percentage: 66,
+            approval_threshold_percentage: 80,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(200_000_u32)),
+            voting_mode: VotingMode::Headcount,
+            constitutionality: 1,
+            execution_retry_policy: None,
+        },
+    )
 }
 
 // Proposal parameters for the 'Set content working group mint capacity' proposal
 pub(crate) fn set_content_working_group_mint_capacity_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::set_content_working_group_mint_capacity_proposal_voting_period(
-        ),
-        grace_period: <Module<T>>::set_content_working_group_mint_capacity_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 75,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(50000u32)),
-    }
+    configured_or_default::<T>(
+        ProposalType::SetContentWorkingGroupMintCapacity,
+        ProposalParameters {
+            voting_period:
+                <Module<T>>::set_content_working_group_mint_capacity_proposal_voting_period(),
+            grace_period:
+                <Module<T>>::set_content_working_group_mint_capacity_proposal_grace_period(),
+            approval_quorum_percentage: 60,
+            approval_threshold_percentage: 75,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(50000u32)),
+            voting_mode: VotingMode::Headcount,
+            constitutionality: 1,
+            execution_retry_policy: None,
+        },
+    )
 }
 
 // Proposal parameters for the 'Spending' proposal
 pub(crate) fn spending_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::spending_proposal_voting_period(),
-        grace_period: <Module<T>>::spending_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 80,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(25000u32)),
-    }
+    configured_or_default::<T>(
+        ProposalType::Spending,
+        ProposalParameters {
+            voting_period: <Module<T>>::spending_proposal_voting_period(),
+            grace_period: <Module<T>>::spending_proposal_grace_period(),
+            approval_quorum_percentage: 60,
+            approval_threshold_percentage: 80,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(25000u32)),
+            voting_mode: VotingMode::Headcount,
+            constitutionality: 1,
+            execution_retry_policy: Some(ExecutionRetryPolicy {
+                retry_interval: T::BlockNumber::from(600u32),
+                retry_period: T::BlockNumber::from(14400u32),
+            }),
+        },
+    )
 }
 
 // Proposal parameters for the 'Set lead' proposal
 pub(crate) fn set_lead_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::set_lead_proposal_voting_period(),
-        grace_period: <Module<T>>::set_lead_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 75,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(50000u32)),
-    }
+    configured_or_default::<T>(
+        ProposalType::SetLead,
+        ProposalParameters {
+            voting_period: <Module<T>>::set_lead_proposal_voting_period(),
+            grace_period: <Module<T>>::set_lead_proposal_grace_period(),
+            approval_quorum_percentage: 60,
+            approval_threshold_percentage: 75,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(50000u32)),
+            voting_mode: VotingMode::Headcount,
+            constitutionality: 1,
+            execution_retry_policy: None,
+        },
+    )
 }
 
 // Proposal parameters for the 'Evict storage provider' proposal
 pub(crate) fn evict_storage_provider_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::evict_storage_provider_proposal_voting_period(),
-        grace_period: <Module<T>>::evict_storage_provider_proposal_grace_period(),
-        approval_quorum_percentage: 50,
-        approval_threshold_percentage: 75,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(25000u32)),
-    }
+    configured_or_default::<T>(
+        ProposalType::EvictStorageProvider,
+        ProposalParameters {
+            voting_period: <Module<T>>::evict_storage_provider_proposal_voting_period(),
+            grace_period: <Module<T>>::evict_storage_provider_proposal_grace_period(),
+            approval_quorum_percentage: 50,
+            approval_threshold_percentage: 75,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(25000u32)),
+            voting_mode: VotingMode::Headcount,
+            constitutionality: 1,
+            execution_retry_policy: None,
+        },
+    )
 }
 
 // Proposal parameters for the 'Set storage role parameters' proposal
 pub(crate) fn set_storage_role_parameters_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::set_storage_role_parameters_proposal_voting_period(),
-        grace_period: <Module<T>>::set_storage_role_parameters_proposal_grace_period(),
-        approval_quorum_percentage: 66,
-        approval_threshold_percentage: 80,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(100_000_u32)),
+    configured_or_default::<T>(
+        ProposalType::SetStorageRoleParameters,
+        ProposalParameters {
+            voting_period: <Module<T>>::set_storage_role_parameters_proposal_voting_period(),
+            grace_period: <Module<T>>::set_storage_role_parameters_proposal_grace_period(),
+            approval_quorum_percentage: 66,
+            approval_threshold_percentage: 80,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(100_000_u32)),
+            voting_mode: VotingMode::Headcount,
+            constitutionality: 1,
+            execution_retry_policy: None,
+        },
+    )
+}
+
+// Proposal parameters for the 'Set proposal parameters' proposal
+pub(crate) fn set_proposal_parameters_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    configured_or_default::<T>(
+        ProposalType::SetProposalParameters,
+        ProposalParameters {
+            voting_period: <Module<T>>::set_proposal_parameters_proposal_voting_period(),
+            grace_period: <Module<T>>::set_proposal_parameters_proposal_grace_period(),
+            approval_quorum_percentage: 80,
+            approval_threshold_percentage: 100,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(500_000_u32)),
+            voting_mode: VotingMode::Headcount,
+            constitutionality: 2,
+            execution_retry_policy: None,
+        },
+    )
+}
+
+// Proposal parameters for the 'Add content working group leader opening' proposal
+pub(crate) fn add_content_working_group_leader_opening_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    configured_or_default::<T>(
+        ProposalType::AddContentWorkingGroupLeaderOpening,
+        ProposalParameters {
+            voting_period:
+                <Module<T>>::add_content_working_group_leader_opening_proposal_voting_period(),
+            grace_period:
+                <Module<T>>::add_content_working_group_leader_opening_proposal_grace_period(),
+            approval_quorum_percentage: 60,
+            approval_threshold_percentage: 75,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(50000u32)),
+            voting_mode: VotingMode::Headcount,
+            constitutionality: 1,
+            execution_retry_policy: None,
+        },
+    )
+}
+
+// Proposal parameters for the 'Begin review content working group leader applications' proposal
+pub(crate) fn begin_review_content_working_group_leader_applications_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    configured_or_default::<T>(
+        ProposalType::BeginReviewContentWorkingGroupLeaderApplications,
+        ProposalParameters {
+            voting_period: <Module<T>>::begin_review_content_working_group_leader_applications_proposal_voting_period(),
+            grace_period: <Module<T>>::begin_review_content_working_group_leader_applications_proposal_grace_period(),
+            approval_quorum_percentage: 60,
+            approval_threshold_percentage: 75,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(25000u32)),
+            voting_mode: VotingMode::Headcount,
+            constitutionality: 1,
+            execution_retry_policy: None,
+        },
+    )
+}
+
+// Proposal parameters for the 'Fill content working group leader opening' proposal
+pub(crate) fn fill_content_working_group_leader_opening_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    configured_or_default::<T>(
+        ProposalType::FillContentWorkingGroupLeaderOpening,
+        ProposalParameters {
+            voting_period:
+                <Module<T>>::fill_content_working_group_leader_opening_proposal_voting_period(),
+            grace_period:
+                <Module<T>>::fill_content_working_group_leader_opening_proposal_grace_period(),
+            approval_quorum_percentage: 60,
+            approval_threshold_percentage: 75,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(50000u32)),
+            voting_mode: VotingMode::Headcount,
+            constitutionality: 1,
+            execution_retry_policy: None,
+        },
+    )
+}
+
+// Proposal parameters for the 'Slash content working group leader stake' proposal
+pub(crate) fn slash_content_working_group_leader_stake_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    configured_or_default::<T>(
+        ProposalType::SlashContentWorkingGroupLeaderStake,
+        ProposalParameters {
+            voting_period:
+                <Module<T>>::slash_content_working_group_leader_stake_proposal_voting_period(),
+            grace_period:
+                <Module<T>>::slash_content_working_group_leader_stake_proposal_grace_period(),
+            approval_quorum_percentage: 66,
+            approval_threshold_percentage: 80,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(50000u32)),
+            voting_mode: VotingMode::Headcount,
+            constitutionality: 1,
+            execution_retry_policy: None,
+        },
+    )
+}
+
+// Proposal parameters for the 'Terminate content working group leader role' proposal
+pub(crate) fn terminate_content_working_group_leader_role_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    configured_or_default::<T>(
+        ProposalType::TerminateContentWorkingGroupLeaderRole,
+        ProposalParameters {
+            voting_period:
+                <Module<T>>::terminate_content_working_group_leader_role_proposal_voting_period(),
+            grace_period:
+                <Module<T>>::terminate_content_working_group_leader_role_proposal_grace_period(),
+            approval_quorum_percentage: 66,
+            approval_threshold_percentage: 80,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(100_000_u32)),
+            voting_mode: VotingMode::Headcount,
+            constitutionality: 1,
+            execution_retry_policy: None,
+        },
+    )
+}
+
+// Proposal parameters for the 'Batch' proposal. The strictest parameters of the batch items
+// are used.
+pub(crate) fn batch_proposal<T: crate::Trait>(
+    batch_items: &[ProposalDetailsOf<T>],
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    batch_items
+        .iter()
+        .map(proposal_details_parameters::<T>)
+        .fold(ProposalParameters::default(), |strictest, item| {
+            let voting_mode = if matches!(item.voting_mode, VotingMode::StakeWeighted) {
+                VotingMode::StakeWeighted
+            } else {
+                strictest.voting_mode
+            };
+
+            ProposalParameters {
+                voting_period: strictest.voting_period.max(item.voting_period),
+                grace_period: strictest.grace_period.max(item.grace_period),
+                approval_quorum_percentage: strictest
+                    .approval_quorum_percentage
+                    .max(item.approval_quorum_percentage),
+                approval_threshold_percentage: strictest
+                    .approval_threshold_percentage
+                    .max(item.approval_threshold_percentage),
+                slashing_quorum_percentage: strictest
+                    .slashing_quorum_percentage
+                    .max(item.slashing_quorum_percentage),
+                slashing_threshold_percentage: strictest
+                    .slashing_threshold_percentage
+                    .max(item.slashing_threshold_percentage),
+                required_stake: strictest.required_stake.max(item.required_stake),
+                voting_mode,
+                constitutionality: strictest.constitutionality.max(item.constitutionality),
+                execution_retry_policy: strictest
+                    .execution_retry_policy
+                    .or(item.execution_retry_policy),
+            }
+        })
+}
+
+// Proposal parameters for the proposal details of any type
+fn proposal_details_parameters<T: crate::Trait>(
+    proposal_details: &ProposalDetailsOf<T>,
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    match proposal_details {
+        ProposalDetails::Text(_) => text_proposal::<T>(),
+        ProposalDetails::RuntimeUpgrade(_) | ProposalDetails::RuntimeUpgradeCodeHash(_) => {
+            runtime_upgrade_proposal::<T>()
+        }
+        ProposalDetails::SetElectionParameters(_) => set_election_parameters_proposal::<T>(),
+        ProposalDetails::Spending(..) => spending_proposal::<T>(),
+        ProposalDetails::SetLead(_) => set_lead_proposal::<T>(),
+        ProposalDetails::SetContentWorkingGroupMintCapacity(_) => {
+            set_content_working_group_mint_capacity_proposal::<T>()
+        }
+        ProposalDetails::EvictStorageProvider(_) => evict_storage_provider_proposal::<T>(),
+        ProposalDetails::SetValidatorCount(_) => set_validator_count_proposal::<T>(),
+        ProposalDetails::SetStorageRoleParameters(_) => set_storage_role_parameters_proposal::<T>(),
+        ProposalDetails::Batch(batch_items) => batch_proposal::<T>(batch_items),
+        ProposalDetails::SetProposalParameters(..) => set_proposal_parameters_proposal::<T>(),
+        ProposalDetails::AddContentWorkingGroupLeaderOpening(..) => {
+            add_content_working_group_leader_opening_proposal::<T>()
+        }
+        ProposalDetails::BeginReviewContentWorkingGroupLeaderApplications(_) => {
+            begin_review_content_working_group_leader_applications_proposal::<T>()
+        }
+        ProposalDetails::FillContentWorkingGroupLeaderOpening(..) => {
+            fill_content_working_group_leader_opening_proposal::<T>()
+        }
+        ProposalDetails::SlashContentWorkingGroupLeaderStake(_) => {
+            slash_content_working_group_leader_stake_proposal::<T>()
+        }
+        ProposalDetails::TerminateContentWorkingGroupLeaderRole(_) => {
+            terminate_content_working_group_leader_role_proposal::<T>()
+        }
     }
 }
+
+// Proposal parameters set by the 'Set proposal parameters' proposal or the default parameters
+// for the proposal type
+fn configured_or_default<T: crate::Trait>(
+    proposal_type: ProposalType,
+    default_parameters: ProposalParameters<T::BlockNumber, BalanceOf<T>>,
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    <Module<T>>::proposal_parameters_by_proposal_type(proposal_type).unwrap_or(default_parameters)
+}
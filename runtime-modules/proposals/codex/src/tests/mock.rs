@@ -5,7 +5,7 @@
 
 use crate::{ProposalDetailsOf, ProposalEncoder};
 pub use primitives::{Blake2Hasher, H256};
-use proposal_engine::VotersParameters;
+use proposal_engine::{VoterStakesProvider, VotersParameters};
 use sr_primitives::curve::PiecewiseLinear;
 pub use sr_primitives::{
     testing::{Digest, DigestItem, Header, UintAuthorityId},
@@ -89,22 +89,42 @@ parameter_types! {
     pub const RejectionFee: u64 = 3;
     pub const TitleMaxLength: u32 = 100;
     pub const DescriptionMaxLength: u32 = 10000;
+    pub const VetoRationaleMaxLength: u32 = 1000;
     pub const MaxActiveProposalLimit: u32 = 100;
+    pub const VotesPruningPolicy: proposal_engine::VoteRecordsPruningPolicy<u64> =
+        proposal_engine::VoteRecordsPruningPolicy::KeepForever;
+    pub const MinProposerStakePercentage: u32 = 60;
+    pub const FinalizedProposalRetentionPeriod: u64 = 100;
+    pub const MaxFinalizedProposalIds: u32 = 100;
+    pub const DeferredCodeTimeout: u64 = 10;
+    pub const MaxPendingConstitutionalityPeriod: u64 = 100;
+    pub const MaxProposalAmendments: u32 = 5;
 }
 
 impl proposal_engine::Trait for Test {
     type Event = ();
     type ProposerOriginValidator = ();
     type VoterOriginValidator = ();
+    type VetoOriginValidator = ();
     type TotalVotersCounter = MockVotersParameters;
+    type VoterStakesProvider = MockVotersParameters;
     type ProposalId = u32;
     type StakeHandlerProvider = proposal_engine::DefaultStakeHandlerProvider;
     type CancellationFee = CancellationFee;
     type RejectionFee = RejectionFee;
     type TitleMaxLength = TitleMaxLength;
     type DescriptionMaxLength = DescriptionMaxLength;
+    type VetoRationaleMaxLength = VetoRationaleMaxLength;
     type MaxActiveProposalLimit = MaxActiveProposalLimit;
     type DispatchableCallCode = crate::Call<Test>;
+    type VotesPruningPolicy = VotesPruningPolicy;
+    type ProposalFinalizationHandler = ProposalCodex;
+    type MinProposerStakePercentage = MinProposerStakePercentage;
+    type FinalizedProposalRetentionPeriod = FinalizedProposalRetentionPeriod;
+    type MaxFinalizedProposalIds = MaxFinalizedProposalIds;
+    type DeferredCodeTimeout = DeferredCodeTimeout;
+    type MaxPendingConstitutionalityPeriod = MaxPendingConstitutionalityPeriod;
+    type MaxProposalAmendments = MaxProposalAmendments;
 }
 
 impl Default for crate::Call<Test> {
@@ -118,9 +138,16 @@ impl mint::Trait for Test {
     type MintId = u64;
 }
 
+parameter_types! {
+    pub const MinRecallMotionStake: u64 = 100;
+}
+
 impl governance::council::Trait for Test {
     type Event = ();
     type CouncilTermEnded = ();
+    type CouncilSeatVacated = ();
+    type VotingParticipation = ();
+    type MinRecallMotionStake = MinRecallMotionStake;
 }
 
 impl common::origin_validator::ActorOriginValidator<Origin, u64, u64> for () {
@@ -136,17 +163,22 @@ parameter_types! {
     pub const MaxThreadInARowNumber: u32 = 3;
     pub const ThreadTitleLengthLimit: u32 = 200;
     pub const PostLengthLimit: u32 = 2000;
+    pub const MaxWhiteListSize: u32 = 20;
+    pub const MaxPostDepth: u32 = 5;
 }
 
 impl proposal_discussion::Trait for Test {
     type Event = ();
     type PostAuthorOriginValidator = ();
+    type CouncilOriginValidator = ();
     type ThreadId = u64;
     type PostId = u64;
     type MaxPostEditionNumber = MaxPostEditionNumber;
     type ThreadTitleLengthLimit = ThreadTitleLengthLimit;
     type PostLengthLimit = PostLengthLimit;
     type MaxThreadInARowNumber = MaxThreadInARowNumber;
+    type MaxWhiteListSize = MaxWhiteListSize;
+    type MaxPostDepth = MaxPostDepth;
 }
 
 pub struct MockVotersParameters;
@@ -156,14 +188,33 @@ impl VotersParameters for MockVotersParameters {
     }
 }
 
+impl VoterStakesProvider<u64, u64> for MockVotersParameters {
+    fn voter_stake(_voter_id: &u64) -> u64 {
+        0
+    }
+
+    fn total_voters_stake() -> u64 {
+        0
+    }
+}
+
 parameter_types! {
     pub const TextProposalMaxLength: u32 = 20_000;
     pub const RuntimeUpgradeWasmProposalMaxLength: u32 = 20_000;
 }
 
+parameter_types! {
+    pub const MaxArchivedElections: u32 = 10;
+    pub const MaxDelegatorsPerDelegate: u32 = 10;
+    pub const MaxEscrowedVotes: u32 = 10;
+}
+
 impl governance::election::Trait for Test {
     type Event = ();
     type CouncilElected = ();
+    type MaxArchivedElections = MaxArchivedElections;
+    type MaxDelegatorsPerDelegate = MaxDelegatorsPerDelegate;
+    type MaxEscrowedVotes = MaxEscrowedVotes;
 }
 
 impl content_working_group::Trait for Test {
@@ -172,6 +223,7 @@ impl content_working_group::Trait for Test {
 
 impl recurring_rewards::Trait for Test {
     type PayoutStatusHandler = ();
+    type PayoutPenaltyPolicy = ();
     type RecipientId = u64;
     type RewardRelationshipId = u64;
 }
@@ -1,19 +1,25 @@
 mod mock;
 
-use governance::election_params::ElectionParameters;
+use governance::election_params::{ElectionParameters, TallyMethod};
 use srml_support::traits::Currency;
-use srml_support::StorageMap;
+use srml_support::{StorageLinkedMap, StorageMap, StorageValue};
 use system::RawOrigin;
 
 use crate::*;
 use crate::{BalanceOf, Error, ProposalDetails};
-use proposal_engine::ProposalParameters;
+use content_working_group::{CuratorApplication, CuratorOpening, OpeningType, RewardPolicy};
+use proposal_engine::{ExecutionRetryPolicy, ProposalParameters};
 use roles::actors::RoleParameters;
+use sr_primitives::traits::{Hash, OnFinalize};
 use srml_support::dispatch::DispatchResult;
 
 use crate::proposal_types::ProposalsConfigParameters;
 pub use mock::*;
 
+fn runtime_upgrade_code_hash(wasm: &[u8]) -> H256 {
+    <Test as system::Trait>::Hashing::hash(wasm)
+}
+
 pub(crate) fn increase_total_balance_issuance(balance: u64) {
     increase_total_balance_issuance_using_account_id(999, balance);
 }
@@ -38,7 +44,7 @@ where
     invalid_stake_call: InvalidStakeCall,
     successful_call: SuccessfulCall,
     proposal_parameters: ProposalParameters<u64, u64>,
-    proposal_details: ProposalDetails<u64, u64, u64, u64, u64>,
+    proposal_details: ProposalDetails<u64, u64, u64, u64, u64, u64, u64, H256>,
 }
 
 impl<InsufficientRightsCall, EmptyStakeCall, InvalidStakeCall, SuccessfulCall>
@@ -54,7 +60,7 @@ where
 
         assert_eq!(
             (self.invalid_stake_call)(),
-            Err(Error::Other("StakeDiffersFromRequired"))
+            Err(Error::Other("StakeIsBelowMinimum"))
         );
     }
 
@@ -105,6 +111,7 @@ fn create_text_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    ThreadMode::Open,
                     b"text".to_vec(),
                 )
             },
@@ -115,6 +122,7 @@ fn create_text_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    ThreadMode::Open,
                     b"text".to_vec(),
                 )
             },
@@ -125,6 +133,7 @@ fn create_text_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
+                    ThreadMode::Open,
                     b"text".to_vec(),
                 )
             },
@@ -135,6 +144,7 @@ fn create_text_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(25000u32)),
+                    ThreadMode::Open,
                     b"text".to_vec(),
                 )
             },
@@ -158,6 +168,7 @@ fn create_text_proposal_codex_call_fails_with_incorrect_text_size() {
                 b"title".to_vec(),
                 b"body".to_vec(),
                 None,
+                ThreadMode::Open,
                 long_text,
             ),
             Err(Error::TextProposalSizeExceeded)
@@ -170,6 +181,7 @@ fn create_text_proposal_codex_call_fails_with_incorrect_text_size() {
                 b"title".to_vec(),
                 b"body".to_vec(),
                 None,
+                ThreadMode::Open,
                 Vec::new(),
             ),
             Err(Error::TextProposalIsEmpty)
@@ -189,8 +201,9 @@ fn create_runtime_upgrade_common_checks_succeed() {
                     1,
                     b"title".to_vec(),
                     b"body".to_vec(),
+                    None, ThreadMode::Open,
+                    runtime_upgrade_code_hash(b"wasm"),
                     None,
-                    b"wasm".to_vec(),
                 )
             },
             empty_stake_call: || {
@@ -199,8 +212,9 @@ fn create_runtime_upgrade_common_checks_succeed() {
                     1,
                     b"title".to_vec(),
                     b"body".to_vec(),
+                    None, ThreadMode::Open,
+                    runtime_upgrade_code_hash(b"wasm"),
                     None,
-                    b"wasm".to_vec(),
                 )
             },
             invalid_stake_call: || {
@@ -209,8 +223,9 @@ fn create_runtime_upgrade_common_checks_succeed() {
                     1,
                     b"title".to_vec(),
                     b"body".to_vec(),
-                    Some(<BalanceOf<Test>>::from(500u32)),
-                    b"wasm".to_vec(),
+                    Some(<BalanceOf<Test>>::from(500u32)), ThreadMode::Open,
+                    runtime_upgrade_code_hash(b"wasm"),
+                    None,
                 )
             },
             successful_call: || {
@@ -219,33 +234,34 @@ fn create_runtime_upgrade_common_checks_succeed() {
                     1,
                     b"title".to_vec(),
                     b"body".to_vec(),
-                    Some(<BalanceOf<Test>>::from(1_000_000_u32)),
-                    b"wasm".to_vec(),
+                    Some(<BalanceOf<Test>>::from(1_000_000_u32)), ThreadMode::Open,
+                    runtime_upgrade_code_hash(b"wasm"),
+                    None,
                 )
             },
             proposal_parameters: crate::proposal_types::parameters::runtime_upgrade_proposal::<Test>(),
-            proposal_details: ProposalDetails::RuntimeUpgrade(b"wasm".to_vec()),
+            proposal_details: ProposalDetails::RuntimeUpgradeCodeHash(runtime_upgrade_code_hash(b"wasm")),
         };
         proposal_fixture.check_all();
     });
 }
 
 #[test]
-fn create_upgrade_runtime_proposal_codex_call_fails_with_incorrect_wasm_size() {
+fn provide_runtime_upgrade_code_fails_with_invalid_code() {
     initial_test_ext().execute_with(|| {
-        let origin = RawOrigin::Signed(1).into();
+        increase_total_balance_issuance_using_account_id(1, 5000000);
 
-        let long_wasm = [0u8; 30000].to_vec();
         assert_eq!(
-            ProposalCodex::create_runtime_upgrade_proposal(
-                origin,
+            ProposalCodex::create_text_proposal(
+                RawOrigin::Signed(1).into(),
                 1,
                 b"title".to_vec(),
                 b"body".to_vec(),
-                None,
-                long_wasm,
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                ThreadMode::Open,
+                b"text".to_vec(),
             ),
-            Err(Error::RuntimeProposalSizeExceeded)
+            Ok(())
         );
 
         assert_eq!(
@@ -254,14 +270,98 @@ fn create_upgrade_runtime_proposal_codex_call_fails_with_incorrect_wasm_size() {
                 1,
                 b"title".to_vec(),
                 b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(1_000_000_u32)),
+                ThreadMode::Open,
+                runtime_upgrade_code_hash(b"wasm"),
                 None,
+            ),
+            Ok(())
+        );
+
+        let text_proposal_id = 1;
+        let runtime_upgrade_proposal_id = 2;
+
+        assert_eq!(
+            ProposalCodex::provide_runtime_upgrade_code(
+                RawOrigin::None.into(),
+                runtime_upgrade_proposal_id,
+                b"wasm".to_vec(),
+            ),
+            Err(Error::Other("RequireSignedOrigin"))
+        );
+
+        assert_eq!(
+            ProposalCodex::provide_runtime_upgrade_code(
+                RawOrigin::Signed(2).into(),
+                text_proposal_id,
+                b"wasm".to_vec(),
+            ),
+            Err(Error::NotRuntimeUpgradeCodeHashProposal)
+        );
+
+        let long_wasm = [0u8; 30000].to_vec();
+        assert_eq!(
+            ProposalCodex::provide_runtime_upgrade_code(
+                RawOrigin::Signed(2).into(),
+                runtime_upgrade_proposal_id,
+                long_wasm,
+            ),
+            Err(Error::RuntimeProposalSizeExceeded)
+        );
+
+        assert_eq!(
+            ProposalCodex::provide_runtime_upgrade_code(
+                RawOrigin::Signed(2).into(),
+                runtime_upgrade_proposal_id,
                 Vec::new(),
             ),
             Err(Error::RuntimeProposalIsEmpty)
         );
+
+        assert_eq!(
+            ProposalCodex::provide_runtime_upgrade_code(
+                RawOrigin::Signed(2).into(),
+                runtime_upgrade_proposal_id,
+                b"another wasm".to_vec(),
+            ),
+            Err(Error::RuntimeUpgradeCodeHashMismatch)
+        );
+
+        // the code can be provided only after the approval
+        assert_eq!(
+            ProposalCodex::provide_runtime_upgrade_code(
+                RawOrigin::Signed(2).into(),
+                runtime_upgrade_proposal_id,
+                b"wasm".to_vec(),
+            ),
+            Err(Error::Other("ProposalIsNotPendingExecution"))
+        );
+        assert!(ProposalsEngine::is_deferred_code_proposal(
+            runtime_upgrade_proposal_id
+        ));
     });
 }
 
+#[test]
+fn create_upgrade_runtime_proposal_codex_call_fails_with_invalid_exact_execution_block() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 5000000);
+
+        assert_eq!(
+            ProposalCodex::create_runtime_upgrade_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(1_000_000_u32)),
+                ThreadMode::Open,
+                runtime_upgrade_code_hash(b"wasm"),
+                Some(1),
+            ),
+            Err(Error::Other("InvalidExactExecutionBlock"))
+        );
+    });
+}
 #[test]
 fn create_set_election_parameters_proposal_common_checks_succeed() {
     initial_test_ext().execute_with(|| {
@@ -275,6 +375,7 @@ fn create_set_election_parameters_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    ThreadMode::Open,
                     get_valid_election_parameters(),
                 )
             },
@@ -285,6 +386,7 @@ fn create_set_election_parameters_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    ThreadMode::Open,
                     get_valid_election_parameters(),
                 )
             },
@@ -295,6 +397,7 @@ fn create_set_election_parameters_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(50000u32)),
+                    ThreadMode::Open,
                     get_valid_election_parameters(),
                 )
             },
@@ -305,6 +408,7 @@ fn create_set_election_parameters_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(200_000_u32)),
+                    ThreadMode::Open,
                     get_valid_election_parameters(),
                 )
             },
@@ -329,6 +433,7 @@ fn assert_failed_election_parameters_call(
             b"title".to_vec(),
             b"body".to_vec(),
             Some(<BalanceOf<Test>>::from(3750u32)),
+            ThreadMode::Open,
             election_parameters,
         ),
         Err(error)
@@ -345,6 +450,8 @@ fn get_valid_election_parameters() -> ElectionParameters<u64, u64> {
         new_term_duration: 14400,
         min_council_stake: 1,
         min_voting_stake: 1,
+        tally_method: TallyMethod::FirstPastThePost,
+        max_ranked_candidates: 0,
     }
 }
 
@@ -422,6 +529,20 @@ fn create_set_election_parameters_call_fails_with_incorrect_parameters() {
             Error::InvalidCouncilElectionParameterMinCouncilStake,
         );
 
+        election_parameters = get_valid_election_parameters();
+        election_parameters.tally_method = TallyMethod::SingleTransferableVote;
+        assert_failed_election_parameters_call(
+            election_parameters,
+            Error::InvalidCouncilElectionParameterMaxRankedCandidates,
+        );
+
+        election_parameters = get_valid_election_parameters();
+        election_parameters.max_ranked_candidates = 21;
+        assert_failed_election_parameters_call(
+            election_parameters,
+            Error::InvalidCouncilElectionParameterMaxRankedCandidates,
+        );
+
         election_parameters = get_valid_election_parameters();
         election_parameters.voting_period = 10000;
         assert_failed_election_parameters_call(
@@ -478,6 +599,7 @@ fn create_working_group_mint_capacity_proposal_fails_with_invalid_parameters() {
                 b"title".to_vec(),
                 b"body".to_vec(),
                 Some(<BalanceOf<Test>>::from(50000u32)),
+                ThreadMode::Open,
                 (crate::CONTENT_WORKING_GROUP_MINT_CAPACITY_MAX_VALUE + 1) as u64,
             ),
             Err(Error::InvalidStorageWorkingGroupMintCapacity)
@@ -497,7 +619,7 @@ fn create_set_content_working_group_mint_capacity_proposal_common_checks_succeed
                     1,
                     b"title".to_vec(),
                     b"body".to_vec(),
-                    None,
+                    None, ThreadMode::Open,
                     0,
                 )
             },
@@ -507,7 +629,7 @@ fn create_set_content_working_group_mint_capacity_proposal_common_checks_succeed
                     1,
                     b"title".to_vec(),
                     b"body".to_vec(),
-                    None,
+                    None, ThreadMode::Open,
                     0,
                 )
             },
@@ -517,7 +639,7 @@ fn create_set_content_working_group_mint_capacity_proposal_common_checks_succeed
                     1,
                     b"title".to_vec(),
                     b"body".to_vec(),
-                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    Some(<BalanceOf<Test>>::from(5000u32)), ThreadMode::Open,
                     0,
                 )
             },
@@ -527,7 +649,7 @@ fn create_set_content_working_group_mint_capacity_proposal_common_checks_succeed
                     1,
                     b"title".to_vec(),
                     b"body".to_vec(),
-                    Some(<BalanceOf<Test>>::from(50000u32)),
+                    Some(<BalanceOf<Test>>::from(50000u32)), ThreadMode::Open,
                     10,
                 )
             },
@@ -551,6 +673,7 @@ fn create_spending_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    ThreadMode::Open,
                     20,
                     10,
                 )
@@ -562,6 +685,7 @@ fn create_spending_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    ThreadMode::Open,
                     20,
                     10,
                 )
@@ -573,6 +697,7 @@ fn create_spending_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
+                    ThreadMode::Open,
                     20,
                     10,
                 )
@@ -584,6 +709,7 @@ fn create_spending_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(25000u32)),
+                    ThreadMode::Open,
                     100,
                     2,
                 )
@@ -607,6 +733,7 @@ fn create_spending_proposal_call_fails_with_incorrect_balance() {
                 b"title".to_vec(),
                 b"body".to_vec(),
                 Some(<BalanceOf<Test>>::from(1250u32)),
+                ThreadMode::Open,
                 0,
                 2,
             ),
@@ -620,6 +747,7 @@ fn create_spending_proposal_call_fails_with_incorrect_balance() {
                 b"title".to_vec(),
                 b"body".to_vec(),
                 Some(<BalanceOf<Test>>::from(1250u32)),
+                ThreadMode::Open,
                 2000001,
                 2,
             ),
@@ -647,6 +775,7 @@ fn create_set_lead_proposal_fails_with_proposed_councilor() {
                 b"title".to_vec(),
                 b"body".to_vec(),
                 Some(<BalanceOf<Test>>::from(1250u32)),
+                ThreadMode::Open,
                 Some((20, lead_account_id)),
             ),
             Err(Error::InvalidSetLeadParameterCannotBeCouncilor)
@@ -667,6 +796,7 @@ fn create_set_lead_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    ThreadMode::Open,
                     Some((20, 10)),
                 )
             },
@@ -677,6 +807,7 @@ fn create_set_lead_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    ThreadMode::Open,
                     Some((20, 10)),
                 )
             },
@@ -687,6 +818,7 @@ fn create_set_lead_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
+                    ThreadMode::Open,
                     Some((20, 10)),
                 )
             },
@@ -697,6 +829,7 @@ fn create_set_lead_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(50000u32)),
+                    ThreadMode::Open,
                     Some((20, 10)),
                 )
             },
@@ -719,7 +852,7 @@ fn create_evict_storage_provider_proposal_common_checks_succeed() {
                     1,
                     b"title".to_vec(),
                     b"body".to_vec(),
-                    None,
+                    None, ThreadMode::Open,
                     1,
                 )
             },
@@ -729,7 +862,7 @@ fn create_evict_storage_provider_proposal_common_checks_succeed() {
                     1,
                     b"title".to_vec(),
                     b"body".to_vec(),
-                    None,
+                    None, ThreadMode::Open,
                     1,
                 )
             },
@@ -739,7 +872,7 @@ fn create_evict_storage_provider_proposal_common_checks_succeed() {
                     1,
                     b"title".to_vec(),
                     b"body".to_vec(),
-                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    Some(<BalanceOf<Test>>::from(5000u32)), ThreadMode::Open,
                     1,
                 )
             },
@@ -749,7 +882,7 @@ fn create_evict_storage_provider_proposal_common_checks_succeed() {
                     1,
                     b"title".to_vec(),
                     b"body".to_vec(),
-                    Some(<BalanceOf<Test>>::from(25000u32)),
+                    Some(<BalanceOf<Test>>::from(25000u32)), ThreadMode::Open,
                     1,
                 )
             },
@@ -773,7 +906,9 @@ fn create_set_validator_count_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    ThreadMode::Open,
                     4,
+                    None,
                 )
             },
             empty_stake_call: || {
@@ -783,7 +918,9 @@ fn create_set_validator_count_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    ThreadMode::Open,
                     4,
+                    None,
                 )
             },
             invalid_stake_call: || {
@@ -793,7 +930,9 @@ fn create_set_validator_count_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
+                    ThreadMode::Open,
                     4,
+                    None,
                 )
             },
             successful_call: || {
@@ -803,7 +942,9 @@ fn create_set_validator_count_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(100_000_u32)),
+                    ThreadMode::Open,
                     4,
+                    None,
                 )
             },
             proposal_parameters: crate::proposal_types::parameters::set_validator_count_proposal::<
@@ -825,7 +966,9 @@ fn create_set_validator_count_proposal_failed_with_invalid_validator_count() {
                 b"title".to_vec(),
                 b"body".to_vec(),
                 Some(<BalanceOf<Test>>::from(500u32)),
+                ThreadMode::Open,
                 3,
+                None,
             ),
             Err(Error::InvalidValidatorCount)
         );
@@ -837,7 +980,9 @@ fn create_set_validator_count_proposal_failed_with_invalid_validator_count() {
                 b"title".to_vec(),
                 b"body".to_vec(),
                 Some(<BalanceOf<Test>>::from(1001u32)),
+                ThreadMode::Open,
                 3,
+                None,
             ),
             Err(Error::InvalidValidatorCount)
         );
@@ -860,6 +1005,7 @@ fn create_set_storage_role_parameters_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    ThreadMode::Open,
                     role_parameters.clone(),
                 )
             },
@@ -870,6 +1016,7 @@ fn create_set_storage_role_parameters_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    ThreadMode::Open,
                     role_parameters.clone(),
                 )
             },
@@ -880,6 +1027,7 @@ fn create_set_storage_role_parameters_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
+                    ThreadMode::Open,
                     role_parameters.clone(),
                 )
             },
@@ -890,6 +1038,7 @@ fn create_set_storage_role_parameters_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(100_000_u32)),
+                    ThreadMode::Open,
                     role_parameters.clone(),
                 )
             },
@@ -912,6 +1061,7 @@ fn assert_failed_set_storage_parameters_call(
             b"title".to_vec(),
             b"body".to_vec(),
             Some(<BalanceOf<Test>>::from(100_000_u32)),
+            ThreadMode::Open,
             role_parameters,
         ),
         Err(error)
@@ -1061,6 +1211,74 @@ fn create_set_storage_role_parameters_proposal_fails_with_invalid_parameters() {
     });
 }
 
+#[test]
+fn set_missing_proposal_parameters_keeps_existing_values() {
+    initial_test_ext().execute_with(|| {
+        let p = ProposalsConfigParameters::default();
+
+        <TextProposalVotingPeriod<Test>>::put(5);
+
+        ProposalCodex::set_missing_config_values();
+
+        assert_eq!(<TextProposalVotingPeriod<Test>>::get(), 5);
+        assert_eq!(
+            <TextProposalGracePeriod<Test>>::get(),
+            p.text_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <TerminateContentWorkingGroupLeaderRoleProposalVotingPeriod<Test>>::get(),
+            p.terminate_content_working_group_leader_role_proposal_voting_period as u64
+        );
+    });
+}
+
+#[test]
+fn migrate_proposal_details_converts_legacy_election_parameters() {
+    initial_test_ext().execute_with(|| {
+        use crate::migration::LegacyElectionParameters;
+        use codec::Encode;
+
+        let legacy_parameters = LegacyElectionParameters {
+            announcing_period: 14400,
+            voting_period: 14400,
+            revealing_period: 14400,
+            council_size: 4,
+            candidacy_limit: 25,
+            new_term_duration: 14400,
+            min_council_stake: 1,
+            min_voting_stake: 1,
+        };
+
+        // encoded `set election parameters` details of the previous runtime version
+        let mut encoded_details = vec![2u8];
+        encoded_details.extend(legacy_parameters.encode());
+
+        srml_support::storage::unhashed::put_raw(
+            &<ProposalDetailsByProposalId<Test>>::hashed_key_for(1),
+            &encoded_details,
+        );
+        proposal_engine::ProposalCount::put(1);
+
+        ProposalCodex::migrate_proposal_details();
+
+        assert_eq!(
+            <ProposalDetailsByProposalId<Test>>::get(1),
+            ProposalDetails::SetElectionParameters(ElectionParameters {
+                announcing_period: 14400,
+                voting_period: 14400,
+                revealing_period: 14400,
+                council_size: 4,
+                candidacy_limit: 25,
+                new_term_duration: 14400,
+                min_council_stake: 1,
+                min_voting_stake: 1,
+                tally_method: TallyMethod::FirstPastThePost,
+                max_ranked_candidates: 0,
+            })
+        );
+    });
+}
+
 #[test]
 fn set_default_proposal_parameters_succeeded() {
     initial_test_ext().execute_with(|| {
@@ -1143,5 +1361,1108 @@ fn set_default_proposal_parameters_succeeded() {
             <SetStorageRoleParametersProposalGracePeriod<Test>>::get(),
             p.set_storage_role_parameters_proposal_grace_period as u64
         );
+        assert_eq!(
+            <SetProposalParametersProposalVotingPeriod<Test>>::get(),
+            p.set_proposal_parameters_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <SetProposalParametersProposalGracePeriod<Test>>::get(),
+            p.set_proposal_parameters_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <AddContentWorkingGroupLeaderOpeningProposalVotingPeriod<Test>>::get(),
+            p.add_content_working_group_leader_opening_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <AddContentWorkingGroupLeaderOpeningProposalGracePeriod<Test>>::get(),
+            p.add_content_working_group_leader_opening_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <BeginReviewContentWorkingGroupLeaderApplicationsProposalVotingPeriod<Test>>::get(),
+            p.begin_review_content_working_group_leader_applications_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <BeginReviewContentWorkingGroupLeaderApplicationsProposalGracePeriod<Test>>::get(),
+            p.begin_review_content_working_group_leader_applications_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <FillContentWorkingGroupLeaderOpeningProposalVotingPeriod<Test>>::get(),
+            p.fill_content_working_group_leader_opening_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <FillContentWorkingGroupLeaderOpeningProposalGracePeriod<Test>>::get(),
+            p.fill_content_working_group_leader_opening_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <SlashContentWorkingGroupLeaderStakeProposalVotingPeriod<Test>>::get(),
+            p.slash_content_working_group_leader_stake_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <SlashContentWorkingGroupLeaderStakeProposalGracePeriod<Test>>::get(),
+            p.slash_content_working_group_leader_stake_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <TerminateContentWorkingGroupLeaderRoleProposalVotingPeriod<Test>>::get(),
+            p.terminate_content_working_group_leader_role_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <TerminateContentWorkingGroupLeaderRoleProposalGracePeriod<Test>>::get(),
+            p.terminate_content_working_group_leader_role_proposal_grace_period as u64
+        );
+    });
+}
+
+#[test]
+fn create_batch_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let batch_items = vec![
+            ProposalDetails::Text(b"text".to_vec()),
+            ProposalDetails::SetContentWorkingGroupMintCapacity(100),
+        ];
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_batch_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    ThreadMode::Open,
+                    batch_items.clone(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_batch_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    ThreadMode::Open,
+                    batch_items.clone(),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_batch_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(25000u32)),
+                    ThreadMode::Open,
+                    batch_items.clone(),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_batch_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(50000u32)),
+                    ThreadMode::Open,
+                    batch_items.clone(),
+                )
+            },
+            proposal_parameters: crate::proposal_types::parameters::batch_proposal::<Test>(
+                &batch_items,
+            ),
+            proposal_details: ProposalDetails::Batch(batch_items.clone()),
+        };
+        proposal_fixture.check_all();
+
+        assert!(ProposalsEngine::is_batch_proposal(1));
+    });
+}
+
+#[test]
+fn batch_proposal_uses_the_strictest_item_parameters() {
+    initial_test_ext().execute_with(|| {
+        let text_parameters = crate::proposal_types::parameters::text_proposal::<Test>();
+        let mint_capacity_parameters =
+            crate::proposal_types::parameters::set_content_working_group_mint_capacity_proposal::<
+                Test,
+            >();
+
+        let batch_parameters = crate::proposal_types::parameters::batch_proposal::<Test>(&[
+            ProposalDetails::Text(b"text".to_vec()),
+            ProposalDetails::SetContentWorkingGroupMintCapacity(100),
+        ]);
+
+        assert_eq!(
+            batch_parameters.approval_threshold_percentage,
+            text_parameters.approval_threshold_percentage
+        );
+        assert_eq!(
+            batch_parameters.required_stake,
+            mint_capacity_parameters.required_stake
+        );
+    });
+}
+
+fn assert_failed_batch_proposal_call(
+    batch_items: Vec<ProposalDetails<u64, u64, u64, u64, u64, u64, u64, H256>>,
+    error: Error,
+) {
+    assert_eq!(
+        ProposalCodex::create_batch_proposal(
+            RawOrigin::Signed(1).into(),
+            1,
+            b"title".to_vec(),
+            b"body".to_vec(),
+            Some(<BalanceOf<Test>>::from(50000u32)),
+            ThreadMode::Open,
+            batch_items,
+        ),
+        Err(error)
+    );
+}
+
+#[test]
+fn create_batch_proposal_codex_call_fails_with_invalid_items() {
+    initial_test_ext().execute_with(|| {
+        assert_failed_batch_proposal_call(Vec::new(), Error::BatchProposalIsEmpty);
+
+        assert_failed_batch_proposal_call(
+            vec![ProposalDetails::Text(b"text".to_vec()); 6],
+            Error::BatchProposalSizeExceeded,
+        );
+
+        assert_failed_batch_proposal_call(
+            vec![ProposalDetails::Batch(vec![ProposalDetails::Text(
+                b"text".to_vec(),
+            )])],
+            Error::NestedBatchProposal,
+        );
+
+        assert_failed_batch_proposal_call(
+            vec![ProposalDetails::RuntimeUpgradeCodeHash(
+                runtime_upgrade_code_hash(b"wasm"),
+            )],
+            Error::RuntimeUpgradeCodeHashInBatchProposal,
+        );
+
+        assert_failed_batch_proposal_call(
+            vec![
+                ProposalDetails::Text(b"text".to_vec()),
+                ProposalDetails::RuntimeUpgrade(b"wasm".to_vec()),
+            ],
+            Error::RuntimeUpgradeInBatchProposal,
+        );
+
+        assert_failed_batch_proposal_call(
+            vec![
+                ProposalDetails::Text(b"text".to_vec()),
+                ProposalDetails::Spending(100, 2),
+            ],
+            Error::FallibleBatchProposalItemIsNotFirst,
+        );
+
+        assert_failed_batch_proposal_call(
+            vec![
+                ProposalDetails::Text(b"text".to_vec()),
+                ProposalDetails::Text(Vec::new()),
+            ],
+            Error::TextProposalIsEmpty,
+        );
+    });
+}
+
+#[test]
+fn create_batch_proposal_accepts_fallible_first_item() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let batch_items = vec![
+            ProposalDetails::Spending(100, 2),
+            ProposalDetails::Text(b"text".to_vec()),
+        ];
+        let proposal_parameters =
+            crate::proposal_types::parameters::batch_proposal::<Test>(&batch_items);
+
+        assert_eq!(
+            ProposalCodex::create_batch_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                proposal_parameters.required_stake,
+                ThreadMode::Open,
+                batch_items,
+            ),
+            Ok(())
+        );
+    });
+}
+
+#[test]
+fn amend_text_proposal_succeeds() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        assert_eq!(
+            ProposalCodex::create_text_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                ThreadMode::Open,
+                b"text with a typo".to_vec(),
+            ),
+            Ok(())
+        );
+
+        let proposal_id = 1;
+        assert_eq!(
+            ProposalCodex::amend_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                proposal_id,
+                ProposalDetails::Text(b"text".to_vec()),
+            ),
+            Ok(())
+        );
+
+        assert_eq!(
+            <crate::ProposalDetailsByProposalId<Test>>::get(proposal_id),
+            ProposalDetails::Text(b"text".to_vec())
+        );
+        assert_eq!(
+            ProposalCodex::proposal_details_amendment_history(proposal_id),
+            vec![ProposalDetails::Text(b"text with a typo".to_vec())]
+        );
+
+        let proposal = ProposalsEngine::proposals(proposal_id);
+        assert_eq!(proposal.amendments.len(), 1);
+    });
+}
+
+#[test]
+fn amend_proposal_codex_call_fails_with_invalid_amendment() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        assert_eq!(
+            ProposalCodex::create_text_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                ThreadMode::Open,
+                b"text".to_vec(),
+            ),
+            Ok(())
+        );
+
+        let proposal_id = 1;
+        assert_eq!(
+            ProposalCodex::amend_proposal(
+                RawOrigin::None.into(),
+                1,
+                proposal_id,
+                ProposalDetails::Text(b"new text".to_vec()),
+            ),
+            Err(Error::Other("RequireSignedOrigin"))
+        );
+
+        assert_eq!(
+            ProposalCodex::amend_proposal(
+                RawOrigin::Signed(2).into(),
+                2,
+                proposal_id,
+                ProposalDetails::Text(b"new text".to_vec()),
+            ),
+            Err(Error::Other("NotAuthor"))
+        );
+
+        assert_eq!(
+            ProposalCodex::amend_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                proposal_id,
+                ProposalDetails::SetContentWorkingGroupMintCapacity(100),
+            ),
+            Err(Error::AmendedProposalTypeMismatch)
+        );
+
+        assert_eq!(
+            ProposalCodex::amend_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                proposal_id,
+                ProposalDetails::Text(Vec::new()),
+            ),
+            Err(Error::TextProposalIsEmpty)
+        );
+    });
+}
+
+#[test]
+fn create_set_proposal_parameters_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 1_000_000);
+
+        let text_parameters = ProposalParameters {
+            approval_quorum_percentage: 70,
+            voting_period: 100,
+            ..crate::proposal_types::parameters::text_proposal::<Test>()
+        };
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_set_proposal_parameters_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    ThreadMode::Open,
+                    ProposalType::Text,
+                    text_parameters,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_set_proposal_parameters_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    ThreadMode::Open,
+                    ProposalType::Text,
+                    text_parameters,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_set_proposal_parameters_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    ThreadMode::Open,
+                    ProposalType::Text,
+                    text_parameters,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_set_proposal_parameters_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(500_000u32)),
+                    ThreadMode::Open,
+                    ProposalType::Text,
+                    text_parameters,
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::set_proposal_parameters_proposal::<Test>(),
+            proposal_details: ProposalDetails::SetProposalParameters(
+                ProposalType::Text,
+                text_parameters,
+            ),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn set_proposal_parameters_overrides_default_parameters() {
+    initial_test_ext().execute_with(|| {
+        let text_parameters = ProposalParameters {
+            approval_quorum_percentage: 70,
+            voting_period: 100,
+            ..crate::proposal_types::parameters::text_proposal::<Test>()
+        };
+
+        assert_eq!(
+            ProposalCodex::set_proposal_parameters(
+                RawOrigin::Signed(1).into(),
+                ProposalType::Text,
+                text_parameters,
+            ),
+            Err(Error::RequireRootOrigin)
+        );
+
+        assert_eq!(
+            ProposalCodex::set_proposal_parameters(
+                RawOrigin::Root.into(),
+                ProposalType::Text,
+                text_parameters,
+            ),
+            Ok(())
+        );
+
+        assert_eq!(
+            crate::proposal_types::parameters::text_proposal::<Test>(),
+            text_parameters
+        );
+        assert_ne!(
+            crate::proposal_types::parameters::spending_proposal::<Test>(),
+            text_parameters
+        );
+    });
+}
+
+fn assert_failed_set_proposal_parameters_call(
+    proposal_parameters: ProposalParameters<u64, u64>,
+    error: Error,
+) {
+    assert_eq!(
+        ProposalCodex::create_set_proposal_parameters_proposal(
+            RawOrigin::Signed(1).into(),
+            1,
+            b"title".to_vec(),
+            b"body".to_vec(),
+            Some(<BalanceOf<Test>>::from(500_000u32)),
+            ThreadMode::Open,
+            ProposalType::Spending,
+            proposal_parameters,
+        ),
+        Err(error)
+    );
+}
+
+#[test]
+fn create_set_proposal_parameters_proposal_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        let parameters = ProposalParameters {
+            voting_period: 100,
+            ..crate::proposal_types::parameters::spending_proposal::<Test>()
+        };
+
+        assert_failed_set_proposal_parameters_call(
+            ProposalParameters {
+                voting_period: 0,
+                ..parameters
+            },
+            Error::InvalidProposalParametersVotingPeriod,
+        );
+
+        assert_failed_set_proposal_parameters_call(
+            ProposalParameters {
+                approval_quorum_percentage: 101,
+                ..parameters
+            },
+            Error::InvalidProposalParametersPercentage,
+        );
+
+        assert_failed_set_proposal_parameters_call(
+            ProposalParameters {
+                slashing_threshold_percentage: 0,
+                ..parameters
+            },
+            Error::InvalidProposalParametersPercentage,
+        );
+
+        assert_failed_set_proposal_parameters_call(
+            ProposalParameters {
+                constitutionality: 0,
+                ..parameters
+            },
+            Error::InvalidProposalParametersConstitutionality,
+        );
+
+        assert_failed_set_proposal_parameters_call(
+            ProposalParameters {
+                execution_retry_policy: Some(ExecutionRetryPolicy {
+                    retry_interval: 0,
+                    retry_period: 100,
+                }),
+                ..parameters
+            },
+            Error::InvalidProposalParametersExecutionRetryInterval,
+        );
+    });
+}
+
+fn add_content_working_group_opening(opening_id: u64, opening_type: OpeningType) {
+    <content_working_group::CuratorOpeningById<Test>>::insert(
+        opening_id,
+        CuratorOpening {
+            opening_type,
+            ..CuratorOpening::default()
+        },
+    );
+}
+
+fn add_content_working_group_application(application_id: u64, opening_id: u64) {
+    <content_working_group::CuratorApplicationById<Test>>::insert(
+        application_id,
+        CuratorApplication {
+            curator_opening_id: opening_id,
+            ..CuratorApplication::default()
+        },
+    );
+}
+
+fn set_content_working_group_lead() {
+    <content_working_group::CurrentLeadId<Test>>::put(0);
+}
+
+#[test]
+fn create_add_content_working_group_leader_opening_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_add_content_working_group_leader_opening_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None, ThreadMode::Open,
+                    OpeningPolicyCommitment::default(),
+                    b"opening".to_vec(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_add_content_working_group_leader_opening_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None, ThreadMode::Open,
+                    OpeningPolicyCommitment::default(),
+                    b"opening".to_vec(),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_add_content_working_group_leader_opening_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)), ThreadMode::Open,
+                    OpeningPolicyCommitment::default(),
+                    b"opening".to_vec(),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_add_content_working_group_leader_opening_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(50000u32)), ThreadMode::Open,
+                    OpeningPolicyCommitment::default(),
+                    b"opening".to_vec(),
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::add_content_working_group_leader_opening_proposal::<Test>(),
+            proposal_details: ProposalDetails::AddContentWorkingGroupLeaderOpening(
+                OpeningPolicyCommitment::default(),
+                b"opening".to_vec(),
+            ),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_begin_review_content_working_group_leader_applications_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+        add_content_working_group_opening(0, OpeningType::Leader);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_begin_review_content_working_group_leader_applications_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None, ThreadMode::Open,
+                    0,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_begin_review_content_working_group_leader_applications_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None, ThreadMode::Open,
+                    0,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_begin_review_content_working_group_leader_applications_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)), ThreadMode::Open,
+                    0,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_begin_review_content_working_group_leader_applications_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(25000u32)), ThreadMode::Open,
+                    0,
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::begin_review_content_working_group_leader_applications_proposal::<Test>(),
+            proposal_details: ProposalDetails::BeginReviewContentWorkingGroupLeaderApplications(0),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_fill_content_working_group_leader_opening_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+        add_content_working_group_opening(0, OpeningType::Leader);
+        add_content_working_group_application(1, 0);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_fill_content_working_group_leader_opening_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None, ThreadMode::Open,
+                    0,
+                    1,
+                    Some(RewardPolicy {
+                        amount_per_payout: 100,
+                        next_payment_at_block: 1000,
+                        payout_interval: Some(100),
+                    }),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_fill_content_working_group_leader_opening_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None, ThreadMode::Open,
+                    0,
+                    1,
+                    Some(RewardPolicy {
+                        amount_per_payout: 100,
+                        next_payment_at_block: 1000,
+                        payout_interval: Some(100),
+                    }),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_fill_content_working_group_leader_opening_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)), ThreadMode::Open,
+                    0,
+                    1,
+                    Some(RewardPolicy {
+                        amount_per_payout: 100,
+                        next_payment_at_block: 1000,
+                        payout_interval: Some(100),
+                    }),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_fill_content_working_group_leader_opening_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(50000u32)), ThreadMode::Open,
+                    0,
+                    1,
+                    Some(RewardPolicy {
+                        amount_per_payout: 100,
+                        next_payment_at_block: 1000,
+                        payout_interval: Some(100),
+                    }),
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::fill_content_working_group_leader_opening_proposal::<Test>(),
+            proposal_details: ProposalDetails::FillContentWorkingGroupLeaderOpening(
+                0,
+                1,
+                Some(RewardPolicy {
+                    amount_per_payout: 100,
+                    next_payment_at_block: 1000,
+                    payout_interval: Some(100),
+                }),
+            ),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_slash_content_working_group_leader_stake_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+        set_content_working_group_lead();
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_slash_content_working_group_leader_stake_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None, ThreadMode::Open,
+                    100,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_slash_content_working_group_leader_stake_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None, ThreadMode::Open,
+                    100,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_slash_content_working_group_leader_stake_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)), ThreadMode::Open,
+                    100,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_slash_content_working_group_leader_stake_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(50000u32)), ThreadMode::Open,
+                    100,
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::slash_content_working_group_leader_stake_proposal::<Test>(),
+            proposal_details: ProposalDetails::SlashContentWorkingGroupLeaderStake(100),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_terminate_content_working_group_leader_role_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+        set_content_working_group_lead();
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_terminate_content_working_group_leader_role_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None, ThreadMode::Open,
+                    b"rationale".to_vec(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_terminate_content_working_group_leader_role_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None, ThreadMode::Open,
+                    b"rationale".to_vec(),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_terminate_content_working_group_leader_role_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)), ThreadMode::Open,
+                    b"rationale".to_vec(),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_terminate_content_working_group_leader_role_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(100_000_u32)), ThreadMode::Open,
+                    b"rationale".to_vec(),
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::terminate_content_working_group_leader_role_proposal::<Test>(),
+            proposal_details: ProposalDetails::TerminateContentWorkingGroupLeaderRole(b"rationale".to_vec()),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_content_working_group_leader_proposals_fail_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        assert_eq!(
+            ProposalCodex::create_begin_review_content_working_group_leader_applications_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                ThreadMode::Open,
+                0,
+            ),
+            Err(Error::InvalidContentWorkingGroupLeaderOpening)
+        );
+
+        add_content_working_group_opening(0, OpeningType::Curator);
+        add_content_working_group_opening(1, OpeningType::Leader);
+        add_content_working_group_application(2, 0);
+
+        assert_eq!(
+            ProposalCodex::create_begin_review_content_working_group_leader_applications_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                ThreadMode::Open,
+                0,
+            ),
+            Err(Error::InvalidContentWorkingGroupLeaderOpening)
+        );
+
+        assert_eq!(
+            ProposalCodex::create_fill_content_working_group_leader_opening_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(50000u32)),
+                ThreadMode::Open,
+                1,
+                2,
+                None,
+            ),
+            Err(Error::InvalidContentWorkingGroupLeaderApplication)
+        );
+
+        assert_eq!(
+            ProposalCodex::create_slash_content_working_group_leader_stake_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(50000u32)),
+                ThreadMode::Open,
+                100,
+            ),
+            Err(Error::ContentWorkingGroupLeaderIsNotSet)
+        );
+
+        assert_eq!(
+            ProposalCodex::create_terminate_content_working_group_leader_role_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(100_000_u32)),
+                ThreadMode::Open,
+                b"rationale".to_vec(),
+            ),
+            Err(Error::ContentWorkingGroupLeaderIsNotSet)
+        );
+
+        set_content_working_group_lead();
+
+        assert_eq!(
+            ProposalCodex::create_slash_content_working_group_leader_stake_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(50000u32)),
+                ThreadMode::Open,
+                0,
+            ),
+            Err(Error::InvalidSlashContentWorkingGroupLeaderStakeAmount)
+        );
+    });
+}
+
+#[test]
+fn proposal_discussion_thread_is_created_in_the_provided_mode() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let create_text_proposal = |thread_mode| {
+            ProposalCodex::create_text_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                thread_mode,
+                b"text".to_vec(),
+            )
+        };
+
+        assert_eq!(
+            create_text_proposal(ThreadMode::WhitelistOnly(vec![1; 21])),
+            Err(Error::Other("MaxWhiteListSizeExceeded"))
+        );
+        assert_eq!(ProposalsEngine::proposal_count(), 0);
+
+        assert_eq!(create_text_proposal(ThreadMode::CouncilOnly), Ok(()));
+
+        let thread_id = <crate::ThreadIdByProposalId<Test>>::get(1);
+        assert_eq!(
+            <proposal_discussion::ThreadById<Test>>::get(thread_id).mode,
+            ThreadMode::CouncilOnly
+        );
+    });
+}
+
+#[test]
+fn proposal_discussion_thread_mode_can_be_changed_by_the_proposer() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        assert_eq!(
+            ProposalCodex::create_text_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                ThreadMode::Open,
+                b"text".to_vec(),
+            ),
+            Ok(())
+        );
+
+        let thread_id = <crate::ThreadIdByProposalId<Test>>::get(1);
+
+        assert_eq!(
+            proposal_discussion::Module::<Test>::change_thread_mode(
+                RawOrigin::Signed(1).into(),
+                1,
+                thread_id,
+                ThreadMode::CouncilOnly,
+            ),
+            Ok(())
+        );
+
+        assert_eq!(
+            <proposal_discussion::ThreadById<Test>>::get(thread_id).mode,
+            ThreadMode::CouncilOnly
+        );
+    });
+}
+
+#[test]
+fn proposal_data_is_removed_on_proposal_pruning() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        assert_eq!(
+            ProposalCodex::create_text_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                ThreadMode::Open,
+                b"text".to_vec(),
+            ),
+            Ok(())
+        );
+
+        assert_eq!(
+            ProposalCodex::amend_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                1,
+                ProposalDetails::Text(b"amended text".to_vec()),
+            ),
+            Ok(())
+        );
+
+        assert_eq!(
+            ProposalsEngine::cancel_proposal(RawOrigin::Signed(1).into(), 1, 1),
+            Ok(())
+        );
+
+        assert!(<crate::ProposalDetailsByProposalId<Test>>::exists(1));
+        assert!(<crate::ProposalDetailsAmendmentHistory<Test>>::exists(1));
+        assert!(<crate::ThreadIdByProposalId<Test>>::exists(1));
+
+        let prune_at =
+            <system::Module<Test>>::block_number() + FinalizedProposalRetentionPeriod::get();
+        <ProposalsEngine as OnFinalize<u64>>::on_finalize(prune_at);
+
+        assert!(!<crate::ProposalDetailsByProposalId<Test>>::exists(1));
+        assert!(!<crate::ProposalDetailsAmendmentHistory<Test>>::exists(1));
+        assert!(!<crate::ThreadIdByProposalId<Test>>::exists(1));
+    });
+}
+
+#[test]
+fn proposal_discussion_thread_is_closed_on_proposal_finalization() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        assert_eq!(
+            ProposalCodex::create_text_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                ThreadMode::Open,
+                b"text".to_vec(),
+            ),
+            Ok(())
+        );
+
+        let thread_id = <crate::ThreadIdByProposalId<Test>>::get(1);
+        assert_eq!(
+            <proposal_discussion::ThreadById<Test>>::get(thread_id).mode,
+            ThreadMode::Open
+        );
+
+        assert_eq!(
+            ProposalsEngine::cancel_proposal(RawOrigin::Signed(1).into(), 1, 1),
+            Ok(())
+        );
+
+        assert_eq!(
+            <proposal_discussion::ThreadById<Test>>::get(thread_id).mode,
+            ThreadMode::Closed
+        );
     });
 }
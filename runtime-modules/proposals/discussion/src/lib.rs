@@ -6,22 +6,30 @@
 //!
 //! The proposals discussion module is used by the codex module to provide a platform for discussions
 //! about different proposals. It allows to create discussion threads and then add and update related
-//! posts.
+//! posts. Posts can reply to other posts of the thread. Council members can moderate posts.
+//! Thread mode defines who can post in the thread.
 //!
 //! ## Supported extrinsics
-//! - [add_post](./struct.Module.html#method.add_post) - adds a post to an existing discussion thread
+//! - [add_post](./struct.Module.html#method.add_post) - adds a post or a reply to an existing
+//! discussion thread
 //! - [update_post](./struct.Module.html#method.update_post) - updates existing post
+//! - [react_to_post](./struct.Module.html#method.react_to_post) - sets or removes a reaction to
+//! the post
+//! - [moderate_post](./struct.Module.html#method.moderate_post) - moderates existing post
+//! - [change_thread_mode](./struct.Module.html#method.change_thread_mode) - changes thread mode
 //!
 //! ## Public API methods
 //! - [create_thread](./struct.Module.html#method.create_thread) - creates a discussion thread
 //! - [ensure_can_create_thread](./struct.Module.html#method.ensure_can_create_thread) - ensures safe thread creation
+//! - [ensure_thread_mode_is_valid](./struct.Module.html#method.ensure_thread_mode_is_valid) - ensures that the thread mode is valid
+//! - [close_thread](./struct.Module.html#method.close_thread) - closes a discussion thread
 //!
 //! ## Usage
 //!
 //! ```
 //! use srml_support::{decl_module, dispatch::Result};
 //! use system::ensure_root;
-//! use substrate_proposals_discussion_module::{self as discussions};
+//! use substrate_proposals_discussion_module::{self as discussions, ThreadMode};
 //!
 //! pub trait Trait: discussions::Trait + membership::members::Trait {}
 //!
@@ -30,7 +38,7 @@
 //!         pub fn create_discussion(origin, title: Vec<u8>, author_id : T::MemberId) -> Result {
 //!             ensure_root(origin)?;
 //!             <discussions::Module<T>>::ensure_can_create_thread(author_id, &title)?;
-//!             <discussions::Module<T>>::create_thread(author_id, title)?;
+//!             <discussions::Module<T>>::create_thread(author_id, title, ThreadMode::Open)?;
 //!             Ok(())
 //!         }
 //!     }
@@ -46,17 +54,21 @@
 // Do not delete! Cannot be uncommented by default, because of Parity decl_module! issue.
 //#![warn(missing_docs)]
 
+mod migration;
 #[cfg(test)]
 mod tests;
 mod types;
 
 use rstd::clone::Clone;
+use rstd::collections::btree_map::BTreeMap;
 use rstd::prelude::*;
 use rstd::vec::Vec;
 use srml_support::{decl_error, decl_event, decl_module, decl_storage, ensure, Parameter};
 
 use srml_support::traits::Get;
-use types::{DiscussionPost, DiscussionThread, ThreadCounter};
+use types::{DiscussionPost, DiscussionThread, PostModeration, ThreadCounter};
+
+pub use types::{PostReaction, ThreadMode};
 
 use common::origin_validator::ActorOriginValidator;
 use srml_support::dispatch::DispatchResult;
@@ -70,6 +82,7 @@ decl_event!(
         <T as Trait>::ThreadId,
         MemberId = MemberId<T>,
         <T as Trait>::PostId,
+        ThreadMode = ThreadMode<MemberId<T>>,
     {
         /// Emits on thread creation.
         ThreadCreated(ThreadId, MemberId),
@@ -79,6 +92,22 @@ decl_event!(
 
         /// Emits on post update.
         PostUpdated(PostId, MemberId),
+
+        /// Emits on post moderation.
+        /// Params:
+        /// - Id of the moderated post.
+        /// - Member id of the moderator.
+        PostModerated(PostId, MemberId),
+
+        /// Emits on thread mode change.
+        ThreadModeChanged(ThreadId, ThreadMode),
+
+        /// Emits on post reaction change.
+        /// Params:
+        /// - Id of the post.
+        /// - Member id of the reacting member.
+        /// - New reaction. None if the reaction was removed.
+        PostReactionChanged(PostId, MemberId, Option<PostReaction>),
     }
 );
 
@@ -94,6 +123,9 @@ pub trait Trait: system::Trait + membership::members::Trait {
         Self::AccountId,
     >;
 
+    /// Validates council member id and origin combination
+    type CouncilOriginValidator: ActorOriginValidator<Self::Origin, MemberId<Self>, Self::AccountId>;
+
     /// Discussion thread Id type
     type ThreadId: From<u64> + Into<u64> + Parameter + Default + Copy;
 
@@ -111,6 +143,12 @@ pub trait Trait: system::Trait + membership::members::Trait {
 
     /// Defines max thread by same author in a row number limit.
     type MaxThreadInARowNumber: Get<u32>;
+
+    /// Defines max members number in the thread whitelist.
+    type MaxWhiteListSize: Get<u32>;
+
+    /// Defines max post nesting level. Top level posts have zero nesting level.
+    type MaxPostDepth: Get<u32>;
 }
 
 decl_error! {
@@ -145,6 +183,33 @@ decl_error! {
 
         /// Require root origin in extrinsics
         RequireRootOrigin,
+
+        /// Thread is closed: posting and thread mode changes are not allowed
+        ThreadIsClosed,
+
+        /// Post author is not in the thread whitelist
+        AuthorIsNotWhitelisted,
+
+        /// Max number of members in the thread whitelist exceeded
+        MaxWhiteListSizeExceeded,
+
+        /// Moderated post cannot be changed
+        PostIsModerated,
+
+        /// Moderation rationale cannot be empty
+        EmptyRationaleProvided,
+
+        /// Moderation rationale is too long
+        RationaleIsTooLong,
+
+        /// Parent post doesn't exist in the thread
+        ParentPostDoesntExist,
+
+        /// Max post nesting level exceeded
+        MaxPostDepthExceeded,
+
+        /// Member has already set the same reaction to the post
+        PostReactionNotChanged,
     }
 }
 
@@ -170,7 +235,15 @@ decl_storage! {
 
         /// Map thread id and post id to corresponding post.
         pub PostThreadIdByPostId: double_map T::ThreadId, twox_128(T::PostId) =>
-             DiscussionPost<MemberId<T>, T::BlockNumber, T::ThreadId>;
+             DiscussionPost<MemberId<T>, T::BlockNumber, T::ThreadId, T::PostId>;
+
+        /// Map post id to the reaction counts of the post.
+        pub PostReactionCounts get(fn post_reaction_counts): map T::PostId =>
+            BTreeMap<PostReaction, u32>;
+
+        /// Map post id and member id to the reaction of the member to the post.
+        pub PostReactionByMemberId: double_map T::PostId, twox_128(MemberId<T>) =>
+            Option<PostReaction>;
 
         /// Count of all posts that have been created.
         pub PostCount get(fn post_count): u64;
@@ -202,18 +275,24 @@ decl_module! {
         /// Exports max thread by same author in a row number limit const.
         const MaxThreadInARowNumber: u32 = T::MaxThreadInARowNumber::get();
 
-        /// Adds a post with author origin check.
+        /// Exports max members number in the thread whitelist const.
+        const MaxWhiteListSize: u32 = T::MaxWhiteListSize::get();
+
+        /// Exports max post nesting level const.
+        const MaxPostDepth: u32 = T::MaxPostDepth::get();
+
+        /// Adds a post with author origin check. Thread mode defines allowed authors.
+        /// The post replies to the parent post if provided. Nesting level of replies is limited.
         pub fn add_post(
             origin,
             post_author_id: MemberId<T>,
             thread_id : T::ThreadId,
+            parent_post_id: Option<T::PostId>,
             text : Vec<u8>
         ) {
-            T::PostAuthorOriginValidator::ensure_actor_origin(
-                origin,
-                post_author_id,
-            )?;
-            ensure!(<ThreadById<T>>::exists(thread_id), Error::ThreadDoesntExist);
+            Self::ensure_can_post(origin, post_author_id, thread_id)?;
+
+            let depth = Self::ensure_reply_depth_is_valid(thread_id, parent_post_id)?;
 
             ensure!(!text.is_empty(),Error::EmptyPostProvided);
             ensure!(
@@ -233,6 +312,9 @@ decl_module! {
                 author_id: post_author_id,
                 edition_number : 0,
                 thread_id,
+                parent_post_id,
+                depth,
+                moderation: None,
             };
 
             let post_id = T::PostId::from(new_post_id);
@@ -242,6 +324,7 @@ decl_module! {
        }
 
         /// Updates a post with author origin check. Update attempts number is limited.
+        /// Moderated posts cannot be updated.
         pub fn update_post(
             origin,
             post_author_id: MemberId<T>,
@@ -249,12 +332,8 @@ decl_module! {
             post_id : T::PostId,
             text : Vec<u8>
         ){
-            T::PostAuthorOriginValidator::ensure_actor_origin(
-                origin,
-                post_author_id,
-            )?;
+            Self::ensure_can_post(origin, post_author_id, thread_id)?;
 
-            ensure!(<ThreadById<T>>::exists(thread_id), Error::ThreadDoesntExist);
             ensure!(<PostThreadIdByPostId<T>>::exists(thread_id, post_id), Error::PostDoesntExist);
 
             ensure!(!text.is_empty(), Error::EmptyPostProvided);
@@ -266,6 +345,7 @@ decl_module! {
             let post = <PostThreadIdByPostId<T>>::get(&thread_id, &post_id);
 
             ensure!(post.author_id == post_author_id, Error::NotAuthor);
+            ensure!(post.moderation.is_none(), Error::PostIsModerated);
             ensure!(post.edition_number < T::MaxPostEditionNumber::get(),
                 Error::PostEditionNumberExceeded);
 
@@ -281,6 +361,118 @@ decl_module! {
             <PostThreadIdByPostId<T>>::insert(thread_id, post_id, new_post);
             Self::deposit_event(RawEvent::PostUpdated(post_id, post_author_id));
        }
+
+        /// Moderates a post with council member origin check. Rationale length is limited by the
+        /// post length limit.
+        pub fn moderate_post(
+            origin,
+            moderator_id: MemberId<T>,
+            thread_id: T::ThreadId,
+            post_id : T::PostId,
+            rationale : Vec<u8>
+        ){
+            T::CouncilOriginValidator::ensure_actor_origin(
+                origin,
+                moderator_id,
+            )?;
+
+            ensure!(<ThreadById<T>>::exists(thread_id), Error::ThreadDoesntExist);
+            ensure!(<PostThreadIdByPostId<T>>::exists(thread_id, post_id), Error::PostDoesntExist);
+
+            ensure!(!rationale.is_empty(), Error::EmptyRationaleProvided);
+            ensure!(
+                rationale.len() as u32 <= T::PostLengthLimit::get(),
+                Error::RationaleIsTooLong
+            );
+
+            let post = <PostThreadIdByPostId<T>>::get(&thread_id, &post_id);
+
+            ensure!(post.moderation.is_none(), Error::PostIsModerated);
+
+            let moderated_post = DiscussionPost {
+                moderation: Some(PostModeration {
+                    moderated_at: Self::current_block(),
+                    moderator_id,
+                    rationale,
+                }),
+                ..post
+            };
+
+            // mutation
+
+            <PostThreadIdByPostId<T>>::insert(thread_id, post_id, moderated_post);
+            Self::deposit_event(RawEvent::PostModerated(post_id, moderator_id));
+       }
+
+        /// Sets the reaction of the member to the post. Removes the reaction if None provided.
+        /// Thread mode defines members allowed to react.
+        pub fn react_to_post(
+            origin,
+            member_id: MemberId<T>,
+            thread_id: T::ThreadId,
+            post_id : T::PostId,
+            reaction: Option<PostReaction>
+        ){
+            Self::ensure_can_post(origin, member_id, thread_id)?;
+
+            ensure!(<PostThreadIdByPostId<T>>::exists(thread_id, post_id), Error::PostDoesntExist);
+
+            let previous_reaction = <PostReactionByMemberId<T>>::get(post_id, member_id);
+
+            ensure!(previous_reaction != reaction, Error::PostReactionNotChanged);
+
+            // mutation
+
+            <PostReactionCounts<T>>::mutate(post_id, |counts| {
+                if let Some(previous_reaction) = previous_reaction {
+                    if let Some(count) = counts.get_mut(&previous_reaction) {
+                        *count = count.saturating_sub(1);
+
+                        if *count == 0 {
+                            counts.remove(&previous_reaction);
+                        }
+                    }
+                }
+
+                if let Some(reaction) = reaction {
+                    *counts.entry(reaction).or_insert(0) += 1;
+                }
+            });
+
+            if let Some(reaction) = reaction {
+                <PostReactionByMemberId<T>>::insert(post_id, member_id, reaction);
+            } else {
+                <PostReactionByMemberId<T>>::remove(post_id, member_id);
+            }
+
+            Self::deposit_event(RawEvent::PostReactionChanged(post_id, member_id, reaction));
+       }
+
+        /// Changes the thread mode. Can be called by the thread author or a council member.
+        /// Closed thread mode cannot be changed.
+        pub fn change_thread_mode(
+            origin,
+            member_id: MemberId<T>,
+            thread_id: T::ThreadId,
+            mode: ThreadMode<MemberId<T>>
+        ){
+            ensure!(<ThreadById<T>>::exists(thread_id), Error::ThreadDoesntExist);
+
+            let thread = Self::thread_by_id(thread_id);
+
+            if thread.author_id == member_id {
+                T::PostAuthorOriginValidator::ensure_actor_origin(origin, member_id)?;
+            } else {
+                T::CouncilOriginValidator::ensure_actor_origin(origin, member_id)?;
+            }
+
+            ensure!(thread.mode != ThreadMode::Closed, Error::ThreadIsClosed);
+            Self::ensure_thread_mode_is_valid(&mode)?;
+
+            // mutation
+
+            Self::set_thread_mode(thread_id, mode);
+       }
     }
 }
 
@@ -290,8 +482,10 @@ impl<T: Trait> Module<T> {
     pub fn create_thread(
         thread_author_id: MemberId<T>,
         title: Vec<u8>,
+        mode: ThreadMode<MemberId<T>>,
     ) -> Result<T::ThreadId, Error> {
         Self::ensure_can_create_thread(thread_author_id, &title)?;
+        Self::ensure_thread_mode_is_valid(&mode)?;
 
         let next_thread_count_value = Self::thread_count() + 1;
         let new_thread_id = next_thread_count_value;
@@ -300,6 +494,7 @@ impl<T: Trait> Module<T> {
             title,
             created_at: Self::current_block(),
             author_id: thread_author_id,
+            mode,
         };
 
         // get new 'threads in a row' counter for the author
@@ -340,6 +535,16 @@ impl<T: Trait> Module<T> {
 
         Ok(())
     }
+
+    /// Closes the discussion thread: nobody can post in the closed thread.
+    /// Does nothing if the thread doesn't exist or is already closed.
+    pub fn close_thread(thread_id: T::ThreadId) {
+        if <ThreadById<T>>::exists(thread_id)
+            && Self::thread_by_id(thread_id).mode != ThreadMode::Closed
+        {
+            Self::set_thread_mode(thread_id, ThreadMode::Closed);
+        }
+    }
 }
 
 impl<T: Trait> Module<T> {
@@ -348,6 +553,76 @@ impl<T: Trait> Module<T> {
         <system::Module<T>>::block_number()
     }
 
+    // Validates the post author origin against the thread mode.
+    fn ensure_can_post(
+        origin: T::Origin,
+        post_author_id: MemberId<T>,
+        thread_id: T::ThreadId,
+    ) -> DispatchResult<Error> {
+        ensure!(<ThreadById<T>>::exists(thread_id), Error::ThreadDoesntExist);
+
+        match Self::thread_by_id(thread_id).mode {
+            ThreadMode::Open => {
+                T::PostAuthorOriginValidator::ensure_actor_origin(origin, post_author_id)?;
+            }
+            ThreadMode::Closed => return Err(Error::ThreadIsClosed),
+            ThreadMode::CouncilOnly => {
+                T::CouncilOriginValidator::ensure_actor_origin(origin, post_author_id)?;
+            }
+            ThreadMode::WhitelistOnly(members) => {
+                T::PostAuthorOriginValidator::ensure_actor_origin(origin, post_author_id)?;
+
+                ensure!(
+                    members.contains(&post_author_id),
+                    Error::AuthorIsNotWhitelisted
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    // Checks that the parent post exists in the thread and the reply nesting level is allowed.
+    // Returns the nesting level of the new post.
+    fn ensure_reply_depth_is_valid(
+        thread_id: T::ThreadId,
+        parent_post_id: Option<T::PostId>,
+    ) -> Result<u32, Error> {
+        if let Some(parent_post_id) = parent_post_id {
+            ensure!(
+                <PostThreadIdByPostId<T>>::exists(thread_id, parent_post_id),
+                Error::ParentPostDoesntExist
+            );
+
+            let parent_post = <PostThreadIdByPostId<T>>::get(&thread_id, &parent_post_id);
+            let depth = parent_post.depth + 1;
+
+            ensure!(depth <= T::MaxPostDepth::get(), Error::MaxPostDepthExceeded);
+
+            Ok(depth)
+        } else {
+            Ok(0)
+        }
+    }
+
+    /// Ensures that the thread mode is valid: checks the thread whitelist size.
+    pub fn ensure_thread_mode_is_valid(mode: &ThreadMode<MemberId<T>>) -> DispatchResult<Error> {
+        if let ThreadMode::WhitelistOnly(members) = mode {
+            ensure!(
+                members.len() as u32 <= T::MaxWhiteListSize::get(),
+                Error::MaxWhiteListSizeExceeded
+            );
+        }
+
+        Ok(())
+    }
+
+    // Saves the new thread mode and fires an event.
+    fn set_thread_mode(thread_id: T::ThreadId, mode: ThreadMode<MemberId<T>>) {
+        <ThreadById<T>>::mutate(thread_id, |thread| thread.mode = mode.clone());
+        Self::deposit_event(RawEvent::ThreadModeChanged(thread_id, mode));
+    }
+
     // returns incremented thread counter if last thread author equals with provided parameter
     fn get_updated_thread_counter(author_id: MemberId<T>) -> ThreadCounter<MemberId<T>> {
         // if thread counter exists
@@ -0,0 +1,103 @@
+//! Migration of the threads and posts saved by the previous runtime version.
+//! The previous thread layout lacks the thread mode. The previous post layout lacks the parent
+//! post, the nesting level and the moderation data.
+
+use codec::{Decode, Encode};
+use rstd::prelude::*;
+use srml_support::storage::unhashed;
+use srml_support::{StorageDoubleMap, StorageMap};
+
+use crate::types::{DiscussionPost, DiscussionThread};
+use crate::{MemberId, Module, PostThreadIdByPostId, ThreadById, ThreadMode, Trait};
+
+// Discussion thread of the previous runtime version.
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub(crate) struct LegacyDiscussionThread<ThreadAuthorId, BlockNumber> {
+    pub title: Vec<u8>,
+    pub created_at: BlockNumber,
+    pub author_id: ThreadAuthorId,
+}
+
+// Discussion post of the previous runtime version.
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub(crate) struct LegacyDiscussionPost<PostAuthorId, BlockNumber, ThreadId> {
+    pub text: Vec<u8>,
+    pub created_at: BlockNumber,
+    pub updated_at: BlockNumber,
+    pub author_id: PostAuthorId,
+    pub thread_id: ThreadId,
+    pub edition_number: u32,
+}
+
+impl<ThreadAuthorId, BlockNumber> From<LegacyDiscussionThread<ThreadAuthorId, BlockNumber>>
+    for DiscussionThread<ThreadAuthorId, BlockNumber>
+{
+    fn from(thread: LegacyDiscussionThread<ThreadAuthorId, BlockNumber>) -> Self {
+        DiscussionThread {
+            title: thread.title,
+            created_at: thread.created_at,
+            author_id: thread.author_id,
+            mode: ThreadMode::Open,
+        }
+    }
+}
+
+impl<PostAuthorId, BlockNumber, ThreadId, PostId>
+    From<LegacyDiscussionPost<PostAuthorId, BlockNumber, ThreadId>>
+    for DiscussionPost<PostAuthorId, BlockNumber, ThreadId, PostId>
+{
+    fn from(post: LegacyDiscussionPost<PostAuthorId, BlockNumber, ThreadId>) -> Self {
+        DiscussionPost {
+            text: post.text,
+            created_at: post.created_at,
+            updated_at: post.updated_at,
+            author_id: post.author_id,
+            thread_id: post.thread_id,
+            parent_post_id: None,
+            depth: 0,
+            edition_number: post.edition_number,
+            moderation: None,
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// Migrates the threads and posts saved by the previous runtime version to the current
+    /// layout. Threads become open, posts become top level posts.
+    /// Should be called on the migration to the new runtime version only once.
+    pub fn migrate_threads_and_posts() {
+        for id in 1..=Self::thread_count() {
+            let thread_id = T::ThreadId::from(id);
+            let key = <ThreadById<T>>::hashed_key_for(thread_id);
+
+            if let Some(legacy_thread) =
+                unhashed::get::<LegacyDiscussionThread<MemberId<T>, T::BlockNumber>>(&key)
+            {
+                let thread: DiscussionThread<MemberId<T>, T::BlockNumber> = legacy_thread.into();
+
+                <ThreadById<T>>::insert(thread_id, thread);
+            }
+        }
+
+        // Post id doesn't reveal its thread: posts are looked up through all threads.
+        for post_id in (1..=Self::post_count()).map(T::PostId::from) {
+            let thread_id = (1..=Self::thread_count())
+                .map(T::ThreadId::from)
+                .find(|thread_id| <PostThreadIdByPostId<T>>::exists(thread_id, post_id));
+
+            if let Some(thread_id) = thread_id {
+                let key = <PostThreadIdByPostId<T>>::hashed_key_for(thread_id, post_id);
+
+                if let Some(legacy_post) = unhashed::get::<
+                    LegacyDiscussionPost<MemberId<T>, T::BlockNumber, T::ThreadId>,
+                >(&key)
+                {
+                    let post: DiscussionPost<MemberId<T>, T::BlockNumber, T::ThreadId, T::PostId> =
+                        legacy_post.into();
+
+                    <PostThreadIdByPostId<T>>::insert(thread_id, post_id, post);
+                }
+            }
+        }
+    }
+}
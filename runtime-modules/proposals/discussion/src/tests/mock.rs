@@ -34,6 +34,8 @@ parameter_types! {
     pub const MaxThreadInARowNumber: u32 = 3;
     pub const ThreadTitleLengthLimit: u32 = 200;
     pub const PostLengthLimit: u32 = 2000;
+    pub const MaxWhiteListSize: u32 = 3;
+    pub const MaxPostDepth: u32 = 2;
 }
 
 mod discussion {
@@ -87,12 +89,15 @@ impl membership::members::Trait for Test {
 impl crate::Trait for Test {
     type Event = TestEvent;
     type PostAuthorOriginValidator = ();
+    type CouncilOriginValidator = CouncilMock;
     type ThreadId = u64;
     type PostId = u64;
     type MaxPostEditionNumber = MaxPostEditionNumber;
     type ThreadTitleLengthLimit = ThreadTitleLengthLimit;
     type PostLengthLimit = PostLengthLimit;
     type MaxThreadInARowNumber = MaxThreadInARowNumber;
+    type MaxWhiteListSize = MaxWhiteListSize;
+    type MaxPostDepth = MaxPostDepth;
 }
 
 impl ActorOriginValidator<Origin, u64, u64> for () {
@@ -109,6 +114,18 @@ impl ActorOriginValidator<Origin, u64, u64> for () {
     }
 }
 
+// Member with id = 2 is the only council member.
+pub struct CouncilMock;
+impl ActorOriginValidator<Origin, u64, u64> for CouncilMock {
+    fn ensure_actor_origin(_origin: Origin, actor_id: u64) -> Result<u64, &'static str> {
+        if actor_id == 2 {
+            return Ok(2);
+        }
+
+        Err("Not a council member")
+    }
+}
+
 impl system::Trait for Test {
     type Origin = Origin;
     type Call = ();
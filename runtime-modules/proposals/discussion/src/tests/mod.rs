@@ -8,7 +8,7 @@ use system::{EventRecord, Phase};
 
 struct EventFixture;
 impl EventFixture {
-    fn assert_events(expected_raw_events: Vec<RawEvent<u64, u64, u64>>) {
+    fn assert_events(expected_raw_events: Vec<RawEvent<u64, u64, u64, ThreadMode<u64>>>) {
         let expected_events = expected_raw_events
             .iter()
             .map(|ev| EventRecord {
@@ -41,6 +41,7 @@ fn assert_thread_content(thread_entry: TestThreadEntry, post_entries: Vec<TestPo
         title: thread_entry.title,
         created_at: 1,
         author_id: 1,
+        mode: ThreadMode::Open,
     };
     assert_eq!(actual_thread, expected_thread);
 
@@ -53,7 +54,10 @@ fn assert_thread_content(thread_entry: TestThreadEntry, post_entries: Vec<TestPo
             updated_at: 1,
             author_id: 1,
             thread_id: thread_entry.thread_id,
+            parent_post_id: None,
+            depth: 0,
             edition_number: post_entry.edition_number,
+            moderation: None,
         };
 
         assert_eq!(actual_post, expected_post);
@@ -64,6 +68,7 @@ struct DiscussionFixture {
     pub title: Vec<u8>,
     pub origin: RawOrigin<u64>,
     pub author_id: u64,
+    pub mode: ThreadMode<u64>,
 }
 
 impl Default for DiscussionFixture {
@@ -72,6 +77,7 @@ impl Default for DiscussionFixture {
             title: b"title".to_vec(),
             origin: RawOrigin::Signed(1),
             author_id: 1,
+            mode: ThreadMode::Open,
         }
     }
 }
@@ -81,9 +87,13 @@ impl DiscussionFixture {
         DiscussionFixture { title, ..self }
     }
 
+    fn with_mode(self, mode: ThreadMode<u64>) -> Self {
+        DiscussionFixture { mode, ..self }
+    }
+
     fn create_discussion_and_assert(&self, result: Result<u64, Error>) -> Option<u64> {
         let create_discussion_result =
-            Discussions::create_thread(self.author_id, self.title.clone());
+            Discussions::create_thread(self.author_id, self.title.clone(), self.mode.clone());
 
         assert_eq!(create_discussion_result, result);
 
@@ -97,6 +107,7 @@ struct PostFixture {
     pub thread_id: u64,
     pub post_id: Option<u64>,
     pub author_id: u64,
+    pub parent_post_id: Option<u64>,
 }
 
 impl PostFixture {
@@ -107,6 +118,14 @@ impl PostFixture {
             thread_id,
             origin: RawOrigin::Signed(1),
             post_id: None,
+            parent_post_id: None,
+        }
+    }
+
+    fn with_parent(self, parent_post_id: u64) -> Self {
+        PostFixture {
+            parent_post_id: Some(parent_post_id),
+            ..self
         }
     }
 
@@ -138,6 +157,7 @@ impl PostFixture {
             self.origin.clone().into(),
             self.author_id,
             self.thread_id,
+            self.parent_post_id,
             self.text.clone(),
         );
 
@@ -165,6 +185,54 @@ impl PostFixture {
     fn update_post_and_assert(&mut self, result: Result<(), Error>) {
         self.update_post_with_text_and_assert(self.text.clone(), result);
     }
+
+    fn moderate_post_and_assert(
+        &self,
+        moderator_id: u64,
+        rationale: Vec<u8>,
+        result: Result<(), Error>,
+    ) {
+        let moderate_post_result = Discussions::moderate_post(
+            RawOrigin::Signed(moderator_id).into(),
+            moderator_id,
+            self.thread_id,
+            self.post_id.unwrap(),
+            rationale,
+        );
+
+        assert_eq!(moderate_post_result, result);
+    }
+}
+
+fn react_to_post_and_assert(
+    member_id: u64,
+    thread_id: u64,
+    post_id: u64,
+    reaction: Option<PostReaction>,
+    result: Result<(), Error>,
+) {
+    let react_to_post_result = Discussions::react_to_post(
+        RawOrigin::Signed(member_id).into(),
+        member_id,
+        thread_id,
+        post_id,
+        reaction,
+    );
+
+    assert_eq!(react_to_post_result, result);
+}
+
+fn change_thread_mode_and_assert(
+    origin: RawOrigin<u64>,
+    member_id: u64,
+    thread_id: u64,
+    mode: ThreadMode<u64>,
+    result: Result<(), Error>,
+) {
+    let change_thread_mode_result =
+        Discussions::change_thread_mode(origin.into(), member_id, thread_id, mode);
+
+    assert_eq!(change_thread_mode_result, result);
 }
 
 #[test]
@@ -415,3 +483,441 @@ fn discussion_thread_and_post_counters_are_valid() {
         assert_eq!(Discussions::post_count(), 1);
     });
 }
+
+#[test]
+fn moderate_post_call_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        let post_id = post_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        post_fixture.moderate_post_and_assert(2, b"spam".to_vec(), Ok(()));
+
+        let post = <PostThreadIdByPostId<Test>>::get(thread_id, post_id);
+        assert_eq!(
+            post.moderation,
+            Some(PostModeration {
+                moderated_at: 1,
+                moderator_id: 2,
+                rationale: b"spam".to_vec(),
+            })
+        );
+
+        EventFixture::assert_events(vec![
+            RawEvent::ThreadCreated(1, 1),
+            RawEvent::PostCreated(1, 1),
+            RawEvent::PostModerated(1, 2),
+        ]);
+    });
+}
+
+#[test]
+fn moderate_post_call_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        post_fixture.add_post_and_assert(Ok(()));
+
+        post_fixture.moderate_post_and_assert(
+            1,
+            b"spam".to_vec(),
+            Err(Error::Other("Not a council member")),
+        );
+        post_fixture.moderate_post_and_assert(2, Vec::new(), Err(Error::EmptyRationaleProvided));
+        post_fixture.moderate_post_and_assert(
+            2,
+            [0; 2001].to_vec(),
+            Err(Error::RationaleIsTooLong),
+        );
+
+        post_fixture.change_post_id(2).moderate_post_and_assert(
+            2,
+            b"spam".to_vec(),
+            Err(Error::PostDoesntExist),
+        );
+    });
+}
+
+#[test]
+fn moderated_post_cannot_be_changed() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        post_fixture.add_post_and_assert(Ok(()));
+
+        post_fixture.moderate_post_and_assert(2, b"spam".to_vec(), Ok(()));
+        post_fixture.moderate_post_and_assert(2, b"spam".to_vec(), Err(Error::PostIsModerated));
+        post_fixture.update_post_and_assert(Err(Error::PostIsModerated));
+    });
+}
+
+#[test]
+fn add_post_call_fails_on_closed_thread() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        post_fixture.add_post_and_assert(Ok(()));
+
+        Discussions::close_thread(thread_id);
+
+        assert_eq!(<ThreadById<Test>>::get(thread_id).mode, ThreadMode::Closed);
+
+        post_fixture.update_post_and_assert(Err(Error::ThreadIsClosed));
+
+        let mut post_fixture2 = PostFixture::default_for_thread(thread_id);
+        post_fixture2.add_post_and_assert(Err(Error::ThreadIsClosed));
+    });
+}
+
+#[test]
+fn add_post_call_checks_council_only_thread_mode() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default().with_mode(ThreadMode::CouncilOnly);
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture1 = PostFixture::default_for_thread(thread_id);
+        post_fixture1.add_post_and_assert(Err(Error::Other("Not a council member")));
+
+        let mut post_fixture2 = PostFixture::default_for_thread(thread_id)
+            .with_origin(RawOrigin::Signed(2))
+            .with_author(2);
+        post_fixture2.add_post_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn add_post_call_checks_whitelist_only_thread_mode() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture =
+            DiscussionFixture::default().with_mode(ThreadMode::WhitelistOnly(vec![1]));
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture1 = PostFixture::default_for_thread(thread_id);
+        post_fixture1.add_post_and_assert(Ok(()));
+
+        let mut post_fixture2 = PostFixture::default_for_thread(thread_id)
+            .with_origin(RawOrigin::None)
+            .with_author(3);
+        post_fixture2.add_post_and_assert(Err(Error::AuthorIsNotWhitelisted));
+    });
+}
+
+#[test]
+fn change_thread_mode_call_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        change_thread_mode_and_assert(
+            RawOrigin::Signed(1),
+            1,
+            thread_id,
+            ThreadMode::WhitelistOnly(vec![1, 3]),
+            Ok(()),
+        );
+        change_thread_mode_and_assert(
+            RawOrigin::Signed(2),
+            2,
+            thread_id,
+            ThreadMode::CouncilOnly,
+            Ok(()),
+        );
+
+        assert_eq!(
+            <ThreadById<Test>>::get(thread_id).mode,
+            ThreadMode::CouncilOnly
+        );
+
+        EventFixture::assert_events(vec![
+            RawEvent::ThreadCreated(1, 1),
+            RawEvent::ThreadModeChanged(1, ThreadMode::WhitelistOnly(vec![1, 3])),
+            RawEvent::ThreadModeChanged(1, ThreadMode::CouncilOnly),
+        ]);
+    });
+}
+
+#[test]
+fn change_thread_mode_call_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        change_thread_mode_and_assert(
+            RawOrigin::Signed(3),
+            3,
+            thread_id,
+            ThreadMode::CouncilOnly,
+            Err(Error::Other("Not a council member")),
+        );
+        change_thread_mode_and_assert(
+            RawOrigin::Signed(1),
+            1,
+            2,
+            ThreadMode::CouncilOnly,
+            Err(Error::ThreadDoesntExist),
+        );
+        change_thread_mode_and_assert(
+            RawOrigin::Signed(1),
+            1,
+            thread_id,
+            ThreadMode::WhitelistOnly(vec![1, 2, 3, 4]),
+            Err(Error::MaxWhiteListSizeExceeded),
+        );
+
+        Discussions::close_thread(thread_id);
+
+        change_thread_mode_and_assert(
+            RawOrigin::Signed(2),
+            2,
+            thread_id,
+            ThreadMode::Open,
+            Err(Error::ThreadIsClosed),
+        );
+    });
+}
+
+#[test]
+fn create_discussion_call_with_bad_whitelist_failed() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture =
+            DiscussionFixture::default().with_mode(ThreadMode::WhitelistOnly(vec![1, 2, 3, 4]));
+
+        discussion_fixture.create_discussion_and_assert(Err(Error::MaxWhiteListSizeExceeded));
+    });
+}
+
+#[test]
+fn add_reply_call_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        let post_id = post_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        let mut reply_fixture = PostFixture::default_for_thread(thread_id).with_parent(post_id);
+        let reply_id = reply_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        let reply = <PostThreadIdByPostId<Test>>::get(thread_id, reply_id);
+        assert_eq!(reply.parent_post_id, Some(post_id));
+        assert_eq!(reply.depth, 1);
+    });
+}
+
+#[test]
+fn add_reply_call_fails_with_invalid_parent() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id1 = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+        let thread_id2 = discussion_fixture
+            .create_discussion_and_assert(Ok(2))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id1);
+        let post_id = post_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        let mut reply_fixture1 = PostFixture::default_for_thread(thread_id1).with_parent(2);
+        reply_fixture1.add_post_and_assert(Err(Error::ParentPostDoesntExist));
+
+        let mut reply_fixture2 = PostFixture::default_for_thread(thread_id2).with_parent(post_id);
+        reply_fixture2.add_post_and_assert(Err(Error::ParentPostDoesntExist));
+    });
+}
+
+#[test]
+fn add_reply_call_fails_because_of_max_post_depth() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        let mut parent_post_id = post_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        for _ in 1..=2 {
+            let mut reply_fixture =
+                PostFixture::default_for_thread(thread_id).with_parent(parent_post_id);
+            parent_post_id = reply_fixture.add_post_and_assert(Ok(())).unwrap();
+        }
+
+        let mut reply_fixture =
+            PostFixture::default_for_thread(thread_id).with_parent(parent_post_id);
+        reply_fixture.add_post_and_assert(Err(Error::MaxPostDepthExceeded));
+    });
+}
+
+#[test]
+fn react_to_post_call_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        let post_id = post_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        react_to_post_and_assert(1, thread_id, post_id, Some(PostReaction::ThumbsUp), Ok(()));
+
+        let mut expected_counts = BTreeMap::new();
+        expected_counts.insert(PostReaction::ThumbsUp, 1);
+        assert_eq!(Discussions::post_reaction_counts(post_id), expected_counts);
+        assert_eq!(
+            <PostReactionByMemberId<Test>>::get(post_id, 1),
+            Some(PostReaction::ThumbsUp)
+        );
+
+        react_to_post_and_assert(1, thread_id, post_id, Some(PostReaction::Heart), Ok(()));
+
+        let mut expected_counts = BTreeMap::new();
+        expected_counts.insert(PostReaction::Heart, 1);
+        assert_eq!(Discussions::post_reaction_counts(post_id), expected_counts);
+
+        react_to_post_and_assert(1, thread_id, post_id, None, Ok(()));
+
+        assert!(Discussions::post_reaction_counts(post_id).is_empty());
+        assert_eq!(<PostReactionByMemberId<Test>>::get(post_id, 1), None);
+
+        EventFixture::assert_events(vec![
+            RawEvent::ThreadCreated(1, 1),
+            RawEvent::PostCreated(1, 1),
+            RawEvent::PostReactionChanged(1, 1, Some(PostReaction::ThumbsUp)),
+            RawEvent::PostReactionChanged(1, 1, Some(PostReaction::Heart)),
+            RawEvent::PostReactionChanged(1, 1, None),
+        ]);
+    });
+}
+
+#[test]
+fn react_to_post_call_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        let post_id = post_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        react_to_post_and_assert(
+            1,
+            thread_id,
+            2,
+            Some(PostReaction::Laugh),
+            Err(Error::PostDoesntExist),
+        );
+        react_to_post_and_assert(
+            1,
+            thread_id,
+            post_id,
+            None,
+            Err(Error::PostReactionNotChanged),
+        );
+
+        react_to_post_and_assert(1, thread_id, post_id, Some(PostReaction::Laugh), Ok(()));
+        react_to_post_and_assert(
+            1,
+            thread_id,
+            post_id,
+            Some(PostReaction::Laugh),
+            Err(Error::PostReactionNotChanged),
+        );
+
+        Discussions::close_thread(thread_id);
+
+        react_to_post_and_assert(
+            1,
+            thread_id,
+            post_id,
+            Some(PostReaction::Confused),
+            Err(Error::ThreadIsClosed),
+        );
+    });
+}
+
+#[test]
+fn migrate_threads_and_posts_converts_legacy_threads_and_posts() {
+    initial_test_ext().execute_with(|| {
+        use crate::migration::{LegacyDiscussionPost, LegacyDiscussionThread};
+        use srml_support::storage::unhashed;
+        use srml_support::{StorageDoubleMap, StorageMap, StorageValue};
+
+        let legacy_thread = LegacyDiscussionThread {
+            title: b"title".to_vec(),
+            created_at: 1,
+            author_id: 1,
+        };
+        let legacy_post = LegacyDiscussionPost {
+            text: b"text".to_vec(),
+            created_at: 1,
+            updated_at: 2,
+            author_id: 1,
+            thread_id: 2,
+            edition_number: 1,
+        };
+
+        unhashed::put(&<ThreadById<Test>>::hashed_key_for(1), &legacy_thread);
+        unhashed::put(&<ThreadById<Test>>::hashed_key_for(2), &legacy_thread);
+        unhashed::put(
+            &<PostThreadIdByPostId<Test>>::hashed_key_for(2, 1),
+            &legacy_post,
+        );
+        ThreadCount::put(2);
+        PostCount::put(1);
+
+        Discussions::migrate_threads_and_posts();
+
+        assert_eq!(
+            Discussions::thread_by_id(2),
+            DiscussionThread {
+                title: b"title".to_vec(),
+                created_at: 1,
+                author_id: 1,
+                mode: ThreadMode::Open,
+            }
+        );
+        assert_eq!(
+            <PostThreadIdByPostId<Test>>::get(2, 1),
+            DiscussionPost {
+                text: b"text".to_vec(),
+                created_at: 1,
+                updated_at: 2,
+                author_id: 1,
+                thread_id: 2,
+                parent_post_id: None,
+                depth: 0,
+                edition_number: 1,
+                moderation: None,
+            }
+        );
+    });
+}
@@ -18,12 +18,52 @@ pub struct DiscussionThread<ThreadAuthorId, BlockNumber> {
 
     /// Author of the thread.
     pub author_id: ThreadAuthorId,
+
+    /// Defines who can post in the thread.
+    pub mode: ThreadMode<ThreadAuthorId>,
+}
+
+/// Defines who can post in the discussion thread.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub enum ThreadMode<MemberId> {
+    /// Every member can post in the thread.
+    Open,
+
+    /// Nobody can post in the thread.
+    Closed,
+
+    /// Only council members can post in the thread.
+    CouncilOnly,
+
+    /// Only provided members can post in the thread.
+    WhitelistOnly(Vec<MemberId>),
+}
+
+impl<MemberId> Default for ThreadMode<MemberId> {
+    fn default() -> Self {
+        ThreadMode::Open
+    }
+}
+
+/// Moderation action applied to the post.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct PostModeration<ModeratorId, BlockNumber> {
+    /// When the post was moderated.
+    pub moderated_at: BlockNumber,
+
+    /// Council member who moderated the post.
+    pub moderator_id: ModeratorId,
+
+    /// Moderation rationale
+    pub rationale: Vec<u8>,
 }
 
 /// Post for the discussion thread
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
-pub struct DiscussionPost<PostAuthorId, BlockNumber, ThreadId> {
+pub struct DiscussionPost<PostAuthorId, BlockNumber, ThreadId, PostId> {
     /// Text
     pub text: Vec<u8>,
 
@@ -39,8 +79,37 @@ pub struct DiscussionPost<PostAuthorId, BlockNumber, ThreadId> {
     /// Parent thread id for this post
     pub thread_id: ThreadId,
 
+    /// Parent post id if the post is a reply.
+    pub parent_post_id: Option<PostId>,
+
+    /// Post nesting level. Zero for the top level posts.
+    pub depth: u32,
+
     /// Defines how many times this post was edited. Zero on creation.
     pub edition_number: u32,
+
+    /// Moderation action if the post was moderated.
+    pub moderation: Option<PostModeration<PostAuthorId, BlockNumber>>,
+}
+
+/// Reaction to the discussion post
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum PostReaction {
+    /// Agree with the post.
+    ThumbsUp,
+
+    /// Disagree with the post.
+    ThumbsDown,
+
+    /// Find the post funny.
+    Laugh,
+
+    /// Like the post.
+    Heart,
+
+    /// Find the post confusing.
+    Confused,
 }
 
 /// Post for the discussion thread
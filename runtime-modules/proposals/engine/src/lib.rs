@@ -22,7 +22,8 @@
 //! ### Notes
 //!
 //! - The proposal can be [vetoed](./struct.Module.html#method.veto_proposal)
-//! anytime before the proposal execution by the _sudo_.
+//! anytime before the proposal execution by the member allowed by the _VetoOriginValidator_.
+//! The [veto record](./struct.VetoRecord.html) keeps the vetoer and the veto rationale.
 //! - When the proposal is created with some stake - refunding on proposal finalization with
 //! different statuses should be accomplished from the external handler from the _stake module_
 //! (_StakingEventsHandler_). Such a handler should call
@@ -30,6 +31,38 @@
 //! - If the _council_ got reelected during the proposal _voting period_ the external handler calls
 //! [reset_active_proposals](./trait.Module.html#method.reset_active_proposals) function and
 //! all voting results get cleared.
+//! - Proposals with the _constitutionality_ parameter greater than one require approvals of
+//! several consecutive councils. Such a proposal approved by the current council gets
+//! _PendingConstitutionality_ status and waits for the next council, which restarts its _voting period_.
+//! The proposal becomes _Finalized_ only after the last council approval. The waiting proposal
+//! can be canceled by its proposer and expires after the _MaxPendingConstitutionalityPeriod_.
+//! - Failed proposal execution can be retried according to the proposal
+//! [retry policy](./struct.ExecutionRetryPolicy.html). Every execution attempt is recorded.
+//! - Batch proposal calls are decoded before the execution and dispatched in order. The batch
+//! execution is all-or-nothing: dispatched calls cannot be reverted, so only the first call of
+//! the batch can fail. The proposal creator should ensure that other calls cannot fail on
+//! dispatch. The execution stops on the failed call with the _ExecutionFailed_ status and
+//! the failed call index, the retries execute the whole batch again.
+//! Dispatchable calls should follow the 'verify first, write last' rule, so the failed call
+//! makes no changes.
+//! - The proposer can stake only a part of the required stake (not less than
+//! _MinProposerStakePercentage_). Such a proposal gets _PendingSponsorship_ status and becomes
+//! _Active_ when other members [sponsor](./struct.Module.html#method.sponsor_proposal) the rest of
+//! the required stake. Otherwise, it expires after its _voting period_. Stake contributions are
+//! tracked per sponsor: slashes and refunds are split pro rata.
+//! - Vote records are kept after the proposal finalization. They are removed according to the
+//! [pruning policy](./enum.VoteRecordsPruningPolicy.html).
+//! - Executable code of the proposal can be deferred: such a proposal is created without the code,
+//! which should be [provided](./struct.Module.html#method.provide_proposal_code) after
+//! the approval. The approved proposal fails with the _ProposalCodeTimeout_ error if the code
+//! wasn't provided during the _DeferredCodeTimeout_ after its execution block (the grace period
+//! end or the exact execution block).
+//! - Executable code of the proposal is removed when the proposal cannot be executed anymore:
+//! after the execution or the finalization with the non-approved decision.
+//! Such a proposal is replaced with the compact [outcome](./struct.ProposalOutcome.html) record
+//! after the _FinalizedProposalRetentionPeriod_. Ids of finalized proposals are kept in
+//! the bounded _FinalizedProposalIds_ index ordered by the finalization block: the oldest ids and
+//! theirs outcomes are removed on the _MaxFinalizedProposalIds_ limit exceeding.
 //!
 //! ### Important abstract types to be implemented
 //! Proposals `engine` module has several abstractions to be implemented in order to work correctly.
@@ -37,22 +70,39 @@
 //! they should be council members.
 //! - [VotersParameters](./trait.VotersParameters.html) - defines total voter number, which is
 //! the council size
+//! - [VoterStakesProvider](./trait.VoterStakesProvider.html) - defines voters stake for the
+//! stake weighted [voting mode](./enum.VotingMode.html)
 //! - _ProposerOriginValidator_ - ensure valid proposer identity. Proposers should have permissions
 //! to create a proposal: they should be members of the Joystream.
+//! - _VetoOriginValidator_ - ensure valid vetoer identity. Vetoers should have permissions to veto
+//! proposals.
 //! - [StakeHandlerProvider](./trait.StakeHandlerProvider.html) - defines an interface for the staking.
 //!
 //! A full list of the abstractions can be found [here](./trait.Trait.html).
 //!
 //! ### Supported extrinsics
 //! - [vote](./struct.Module.html#method.vote) - registers a vote for the proposal
+//! - [change_vote](./struct.Module.html#method.change_vote) - changes the previously registered vote
+//! - [withdraw_vote](./struct.Module.html#method.withdraw_vote) - withdraws the previously registered vote
 //! - [cancel_proposal](./struct.Module.html#method.cancel_proposal) - cancels the proposal (can be canceled only by owner)
 //! - [veto_proposal](./struct.Module.html#method.veto_proposal) - vetoes the proposal
+//! - [sponsor_proposal](./struct.Module.html#method.sponsor_proposal) - adds stake to the proposal waiting for sponsors
 //!
 //! ### Public API
 //! - [create_proposal](./struct.Module.html#method.create_proposal) - creates proposal using provided parameters
+//! - [create_batch_proposal](./struct.Module.html#method.create_batch_proposal) - creates proposal executing several calls in order
+//! - [create_deferred_code_proposal](./struct.Module.html#method.create_deferred_code_proposal) - creates proposal with the executable code provided after the approval
+//! - [provide_proposal_code](./struct.Module.html#method.provide_proposal_code) - provides the deferred executable code of the approved proposal
 //! - [ensure_create_proposal_parameters_are_valid](./struct.Module.html#method.ensure_create_proposal_parameters_are_valid) - ensures that we can create the proposal
 //! - [refund_proposal_stake](./struct.Module.html#method.refund_proposal_stake) - a callback for _StakingHandlerEvents_
 //! - [reset_active_proposals](./trait.Module.html#method.reset_active_proposals) - resets voting results for active proposals
+//! - [votes_by_proposal](./struct.Module.html#method.votes_by_proposal) - returns persistent vote records for the proposal
+//! - [votes_by_voter](./struct.Module.html#method.votes_by_voter) - returns persistent vote records of the voter across proposals
+//! - [proposal_vetoes](./struct.Module.html#method.proposal_vetoes) - returns the veto record of the vetoed proposal
+//! - [vote_status](./struct.Module.html#method.vote_status) - returns the vote of the voter for the proposal
+//! - [next_execution_block](./struct.Module.html#method.next_execution_block) - returns the next execution block of the approved proposal
+//! - [decode_proposal_calls](./struct.Module.html#method.decode_proposal_calls) - decodes the proposal code to the calls dispatched on the execution
+//! - [failed_call_execution_status](./struct.Module.html#method.failed_call_execution_status) - returns the execution status of the proposal failed on the call dispatch
 //!
 //! ## Usage
 //!
@@ -85,6 +135,7 @@
 //!                 &parameters,
 //!                 &title,
 //!                 &description,
+//!                 None,
 //!                 None
 //!             )?;
 //!             <engine::Module<T>>::create_proposal(
@@ -94,7 +145,8 @@
 //!                 title,
 //!                 description,
 //!                 None,
-//!                 encoded_proposal_code
+//!                 encoded_proposal_code,
+//!                 None
 //!             )?;
 //!             Ok(())
 //!         }
@@ -112,27 +164,31 @@
 use types::FinalizedProposalData;
 use types::ProposalStakeManager;
 pub use types::{
-    ActiveStake, ApprovedProposalStatus, FinalizationData, Proposal, ProposalDecisionStatus,
-    ProposalParameters, ProposalStatus, VotingResults,
+    ActiveStake, ApprovedProposalStatus, ExecutionAttempt, ExecutionRetryPolicy, FinalizationData,
+    Proposal, ProposalAmendment, ProposalDecisionStatus, ProposalOutcome, ProposalParameters,
+    ProposalStatus, StakeContribution, VetoRecord, VoteRecord, VoteRecordsPruningPolicy,
+    VotingMode, VotingResults, WeightedVotingResults,
 };
 pub use types::{BalanceOf, CurrencyOf, NegativeImbalance};
 pub use types::{DefaultStakeHandlerProvider, StakeHandler, StakeHandlerProvider};
-pub use types::{ProposalCodeDecoder, ProposalExecutable};
-pub use types::{VoteKind, VotersParameters};
+pub use types::{ProposalCodeDecoder, ProposalExecutable, ProposalFinalizationHandler};
+pub use types::{VoteKind, VoterStakesProvider, VotersParameters};
 
+pub(crate) mod migration;
 pub(crate) mod types;
 
 #[cfg(test)]
 mod tests;
 
-use codec::Decode;
+use codec::{Decode, Encode};
 use rstd::prelude::*;
 use sr_primitives::traits::{DispatchResult, Zero};
-use srml_support::traits::{Currency, Get};
+use sr_primitives::Perbill;
+use srml_support::traits::{Currency, Get, Imbalance};
 use srml_support::{
     decl_error, decl_event, decl_module, decl_storage, ensure, print, Parameter, StorageDoubleMap,
 };
-use system::{ensure_root, RawOrigin};
+use system::RawOrigin;
 
 use crate::types::ApprovedProposalData;
 use common::origin_validator::ActorOriginValidator;
@@ -157,9 +213,15 @@ pub trait Trait:
     /// Validates voter id and origin combination
     type VoterOriginValidator: ActorOriginValidator<Self::Origin, MemberId<Self>, Self::AccountId>;
 
+    /// Validates vetoer id and origin combination
+    type VetoOriginValidator: ActorOriginValidator<Self::Origin, MemberId<Self>, Self::AccountId>;
+
     /// Provides data for voting. Defines maximum voters count for the proposal.
     type TotalVotersCounter: VotersParameters;
 
+    /// Provides voters stake for the stake weighted voting mode.
+    type VoterStakesProvider: VoterStakesProvider<MemberId<Self>, BalanceOf<Self>>;
+
     /// Proposal Id type
     type ProposalId: From<u32> + Parameter + Default + Copy;
 
@@ -178,11 +240,42 @@ pub trait Trait:
     /// Defines max allowed proposal description length.
     type DescriptionMaxLength: Get<u32>;
 
+    /// Defines max allowed veto rationale length.
+    type VetoRationaleMaxLength: Get<u32>;
+
     /// Defines max simultaneous active proposals number.
     type MaxActiveProposalLimit: Get<u32>;
 
     /// Proposals executable code. Can be instantiated by external module Call enum members.
     type DispatchableCallCode: Parameter + Dispatchable<Origin = Self::Origin> + Default;
+
+    /// Defines how long vote records are kept after the proposal finalization.
+    type VotesPruningPolicy: Get<VoteRecordsPruningPolicy<Self::BlockNumber>>;
+
+    /// Handles the proposal finalization in other modules.
+    type ProposalFinalizationHandler: ProposalFinalizationHandler<Self::ProposalId>;
+
+    /// Defines the minimum part of the required stake (in percents) the proposer should stake.
+    /// The rest of the required stake can be staked by sponsors.
+    type MinProposerStakePercentage: Get<u32>;
+
+    /// Defines how long the finalized proposal is kept before replacing it with the compact
+    /// outcome record. Counts from the block the proposal cannot be executed anymore.
+    type FinalizedProposalRetentionPeriod: Get<Self::BlockNumber>;
+
+    /// Defines max length of the finalized proposal ids index.
+    type MaxFinalizedProposalIds: Get<u32>;
+
+    /// Defines how long the approved proposal waits for its deferred executable code after
+    /// its execution block.
+    type DeferredCodeTimeout: Get<Self::BlockNumber>;
+
+    /// Defines how long the proposal approved by the current council waits for the next council
+    /// approval before the expiration.
+    type MaxPendingConstitutionalityPeriod: Get<Self::BlockNumber>;
+
+    /// Defines max allowed amendments number of the proposal.
+    type MaxProposalAmendments: Get<u32>;
 }
 
 decl_event!(
@@ -194,6 +287,7 @@ decl_event!(
         <T as system::Trait>::BlockNumber,
         <T as system::Trait>::AccountId,
         <T as stake::Trait>::StakeId,
+        Balance = BalanceOf<T>,
     {
         /// Emits on proposal creation.
         /// Params:
@@ -213,6 +307,49 @@ decl_event!(
         /// - Id of a proposal.
         /// - Kind of vote.
         Voted(MemberId, ProposalId, VoteKind),
+
+        /// Emits on changing the vote for the proposal
+        /// Params:
+        /// - Voter - member id of a voter.
+        /// - Id of a proposal.
+        /// - Kind of the new vote.
+        VoteChanged(MemberId, ProposalId, VoteKind),
+
+        /// Emits on withdrawing the vote for the proposal
+        /// Params:
+        /// - Voter - member id of a voter.
+        /// - Id of a proposal.
+        VoteWithdrawn(MemberId, ProposalId),
+
+        /// Emits on every proposal execution attempt
+        /// Params:
+        /// - Id of a proposal.
+        /// - Execution result.
+        ProposalExecutionAttempted(ProposalId, ApprovedProposalStatus),
+
+        /// Emits on the proposal amendment
+        /// Params:
+        /// - Member id of a proposer.
+        /// - Id of an amended proposal.
+        ProposalAmended(MemberId, ProposalId),
+
+        /// Emits on the proposal sponsoring
+        /// Params:
+        /// - Member id of a sponsor.
+        /// - Id of a sponsored proposal.
+        /// - Staked balance.
+        ProposalSponsored(MemberId, ProposalId, Balance),
+
+        /// Emits on providing the deferred executable code of the approved proposal.
+        /// Params:
+        /// - Id of a proposal.
+        ProposalCodeProvided(ProposalId),
+
+        /// Emits on the proposal veto
+        /// Params:
+        /// - Member id of a vetoer.
+        /// - Id of a vetoed proposal.
+        ProposalVetoed(MemberId, ProposalId),
     }
 );
 
@@ -240,6 +377,9 @@ decl_error! {
         /// The proposal have been already voted on
         AlreadyVoted,
 
+        /// The proposal was not voted on by the voter
+        NotVoted,
+
         /// Not an author
         NotAuthor,
 
@@ -255,14 +395,50 @@ decl_error! {
         /// Stake differs from the proposal requirements
         StakeDiffersFromRequired,
 
+        /// Stake is below the min proposer stake of the proposal requirements
+        StakeIsBelowMinimum,
+
         /// Approval threshold cannot be zero
         InvalidParameterApprovalThreshold,
 
         /// Slashing threshold cannot be zero
         InvalidParameterSlashingThreshold,
 
+        /// Exact execution block cannot be earlier than the end of voting and grace periods
+        InvalidExactExecutionBlock,
+
+        /// Batch proposal should contain at least one call
+        EmptyBatchProposal,
+
+        /// Execution retry interval cannot be zero
+        InvalidParameterExecutionRetryInterval,
+
+        /// The proposal doesn't wait for sponsors
+        ProposalIsNotPendingSponsorship,
+
+        /// Sponsored stake exceeds the rest of the required stake
+        SponsorStakeExceedsRequired,
+
+        /// Executable code of the proposal is not deferred
+        ProposalCodeIsNotDeferred,
+
+        /// Deferred executable code was already provided
+        ProposalCodeAlreadyProvided,
+
+        /// The proposal is not approved or its execution was finished
+        ProposalIsNotPendingExecution,
+
+        /// Veto rationale cannot be empty
+        EmptyVetoRationaleProvided,
+
+        /// Veto rationale is too long
+        VetoRationaleIsTooLong,
+
         /// Require root origin in extrinsics
         RequireRootOrigin,
+
+        /// Max proposal amendments number exceeded
+        MaxProposalAmendmentsExceeded,
     }
 }
 
@@ -288,6 +464,18 @@ decl_storage! {
         /// Map proposal executable code by proposal id.
         pub DispatchableCallCode get(fn proposal_codes): map T::ProposalId =>  Vec<u8>;
 
+        /// Execution attempts of the approved proposals.
+        pub ExecutionAttempts get(fn execution_attempts):
+            map T::ProposalId => Vec<ExecutionAttempt<T::BlockNumber>>;
+
+        /// Marks batch proposals. Executable code of the batch proposal contains encoded list
+        /// of the dispatchable calls.
+        pub BatchProposals get(fn is_batch_proposal): map T::ProposalId => bool;
+
+        /// Marks proposals with the deferred executable code. The code is provided after
+        /// the proposal approval.
+        pub DeferredCodeProposals get(fn is_deferred_code_proposal): map T::ProposalId => bool;
+
         /// Count of active proposals.
         pub ActiveProposalCount get(fn active_proposal_count): u32;
 
@@ -297,12 +485,63 @@ decl_storage! {
         /// Ids of proposals that were approved and theirs grace period was not expired.
         pub PendingExecutionProposalIds get(fn pending_proposal_ids): linked_map T::ProposalId=> ();
 
+        /// Ids of proposals that were approved by the current council and wait for the next
+        /// council approval. Values are the blocks the proposals started waiting at.
+        pub PendingConstitutionalityProposalIds get(fn pending_constitutionality_proposal_ids):
+            linked_map T::ProposalId => T::BlockNumber;
+
         /// Double map for preventing duplicate votes. Should be cleaned after usage.
         pub VoteExistsByProposalByVoter get(fn vote_by_proposal_by_voter):
             double_map T::ProposalId, twox_256(MemberId<T>) => VoteKind;
 
         /// Map proposal id by stake id. Required by StakingEventsHandler callback call
         pub StakesProposals get(fn stakes_proposals): map T::StakeId =>  T::ProposalId;
+
+        /// Ids of proposals waiting for sponsors to cover their required stake.
+        pub PendingSponsorshipProposalIds get(fn pending_sponsorship_proposal_ids):
+            linked_map T::ProposalId => ();
+
+        /// Stake contributions of the proposer and sponsors by proposal id. Used to split
+        /// slashes and refunds pro rata.
+        pub StakeContributions get(fn stake_contributions):
+            map T::ProposalId => Vec<StakeContribution<MemberId<T>, T::AccountId, BalanceOf<T>>>;
+
+        /// Persistent vote records by proposal id. Records survive the proposal finalization and
+        /// are removed according to the pruning policy.
+        pub VotesByProposal get(fn votes_by_proposal):
+            map T::ProposalId => Vec<VoteRecord<MemberId<T>, T::BlockNumber>>;
+
+        /// Ids of proposals with persistent vote records by voter member id. Kept in sync with
+        /// the vote records by proposal id.
+        pub ProposalIdsByVoter get(fn proposal_ids_by_voter):
+            map MemberId<T> => Vec<T::ProposalId>;
+
+        /// Ids of finalized proposals which vote records should be removed at the block.
+        pub VoteRecordsPruningSchedule get(fn vote_records_pruning_schedule):
+            map T::BlockNumber => Vec<T::ProposalId>;
+
+        /// Ids of finalized proposals which should be replaced with their outcomes at the block.
+        pub ProposalPruningSchedule get(fn proposal_pruning_schedule):
+            map T::BlockNumber => Vec<T::ProposalId>;
+
+        /// Compact outcome records of the pruned proposals.
+        pub ProposalOutcomes get(fn proposal_outcomes):
+            map T::ProposalId => Option<ProposalOutcome<T::BlockNumber, MemberId<T>>>;
+
+        /// Veto records of the vetoed proposals. Records are removed with the proposal outcomes.
+        pub ProposalVetoes get(fn proposal_vetoes):
+            map T::ProposalId => Option<VetoRecord<MemberId<T>, T::BlockNumber>>;
+
+        /// Bounded index of finalized proposal ids ordered by the finalization block.
+        pub FinalizedProposalIds get(fn finalized_proposal_ids): Vec<T::ProposalId>;
+
+        /// Number of the ended voting rounds: proposals decided by the votes and the council
+        /// approvals of the constitutional proposals. Canceled and vetoed proposals and proposals
+        /// expired waiting for sponsors end without the voting round.
+        pub VotingRoundsCount get(fn voting_rounds_count): u32;
+
+        /// Number of the ended voting rounds the voter voted in by voter member id.
+        pub VotedRoundsCountByVoter get(fn voted_rounds_count_by_voter): map MemberId<T> => u32;
     }
 }
 
@@ -327,9 +566,40 @@ decl_module! {
         /// Exports const -  max allowed proposal description length.
         const DescriptionMaxLength: u32 = T::DescriptionMaxLength::get();
 
+        /// Exports const -  max allowed veto rationale length.
+        const VetoRationaleMaxLength: u32 = T::VetoRationaleMaxLength::get();
+
         /// Exports const -  max simultaneous active proposals number.
         const MaxActiveProposalLimit: u32 = T::MaxActiveProposalLimit::get();
 
+        /// Exports const -  defines how long vote records are kept after the proposal finalization.
+        const VotesPruningPolicy: VoteRecordsPruningPolicy<T::BlockNumber> =
+            T::VotesPruningPolicy::get();
+
+        /// Exports const -  the minimum part of the required stake (in percents) the proposer
+        /// should stake.
+        const MinProposerStakePercentage: u32 = T::MinProposerStakePercentage::get();
+
+        /// Exports const -  defines how long the finalized proposal is kept before replacing it
+        /// with the compact outcome record.
+        const FinalizedProposalRetentionPeriod: T::BlockNumber =
+            T::FinalizedProposalRetentionPeriod::get();
+
+        /// Exports const -  max length of the finalized proposal ids index.
+        const MaxFinalizedProposalIds: u32 = T::MaxFinalizedProposalIds::get();
+
+        /// Exports const -  defines how long the approved proposal waits for its deferred
+        /// executable code after its execution block.
+        const DeferredCodeTimeout: T::BlockNumber = T::DeferredCodeTimeout::get();
+
+        /// Exports const -  defines how long the proposal approved by the current council waits
+        /// for the next council approval before the expiration.
+        const MaxPendingConstitutionalityPeriod: T::BlockNumber =
+            T::MaxPendingConstitutionalityPeriod::get();
+
+        /// Exports const -  max allowed amendments number of the proposal.
+        const MaxProposalAmendments: u32 = T::MaxProposalAmendments::get();
+
         /// Vote extrinsic. Conditions:  origin must allow votes.
         pub fn vote(origin, voter_id: MemberId<T>, proposal_id: T::ProposalId, vote: VoteKind)  {
             T::VoterOriginValidator::ensure_actor_origin(
@@ -355,9 +625,53 @@ decl_module! {
 
             <Proposals<T>>::insert(proposal_id, proposal);
             <VoteExistsByProposalByVoter<T>>::insert( proposal_id, voter_id, vote.clone());
+            Self::save_vote_record(proposal_id, voter_id, vote.clone());
             Self::deposit_event(RawEvent::Voted(voter_id, proposal_id, vote));
         }
 
+        /// Change vote extrinsic. Conditions: origin must allow votes, the voter must have voted
+        /// on the active proposal before.
+        pub fn change_vote(origin, voter_id: MemberId<T>, proposal_id: T::ProposalId, vote: VoteKind)  {
+            T::VoterOriginValidator::ensure_actor_origin(
+                origin,
+                voter_id,
+            )?;
+
+            let (mut proposal, previous_vote) =
+                Self::ensure_active_proposal_was_voted(proposal_id, voter_id)?;
+
+            proposal.voting_results.remove_vote(previous_vote);
+            proposal.voting_results.add_vote(vote.clone());
+
+            // mutation
+
+            <Proposals<T>>::insert(proposal_id, proposal);
+            <VoteExistsByProposalByVoter<T>>::insert( proposal_id, voter_id, vote.clone());
+            Self::save_vote_record(proposal_id, voter_id, vote.clone());
+            Self::deposit_event(RawEvent::VoteChanged(voter_id, proposal_id, vote));
+        }
+
+        /// Withdraw vote extrinsic. Conditions: origin must allow votes, the voter must have voted
+        /// on the active proposal before.
+        pub fn withdraw_vote(origin, voter_id: MemberId<T>, proposal_id: T::ProposalId)  {
+            T::VoterOriginValidator::ensure_actor_origin(
+                origin,
+                voter_id,
+            )?;
+
+            let (mut proposal, previous_vote) =
+                Self::ensure_active_proposal_was_voted(proposal_id, voter_id)?;
+
+            proposal.voting_results.remove_vote(previous_vote);
+
+            // mutation
+
+            <Proposals<T>>::insert(proposal_id, proposal);
+            <VoteExistsByProposalByVoter<T>>::remove(proposal_id, voter_id);
+            Self::remove_vote_record(proposal_id, voter_id);
+            Self::deposit_event(RawEvent::VoteWithdrawn(voter_id, proposal_id));
+        }
+
         /// Cancel a proposal by its original proposer.
         pub fn cancel_proposal(origin, proposer_id: MemberId<T>, proposal_id: T::ProposalId) {
             T::ProposerOriginValidator::ensure_actor_origin(
@@ -369,51 +683,190 @@ decl_module! {
             let proposal = Self::proposals(proposal_id);
 
             ensure!(proposer_id == proposal.proposer_id, Error::NotAuthor);
-            ensure!(matches!(proposal.status, ProposalStatus::Active{..}), Error::ProposalFinalized);
+            ensure!(
+                matches!(
+                    proposal.status,
+                    ProposalStatus::Active{..}
+                        | ProposalStatus::PendingSponsorship{..}
+                        | ProposalStatus::PendingConstitutionality{..}
+                ),
+                Error::ProposalFinalized
+            );
 
             // mutation
 
             Self::finalize_proposal(proposal_id, ProposalDecisionStatus::Canceled);
         }
 
-        /// Veto a proposal. Must be root.
-        pub fn veto_proposal(origin, proposal_id: T::ProposalId) {
-            ensure_root(origin)?;
+        /// Sponsor a proposal waiting for sponsors. Adds the stake balance from the sponsor
+        /// account to the proposal stake. The proposal becomes active when its required stake is
+        /// fully covered.
+        pub fn sponsor_proposal(
+            origin,
+            sponsor_id: MemberId<T>,
+            proposal_id: T::ProposalId,
+            stake_balance: BalanceOf<T>
+        ) {
+            let account_id = T::ProposerOriginValidator::ensure_actor_origin(
+                origin,
+                sponsor_id,
+            )?;
 
             ensure!(<Proposals<T>>::exists(proposal_id), Error::ProposalNotFound);
             let proposal = Self::proposals(proposal_id);
 
+            let active_stake =
+                if let ProposalStatus::PendingSponsorship(Some(active_stake)) = proposal.status {
+                    active_stake
+                } else {
+                    return Err(Error::ProposalIsNotPendingSponsorship);
+                };
+
+            ensure!(!stake_balance.is_zero(), Error::EmptyStake);
+
+            let required_stake = proposal.parameters.required_stake.unwrap_or_else(Zero::zero);
+            let staked_balance = Self::total_stake_contribution(proposal_id) + stake_balance;
+
+            ensure!(staked_balance <= required_stake, Error::SponsorStakeExceedsRequired);
+
+            // mutation
+
+            ProposalStakeManager::<T>::increase_stake(
+                active_stake.stake_id,
+                stake_balance,
+                account_id.clone(),
+            )?;
+
+            <StakeContributions<T>>::mutate(proposal_id, |contributions| {
+                contributions.push(StakeContribution {
+                    member_id: sponsor_id,
+                    account_id,
+                    amount: stake_balance,
+                })
+            });
+
+            Self::deposit_event(RawEvent::ProposalSponsored(sponsor_id, proposal_id, stake_balance));
+
+            if staked_balance == required_stake {
+                Self::activate_sponsored_proposal(proposal_id);
+            }
+        }
+
+        /// Veto a proposal. Origin must allow vetoes. Saves the vetoer and the veto rationale.
+        pub fn veto_proposal(
+            origin,
+            vetoer_id: MemberId<T>,
+            proposal_id: T::ProposalId,
+            rationale: Vec<u8>
+        ) {
+            T::VetoOriginValidator::ensure_actor_origin(
+                origin,
+                vetoer_id,
+            )?;
+
+            ensure!(<Proposals<T>>::exists(proposal_id), Error::ProposalNotFound);
+            let proposal = Self::proposals(proposal_id);
+
+            ensure!(!rationale.is_empty(), Error::EmptyVetoRationaleProvided);
+            ensure!(
+                rationale.len() as u32 <= T::VetoRationaleMaxLength::get(),
+                Error::VetoRationaleIsTooLong
+            );
+
+            let is_pending_execution = <PendingExecutionProposalIds<T>>::exists(proposal_id);
+            ensure!(
+                is_pending_execution
+                    || matches!(
+                        proposal.status,
+                        ProposalStatus::Active{..}
+                            | ProposalStatus::PendingConstitutionality{..}
+                            | ProposalStatus::PendingSponsorship{..}
+                    ),
+                Error::ProposalFinalized
+            );
+
             // mutation
 
-            if <PendingExecutionProposalIds<T>>::exists(proposal_id) {
+            <ProposalVetoes<T>>::insert(
+                proposal_id,
+                VetoRecord {
+                    vetoer_id,
+                    vetoed_at: Self::current_block(),
+                    rationale,
+                },
+            );
+            Self::deposit_event(RawEvent::ProposalVetoed(vetoer_id, proposal_id));
+
+            if is_pending_execution {
                 Self::veto_pending_execution_proposal(proposal_id, proposal);
             } else {
-                ensure!(matches!(proposal.status, ProposalStatus::Active{..}), Error::ProposalFinalized);
                 Self::finalize_proposal(proposal_id, ProposalDecisionStatus::Vetoed);
             }
         }
 
         /// Block finalization. Perform voting period check, vote result tally, approved proposals
-        /// grace period checks, and proposal execution.
-        fn on_finalize(_n: T::BlockNumber) {
+        /// grace period checks, proposal execution, vote records and finalized proposals pruning.
+        fn on_finalize(n: T::BlockNumber) {
             let finalized_proposals = Self::get_finalized_proposals();
+            let expired_sponsorship_proposal_ids = Self::get_expired_sponsorship_proposal_ids();
+            let expired_pending_constitutionality_proposal_ids =
+                Self::get_expired_pending_constitutionality_proposal_ids();
 
             // mutation
 
+            // Proposals which required stake wasn't covered by sponsors during the voting period
+            // expire.
+            for proposal_id in expired_sponsorship_proposal_ids {
+                Self::finalize_proposal(proposal_id, ProposalDecisionStatus::Expired);
+            }
+
+            // Proposals which didn't get the next council approval in time expire.
+            for proposal_id in expired_pending_constitutionality_proposal_ids {
+                Self::finalize_proposal(proposal_id, ProposalDecisionStatus::Expired);
+            }
+
             // Check vote results. Approved proposals with zero grace period will be
-            // transitioned to the PendingExecution status.
+            // transitioned to the PendingExecution status. Approved proposals requiring more
+            // council approvals will wait for the next council.
             for  proposal_data in finalized_proposals {
-                <Proposals<T>>::insert(proposal_data.proposal_id, proposal_data.proposal);
-                Self::finalize_proposal(proposal_data.proposal_id, proposal_data.status);
+                Self::count_voting_round(proposal_data.proposal_id);
+
+                let mut proposal = proposal_data.proposal;
+
+                let approved = matches!(proposal_data.status, ProposalDecisionStatus::Approved{..});
+                if approved {
+                    proposal.council_approvals += 1;
+                }
+
+                let requires_council_approval = approved && proposal.requires_council_approval();
+                <Proposals<T>>::insert(proposal_data.proposal_id, proposal);
+
+                if requires_council_approval {
+                    Self::wait_for_next_council_approval(proposal_data.proposal_id);
+                } else {
+                    Self::finalize_proposal(proposal_data.proposal_id, proposal_data.status);
+                }
             }
 
             let executable_proposals =
                 Self::get_approved_proposal_with_expired_grace_period();
+            let timed_out_proposals = Self::get_approved_proposals_with_expired_code_timeout();
+
+            // Fail approved proposals which deferred code wasn't provided in time
+            for timed_out_proposal in timed_out_proposals {
+                Self::complete_proposal_execution(
+                    timed_out_proposal,
+                    ApprovedProposalStatus::failed_execution("ProposalCodeTimeout"),
+                );
+            }
 
             // Execute approved proposals with expired grace period
             for approved_proosal in executable_proposals {
                 Self::execute_proposal(approved_proosal);
             }
+
+            Self::prune_vote_records(n);
+            Self::prune_finalized_proposals(n);
         }
     }
 }
@@ -428,12 +881,14 @@ impl<T: Trait> Module<T> {
         description: Vec<u8>,
         stake_balance: Option<types::BalanceOf<T>>,
         encoded_dispatchable_call_code: Vec<u8>,
+        exactly_execute_at: Option<T::BlockNumber>,
     ) -> Result<T::ProposalId, Error> {
         Self::ensure_create_proposal_parameters_are_valid(
             &parameters,
             &title,
             &description,
             stake_balance,
+            exactly_execute_at,
         )?;
 
         // checks passed
@@ -455,25 +910,51 @@ impl<T: Trait> Module<T> {
         if let Some(stake_id) = stake_id_result {
             stake_data = Some(ActiveStake {
                 stake_id,
-                source_account_id: account_id,
+                source_account_id: account_id.clone(),
             });
 
             <StakesProposals<T>>::insert(stake_id, proposal_id);
         }
 
+        if let Some(stake_amount) = stake_balance {
+            <StakeContributions<T>>::insert(
+                proposal_id,
+                vec![StakeContribution {
+                    member_id: proposer_id,
+                    account_id,
+                    amount: stake_amount,
+                }],
+            );
+        }
+
+        // Proposal waits for sponsors if the proposer staked only a part of the required stake
+        let requires_sponsorship = parameters.required_stake != stake_balance;
+        let proposal_status = if requires_sponsorship {
+            <PendingSponsorshipProposalIds<T>>::insert(proposal_id, ());
+
+            ProposalStatus::PendingSponsorship(stake_data)
+        } else {
+            <ActiveProposalIds<T>>::insert(proposal_id, ());
+
+            ProposalStatus::Active(stake_data)
+        };
+
         let new_proposal = Proposal {
             created_at: Self::current_block(),
             parameters,
             title,
             description,
             proposer_id,
-            status: ProposalStatus::Active(stake_data),
+            status: proposal_status,
             voting_results: VotingResults::default(),
+            exactly_execute_at,
+            activated_at: Self::current_block(),
+            council_approvals: 0,
+            amendments: Vec::new(),
         };
 
         <Proposals<T>>::insert(proposal_id, new_proposal);
         <DispatchableCallCode<T>>::insert(proposal_id, encoded_dispatchable_call_code);
-        <ActiveProposalIds<T>>::insert(proposal_id, ());
         ProposalCount::put(next_proposal_count_value);
         Self::increase_active_proposal_counter();
 
@@ -482,16 +963,183 @@ impl<T: Trait> Module<T> {
         Ok(proposal_id)
     }
 
+    /// Create batch proposal. Requires 'proposal origin' membership.
+    /// Provided calls are executed in order as a single proposal. Only the first call can fail
+    /// on dispatch: calls dispatched before the failed call cannot be reverted.
+    pub fn create_batch_proposal(
+        account_id: T::AccountId,
+        proposer_id: MemberId<T>,
+        parameters: ProposalParameters<T::BlockNumber, types::BalanceOf<T>>,
+        title: Vec<u8>,
+        description: Vec<u8>,
+        stake_balance: Option<types::BalanceOf<T>>,
+        encoded_dispatchable_calls: Vec<Vec<u8>>,
+        exactly_execute_at: Option<T::BlockNumber>,
+    ) -> Result<T::ProposalId, Error> {
+        ensure!(
+            !encoded_dispatchable_calls.is_empty(),
+            Error::EmptyBatchProposal
+        );
+
+        let proposal_id = Self::create_proposal(
+            account_id,
+            proposer_id,
+            parameters,
+            title,
+            description,
+            stake_balance,
+            encoded_dispatchable_calls.encode(),
+            exactly_execute_at,
+        )?;
+
+        <BatchProposals<T>>::insert(proposal_id, true);
+
+        Ok(proposal_id)
+    }
+
+    /// Create proposal with the deferred executable code. Requires 'proposal origin' membership.
+    /// The code should be provided after the proposal approval.
+    pub fn create_deferred_code_proposal(
+        account_id: T::AccountId,
+        proposer_id: MemberId<T>,
+        parameters: ProposalParameters<T::BlockNumber, types::BalanceOf<T>>,
+        title: Vec<u8>,
+        description: Vec<u8>,
+        stake_balance: Option<types::BalanceOf<T>>,
+        exactly_execute_at: Option<T::BlockNumber>,
+    ) -> Result<T::ProposalId, Error> {
+        let proposal_id = Self::create_proposal(
+            account_id,
+            proposer_id,
+            parameters,
+            title,
+            description,
+            stake_balance,
+            Vec::new(),
+            exactly_execute_at,
+        )?;
+
+        <DeferredCodeProposals<T>>::insert(proposal_id, true);
+
+        Ok(proposal_id)
+    }
+
+    /// Provides the deferred executable code of the approved proposal. The proposal gets
+    /// executed when it's ready for the execution.
+    pub fn provide_proposal_code(
+        proposal_id: T::ProposalId,
+        encoded_dispatchable_call_code: Vec<u8>,
+    ) -> DispatchResult<Error> {
+        ensure!(<Proposals<T>>::exists(proposal_id), Error::ProposalNotFound);
+        ensure!(
+            Self::is_deferred_code_proposal(proposal_id),
+            Error::ProposalCodeIsNotDeferred
+        );
+        ensure!(
+            <PendingExecutionProposalIds<T>>::exists(proposal_id),
+            Error::ProposalIsNotPendingExecution
+        );
+        ensure!(
+            Self::proposal_codes(proposal_id).is_empty(),
+            Error::ProposalCodeAlreadyProvided
+        );
+
+        // checks passed
+        // mutation
+
+        <DispatchableCallCode<T>>::insert(proposal_id, encoded_dispatchable_call_code);
+
+        Self::deposit_event(RawEvent::ProposalCodeProvided(proposal_id));
+
+        Ok(())
+    }
+
+    /// Amend active proposal. Replaces the proposal executable code, resets the voting results
+    /// and the council approvals, restarts the voting period. Requires the proposal author.
+    /// The proposal can be amended not more than _MaxProposalAmendments_ times.
+    pub fn amend_proposal(
+        proposer_id: MemberId<T>,
+        proposal_id: T::ProposalId,
+        encoded_dispatchable_call_code: Vec<u8>,
+    ) -> DispatchResult<Error> {
+        let mut proposal = Self::ensure_proposal_can_be_amended(proposer_id, proposal_id)?;
+
+        // checks passed
+        // mutation
+
+        proposal.amendments.push(ProposalAmendment {
+            amended_at: Self::current_block(),
+            discarded_voting_results: proposal.voting_results.clone(),
+        });
+        proposal.voting_results = VotingResults::default();
+        proposal.council_approvals = 0;
+        proposal.activated_at = Self::current_block();
+
+        <Proposals<T>>::insert(proposal_id, proposal);
+        <DispatchableCallCode<T>>::insert(proposal_id, encoded_dispatchable_call_code);
+        <VoteExistsByProposalByVoter<T>>::remove_prefix(&proposal_id);
+        Self::remove_vote_records(proposal_id);
+
+        Self::deposit_event(RawEvent::ProposalAmended(proposer_id, proposal_id));
+
+        Ok(())
+    }
+
+    /// Amend active batch proposal. Replaces the calls of the batch proposal, resets the voting
+    /// results and restarts the voting period. Requires the proposal author.
+    pub fn amend_batch_proposal(
+        proposer_id: MemberId<T>,
+        proposal_id: T::ProposalId,
+        encoded_dispatchable_calls: Vec<Vec<u8>>,
+    ) -> DispatchResult<Error> {
+        ensure!(
+            !encoded_dispatchable_calls.is_empty(),
+            Error::EmptyBatchProposal
+        );
+
+        Self::amend_proposal(
+            proposer_id,
+            proposal_id,
+            encoded_dispatchable_calls.encode(),
+        )
+    }
+
+    /// Ensures that the proposal exists, it is active, the member is its author and
+    /// the amendments limit is not exceeded. Returns the proposal.
+    pub fn ensure_proposal_can_be_amended(
+        proposer_id: MemberId<T>,
+        proposal_id: T::ProposalId,
+    ) -> Result<ProposalOf<T>, Error> {
+        ensure!(<Proposals<T>>::exists(proposal_id), Error::ProposalNotFound);
+        let proposal = Self::proposals(proposal_id);
+
+        ensure!(proposer_id == proposal.proposer_id, Error::NotAuthor);
+        ensure!(
+            matches!(proposal.status, ProposalStatus::Active { .. }),
+            Error::ProposalFinalized
+        );
+        ensure!(
+            (proposal.amendments.len() as u32) < T::MaxProposalAmendments::get(),
+            Error::MaxProposalAmendmentsExceeded
+        );
+
+        Ok(proposal)
+    }
+
     /// Performs all checks for the proposal creation:
     /// - title, body lengths
     /// - max active proposal
     /// - provided parameters: approval_threshold_percentage and slashing_threshold_percentage > 0
-    /// - provided stake balance and parameters.required_stake are valid
+    /// - execution retry interval (if any) > 0
+    /// - provided stake balance and parameters.required_stake are valid: stake balance cannot
+    /// exceed the required stake and should be not less than its minimum proposer part
+    /// - exact execution block (if any) is not earlier than the end of voting and grace periods
     pub fn ensure_create_proposal_parameters_are_valid(
         parameters: &ProposalParameters<T::BlockNumber, types::BalanceOf<T>>,
         title: &[u8],
         description: &[u8],
         stake_balance: Option<types::BalanceOf<T>>,
+        exactly_execute_at: Option<T::BlockNumber>,
     ) -> DispatchResult<Error> {
         ensure!(!title.is_empty(), Error::EmptyTitleProvided);
         ensure!(
@@ -520,13 +1168,29 @@ impl<T: Trait> Module<T> {
             Error::InvalidParameterSlashingThreshold
         );
 
+        if let Some(retry_policy) = parameters.execution_retry_policy {
+            ensure!(
+                !retry_policy.retry_interval.is_zero(),
+                Error::InvalidParameterExecutionRetryInterval
+            );
+        }
+
         // check stake parameters
         if let Some(required_stake) = parameters.required_stake {
             if let Some(staked_balance) = stake_balance {
+                let staked_fraction =
+                    Perbill::from_rational_approximation(staked_balance, required_stake);
+                let min_proposer_stake_fraction =
+                    Perbill::from_percent(T::MinProposerStakePercentage::get());
+
                 ensure!(
-                    required_stake == staked_balance,
+                    staked_balance <= required_stake,
                     Error::StakeDiffersFromRequired
                 );
+                ensure!(
+                    staked_fraction.deconstruct() >= min_proposer_stake_fraction.deconstruct(),
+                    Error::StakeIsBelowMinimum
+                );
             } else {
                 return Err(Error::EmptyStake);
             }
@@ -536,10 +1200,21 @@ impl<T: Trait> Module<T> {
             return Err(Error::StakeShouldBeEmpty);
         }
 
+        if let Some(execute_at) = exactly_execute_at {
+            let earliest_execution_block =
+                Self::current_block() + parameters.voting_period + parameters.grace_period;
+
+            ensure!(
+                execute_at >= earliest_execution_block,
+                Error::InvalidExactExecutionBlock
+            );
+        }
+
         Ok(())
     }
 
-    /// Callback from StakingEventsHandler. Refunds unstaked imbalance back to the source account.
+    /// Callback from StakingEventsHandler. Refunds unstaked imbalance back to the source accounts
+    /// of the proposer and sponsors pro rata to their stake contributions.
     /// There can be a lot of invariant breaks in the scope of this proposal.
     /// Such situations are handled by adding error messages to the log.
     pub fn refund_proposal_stake(stake_id: T::StakeId, imbalance: NegativeImbalance<T>) {
@@ -549,16 +1224,12 @@ impl<T: Trait> Module<T> {
             if <Proposals<T>>::exists(proposal_id) {
                 let proposal = Self::proposals(proposal_id);
 
-                if let ProposalStatus::Active(active_stake_result) = proposal.status {
+                if let ProposalStatus::Active(active_stake_result)
+                | ProposalStatus::PendingConstitutionality(active_stake_result)
+                | ProposalStatus::PendingSponsorship(active_stake_result) = proposal.status
+                {
                     if let Some(active_stake) = active_stake_result {
-                        let refunding_result = CurrencyOf::<T>::resolve_into_existing(
-                            &active_stake.source_account_id,
-                            imbalance,
-                        );
-
-                        if refunding_result.is_err() {
-                            print("Broken invariant: cannot refund");
-                        }
+                        Self::refund_stake_contributions(proposal_id, active_stake, imbalance);
                     }
                 } else {
                     print("Broken invariant: proposal status is not Active");
@@ -571,15 +1242,134 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    /// Returns the vote of the voter for the proposal if any.
+    pub fn vote_status(proposal_id: T::ProposalId, voter_id: MemberId<T>) -> Option<VoteKind> {
+        if <VoteExistsByProposalByVoter<T>>::exists(proposal_id, voter_id) {
+            Some(Self::vote_by_proposal_by_voter(proposal_id, voter_id))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the persistent vote records of the voter across proposals (voting record).
+    /// Records are kept according to the pruning policy.
+    pub fn votes_by_voter(
+        voter_id: MemberId<T>,
+    ) -> Vec<(T::ProposalId, VoteRecord<MemberId<T>, T::BlockNumber>)> {
+        Self::proposal_ids_by_voter(voter_id)
+            .into_iter()
+            .filter_map(|proposal_id| {
+                Self::votes_by_proposal(proposal_id)
+                    .into_iter()
+                    .find(|record| record.voter_id == voter_id)
+                    .map(|record| (proposal_id, record))
+            })
+            .collect()
+    }
+
+    /// Returns the number of the ended voting rounds the voters voted in and the number of all
+    /// ended voting rounds. Both numbers are running totals: the participation during a period
+    /// is the difference of the totals read at the period start and end.
+    pub fn voting_participation(voter_ids: &[MemberId<T>]) -> (u32, u32) {
+        let voted = voter_ids.iter().fold(0u32, |voted, voter_id| {
+            voted.saturating_add(Self::voted_rounds_count_by_voter(voter_id))
+        });
+
+        (voted, Self::voting_rounds_count())
+    }
+
+    /// Returns the earliest block of the next execution attempt for the approved proposal
+    /// pending execution. Returns None for other proposals. Proposals with the deferred code
+    /// are executed only after the code is provided.
+    pub fn next_execution_block(proposal_id: T::ProposalId) -> Option<T::BlockNumber> {
+        if !<PendingExecutionProposalIds<T>>::exists(proposal_id) {
+            return None;
+        }
+
+        let proposal = Self::proposals(proposal_id);
+        let execution_block = proposal.execution_block()?;
+
+        let retry_block = Self::execution_attempts(proposal_id)
+            .pop()
+            .and_then(|attempt| {
+                proposal
+                    .parameters
+                    .execution_retry_policy
+                    .map(|retry_policy| attempt.attempted_at + retry_policy.retry_interval)
+            });
+
+        match retry_block {
+            Some(retry_block) if retry_block > execution_block => Some(retry_block),
+            _ => Some(execution_block),
+        }
+    }
+
+    /// Decodes the executable code of the proposal to the calls dispatched on the proposal
+    /// execution, in order. Nothing is dispatched: the execution preview of the runtime API
+    /// dispatches the calls and discards the storage changes. Returns the would-be execution
+    /// status if the code cannot be dispatched: it wasn't provided yet or cannot be decoded.
+    /// All batch items are decoded: nothing is executed if any item cannot be decoded.
+    pub fn decode_proposal_calls(
+        proposal_id: T::ProposalId,
+    ) -> Result<Vec<T::DispatchableCallCode>, ApprovedProposalStatus> {
+        if Self::is_waiting_for_deferred_code(proposal_id) {
+            return Err(ApprovedProposalStatus::failed_execution(
+                "ProposalCodeIsNotProvided",
+            ));
+        }
+
+        let proposal_code = Self::proposal_codes(proposal_id);
+
+        if !Self::is_batch_proposal(proposal_id) {
+            return T::DispatchableCallCode::decode(&mut &proposal_code[..])
+                .map(|call| vec![call])
+                .map_err(|error| ApprovedProposalStatus::failed_execution(error.what()));
+        }
+
+        let encoded_calls = Vec::<Vec<u8>>::decode(&mut &proposal_code[..])
+            .map_err(|error| ApprovedProposalStatus::failed_execution(error.what()))?;
+
+        encoded_calls
+            .iter()
+            .enumerate()
+            .map(|(index, encoded_call)| {
+                T::DispatchableCallCode::decode(&mut &encoded_call[..]).map_err(|error| {
+                    ApprovedProposalStatus::failed_batch_item_execution(index as u32, error.what())
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the execution status of the proposal failed on the dispatch of the decoded call
+    /// with the provided index. Batch proposals save the index of the failed item.
+    pub fn failed_call_execution_status(
+        proposal_id: T::ProposalId,
+        call_index: u32,
+        message: &'static str,
+    ) -> ApprovedProposalStatus {
+        if Self::is_batch_proposal(proposal_id) {
+            ApprovedProposalStatus::failed_batch_item_execution(call_index, message)
+        } else {
+            ApprovedProposalStatus::failed_execution(message)
+        }
+    }
+
     /// Resets voting results for active proposals.
+    /// Proposals waiting for the next council approval become active again with the restarted
+    /// voting period.
     /// Possible application includes new council elections.
     pub fn reset_active_proposals() {
         <ActiveProposalIds<T>>::enumerate().for_each(|(proposal_id, _)| {
             <Proposals<T>>::mutate(proposal_id, |proposal| {
                 proposal.reset_proposal();
                 <VoteExistsByProposalByVoter<T>>::remove_prefix(&proposal_id);
+                Self::remove_vote_records(proposal_id);
             });
         });
+
+        <PendingConstitutionalityProposalIds<T>>::enumerate().for_each(|(proposal_id, _)| {
+            Self::activate_pending_constitutionality_proposal(proposal_id);
+        });
     }
 }
 
@@ -589,6 +1379,165 @@ impl<T: Trait> Module<T> {
         <system::Module<T>>::block_number()
     }
 
+    // Ensures that the proposal exists, it is active and it was voted on by the voter.
+    // Returns the proposal and the previous vote.
+    fn ensure_active_proposal_was_voted(
+        proposal_id: T::ProposalId,
+        voter_id: MemberId<T>,
+    ) -> Result<(ProposalOf<T>, VoteKind), Error> {
+        ensure!(<Proposals<T>>::exists(proposal_id), Error::ProposalNotFound);
+        let proposal = Self::proposals(proposal_id);
+
+        ensure!(
+            matches!(proposal.status, ProposalStatus::Active { .. }),
+            Error::ProposalFinalized
+        );
+
+        ensure!(
+            <VoteExistsByProposalByVoter<T>>::exists(proposal_id, voter_id),
+            Error::NotVoted
+        );
+
+        let previous_vote = Self::vote_by_proposal_by_voter(proposal_id, voter_id);
+
+        Ok((proposal, previous_vote))
+    }
+
+    // Adds a new vote record or updates the existing vote record of the voter.
+    fn save_vote_record(proposal_id: T::ProposalId, voter_id: MemberId<T>, vote_kind: VoteKind) {
+        let vote_record = VoteRecord {
+            voter_id,
+            vote_kind,
+            voted_at: Self::current_block(),
+        };
+
+        <VotesByProposal<T>>::mutate(proposal_id, |vote_records| {
+            if let Some(existing_record) = vote_records
+                .iter_mut()
+                .find(|record| record.voter_id == voter_id)
+            {
+                *existing_record = vote_record;
+            } else {
+                vote_records.push(vote_record);
+
+                <ProposalIdsByVoter<T>>::mutate(voter_id, |proposal_ids| {
+                    proposal_ids.push(proposal_id)
+                });
+            }
+        });
+    }
+
+    // Removes the vote record of the voter if any.
+    fn remove_vote_record(proposal_id: T::ProposalId, voter_id: MemberId<T>) {
+        <VotesByProposal<T>>::mutate(proposal_id, |vote_records| {
+            vote_records.retain(|record| record.voter_id != voter_id);
+        });
+
+        Self::remove_voter_proposal_id(voter_id, proposal_id);
+    }
+
+    // Removes all vote records of the proposal and their voter index entries.
+    fn remove_vote_records(proposal_id: T::ProposalId) {
+        for vote_record in <VotesByProposal<T>>::take(proposal_id) {
+            Self::remove_voter_proposal_id(vote_record.voter_id, proposal_id);
+        }
+    }
+
+    // Removes the proposal id from the voter index.
+    fn remove_voter_proposal_id(voter_id: MemberId<T>, proposal_id: T::ProposalId) {
+        <ProposalIdsByVoter<T>>::mutate(voter_id, |proposal_ids| {
+            proposal_ids.retain(|id| *id != proposal_id);
+        });
+
+        if Self::proposal_ids_by_voter(voter_id).is_empty() {
+            <ProposalIdsByVoter<T>>::remove(voter_id);
+        }
+    }
+
+    // Schedules vote records removal for the finalized proposal according to the pruning policy.
+    fn schedule_vote_records_pruning(proposal_id: T::ProposalId) {
+        if let VoteRecordsPruningPolicy::PruneAfter(retention_period) = T::VotesPruningPolicy::get()
+        {
+            let prune_at = Self::current_block() + retention_period;
+
+            <VoteRecordsPruningSchedule<T>>::mutate(prune_at, |proposal_ids| {
+                proposal_ids.push(proposal_id)
+            });
+        }
+    }
+
+    // Removes vote records scheduled for pruning at the provided block.
+    fn prune_vote_records(now: T::BlockNumber) {
+        for proposal_id in <VoteRecordsPruningSchedule<T>>::take(now) {
+            Self::remove_vote_records(proposal_id);
+        }
+    }
+
+    // Removes the executable code of the proposal that cannot be executed anymore and schedules
+    // the proposal pruning according to the retention period.
+    fn complete_proposal(proposal_id: T::ProposalId) {
+        <DispatchableCallCode<T>>::remove(proposal_id);
+        <BatchProposals<T>>::remove(proposal_id);
+        <DeferredCodeProposals<T>>::remove(proposal_id);
+
+        let prune_at = Self::current_block() + T::FinalizedProposalRetentionPeriod::get();
+
+        <ProposalPruningSchedule<T>>::mutate(prune_at, |proposal_ids| {
+            proposal_ids.push(proposal_id)
+        });
+    }
+
+    // Replaces finalized proposals scheduled for pruning at the provided block with theirs
+    // outcomes. Outcome is saved only for proposals present in the finalized proposal ids index.
+    // Notifies the proposal finalization handler about the pruning.
+    fn prune_finalized_proposals(now: T::BlockNumber) {
+        let finalized_proposal_ids = Self::finalized_proposal_ids();
+
+        for proposal_id in <ProposalPruningSchedule<T>>::take(now) {
+            let proposal = Self::proposals(proposal_id);
+
+            <Proposals<T>>::remove(proposal_id);
+            <ExecutionAttempts<T>>::remove(proposal_id);
+            <VoteExistsByProposalByVoter<T>>::remove_prefix(&proposal_id);
+
+            if finalized_proposal_ids.contains(&proposal_id) {
+                <ProposalOutcomes<T>>::insert(proposal_id, proposal.outcome());
+            }
+
+            T::ProposalFinalizationHandler::proposal_pruned(proposal_id);
+        }
+    }
+
+    // Adds the proposal id to the finalized proposal ids index. Removes the oldest ids,
+    // theirs outcomes and veto records when the index exceeds its limit.
+    fn add_finalized_proposal_id(proposal_id: T::ProposalId) {
+        let mut finalized_proposal_ids = Self::finalized_proposal_ids();
+        finalized_proposal_ids.push(proposal_id);
+
+        let max_finalized_proposal_ids = T::MaxFinalizedProposalIds::get() as usize;
+        if finalized_proposal_ids.len() > max_finalized_proposal_ids {
+            let removed_ids_count = finalized_proposal_ids.len() - max_finalized_proposal_ids;
+
+            for removed_id in finalized_proposal_ids.drain(..removed_ids_count) {
+                <ProposalOutcomes<T>>::remove(removed_id);
+                <ProposalVetoes<T>>::remove(removed_id);
+            }
+        }
+
+        <FinalizedProposalIds<T>>::put(finalized_proposal_ids);
+    }
+
+    // Counts the ended voting round of the proposal and the voters voted in the round.
+    fn count_voting_round(proposal_id: T::ProposalId) {
+        VotingRoundsCount::mutate(|count| *count = count.saturating_add(1));
+
+        for record in Self::votes_by_proposal(proposal_id) {
+            <VotedRoundsCountByVoter<T>>::mutate(record.voter_id, |count| {
+                *count = count.saturating_add(1)
+            });
+        }
+    }
+
     // Enumerates through active proposals. Tally Voting results.
     // Returns proposals with finalized status and id
     fn get_finalized_proposals() -> Vec<FinalizedProposal<T>> {
@@ -601,10 +1550,7 @@ impl<T: Trait> Module<T> {
 
                 // Calculates votes, takes in account voting period expiration.
                 // If voting process is in progress, then decision status is None.
-                let decision_status = proposal.define_proposal_decision_status(
-                    T::TotalVotersCounter::total_voters_count(),
-                    Self::current_block(),
-                );
+                let decision_status = Self::define_proposal_decision_status(proposal_id, &proposal);
 
                 // map to FinalizedProposalData if decision for the proposal is made or return None
                 decision_status.map(|status| FinalizedProposalData {
@@ -617,6 +1563,46 @@ impl<T: Trait> Module<T> {
             .collect() // compose output vector
     }
 
+    // Calculates votes using the proposal voting mode. Stake weighted voting falls back to
+    // the head count when voters have no stake at all.
+    fn define_proposal_decision_status(
+        proposal_id: T::ProposalId,
+        proposal: &ProposalOf<T>,
+    ) -> Option<ProposalDecisionStatus> {
+        let total_voters_count = T::TotalVotersCounter::total_voters_count();
+        let total_voters_stake = T::VoterStakesProvider::total_voters_stake();
+
+        if proposal.parameters.voting_mode == VotingMode::StakeWeighted
+            && !total_voters_stake.is_zero()
+        {
+            let weighted_voting_results = Self::weighted_voting_results(proposal_id);
+
+            proposal.define_weighted_proposal_decision_status(
+                &weighted_voting_results,
+                total_voters_stake,
+                total_voters_count,
+                Self::current_block(),
+            )
+        } else {
+            proposal.define_proposal_decision_status(total_voters_count, Self::current_block())
+        }
+    }
+
+    // Calculates voting results weighted by the current voters stake.
+    fn weighted_voting_results(
+        proposal_id: T::ProposalId,
+    ) -> WeightedVotingResults<types::BalanceOf<T>> {
+        let mut weighted_voting_results = WeightedVotingResults::default();
+
+        for vote_record in Self::votes_by_proposal(proposal_id) {
+            let voter_stake = T::VoterStakesProvider::voter_stake(&vote_record.voter_id);
+
+            weighted_voting_results.add_vote(vote_record.vote_kind, voter_stake);
+        }
+
+        weighted_voting_results
+    }
+
     // Veto approved proposal during its grace period. Saves a new proposal status and removes
     // proposal id from the 'PendingExecutionProposalIds'
     fn veto_pending_execution_proposal(proposal_id: T::ProposalId, proposal: ProposalOf<T>) {
@@ -636,27 +1622,36 @@ impl<T: Trait> Module<T> {
                 ..proposal
             },
         );
+
+        Self::complete_proposal(proposal_id);
     }
 
-    // Executes approved proposal code
+    // Executes approved proposal code. Failed proposal stays pending execution if its
+    // retry policy allows another attempt.
     fn execute_proposal(approved_proposal: ApprovedProposal<T>) {
-        let proposal_code = Self::proposal_codes(approved_proposal.proposal_id);
+        let approved_proposal_status = Self::dispatch_proposal_calls(approved_proposal.proposal_id);
 
-        let proposal_code_result = T::DispatchableCallCode::decode(&mut &proposal_code[..]);
+        Self::record_execution_attempt(
+            approved_proposal.proposal_id,
+            approved_proposal_status.clone(),
+        );
 
-        let approved_proposal_status = match proposal_code_result {
-            Ok(proposal_code) => {
-                if let Err(error) = proposal_code.dispatch(T::Origin::from(RawOrigin::Root)) {
-                    ApprovedProposalStatus::failed_execution(
-                        error.into().message.unwrap_or("Dispatch error"),
-                    )
-                } else {
-                    ApprovedProposalStatus::Executed
-                }
+        if let ApprovedProposalStatus::ExecutionFailed { .. } = approved_proposal_status {
+            if Self::can_retry_execution(approved_proposal.proposal_id, &approved_proposal.proposal)
+            {
+                return;
             }
-            Err(error) => ApprovedProposalStatus::failed_execution(error.what()),
-        };
+        }
 
+        Self::complete_proposal_execution(approved_proposal, approved_proposal_status);
+    }
+
+    // Saves the final execution status of the approved proposal, removes it from the pending
+    // execution proposals and completes the proposal.
+    fn complete_proposal_execution(
+        approved_proposal: ApprovedProposal<T>,
+        approved_proposal_status: ApprovedProposalStatus,
+    ) {
         let proposal_execution_status = approved_proposal
             .finalisation_status_data
             .create_approved_proposal_status(approved_proposal_status);
@@ -671,25 +1666,97 @@ impl<T: Trait> Module<T> {
         ));
 
         <PendingExecutionProposalIds<T>>::remove(&approved_proposal.proposal_id);
+
+        Self::complete_proposal(approved_proposal.proposal_id);
+    }
+
+    // Saves the execution attempt result and fires an event.
+    fn record_execution_attempt(proposal_id: T::ProposalId, result: ApprovedProposalStatus) {
+        let execution_attempt = ExecutionAttempt {
+            attempted_at: Self::current_block(),
+            result: result.clone(),
+        };
+
+        <ExecutionAttempts<T>>::mutate(proposal_id, |attempts| attempts.push(execution_attempt));
+
+        Self::deposit_event(RawEvent::ProposalExecutionAttempted(proposal_id, result));
+    }
+
+    // Checks whether the next execution attempt fits the retry period of the proposal.
+    fn can_retry_execution(proposal_id: T::ProposalId, proposal: &ProposalOf<T>) -> bool {
+        if let Some(retry_policy) = proposal.parameters.execution_retry_policy {
+            if let Some(first_attempt) = Self::execution_attempts(proposal_id).first() {
+                let next_attempt_at = Self::current_block() + retry_policy.retry_interval;
+
+                return next_attempt_at <= first_attempt.attempted_at + retry_policy.retry_period;
+            }
+        }
+
+        false
+    }
+
+    // Checks whether the retry interval passed since the last failed execution attempt.
+    // Returns true if the proposal execution was never attempted.
+    fn is_execution_attempt_due(proposal_id: T::ProposalId, proposal: &ProposalOf<T>) -> bool {
+        let last_attempt = Self::execution_attempts(proposal_id).pop();
+
+        match (last_attempt, proposal.parameters.execution_retry_policy) {
+            (Some(attempt), Some(retry_policy)) => {
+                Self::current_block() >= attempt.attempted_at + retry_policy.retry_interval
+            }
+            _ => true,
+        }
+    }
+
+    // Dispatches the decoded proposal calls in order and stops on the first failed call.
+    // Returns the proposal execution status with the failed batch item index if any. Only the
+    // first batch item can fail (ensured by the proposal creator), so the failed batch made
+    // no changes.
+    fn dispatch_proposal_calls(proposal_id: T::ProposalId) -> ApprovedProposalStatus {
+        let calls = match Self::decode_proposal_calls(proposal_id) {
+            Ok(calls) => calls,
+            Err(approved_proposal_status) => return approved_proposal_status,
+        };
+
+        for (index, call) in calls.into_iter().enumerate() {
+            if let Err(error) = call.dispatch(T::Origin::from(RawOrigin::Root)) {
+                return Self::failed_call_execution_status(
+                    proposal_id,
+                    index as u32,
+                    error.into().message.unwrap_or("Dispatch error"),
+                );
+            }
+        }
+
+        ApprovedProposalStatus::Executed
     }
 
     // Performs all actions on proposal finalization:
-    // - clean active proposal cache
+    // - clean active and pending sponsorship proposal cache
     // - update proposal status fields (status, finalized_at)
-    // - add to pending execution proposal cache if approved
+    // - add to pending execution proposal cache if approved, complete the proposal otherwise
+    // - add to finalized proposal ids index
     // - slash and unstake proposal stake if stake exists
     // - decrease active proposal counter
+    // - notify the proposal finalization handler
     // - fire an event
     // It prints an error message in case of an attempt to finalize the non-active proposal.
     fn finalize_proposal(proposal_id: T::ProposalId, decision_status: ProposalDecisionStatus) {
         Self::decrease_active_proposal_counter();
         <ActiveProposalIds<T>>::remove(&proposal_id.clone());
+        <PendingConstitutionalityProposalIds<T>>::remove(&proposal_id);
+        <PendingSponsorshipProposalIds<T>>::remove(&proposal_id);
 
         let mut proposal = Self::proposals(proposal_id);
 
-        if let ProposalStatus::Active(active_stake) = proposal.status.clone() {
+        if let ProposalStatus::Active(active_stake)
+        | ProposalStatus::PendingConstitutionality(active_stake)
+        | ProposalStatus::PendingSponsorship(active_stake) = proposal.status.clone()
+        {
             if let ProposalDecisionStatus::Approved { .. } = decision_status {
                 <PendingExecutionProposalIds<T>>::insert(proposal_id, ());
+            } else {
+                Self::complete_proposal(proposal_id);
             }
 
             // deal with stakes if necessary
@@ -697,6 +1764,7 @@ impl<T: Trait> Module<T> {
                 Self::calculate_slash_balance(&decision_status, &proposal.parameters);
             let slash_and_unstake_result =
                 Self::slash_and_unstake(active_stake.clone(), slash_balance);
+            <StakeContributions<T>>::remove(&proposal_id);
 
             // create finalized proposal status with error if any
             let new_proposal_status = ProposalStatus::finalized(
@@ -709,6 +1777,11 @@ impl<T: Trait> Module<T> {
             proposal.status = new_proposal_status.clone();
             <Proposals<T>>::insert(proposal_id, proposal);
 
+            Self::schedule_vote_records_pruning(proposal_id);
+            Self::add_finalized_proposal_id(proposal_id);
+
+            T::ProposalFinalizationHandler::proposal_finalized(proposal_id);
+
             Self::deposit_event(RawEvent::ProposalStatusUpdated(
                 proposal_id,
                 new_proposal_status,
@@ -718,6 +1791,164 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    // Moves the proposal approved by the current council to the waiting list for the next
+    // council approval. Proposal remains counted as active.
+    fn wait_for_next_council_approval(proposal_id: T::ProposalId) {
+        let mut proposal = Self::proposals(proposal_id);
+
+        if let ProposalStatus::Active(active_stake) = proposal.status.clone() {
+            <ActiveProposalIds<T>>::remove(&proposal_id);
+            <PendingConstitutionalityProposalIds<T>>::insert(proposal_id, Self::current_block());
+
+            let new_proposal_status = ProposalStatus::PendingConstitutionality(active_stake);
+            proposal.status = new_proposal_status.clone();
+            <Proposals<T>>::insert(proposal_id, proposal);
+
+            Self::deposit_event(RawEvent::ProposalStatusUpdated(
+                proposal_id,
+                new_proposal_status,
+            ));
+        } else {
+            print("Broken invariant: proposal cannot be non-active on the council approval");
+        }
+    }
+
+    // Makes the fully sponsored proposal active and starts its voting period.
+    fn activate_sponsored_proposal(proposal_id: T::ProposalId) {
+        let mut proposal = Self::proposals(proposal_id);
+
+        if let ProposalStatus::PendingSponsorship(active_stake) = proposal.status.clone() {
+            <PendingSponsorshipProposalIds<T>>::remove(&proposal_id);
+            <ActiveProposalIds<T>>::insert(proposal_id, ());
+
+            let new_proposal_status = ProposalStatus::Active(active_stake);
+            proposal.status = new_proposal_status.clone();
+            proposal.activated_at = Self::current_block();
+            <Proposals<T>>::insert(proposal_id, proposal);
+
+            Self::deposit_event(RawEvent::ProposalStatusUpdated(
+                proposal_id,
+                new_proposal_status,
+            ));
+        } else {
+            print("Broken invariant: proposal should wait for sponsors");
+        }
+    }
+
+    // Enumerates proposals waiting for sponsors. Returns ids of proposals with expired
+    // voting period.
+    fn get_expired_sponsorship_proposal_ids() -> Vec<T::ProposalId> {
+        <PendingSponsorshipProposalIds<T>>::enumerate()
+            .filter_map(|(proposal_id, _)| {
+                let proposal = Self::proposals(proposal_id);
+
+                if proposal.is_voting_period_expired(Self::current_block()) {
+                    Some(proposal_id)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    // Enumerates proposals waiting for the next council approval. Returns proposals waiting
+    // longer than the MaxPendingConstitutionalityPeriod.
+    fn get_expired_pending_constitutionality_proposal_ids() -> Vec<T::ProposalId> {
+        <PendingConstitutionalityProposalIds<T>>::enumerate()
+            .filter_map(|(proposal_id, waiting_since)| {
+                let expires_at = waiting_since + T::MaxPendingConstitutionalityPeriod::get();
+
+                if Self::current_block() >= expires_at {
+                    Some(proposal_id)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    // Returns total stake balance contributed by the proposer and sponsors.
+    fn total_stake_contribution(proposal_id: T::ProposalId) -> BalanceOf<T> {
+        Self::stake_contributions(proposal_id)
+            .iter()
+            .fold(BalanceOf::<T>::zero(), |acc, contribution| {
+                acc + contribution.amount
+            })
+    }
+
+    // Splits the unstaked imbalance between the proposer and sponsors pro rata to their stake
+    // contributions: the last contributor gets the rounding remainder. Refunds the whole
+    // imbalance to the stake source account if contributions weren't recorded.
+    fn refund_stake_contributions(
+        proposal_id: T::ProposalId,
+        active_stake: ActiveStake<T::StakeId, T::AccountId>,
+        imbalance: NegativeImbalance<T>,
+    ) {
+        let mut contributions = Self::stake_contributions(proposal_id);
+        let total_contribution = Self::total_stake_contribution(proposal_id);
+
+        let last_refund_account_id = if total_contribution.is_zero() {
+            active_stake.source_account_id
+        } else if let Some(last_contribution) = contributions.pop() {
+            last_contribution.account_id
+        } else {
+            active_stake.source_account_id
+        };
+
+        let total_refund = imbalance.peek();
+        let mut remaining_imbalance = imbalance;
+
+        if !total_contribution.is_zero() {
+            for contribution in contributions {
+                let refund_balance =
+                    Perbill::from_rational_approximation(contribution.amount, total_contribution)
+                        * total_refund;
+
+                let (refund_imbalance, rest_imbalance) = remaining_imbalance.split(refund_balance);
+                remaining_imbalance = rest_imbalance;
+
+                Self::refund_stake(&contribution.account_id, refund_imbalance);
+            }
+        }
+
+        Self::refund_stake(&last_refund_account_id, remaining_imbalance);
+    }
+
+    // Refunds the imbalance to the account.
+    fn refund_stake(account_id: &T::AccountId, imbalance: NegativeImbalance<T>) {
+        let refunding_result = CurrencyOf::<T>::resolve_into_existing(account_id, imbalance);
+
+        if refunding_result.is_err() {
+            print("Broken invariant: cannot refund");
+        }
+    }
+
+    // Makes the proposal waiting for the next council approval active again. Clears previous
+    // voting results and restarts the voting period.
+    fn activate_pending_constitutionality_proposal(proposal_id: T::ProposalId) {
+        let mut proposal = Self::proposals(proposal_id);
+
+        if let ProposalStatus::PendingConstitutionality(active_stake) = proposal.status.clone() {
+            <PendingConstitutionalityProposalIds<T>>::remove(&proposal_id);
+            <ActiveProposalIds<T>>::insert(proposal_id, ());
+            <VoteExistsByProposalByVoter<T>>::remove_prefix(&proposal_id);
+            Self::remove_vote_records(proposal_id);
+
+            let new_proposal_status = ProposalStatus::Active(active_stake);
+            proposal.status = new_proposal_status.clone();
+            proposal.voting_results = VotingResults::default();
+            proposal.activated_at = Self::current_block();
+            <Proposals<T>>::insert(proposal_id, proposal);
+
+            Self::deposit_event(RawEvent::ProposalStatusUpdated(
+                proposal_id,
+                new_proposal_status,
+            ));
+        } else {
+            print("Broken invariant: proposal should wait for the next council approval");
+        }
+    }
+
     // Slashes the stake and perform unstake only in case of existing stake
     fn slash_and_unstake(
         current_stake_data: Option<ActiveStake<T::StakeId, T::AccountId>>,
@@ -756,13 +1987,54 @@ impl<T: Trait> Module<T> {
         }
     }
 
-    // Enumerates approved proposals and checks their grace period expiration
+    // Returns whether the proposal executable code is deferred and wasn't provided yet.
+    fn is_waiting_for_deferred_code(proposal_id: T::ProposalId) -> bool {
+        Self::is_deferred_code_proposal(proposal_id) && Self::proposal_codes(proposal_id).is_empty()
+    }
+
+    // Enumerates approved proposals waiting for the deferred code. Returns proposals with
+    // the expired code timeout (counted from the execution block: the grace period end or
+    // the exact execution block if it's later).
+    fn get_approved_proposals_with_expired_code_timeout() -> Vec<ApprovedProposal<T>> {
+        <PendingExecutionProposalIds<T>>::enumerate()
+            .filter_map(|(proposal_id, _)| {
+                if !Self::is_waiting_for_deferred_code(proposal_id) {
+                    return None;
+                }
+
+                let proposal = Self::proposals(proposal_id);
+
+                if let (ProposalStatus::Finalized(finalisation_data), Some(execution_block)) =
+                    (proposal.status.clone(), proposal.execution_block())
+                {
+                    let timeout_expires_at = execution_block + T::DeferredCodeTimeout::get();
+
+                    if Self::current_block() >= timeout_expires_at {
+                        return Some(ApprovedProposalData {
+                            proposal_id,
+                            proposal,
+                            finalisation_status_data: finalisation_data,
+                        });
+                    }
+                }
+
+                None
+            })
+            .collect()
+    }
+
+    // Enumerates approved proposals and checks their grace period expiration,
+    // the exact execution block if it was set and the retry interval for the failed proposals.
+    // Skips proposals waiting for the deferred code.
     fn get_approved_proposal_with_expired_grace_period() -> Vec<ApprovedProposal<T>> {
         <PendingExecutionProposalIds<T>>::enumerate()
             .filter_map(|(proposal_id, _)| {
                 let proposal = Self::proposals(proposal_id);
 
-                if proposal.is_grace_period_expired(Self::current_block()) {
+                if proposal.is_ready_for_execution(Self::current_block())
+                    && Self::is_execution_attempt_due(proposal_id, &proposal)
+                    && !Self::is_waiting_for_deferred_code(proposal_id)
+                {
                     // this should be true, because it was tested inside is_grace_period_expired()
                     if let ProposalStatus::Finalized(finalisation_data) = proposal.status.clone() {
                         Some(ApprovedProposalData {
@@ -0,0 +1,218 @@
+//! Migration of the proposals saved by the previous runtime version.
+//! The previous proposal layout lacks the activation block, the exact execution block,
+//! the council approvals counter and the amendments history. Its parameters lack the voting
+//! mode, the constitutionality and the execution retry policy.
+//! The previous proposal status lacks the pending constitutionality status and the failed batch
+//! item of the failed execution.
+
+use codec::{Decode, Encode};
+use rstd::prelude::*;
+use srml_support::storage::unhashed;
+use srml_support::StorageMap;
+
+use crate::{
+    ActiveStake, ApprovedProposalStatus, FinalizationData, PendingExecutionProposalIds, Proposal,
+    ProposalDecisionStatus, ProposalParameters, ProposalStatus, Proposals, Trait, VotingMode,
+    VotingResults,
+};
+
+// Proposal parameters of the previous runtime version.
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub(crate) struct LegacyProposalParameters<BlockNumber, Balance> {
+    pub voting_period: BlockNumber,
+    pub grace_period: BlockNumber,
+    pub approval_quorum_percentage: u32,
+    pub approval_threshold_percentage: u32,
+    pub slashing_quorum_percentage: u32,
+    pub slashing_threshold_percentage: u32,
+    pub required_stake: Option<Balance>,
+}
+
+// Status of the approved proposal of the previous runtime version.
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub(crate) enum LegacyApprovedProposalStatus {
+    PendingExecution,
+    Executed,
+    ExecutionFailed { error: Vec<u8> },
+}
+
+// Proposal decision status of the previous runtime version.
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub(crate) enum LegacyProposalDecisionStatus {
+    Canceled,
+    Vetoed,
+    Rejected,
+    Slashed,
+    Expired,
+    Approved(LegacyApprovedProposalStatus),
+}
+
+// Finalization data of the previous runtime version.
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub(crate) struct LegacyFinalizationData<BlockNumber, StakeId, AccountId> {
+    pub proposal_status: LegacyProposalDecisionStatus,
+    pub finalized_at: BlockNumber,
+    pub encoded_unstaking_error_due_to_broken_runtime: Option<Vec<u8>>,
+    pub stake_data_after_unstaking_error: Option<ActiveStake<StakeId, AccountId>>,
+}
+
+// Proposal status of the previous runtime version.
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub(crate) enum LegacyProposalStatus<BlockNumber, StakeId, AccountId> {
+    Active(Option<ActiveStake<StakeId, AccountId>>),
+    Finalized(LegacyFinalizationData<BlockNumber, StakeId, AccountId>),
+}
+
+// Proposal of the previous runtime version.
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub(crate) struct LegacyProposal<BlockNumber, ProposerId, Balance, StakeId, AccountId> {
+    pub parameters: LegacyProposalParameters<BlockNumber, Balance>,
+    pub proposer_id: ProposerId,
+    pub title: Vec<u8>,
+    pub description: Vec<u8>,
+    pub created_at: BlockNumber,
+    pub status: LegacyProposalStatus<BlockNumber, StakeId, AccountId>,
+    pub voting_results: VotingResults,
+}
+
+impl<BlockNumber, Balance> From<LegacyProposalParameters<BlockNumber, Balance>>
+    for ProposalParameters<BlockNumber, Balance>
+{
+    fn from(parameters: LegacyProposalParameters<BlockNumber, Balance>) -> Self {
+        ProposalParameters {
+            voting_period: parameters.voting_period,
+            grace_period: parameters.grace_period,
+            approval_quorum_percentage: parameters.approval_quorum_percentage,
+            approval_threshold_percentage: parameters.approval_threshold_percentage,
+            slashing_quorum_percentage: parameters.slashing_quorum_percentage,
+            slashing_threshold_percentage: parameters.slashing_threshold_percentage,
+            required_stake: parameters.required_stake,
+            voting_mode: VotingMode::Headcount,
+            constitutionality: 1,
+            execution_retry_policy: None,
+        }
+    }
+}
+
+impl From<LegacyApprovedProposalStatus> for ApprovedProposalStatus {
+    fn from(status: LegacyApprovedProposalStatus) -> Self {
+        match status {
+            LegacyApprovedProposalStatus::PendingExecution => {
+                ApprovedProposalStatus::PendingExecution
+            }
+            LegacyApprovedProposalStatus::Executed => ApprovedProposalStatus::Executed,
+            LegacyApprovedProposalStatus::ExecutionFailed { error } => {
+                ApprovedProposalStatus::ExecutionFailed {
+                    error,
+                    failed_batch_item: None,
+                }
+            }
+        }
+    }
+}
+
+impl From<LegacyProposalDecisionStatus> for ProposalDecisionStatus {
+    fn from(status: LegacyProposalDecisionStatus) -> Self {
+        match status {
+            LegacyProposalDecisionStatus::Canceled => ProposalDecisionStatus::Canceled,
+            LegacyProposalDecisionStatus::Vetoed => ProposalDecisionStatus::Vetoed,
+            LegacyProposalDecisionStatus::Rejected => ProposalDecisionStatus::Rejected,
+            LegacyProposalDecisionStatus::Slashed => ProposalDecisionStatus::Slashed,
+            LegacyProposalDecisionStatus::Expired => ProposalDecisionStatus::Expired,
+            LegacyProposalDecisionStatus::Approved(approved_status) => {
+                ProposalDecisionStatus::Approved(approved_status.into())
+            }
+        }
+    }
+}
+
+impl<BlockNumber, StakeId, AccountId> From<LegacyProposalStatus<BlockNumber, StakeId, AccountId>>
+    for ProposalStatus<BlockNumber, StakeId, AccountId>
+{
+    fn from(status: LegacyProposalStatus<BlockNumber, StakeId, AccountId>) -> Self {
+        match status {
+            LegacyProposalStatus::Active(active_stake) => ProposalStatus::Active(active_stake),
+            LegacyProposalStatus::Finalized(finalization_data) => {
+                ProposalStatus::Finalized(FinalizationData {
+                    proposal_status: finalization_data.proposal_status.into(),
+                    finalized_at: finalization_data.finalized_at,
+                    encoded_unstaking_error_due_to_broken_runtime: finalization_data
+                        .encoded_unstaking_error_due_to_broken_runtime,
+                    stake_data_after_unstaking_error: finalization_data
+                        .stake_data_after_unstaking_error,
+                })
+            }
+        }
+    }
+}
+
+impl<BlockNumber: Copy, ProposerId, Balance, StakeId, AccountId>
+    From<LegacyProposal<BlockNumber, ProposerId, Balance, StakeId, AccountId>>
+    for Proposal<BlockNumber, ProposerId, Balance, StakeId, AccountId>
+{
+    // New parameters get the values of the previous runtime behaviour: headcount voting,
+    // single council approval, execution after the grace period and no execution retries.
+    fn from(
+        proposal: LegacyProposal<BlockNumber, ProposerId, Balance, StakeId, AccountId>,
+    ) -> Self {
+        let status: ProposalStatus<BlockNumber, StakeId, AccountId> = proposal.status.into();
+
+        let council_approvals = match status {
+            ProposalStatus::Finalized(FinalizationData {
+                proposal_status: ProposalDecisionStatus::Approved(_),
+                ..
+            }) => 1,
+            _ => 0,
+        };
+
+        Proposal {
+            parameters: proposal.parameters.into(),
+            proposer_id: proposal.proposer_id,
+            title: proposal.title,
+            description: proposal.description,
+            created_at: proposal.created_at,
+            activated_at: proposal.created_at,
+            status,
+            voting_results: proposal.voting_results,
+            exactly_execute_at: None,
+            council_approvals,
+            amendments: Vec::new(),
+        }
+    }
+}
+
+// Simplification of the 'LegacyProposal' type
+type LegacyProposalOf<T> = LegacyProposal<
+    <T as system::Trait>::BlockNumber,
+    crate::MemberId<T>,
+    crate::BalanceOf<T>,
+    <T as stake::Trait>::StakeId,
+    <T as system::Trait>::AccountId,
+>;
+
+impl<T: Trait> crate::Module<T> {
+    /// Migrates the proposals saved by the previous runtime version to the current layout.
+    /// Finalized proposals which cannot be executed anymore are added to the finalized proposal
+    /// ids index and scheduled for pruning.
+    /// Should be called on the migration to the new runtime version only once.
+    pub fn migrate_proposals() {
+        for id in 1..=Self::proposal_count() {
+            let proposal_id = T::ProposalId::from(id);
+            let key = <Proposals<T>>::hashed_key_for(proposal_id);
+
+            if let Some(legacy_proposal) = unhashed::get::<LegacyProposalOf<T>>(&key) {
+                let proposal: crate::ProposalOf<T> = legacy_proposal.into();
+
+                let is_completed = matches!(proposal.status, ProposalStatus::Finalized(_))
+                    && !<PendingExecutionProposalIds<T>>::exists(proposal_id);
+
+                <Proposals<T>>::insert(proposal_id, proposal);
+
+                if is_completed {
+                    Self::add_finalized_proposal_id(proposal_id);
+                    Self::complete_proposal(proposal_id);
+                }
+            }
+        }
+    }
+}
@@ -17,6 +17,8 @@ pub use sr_primitives::{
 use srml_support::{impl_outer_event, impl_outer_origin, parameter_types};
 pub use system;
 
+use crate::VoteRecordsPruningPolicy;
+
 mod balance_manager;
 pub(crate) mod proposals;
 mod stakes;
@@ -91,7 +93,16 @@ parameter_types! {
     pub const RejectionFee: u64 = 3;
     pub const TitleMaxLength: u32 = 100;
     pub const DescriptionMaxLength: u32 = 10000;
+    pub const VetoRationaleMaxLength: u32 = 100;
     pub const MaxActiveProposalLimit: u32 = 100;
+    pub const VotesPruningPolicy: VoteRecordsPruningPolicy<u64> =
+        VoteRecordsPruningPolicy::PruneAfter(10);
+    pub const MinProposerStakePercentage: u32 = 70;
+    pub const FinalizedProposalRetentionPeriod: u64 = 20;
+    pub const MaxFinalizedProposalIds: u32 = 5;
+    pub const DeferredCodeTimeout: u64 = 5;
+    pub const MaxPendingConstitutionalityPeriod: u64 = 10;
+    pub const MaxProposalAmendments: u32 = 2;
 }
 
 impl membership::members::Trait for Test {
@@ -107,15 +118,26 @@ impl crate::Trait for Test {
     type Event = TestEvent;
     type ProposerOriginValidator = ();
     type VoterOriginValidator = ();
+    type VetoOriginValidator = TestVetoOriginValidator;
     type TotalVotersCounter = ();
+    type VoterStakesProvider = ();
     type ProposalId = u32;
     type StakeHandlerProvider = stakes::TestStakeHandlerProvider;
     type CancellationFee = CancellationFee;
     type RejectionFee = RejectionFee;
     type TitleMaxLength = TitleMaxLength;
     type DescriptionMaxLength = DescriptionMaxLength;
+    type VetoRationaleMaxLength = VetoRationaleMaxLength;
     type MaxActiveProposalLimit = MaxActiveProposalLimit;
     type DispatchableCallCode = proposals::Call<Test>;
+    type VotesPruningPolicy = VotesPruningPolicy;
+    type ProposalFinalizationHandler = ();
+    type MinProposerStakePercentage = MinProposerStakePercentage;
+    type FinalizedProposalRetentionPeriod = FinalizedProposalRetentionPeriod;
+    type MaxFinalizedProposalIds = MaxFinalizedProposalIds;
+    type DeferredCodeTimeout = DeferredCodeTimeout;
+    type MaxPendingConstitutionalityPeriod = MaxPendingConstitutionalityPeriod;
+    type MaxProposalAmendments = MaxProposalAmendments;
 }
 
 impl Default for proposals::Call<Test> {
@@ -132,6 +154,21 @@ impl common::origin_validator::ActorOriginValidator<Origin, u64, u64> for () {
     }
 }
 
+// Allows vetoes only for the member 1 with the account 1.
+pub struct TestVetoOriginValidator;
+
+impl common::origin_validator::ActorOriginValidator<Origin, u64, u64> for TestVetoOriginValidator {
+    fn ensure_actor_origin(origin: Origin, vetoer_id: u64) -> Result<u64, &'static str> {
+        let signed_account_id = system::ensure_signed(origin)?;
+
+        if signed_account_id == 1 && vetoer_id == 1 {
+            Ok(signed_account_id)
+        } else {
+            Err("Veto validation failed")
+        }
+    }
+}
+
 // If changing count is required, we can upgrade the implementation as shown here:
 // https://substrate.dev/recipes/3-entrees/testing/externalities.html
 impl crate::VotersParameters for () {
@@ -140,6 +177,17 @@ impl crate::VotersParameters for () {
     }
 }
 
+// Voter stake is proportional to the voter id: total stake of four voters is 100.
+impl crate::VoterStakesProvider<u64, u64> for () {
+    fn voter_stake(voter_id: &u64) -> u64 {
+        voter_id * 10
+    }
+
+    fn total_voters_stake() -> u64 {
+        100
+    }
+}
+
 parameter_types! {
     pub const BlockHashCount: u64 = 250;
     pub const MaximumBlockWeight: u32 = 1024;
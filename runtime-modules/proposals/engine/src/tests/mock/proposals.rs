@@ -2,13 +2,22 @@
 
 use rstd::prelude::*;
 use rstd::vec::Vec;
-use srml_support::decl_module;
+use srml_support::{decl_module, decl_storage, StorageValue};
 pub trait Trait: system::Trait {}
 
+decl_storage! {
+    trait Store for Module<T: Trait> as ProposalsMock {
+        /// Counts working extrinsic calls
+        pub DummyProposalCalls get(fn dummy_proposal_calls): u32;
+    }
+}
+
 decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
         /// Working extrinsic test
-        pub fn dummy_proposal(_origin, _title: Vec<u8>, _description: Vec<u8>) {}
+        pub fn dummy_proposal(_origin, _title: Vec<u8>, _description: Vec<u8>) {
+            DummyProposalCalls::mutate(|calls| *calls += 1);
+        }
 
         /// Broken extrinsic test
         pub fn faulty_proposal(_origin, _title: Vec<u8>, _description: Vec<u8>,) {
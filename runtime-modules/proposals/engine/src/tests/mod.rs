@@ -42,6 +42,36 @@ impl ProposalParametersFixture {
         }
     }
 
+    fn with_execution_retry_policy(&self, retry_interval: u64, retry_period: u64) -> Self {
+        ProposalParametersFixture {
+            parameters: ProposalParameters {
+                execution_retry_policy: Some(ExecutionRetryPolicy {
+                    retry_interval,
+                    retry_period,
+                }),
+                ..self.parameters
+            },
+        }
+    }
+
+    fn with_constitutionality(&self, constitutionality: u32) -> Self {
+        ProposalParametersFixture {
+            parameters: ProposalParameters {
+                constitutionality,
+                ..self.parameters
+            },
+        }
+    }
+
+    fn with_voting_mode(&self, voting_mode: VotingMode) -> Self {
+        ProposalParametersFixture {
+            parameters: ProposalParameters {
+                voting_mode,
+                ..self.parameters
+            },
+        }
+    }
+
     fn params(&self) -> ProposalParameters<u64, u64> {
         self.parameters.clone()
     }
@@ -58,6 +88,9 @@ impl Default for ProposalParametersFixture {
                 slashing_threshold_percentage: 60,
                 grace_period: 0,
                 required_stake: None,
+                voting_mode: VotingMode::Headcount,
+                constitutionality: 1,
+                execution_retry_policy: None,
             },
         }
     }
@@ -72,6 +105,7 @@ struct DummyProposalFixture {
     title: Vec<u8>,
     description: Vec<u8>,
     stake_balance: Option<BalanceOf<Test>>,
+    exactly_execute_at: Option<u64>,
 }
 
 impl Default for DummyProposalFixture {
@@ -90,6 +124,9 @@ impl Default for DummyProposalFixture {
                 slashing_threshold_percentage: 60,
                 grace_period: 0,
                 required_stake: None,
+                voting_mode: VotingMode::Headcount,
+                constitutionality: 1,
+                execution_retry_policy: None,
             },
             account_id: 1,
             proposer_id: 1,
@@ -97,6 +134,7 @@ impl Default for DummyProposalFixture {
             title,
             description,
             stake_balance: None,
+            exactly_execute_at: None,
         }
     }
 }
@@ -125,6 +163,13 @@ impl DummyProposalFixture {
         }
     }
 
+    fn with_exactly_execute_at(self, exactly_execute_at: u64) -> Self {
+        DummyProposalFixture {
+            exactly_execute_at: Some(exactly_execute_at),
+            ..self
+        }
+    }
+
     fn with_proposal_code(self, proposal_code: Vec<u8>) -> Self {
         DummyProposalFixture {
             proposal_code,
@@ -141,6 +186,42 @@ impl DummyProposalFixture {
             self.description,
             self.stake_balance,
             self.proposal_code,
+            self.exactly_execute_at,
+        );
+        assert_eq!(proposal_id_result, result);
+
+        proposal_id_result.ok()
+    }
+
+    fn create_deferred_code_proposal_and_assert(self, result: Result<u32, Error>) -> Option<u32> {
+        let proposal_id_result = ProposalsEngine::create_deferred_code_proposal(
+            self.account_id,
+            self.proposer_id,
+            self.parameters,
+            self.title,
+            self.description,
+            self.stake_balance,
+            self.exactly_execute_at,
+        );
+        assert_eq!(proposal_id_result, result);
+
+        proposal_id_result.ok()
+    }
+
+    fn create_batch_proposal_and_assert(
+        self,
+        calls: Vec<Vec<u8>>,
+        result: Result<u32, Error>,
+    ) -> Option<u32> {
+        let proposal_id_result = ProposalsEngine::create_batch_proposal(
+            self.account_id,
+            self.proposer_id,
+            self.parameters,
+            self.title,
+            self.description,
+            self.stake_balance,
+            calls,
+            self.exactly_execute_at,
         );
         assert_eq!(proposal_id_result, result);
 
@@ -187,14 +268,18 @@ impl CancelProposalFixture {
 }
 struct VetoProposalFixture {
     origin: RawOrigin<u64>,
+    vetoer_id: u64,
     proposal_id: u32,
+    rationale: Vec<u8>,
 }
 
 impl VetoProposalFixture {
     fn new(proposal_id: u32) -> Self {
         VetoProposalFixture {
             proposal_id,
-            origin: RawOrigin::Root,
+            origin: RawOrigin::Signed(1),
+            vetoer_id: 1,
+            rationale: b"rationale".to_vec(),
         }
     }
 
@@ -202,9 +287,48 @@ impl VetoProposalFixture {
         VetoProposalFixture { origin, ..self }
     }
 
+    fn with_rationale(self, rationale: Vec<u8>) -> Self {
+        VetoProposalFixture { rationale, ..self }
+    }
+
     fn veto_and_assert(self, expected_result: DispatchResult<Error>) {
         assert_eq!(
-            ProposalsEngine::veto_proposal(self.origin.into(), self.proposal_id,),
+            ProposalsEngine::veto_proposal(
+                self.origin.into(),
+                self.vetoer_id,
+                self.proposal_id,
+                self.rationale
+            ),
+            expected_result
+        );
+    }
+}
+
+struct SponsorProposalFixture {
+    origin: RawOrigin<u64>,
+    sponsor_id: u64,
+    proposal_id: u32,
+    stake_balance: u64,
+}
+
+impl SponsorProposalFixture {
+    fn new(proposal_id: u32, stake_balance: u64) -> Self {
+        SponsorProposalFixture {
+            origin: RawOrigin::Signed(2),
+            sponsor_id: 2,
+            proposal_id,
+            stake_balance,
+        }
+    }
+
+    fn sponsor_and_assert(self, expected_result: DispatchResult<Error>) {
+        assert_eq!(
+            ProposalsEngine::sponsor_proposal(
+                self.origin.into(),
+                self.sponsor_id,
+                self.proposal_id,
+                self.stake_balance
+            ),
             expected_result
         );
     }
@@ -251,7 +375,7 @@ impl VoteGenerator {
 
 struct EventFixture;
 impl EventFixture {
-    fn assert_events(expected_raw_events: Vec<RawEvent<u32, u64, u64, u64, u64>>) {
+    fn assert_events(expected_raw_events: Vec<RawEvent<u32, u64, u64, u64, u64, u64>>) {
         let expected_events = expected_raw_events
             .iter()
             .map(|ev| EventRecord {
@@ -348,6 +472,10 @@ fn proposal_execution_succeeds() {
                     rejections: 0,
                     slashes: 0,
                 },
+                exactly_execute_at: None,
+                activated_at: 1,
+                council_approvals: 1,
+                amendments: Vec::new(),
             }
         );
 
@@ -400,470 +528,805 @@ fn proposal_execution_failed() {
                     rejections: 0,
                     slashes: 0,
                 },
+                exactly_execute_at: None,
+                activated_at: 1,
+                council_approvals: 1,
+                amendments: Vec::new(),
             }
         )
     });
 }
 
 #[test]
-fn voting_results_calculation_succeeds() {
+fn failed_proposal_execution_retried_until_retry_period_ends() {
     initial_test_ext().execute_with(|| {
-        let parameters = ProposalParameters {
-            voting_period: 3,
-            approval_quorum_percentage: 50,
-            approval_threshold_percentage: 50,
-            slashing_quorum_percentage: 60,
-            slashing_threshold_percentage: 60,
-            grace_period: 0,
-            required_stake: None,
-        };
-        let dummy_proposal = DummyProposalFixture::default().with_parameters(parameters);
+        let parameters_fixture =
+            ProposalParametersFixture::default().with_execution_retry_policy(2, 4);
+
+        let faulty_proposal = mock::proposals::Call::<Test>::faulty_proposal(
+            b"title".to_vec(),
+            b"description".to_vec(),
+        );
+
+        let dummy_proposal = DummyProposalFixture::default()
+            .with_parameters(parameters_fixture.params())
+            .with_proposal_code(faulty_proposal.encode());
+
         let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
         let mut vote_generator = VoteGenerator::new(proposal_id);
         vote_generator.vote_and_assert_ok(VoteKind::Approve);
         vote_generator.vote_and_assert_ok(VoteKind::Approve);
-        vote_generator.vote_and_assert_ok(VoteKind::Reject);
-        vote_generator.vote_and_assert_ok(VoteKind::Abstain);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
 
-        run_to_block_and_finalize(2);
+        run_to_block_and_finalize(4);
 
+        // first attempt at the block 1, retry at the block 3
         let proposal = <crate::Proposals<Test>>::get(proposal_id);
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::approved(ApprovedProposalStatus::PendingExecution, 1)
+        );
+        assert!(<PendingExecutionProposalIds<Test>>::enumerate()
+            .find(|(x, _)| *x == proposal_id)
+            .is_some());
+
+        run_to_block_and_finalize(10);
 
+        // last retry at the block 5
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
         assert_eq!(
-            proposal.voting_results,
-            VotingResults {
-                abstentions: 1,
-                approvals: 2,
-                rejections: 1,
-                slashes: 0,
-            }
-        )
+            proposal.status,
+            ProposalStatus::approved(
+                ApprovedProposalStatus::failed_execution("ExecutionFailed"),
+                1
+            )
+        );
+        assert!(<PendingExecutionProposalIds<Test>>::enumerate()
+            .find(|(x, _)| *x == proposal_id)
+            .is_none());
+
+        let failed_attempt = |attempted_at| ExecutionAttempt {
+            attempted_at,
+            result: ApprovedProposalStatus::failed_execution("ExecutionFailed"),
+        };
+        assert_eq!(
+            ProposalsEngine::execution_attempts(proposal_id),
+            vec![failed_attempt(1), failed_attempt(3), failed_attempt(5)]
+        );
     });
 }
 
 #[test]
-fn rejected_voting_results_and_remove_proposal_id_from_active_succeeds() {
+fn failed_batch_proposal_execution_retry_executes_the_whole_batch() {
     initial_test_ext().execute_with(|| {
-        // internal active proposal counter check
-        assert_eq!(<ActiveProposalCount>::get(), 0);
+        let parameters_fixture =
+            ProposalParametersFixture::default().with_execution_retry_policy(2, 4);
 
-        let dummy_proposal = DummyProposalFixture::default();
-        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+        let dummy_proposal =
+            mock::proposals::Call::<Test>::dummy_proposal(b"title".to_vec(), b"body".to_vec());
+        let faulty_proposal =
+            mock::proposals::Call::<Test>::faulty_proposal(b"title".to_vec(), b"body".to_vec());
+
+        let proposal_id = DummyProposalFixture::default()
+            .with_parameters(parameters_fixture.params())
+            .create_batch_proposal_and_assert(
+                vec![faulty_proposal.encode(), dummy_proposal.encode()],
+                Ok(1),
+            )
+            .unwrap();
 
         let mut vote_generator = VoteGenerator::new(proposal_id);
-        vote_generator.vote_and_assert_ok(VoteKind::Reject);
-        vote_generator.vote_and_assert_ok(VoteKind::Reject);
-        vote_generator.vote_and_assert_ok(VoteKind::Abstain);
-        vote_generator.vote_and_assert_ok(VoteKind::Abstain);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
 
-        assert!(<ActiveProposalIds<Test>>::exists(proposal_id));
+        run_to_block_and_finalize(10);
 
-        // internal active proposal counter check
-        assert_eq!(<ActiveProposalCount>::get(), 1);
+        let failed_attempt = |attempted_at| ExecutionAttempt {
+            attempted_at,
+            result: ApprovedProposalStatus::failed_batch_item_execution(0, "ExecutionFailed"),
+        };
+        assert_eq!(
+            ProposalsEngine::execution_attempts(proposal_id),
+            vec![failed_attempt(1), failed_attempt(3), failed_attempt(5)]
+        );
+
+        // every attempt stopped on the first item: the next item was never executed
+        assert_eq!(mock::proposals::Module::<Test>::dummy_proposal_calls(), 0);
+    });
+}
+
+#[test]
+fn next_execution_block_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let parameters_fixture =
+            ProposalParametersFixture::default().with_execution_retry_policy(2, 4);
+
+        let faulty_proposal = mock::proposals::Call::<Test>::faulty_proposal(
+            b"title".to_vec(),
+            b"description".to_vec(),
+        );
+
+        let dummy_proposal = DummyProposalFixture::default()
+            .with_parameters(parameters_fixture.params())
+            .with_proposal_code(faulty_proposal.encode());
+
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        assert_eq!(ProposalsEngine::next_execution_block(proposal_id), None);
 
         run_to_block_and_finalize(2);
 
-        let proposal = <Proposals<Test>>::get(proposal_id);
+        // first attempt at the block 1, retry at the block 3
+        assert_eq!(ProposalsEngine::next_execution_block(proposal_id), Some(3));
+
+        run_to_block_and_finalize(10);
+
+        assert_eq!(ProposalsEngine::next_execution_block(proposal_id), None);
+    });
+}
+
+#[test]
+fn decode_proposal_calls_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal =
+            mock::proposals::Call::<Test>::dummy_proposal(b"title".to_vec(), b"body".to_vec());
+        let faulty_proposal =
+            mock::proposals::Call::<Test>::faulty_proposal(b"title".to_vec(), b"body".to_vec());
+
+        let proposal_id = DummyProposalFixture::default()
+            .with_proposal_code(faulty_proposal.encode())
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
 
         assert_eq!(
-            proposal.voting_results,
-            VotingResults {
-                abstentions: 2,
-                approvals: 0,
-                rejections: 2,
-                slashes: 0,
-            }
+            ProposalsEngine::decode_proposal_calls(proposal_id),
+            Ok(vec![faulty_proposal.clone()])
         );
 
+        let batch_proposal_id = DummyProposalFixture::default()
+            .create_batch_proposal_and_assert(
+                vec![faulty_proposal.encode(), dummy_proposal.encode()],
+                Ok(2),
+            )
+            .unwrap();
+
         assert_eq!(
-            proposal.status,
-            ProposalStatus::finalized_successfully(ProposalDecisionStatus::Rejected, 1),
+            ProposalsEngine::decode_proposal_calls(batch_proposal_id),
+            Ok(vec![faulty_proposal, dummy_proposal.clone()])
         );
-        assert!(!<ActiveProposalIds<Test>>::exists(proposal_id));
 
-        // internal active proposal counter check
-        assert_eq!(<ActiveProposalCount>::get(), 0);
+        let invalid_proposal_id = DummyProposalFixture::default()
+            .with_proposal_code(b"invalid code".to_vec())
+            .create_proposal_and_assert(Ok(3))
+            .unwrap();
+
+        assert!(matches!(
+            ProposalsEngine::decode_proposal_calls(invalid_proposal_id),
+            Err(ApprovedProposalStatus::ExecutionFailed {
+                failed_batch_item: None,
+                ..
+            })
+        ));
+
+        let invalid_batch_proposal_id = DummyProposalFixture::default()
+            .create_batch_proposal_and_assert(
+                vec![dummy_proposal.encode(), b"invalid code".to_vec()],
+                Ok(4),
+            )
+            .unwrap();
+
+        assert!(matches!(
+            ProposalsEngine::decode_proposal_calls(invalid_batch_proposal_id),
+            Err(ApprovedProposalStatus::ExecutionFailed {
+                failed_batch_item: Some(1),
+                ..
+            })
+        ));
+
+        let deferred_code_proposal_id = DummyProposalFixture::default()
+            .create_deferred_code_proposal_and_assert(Ok(5))
+            .unwrap();
+
+        assert_eq!(
+            ProposalsEngine::decode_proposal_calls(deferred_code_proposal_id),
+            Err(ApprovedProposalStatus::failed_execution(
+                "ProposalCodeIsNotProvided"
+            ))
+        );
+
+        // nothing was dispatched
+        assert_eq!(mock::proposals::Module::<Test>::dummy_proposal_calls(), 0);
     });
 }
 
 #[test]
-fn create_proposal_fails_with_invalid_body_or_title() {
+fn failed_call_execution_status_succeeds() {
     initial_test_ext().execute_with(|| {
-        let mut dummy_proposal =
-            DummyProposalFixture::default().with_title_and_body(Vec::new(), b"body".to_vec());
-        dummy_proposal.create_proposal_and_assert(Err(Error::EmptyTitleProvided.into()));
-
-        dummy_proposal =
-            DummyProposalFixture::default().with_title_and_body(b"title".to_vec(), Vec::new());
-        dummy_proposal.create_proposal_and_assert(Err(Error::EmptyDescriptionProvided.into()));
-
-        let too_long_title = vec![0; 200];
-        dummy_proposal =
-            DummyProposalFixture::default().with_title_and_body(too_long_title, b"body".to_vec());
-        dummy_proposal.create_proposal_and_assert(Err(Error::TitleIsTooLong.into()));
+        let dummy_proposal =
+            mock::proposals::Call::<Test>::dummy_proposal(b"title".to_vec(), b"body".to_vec());
+
+        let proposal_id = DummyProposalFixture::default()
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+        let batch_proposal_id = DummyProposalFixture::default()
+            .create_batch_proposal_and_assert(
+                vec![dummy_proposal.encode(), dummy_proposal.encode()],
+                Ok(2),
+            )
+            .unwrap();
 
-        let too_long_body = vec![0; 11000];
-        dummy_proposal =
-            DummyProposalFixture::default().with_title_and_body(b"title".to_vec(), too_long_body);
-        dummy_proposal.create_proposal_and_assert(Err(Error::DescriptionIsTooLong.into()));
+        assert_eq!(
+            ProposalsEngine::failed_call_execution_status(proposal_id, 0, "ExecutionFailed"),
+            ApprovedProposalStatus::failed_execution("ExecutionFailed")
+        );
+        assert_eq!(
+            ProposalsEngine::failed_call_execution_status(batch_proposal_id, 1, "ExecutionFailed"),
+            ApprovedProposalStatus::failed_batch_item_execution(1, "ExecutionFailed")
+        );
     });
 }
 
 #[test]
-fn vote_fails_with_expired_voting_period() {
+fn vote_status_succeeds() {
     initial_test_ext().execute_with(|| {
         let dummy_proposal = DummyProposalFixture::default();
         let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
-        run_to_block_and_finalize(6);
-
         let mut vote_generator = VoteGenerator::new(proposal_id);
-        vote_generator.vote_and_assert(VoteKind::Approve, Err(Error::ProposalFinalized));
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+
+        assert_eq!(
+            ProposalsEngine::vote_status(proposal_id, 1),
+            Some(VoteKind::Reject)
+        );
+        assert_eq!(ProposalsEngine::vote_status(proposal_id, 2), None);
     });
 }
 
 #[test]
-fn vote_fails_with_not_active_proposal() {
+fn proposal_execution_attempt_recorded() {
     initial_test_ext().execute_with(|| {
         let dummy_proposal = DummyProposalFixture::default();
         let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
         let mut vote_generator = VoteGenerator::new(proposal_id);
-        vote_generator.vote_and_assert_ok(VoteKind::Reject);
-        vote_generator.vote_and_assert_ok(VoteKind::Reject);
-        vote_generator.vote_and_assert_ok(VoteKind::Abstain);
-        vote_generator.vote_and_assert_ok(VoteKind::Abstain);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
 
         run_to_block_and_finalize(2);
 
-        let mut vote_generator_to_fail = VoteGenerator::new(proposal_id);
-        vote_generator_to_fail.vote_and_assert(VoteKind::Approve, Err(Error::ProposalFinalized));
+        assert_eq!(
+            ProposalsEngine::execution_attempts(proposal_id),
+            vec![ExecutionAttempt {
+                attempted_at: 1,
+                result: ApprovedProposalStatus::Executed,
+            }]
+        );
+        assert!(System::events().iter().any(|record| record.event
+            == TestEvent::engine(RawEvent::ProposalExecutionAttempted(
+                proposal_id,
+                ApprovedProposalStatus::Executed
+            ))));
     });
 }
 
 #[test]
-fn vote_fails_with_absent_proposal() {
+fn create_proposal_fails_with_zero_execution_retry_interval() {
     initial_test_ext().execute_with(|| {
-        let mut vote_generator = VoteGenerator::new(2);
-        vote_generator.vote_and_assert(VoteKind::Approve, Err(Error::ProposalNotFound));
+        let parameters_fixture =
+            ProposalParametersFixture::default().with_execution_retry_policy(0, 4);
+
+        DummyProposalFixture::default()
+            .with_parameters(parameters_fixture.params())
+            .create_proposal_and_assert(Err(Error::InvalidParameterExecutionRetryInterval));
     });
 }
 
 #[test]
-fn vote_fails_on_double_voting() {
+fn batch_proposal_execution_succeeds() {
     initial_test_ext().execute_with(|| {
-        let dummy_proposal = DummyProposalFixture::default();
-        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+        let dummy_proposal =
+            mock::proposals::Call::<Test>::dummy_proposal(b"title".to_vec(), b"body".to_vec());
 
-        let mut vote_generator = VoteGenerator::new(proposal_id);
-        vote_generator.auto_increment_voter_id = false;
+        let proposal_id = DummyProposalFixture::default()
+            .create_batch_proposal_and_assert(
+                vec![dummy_proposal.encode(), dummy_proposal.encode()],
+                Ok(1),
+            )
+            .unwrap();
+        assert!(ProposalsEngine::is_batch_proposal(proposal_id));
 
+        let mut vote_generator = VoteGenerator::new(proposal_id);
         vote_generator.vote_and_assert_ok(VoteKind::Approve);
-        vote_generator.vote_and_assert(VoteKind::Approve, Err(Error::AlreadyVoted));
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(2);
+
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::approved(ApprovedProposalStatus::Executed, 1)
+        );
+        assert_eq!(mock::proposals::Module::<Test>::dummy_proposal_calls(), 2);
     });
 }
 
 #[test]
-fn cancel_proposal_succeeds() {
+fn batch_proposal_execution_failed_with_first_item() {
     initial_test_ext().execute_with(|| {
-        let parameters_fixture = ProposalParametersFixture::default();
         let dummy_proposal =
-            DummyProposalFixture::default().with_parameters(parameters_fixture.params());
-        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+            mock::proposals::Call::<Test>::dummy_proposal(b"title".to_vec(), b"body".to_vec());
+        let faulty_proposal =
+            mock::proposals::Call::<Test>::faulty_proposal(b"title".to_vec(), b"body".to_vec());
 
-        // internal active proposal counter check
-        assert_eq!(<ActiveProposalCount>::get(), 1);
+        let proposal_id = DummyProposalFixture::default()
+            .create_batch_proposal_and_assert(
+                vec![faulty_proposal.encode(), dummy_proposal.encode()],
+                Ok(1),
+            )
+            .unwrap();
 
-        let cancel_proposal = CancelProposalFixture::new(proposal_id);
-        cancel_proposal.cancel_and_assert(Ok(()));
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
 
-        // internal active proposal counter check
-        assert_eq!(<ActiveProposalCount>::get(), 0);
+        run_to_block_and_finalize(2);
 
+        // nothing was executed
         let proposal = <crate::Proposals<Test>>::get(proposal_id);
-
         assert_eq!(
-            proposal,
-            Proposal {
-                parameters: parameters_fixture.params(),
-                proposer_id: 1,
-                created_at: 1,
-                status: ProposalStatus::finalized_successfully(ProposalDecisionStatus::Canceled, 1),
-                title: b"title".to_vec(),
-                description: b"description".to_vec(),
-                voting_results: VotingResults::default(),
-            }
-        )
+            proposal.status,
+            ProposalStatus::approved(
+                ApprovedProposalStatus::failed_batch_item_execution(0, "ExecutionFailed"),
+                1
+            )
+        );
     });
 }
 
 #[test]
-fn cancel_proposal_fails_with_not_active_proposal() {
+fn batch_proposal_execution_failed_with_invalid_item_code() {
     initial_test_ext().execute_with(|| {
-        let dummy_proposal = DummyProposalFixture::default();
-        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+        let faulty_proposal =
+            mock::proposals::Call::<Test>::faulty_proposal(b"title".to_vec(), b"body".to_vec());
 
-        run_to_block_and_finalize(6);
+        let proposal_id = DummyProposalFixture::default()
+            .create_batch_proposal_and_assert(
+                vec![faulty_proposal.encode(), b"invalid code".to_vec()],
+                Ok(1),
+            )
+            .unwrap();
 
-        let cancel_proposal = CancelProposalFixture::new(proposal_id);
-        cancel_proposal.cancel_and_assert(Err(Error::ProposalFinalized));
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(2);
+
+        // the faulty item wasn't dispatched, because the next item cannot be decoded
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+        if let ProposalStatus::Finalized(finalization_data) = proposal.status {
+            assert!(matches!(
+                finalization_data.proposal_status,
+                ProposalDecisionStatus::Approved(ApprovedProposalStatus::ExecutionFailed {
+                    failed_batch_item: Some(1),
+                    ..
+                })
+            ));
+        } else {
+            panic!("Proposal should be finalized");
+        }
     });
 }
 
 #[test]
-fn cancel_proposal_fails_with_not_existing_proposal() {
+fn create_batch_proposal_fails_with_empty_calls() {
     initial_test_ext().execute_with(|| {
-        let cancel_proposal = CancelProposalFixture::new(2);
-        cancel_proposal.cancel_and_assert(Err(Error::ProposalNotFound));
+        DummyProposalFixture::default()
+            .create_batch_proposal_and_assert(Vec::new(), Err(Error::EmptyBatchProposal));
     });
 }
-
 #[test]
-fn cancel_proposal_fails_with_insufficient_rights() {
+fn voting_results_calculation_succeeds() {
     initial_test_ext().execute_with(|| {
-        let dummy_proposal = DummyProposalFixture::default();
+        let parameters = ProposalParameters {
+            voting_period: 3,
+            approval_quorum_percentage: 50,
+            approval_threshold_percentage: 50,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 60,
+            grace_period: 0,
+            required_stake: None,
+            voting_mode: VotingMode::Headcount,
+            constitutionality: 1,
+            execution_retry_policy: None,
+        };
+        let dummy_proposal = DummyProposalFixture::default().with_parameters(parameters);
         let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
-        let cancel_proposal = CancelProposalFixture::new(proposal_id)
-            .with_origin(RawOrigin::Signed(2))
-            .with_proposer(2);
-        cancel_proposal.cancel_and_assert(Err(Error::NotAuthor));
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Abstain);
+
+        run_to_block_and_finalize(2);
+
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+
+        assert_eq!(
+            proposal.voting_results,
+            VotingResults {
+                abstentions: 1,
+                approvals: 2,
+                rejections: 1,
+                slashes: 0,
+            }
+        )
     });
 }
 
 #[test]
-fn veto_proposal_succeeds() {
+fn rejected_voting_results_and_remove_proposal_id_from_active_succeeds() {
     initial_test_ext().execute_with(|| {
         // internal active proposal counter check
         assert_eq!(<ActiveProposalCount>::get(), 0);
 
-        let parameters_fixture = ProposalParametersFixture::default();
-        let dummy_proposal =
-            DummyProposalFixture::default().with_parameters(parameters_fixture.params());
+        let dummy_proposal = DummyProposalFixture::default();
         let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Abstain);
+        vote_generator.vote_and_assert_ok(VoteKind::Abstain);
+
+        assert!(<ActiveProposalIds<Test>>::exists(proposal_id));
+
         // internal active proposal counter check
         assert_eq!(<ActiveProposalCount>::get(), 1);
 
-        let veto_proposal = VetoProposalFixture::new(proposal_id);
-        veto_proposal.veto_and_assert(Ok(()));
+        run_to_block_and_finalize(2);
 
-        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+        let proposal = <Proposals<Test>>::get(proposal_id);
 
         assert_eq!(
-            proposal,
-            Proposal {
-                parameters: parameters_fixture.params(),
-                proposer_id: 1,
-                created_at: 1,
-                status: ProposalStatus::finalized_successfully(ProposalDecisionStatus::Vetoed, 1),
-                title: b"title".to_vec(),
-                description: b"description".to_vec(),
-                voting_results: VotingResults::default(),
+            proposal.voting_results,
+            VotingResults {
+                abstentions: 2,
+                approvals: 0,
+                rejections: 2,
+                slashes: 0,
             }
         );
 
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::finalized_successfully(ProposalDecisionStatus::Rejected, 1),
+        );
+        assert!(!<ActiveProposalIds<Test>>::exists(proposal_id));
+
         // internal active proposal counter check
         assert_eq!(<ActiveProposalCount>::get(), 0);
     });
 }
 
 #[test]
-fn veto_proposal_fails_with_not_active_proposal() {
+fn create_proposal_fails_with_invalid_body_or_title() {
     initial_test_ext().execute_with(|| {
-        let dummy_proposal = DummyProposalFixture::default();
-        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+        let mut dummy_proposal =
+            DummyProposalFixture::default().with_title_and_body(Vec::new(), b"body".to_vec());
+        dummy_proposal.create_proposal_and_assert(Err(Error::EmptyTitleProvided.into()));
 
-        run_to_block_and_finalize(6);
+        dummy_proposal =
+            DummyProposalFixture::default().with_title_and_body(b"title".to_vec(), Vec::new());
+        dummy_proposal.create_proposal_and_assert(Err(Error::EmptyDescriptionProvided.into()));
 
-        let veto_proposal = VetoProposalFixture::new(proposal_id);
-        veto_proposal.veto_and_assert(Err(Error::ProposalFinalized));
+        let too_long_title = vec![0; 200];
+        dummy_proposal =
+            DummyProposalFixture::default().with_title_and_body(too_long_title, b"body".to_vec());
+        dummy_proposal.create_proposal_and_assert(Err(Error::TitleIsTooLong.into()));
+
+        let too_long_body = vec![0; 11000];
+        dummy_proposal =
+            DummyProposalFixture::default().with_title_and_body(b"title".to_vec(), too_long_body);
+        dummy_proposal.create_proposal_and_assert(Err(Error::DescriptionIsTooLong.into()));
     });
 }
 
 #[test]
-fn veto_proposal_fails_with_not_existing_proposal() {
+fn vote_fails_with_expired_voting_period() {
     initial_test_ext().execute_with(|| {
-        let veto_proposal = VetoProposalFixture::new(2);
-        veto_proposal.veto_and_assert(Err(Error::ProposalNotFound));
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        run_to_block_and_finalize(6);
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert(VoteKind::Approve, Err(Error::ProposalFinalized));
     });
 }
 
 #[test]
-fn veto_proposal_fails_with_insufficient_rights() {
+fn vote_fails_with_not_active_proposal() {
     initial_test_ext().execute_with(|| {
         let dummy_proposal = DummyProposalFixture::default();
         let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
-        let veto_proposal = VetoProposalFixture::new(proposal_id).with_origin(RawOrigin::Signed(2));
-        veto_proposal.veto_and_assert(Err(Error::RequireRootOrigin));
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Abstain);
+        vote_generator.vote_and_assert_ok(VoteKind::Abstain);
+
+        run_to_block_and_finalize(2);
+
+        let mut vote_generator_to_fail = VoteGenerator::new(proposal_id);
+        vote_generator_to_fail.vote_and_assert(VoteKind::Approve, Err(Error::ProposalFinalized));
     });
 }
 
 #[test]
-fn create_proposal_event_emitted() {
+fn vote_fails_with_absent_proposal() {
     initial_test_ext().execute_with(|| {
-        let dummy_proposal = DummyProposalFixture::default();
-        dummy_proposal.create_proposal_and_assert(Ok(1));
-
-        EventFixture::assert_events(vec![RawEvent::ProposalCreated(1, 1)]);
+        let mut vote_generator = VoteGenerator::new(2);
+        vote_generator.vote_and_assert(VoteKind::Approve, Err(Error::ProposalNotFound));
     });
 }
 
 #[test]
-fn veto_proposal_event_emitted() {
+fn vote_fails_on_double_voting() {
     initial_test_ext().execute_with(|| {
         let dummy_proposal = DummyProposalFixture::default();
         let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
-        let veto_proposal = VetoProposalFixture::new(proposal_id);
-        veto_proposal.veto_and_assert(Ok(()));
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.auto_increment_voter_id = false;
 
-        EventFixture::assert_events(vec![
-            RawEvent::ProposalCreated(1, 1),
-            RawEvent::ProposalStatusUpdated(
-                1,
-                ProposalStatus::finalized_successfully(ProposalDecisionStatus::Vetoed, 1),
-            ),
-        ]);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert(VoteKind::Approve, Err(Error::AlreadyVoted));
     });
 }
 
 #[test]
-fn cancel_proposal_event_emitted() {
+fn change_vote_succeeds() {
     initial_test_ext().execute_with(|| {
         let dummy_proposal = DummyProposalFixture::default();
         let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
-        let cancel_proposal = CancelProposalFixture::new(proposal_id);
-        cancel_proposal.cancel_and_assert(Ok(()));
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
 
-        EventFixture::assert_events(vec![
-            RawEvent::ProposalCreated(1, 1),
-            RawEvent::ProposalStatusUpdated(
+        assert_eq!(
+            ProposalsEngine::change_vote(
+                system::RawOrigin::Signed(1).into(),
                 1,
-                ProposalStatus::Finalized(FinalizationData {
-                    proposal_status: ProposalDecisionStatus::Canceled,
-                    encoded_unstaking_error_due_to_broken_runtime: None,
-                    stake_data_after_unstaking_error: None,
-                    finalized_at: 1,
-                }),
+                proposal_id,
+                VoteKind::Approve
             ),
-        ]);
+            Ok(())
+        );
+
+        let proposal = <Proposals<Test>>::get(proposal_id);
+        assert_eq!(
+            proposal.voting_results,
+            VotingResults {
+                abstentions: 0,
+                approvals: 2,
+                rejections: 0,
+                slashes: 0,
+            }
+        );
+        assert_eq!(
+            <VoteExistsByProposalByVoter<Test>>::get(&proposal_id, &1),
+            VoteKind::Approve
+        );
     });
 }
 
 #[test]
-fn vote_proposal_event_emitted() {
+fn changed_vote_is_used_on_proposal_finalization() {
     initial_test_ext().execute_with(|| {
         let dummy_proposal = DummyProposalFixture::default();
         let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
+        // without the vote change the proposal would be rejected
         let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
         vote_generator.vote_and_assert_ok(VoteKind::Approve);
 
-        EventFixture::assert_events(vec![
-            RawEvent::ProposalCreated(1, 1),
-            RawEvent::Voted(1, 1, VoteKind::Approve),
-        ]);
-    });
-}
-
-#[test]
-fn create_proposal_and_expire_it() {
+        assert_eq!(
+            ProposalsEngine::change_vote(
+                system::RawOrigin::Signed(1).into(),
+                1,
+                proposal_id,
+                VoteKind::Approve
+            ),
+            Ok(())
+        );
+
+        run_to_block_and_finalize(1);
+
+        let proposal = <Proposals<Test>>::get(proposal_id);
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::approved(ApprovedProposalStatus::Executed, 1)
+        );
+    });
+}
+
+#[test]
+fn change_vote_fails_without_previous_vote() {
     initial_test_ext().execute_with(|| {
-        let parameters_fixture = ProposalParametersFixture::default();
-        let dummy_proposal =
-            DummyProposalFixture::default().with_parameters(parameters_fixture.params());
+        let dummy_proposal = DummyProposalFixture::default();
         let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
-        run_to_block_and_finalize(8);
+        assert_eq!(
+            ProposalsEngine::change_vote(
+                system::RawOrigin::Signed(1).into(),
+                1,
+                proposal_id,
+                VoteKind::Approve
+            ),
+            Err(Error::NotVoted)
+        );
+    });
+}
 
-        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+#[test]
+fn change_vote_fails_with_not_active_proposal() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Abstain);
+        vote_generator.vote_and_assert_ok(VoteKind::Abstain);
+
+        run_to_block_and_finalize(2);
 
         assert_eq!(
-            proposal,
-            Proposal {
-                parameters: parameters_fixture.params(),
-                proposer_id: 1,
-                created_at: 1,
-                status: ProposalStatus::finalized_successfully(ProposalDecisionStatus::Expired, 4),
-                title: b"title".to_vec(),
-                description: b"description".to_vec(),
-                voting_results: VotingResults::default(),
-            }
-        )
+            ProposalsEngine::change_vote(
+                system::RawOrigin::Signed(1).into(),
+                1,
+                proposal_id,
+                VoteKind::Approve
+            ),
+            Err(Error::ProposalFinalized)
+        );
     });
 }
 
 #[test]
-fn proposal_execution_postponed_because_of_grace_period() {
+fn withdraw_vote_succeeds() {
     initial_test_ext().execute_with(|| {
-        let parameters_fixture = ProposalParametersFixture::default().with_grace_period(2);
-        let dummy_proposal =
-            DummyProposalFixture::default().with_parameters(parameters_fixture.params());
-
+        let dummy_proposal = DummyProposalFixture::default();
         let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
         let mut vote_generator = VoteGenerator::new(proposal_id);
-        vote_generator.vote_and_assert_ok(VoteKind::Approve);
-        vote_generator.vote_and_assert_ok(VoteKind::Approve);
-        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Slash);
         vote_generator.vote_and_assert_ok(VoteKind::Approve);
 
-        run_to_block_and_finalize(1);
-        run_to_block_and_finalize(2);
+        assert_eq!(
+            ProposalsEngine::withdraw_vote(system::RawOrigin::Signed(1).into(), 1, proposal_id),
+            Ok(())
+        );
 
-        // check internal cache for proposal_id presense
-        assert!(<PendingExecutionProposalIds<Test>>::enumerate()
-            .find(|(x, _)| *x == proposal_id)
-            .is_some());
+        let proposal = <Proposals<Test>>::get(proposal_id);
+        assert_eq!(
+            proposal.voting_results,
+            VotingResults {
+                abstentions: 0,
+                approvals: 1,
+                rejections: 0,
+                slashes: 0,
+            }
+        );
+        assert!(!<VoteExistsByProposalByVoter<Test>>::exists(
+            &proposal_id,
+            &1
+        ));
 
-        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+        // voter can vote again after the withdrawal
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Abstain);
+    });
+}
+
+#[test]
+fn withdraw_vote_fails_without_previous_vote() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
         assert_eq!(
-            proposal,
-            Proposal {
-                parameters: parameters_fixture.params(),
-                proposer_id: 1,
-                created_at: 1,
-                status: ProposalStatus::approved(ApprovedProposalStatus::PendingExecution, 1),
-                title: b"title".to_vec(),
-                description: b"description".to_vec(),
-                voting_results: VotingResults {
-                    abstentions: 0,
-                    approvals: 4,
-                    rejections: 0,
-                    slashes: 0,
-                },
-            }
+            ProposalsEngine::withdraw_vote(system::RawOrigin::Signed(1).into(), 1, proposal_id),
+            Err(Error::NotVoted)
         );
     });
 }
 
 #[test]
-fn proposal_execution_vetoed_successfully_during_the_grace_period() {
+fn withdraw_vote_fails_with_absent_proposal() {
     initial_test_ext().execute_with(|| {
-        let parameters_fixture = ProposalParametersFixture::default().with_grace_period(2);
-        let dummy_proposal =
-            DummyProposalFixture::default().with_parameters(parameters_fixture.params());
+        assert_eq!(
+            ProposalsEngine::withdraw_vote(system::RawOrigin::Signed(1).into(), 1, 2),
+            Err(Error::ProposalNotFound)
+        );
+    });
+}
 
+#[test]
+fn change_and_withdraw_vote_events_emitted() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
         let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
         let mut vote_generator = VoteGenerator::new(proposal_id);
         vote_generator.vote_and_assert_ok(VoteKind::Approve);
-        vote_generator.vote_and_assert_ok(VoteKind::Approve);
-        vote_generator.vote_and_assert_ok(VoteKind::Approve);
-        vote_generator.vote_and_assert_ok(VoteKind::Approve);
 
-        run_to_block_and_finalize(1);
-        run_to_block_and_finalize(2);
+        assert_eq!(
+            ProposalsEngine::change_vote(
+                system::RawOrigin::Signed(1).into(),
+                1,
+                proposal_id,
+                VoteKind::Reject
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            ProposalsEngine::withdraw_vote(system::RawOrigin::Signed(1).into(), 1, proposal_id),
+            Ok(())
+        );
 
-        // check internal cache for proposal_id presense
-        assert!(<PendingExecutionProposalIds<Test>>::enumerate()
-            .find(|(x, _)| *x == proposal_id)
-            .is_some());
+        EventFixture::assert_events(vec![
+            RawEvent::ProposalCreated(1, 1),
+            RawEvent::Voted(1, 1, VoteKind::Approve),
+            RawEvent::VoteChanged(1, 1, VoteKind::Reject),
+            RawEvent::VoteWithdrawn(1, 1),
+        ]);
+    });
+}
+
+#[test]
+fn cancel_proposal_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let parameters_fixture = ProposalParametersFixture::default();
+        let dummy_proposal =
+            DummyProposalFixture::default().with_parameters(parameters_fixture.params());
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        // internal active proposal counter check
+        assert_eq!(<ActiveProposalCount>::get(), 1);
+
+        let cancel_proposal = CancelProposalFixture::new(proposal_id);
+        cancel_proposal.cancel_and_assert(Ok(()));
+
+        // internal active proposal counter check
+        assert_eq!(<ActiveProposalCount>::get(), 0);
 
         let proposal = <crate::Proposals<Test>>::get(proposal_id);
 
@@ -873,17 +1336,66 @@ fn proposal_execution_vetoed_successfully_during_the_grace_period() {
                 parameters: parameters_fixture.params(),
                 proposer_id: 1,
                 created_at: 1,
-                status: ProposalStatus::approved(ApprovedProposalStatus::PendingExecution, 1),
+                status: ProposalStatus::finalized_successfully(ProposalDecisionStatus::Canceled, 1),
                 title: b"title".to_vec(),
                 description: b"description".to_vec(),
-                voting_results: VotingResults {
-                    abstentions: 0,
-                    approvals: 4,
-                    rejections: 0,
-                    slashes: 0,
-                },
+                voting_results: VotingResults::default(),
+                exactly_execute_at: None,
+                activated_at: 1,
+                council_approvals: 0,
+                amendments: Vec::new(),
             }
-        );
+        )
+    });
+}
+
+#[test]
+fn cancel_proposal_fails_with_not_active_proposal() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        run_to_block_and_finalize(6);
+
+        let cancel_proposal = CancelProposalFixture::new(proposal_id);
+        cancel_proposal.cancel_and_assert(Err(Error::ProposalFinalized));
+    });
+}
+
+#[test]
+fn cancel_proposal_fails_with_not_existing_proposal() {
+    initial_test_ext().execute_with(|| {
+        let cancel_proposal = CancelProposalFixture::new(2);
+        cancel_proposal.cancel_and_assert(Err(Error::ProposalNotFound));
+    });
+}
+
+#[test]
+fn cancel_proposal_fails_with_insufficient_rights() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let cancel_proposal = CancelProposalFixture::new(proposal_id)
+            .with_origin(RawOrigin::Signed(2))
+            .with_proposer(2);
+        cancel_proposal.cancel_and_assert(Err(Error::NotAuthor));
+    });
+}
+
+#[test]
+fn veto_proposal_succeeds() {
+    initial_test_ext().execute_with(|| {
+        // internal active proposal counter check
+        assert_eq!(<ActiveProposalCount>::get(), 0);
+
+        let parameters_fixture = ProposalParametersFixture::default();
+        let dummy_proposal =
+            DummyProposalFixture::default().with_parameters(parameters_fixture.params());
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        // internal active proposal counter check
+        assert_eq!(<ActiveProposalCount>::get(), 1);
 
         let veto_proposal = VetoProposalFixture::new(proposal_id);
         veto_proposal.veto_and_assert(Ok(()));
@@ -896,147 +1408,164 @@ fn proposal_execution_vetoed_successfully_during_the_grace_period() {
                 parameters: parameters_fixture.params(),
                 proposer_id: 1,
                 created_at: 1,
-                status: ProposalStatus::finalized_successfully(ProposalDecisionStatus::Vetoed, 2),
+                status: ProposalStatus::finalized_successfully(ProposalDecisionStatus::Vetoed, 1),
                 title: b"title".to_vec(),
                 description: b"description".to_vec(),
-                voting_results: VotingResults {
-                    abstentions: 0,
-                    approvals: 4,
-                    rejections: 0,
-                    slashes: 0,
-                },
+                voting_results: VotingResults::default(),
+                exactly_execute_at: None,
+                activated_at: 1,
+                council_approvals: 0,
+                amendments: Vec::new(),
             }
         );
 
-        // check internal cache for proposal_id presense
-        assert!(<PendingExecutionProposalIds<Test>>::enumerate()
-            .find(|(x, _)| *x == proposal_id)
-            .is_none());
+        // internal active proposal counter check
+        assert_eq!(<ActiveProposalCount>::get(), 0);
+
+        assert_eq!(
+            ProposalsEngine::proposal_vetoes(proposal_id),
+            Some(VetoRecord {
+                vetoer_id: 1,
+                vetoed_at: 1,
+                rationale: b"rationale".to_vec(),
+            })
+        );
     });
 }
 
 #[test]
-fn proposal_execution_succeeds_after_the_grace_period() {
+fn veto_proposal_fails_with_not_active_proposal() {
     initial_test_ext().execute_with(|| {
-        let parameters_fixture = ProposalParametersFixture::default().with_grace_period(1);
-        let dummy_proposal =
-            DummyProposalFixture::default().with_parameters(parameters_fixture.params());
+        let dummy_proposal = DummyProposalFixture::default();
         let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
-        let mut vote_generator = VoteGenerator::new(proposal_id);
-        vote_generator.vote_and_assert_ok(VoteKind::Approve);
-        vote_generator.vote_and_assert_ok(VoteKind::Approve);
-        vote_generator.vote_and_assert_ok(VoteKind::Approve);
-        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        run_to_block_and_finalize(6);
 
-        run_to_block_and_finalize(1);
+        let veto_proposal = VetoProposalFixture::new(proposal_id);
+        veto_proposal.veto_and_assert(Err(Error::ProposalFinalized));
+    });
+}
 
-        // check internal cache for proposal_id presence
-        assert!(<PendingExecutionProposalIds<Test>>::enumerate()
-            .find(|(x, _)| *x == proposal_id)
-            .is_some());
-
-        let mut proposal = <crate::Proposals<Test>>::get(proposal_id);
+#[test]
+fn veto_proposal_fails_with_not_existing_proposal() {
+    initial_test_ext().execute_with(|| {
+        let veto_proposal = VetoProposalFixture::new(2);
+        veto_proposal.veto_and_assert(Err(Error::ProposalNotFound));
+    });
+}
 
-        let mut expected_proposal = Proposal {
-            parameters: parameters_fixture.params(),
-            proposer_id: 1,
-            created_at: 1,
-            status: ProposalStatus::approved(ApprovedProposalStatus::PendingExecution, 1),
-            title: b"title".to_vec(),
-            description: b"description".to_vec(),
-            voting_results: VotingResults {
-                abstentions: 0,
-                approvals: 4,
-                rejections: 0,
-                slashes: 0,
-            },
-        };
+#[test]
+fn veto_proposal_fails_with_insufficient_rights() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
-        assert_eq!(proposal, expected_proposal);
+        let veto_proposal = VetoProposalFixture::new(proposal_id).with_origin(RawOrigin::Signed(2));
+        veto_proposal.veto_and_assert(Err(Error::Other("Veto validation failed")));
 
-        run_to_block_and_finalize(2);
+        let veto_proposal = VetoProposalFixture::new(proposal_id).with_origin(RawOrigin::Root);
+        veto_proposal.veto_and_assert(Err(Error::Other("RequireSignedOrigin")));
+    });
+}
 
-        proposal = <crate::Proposals<Test>>::get(proposal_id);
+#[test]
+fn veto_proposal_fails_with_invalid_rationale() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
-        expected_proposal.status = ProposalStatus::approved(ApprovedProposalStatus::Executed, 1);
+        let veto_proposal = VetoProposalFixture::new(proposal_id).with_rationale(Vec::new());
+        veto_proposal.veto_and_assert(Err(Error::EmptyVetoRationaleProvided));
 
-        assert_eq!(proposal, expected_proposal);
+        let too_long_rationale = vec![b'r'; (VetoRationaleMaxLength::get() + 1) as usize];
+        let veto_proposal =
+            VetoProposalFixture::new(proposal_id).with_rationale(too_long_rationale);
+        veto_proposal.veto_and_assert(Err(Error::VetoRationaleIsTooLong));
 
-        // check internal cache for proposal_id absense
-        assert!(<PendingExecutionProposalIds<Test>>::enumerate()
-            .find(|(x, _)| *x == proposal_id)
-            .is_none());
+        assert_eq!(ProposalsEngine::proposal_vetoes(proposal_id), None);
     });
 }
 
 #[test]
-fn create_proposal_fails_on_exceeding_max_active_proposals_count() {
+fn create_proposal_event_emitted() {
     initial_test_ext().execute_with(|| {
-        for idx in 1..101 {
-            let dummy_proposal = DummyProposalFixture::default();
-            dummy_proposal.create_proposal_and_assert(Ok(idx));
-            // internal active proposal counter check
-            assert_eq!(<ActiveProposalCount>::get(), idx);
-        }
-
         let dummy_proposal = DummyProposalFixture::default();
-        dummy_proposal
-            .create_proposal_and_assert(Err(Error::MaxActiveProposalNumberExceeded.into()));
-        // internal active proposal counter check
-        assert_eq!(<ActiveProposalCount>::get(), 100);
+        dummy_proposal.create_proposal_and_assert(Ok(1));
+
+        EventFixture::assert_events(vec![RawEvent::ProposalCreated(1, 1)]);
     });
 }
 
 #[test]
-fn voting_internal_cache_exists_after_proposal_finalization() {
+fn veto_proposal_event_emitted() {
     initial_test_ext().execute_with(|| {
         let dummy_proposal = DummyProposalFixture::default();
-        dummy_proposal.create_proposal_and_assert(Ok(1));
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
-        // last created proposal id equals current proposal count
-        let proposal_id = <ProposalCount>::get();
+        let veto_proposal = VetoProposalFixture::new(proposal_id);
+        veto_proposal.veto_and_assert(Ok(()));
 
-        let mut vote_generator = VoteGenerator::new(proposal_id);
-        vote_generator.vote_and_assert_ok(VoteKind::Reject);
-        vote_generator.vote_and_assert_ok(VoteKind::Reject);
-        vote_generator.vote_and_assert_ok(VoteKind::Abstain);
-        vote_generator.vote_and_assert_ok(VoteKind::Abstain);
+        EventFixture::assert_events(vec![
+            RawEvent::ProposalCreated(1, 1),
+            RawEvent::ProposalVetoed(1, 1),
+            RawEvent::ProposalStatusUpdated(
+                1,
+                ProposalStatus::finalized_successfully(ProposalDecisionStatus::Vetoed, 1),
+            ),
+        ]);
+    });
+}
 
-        // cache exists
-        assert!(<crate::VoteExistsByProposalByVoter<Test>>::exists(
-            proposal_id,
-            1
-        ));
+#[test]
+fn cancel_proposal_event_emitted() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
-        run_to_block_and_finalize(2);
+        let cancel_proposal = CancelProposalFixture::new(proposal_id);
+        cancel_proposal.cancel_and_assert(Ok(()));
 
-        // cache still exists and is not cleared
-        assert!(<crate::VoteExistsByProposalByVoter<Test>>::exists(
-            proposal_id,
-            1
-        ));
+        EventFixture::assert_events(vec![
+            RawEvent::ProposalCreated(1, 1),
+            RawEvent::ProposalStatusUpdated(
+                1,
+                ProposalStatus::Finalized(FinalizationData {
+                    proposal_status: ProposalDecisionStatus::Canceled,
+                    encoded_unstaking_error_due_to_broken_runtime: None,
+                    stake_data_after_unstaking_error: None,
+                    finalized_at: 1,
+                }),
+            ),
+        ]);
     });
 }
 
 #[test]
-fn create_dummy_proposal_succeeds_with_stake() {
+fn vote_proposal_event_emitted() {
     initial_test_ext().execute_with(|| {
-        let account_id = 1;
-
-        let required_stake = 200;
-        let parameters_fixture =
-            ProposalParametersFixture::default().with_required_stake(required_stake);
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
-        let dummy_proposal = DummyProposalFixture::default()
-            .with_parameters(parameters_fixture.params())
-            .with_account_id(account_id)
-            .with_stake(200);
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
 
-        let _imbalance = <Test as stake::Trait>::Currency::deposit_creating(&account_id, 500);
+        EventFixture::assert_events(vec![
+            RawEvent::ProposalCreated(1, 1),
+            RawEvent::Voted(1, 1, VoteKind::Approve),
+        ]);
+    });
+}
 
+#[test]
+fn create_proposal_and_expire_it() {
+    initial_test_ext().execute_with(|| {
+        let parameters_fixture = ProposalParametersFixture::default();
+        let dummy_proposal =
+            DummyProposalFixture::default().with_parameters(parameters_fixture.params());
         let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
+        run_to_block_and_finalize(8);
+
         let proposal = <crate::Proposals<Test>>::get(proposal_id);
 
         assert_eq!(
@@ -1045,537 +1574,2003 @@ fn create_dummy_proposal_succeeds_with_stake() {
                 parameters: parameters_fixture.params(),
                 proposer_id: 1,
                 created_at: 1,
-                status: ProposalStatus::Active(Some(ActiveStake {
-                    stake_id: 0, // valid stake_id
-                    source_account_id: 1
-                })),
+                status: ProposalStatus::finalized_successfully(ProposalDecisionStatus::Expired, 4),
                 title: b"title".to_vec(),
                 description: b"description".to_vec(),
                 voting_results: VotingResults::default(),
+                exactly_execute_at: None,
+                activated_at: 1,
+                council_approvals: 0,
+                amendments: Vec::new(),
             }
         )
     });
 }
 
 #[test]
-fn create_dummy_proposal_fail_with_stake_on_empty_account() {
+fn proposal_execution_postponed_because_of_grace_period() {
     initial_test_ext().execute_with(|| {
-        let account_id = 1;
+        let parameters_fixture = ProposalParametersFixture::default().with_grace_period(2);
+        let dummy_proposal =
+            DummyProposalFixture::default().with_parameters(parameters_fixture.params());
 
-        let required_stake = 200;
-        let parameters_fixture =
-            ProposalParametersFixture::default().with_required_stake(required_stake);
-        let dummy_proposal = DummyProposalFixture::default()
-            .with_parameters(parameters_fixture.params())
-            .with_account_id(account_id)
-            .with_stake(required_stake);
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
-        dummy_proposal
-            .create_proposal_and_assert(Err(Error::Other("too few free funds in account")));
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(1);
+        run_to_block_and_finalize(2);
+
+        // check internal cache for proposal_id presense
+        assert!(<PendingExecutionProposalIds<Test>>::enumerate()
+            .find(|(x, _)| *x == proposal_id)
+            .is_some());
+
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+
+        assert_eq!(
+            proposal,
+            Proposal {
+                parameters: parameters_fixture.params(),
+                proposer_id: 1,
+                created_at: 1,
+                status: ProposalStatus::approved(ApprovedProposalStatus::PendingExecution, 1),
+                title: b"title".to_vec(),
+                description: b"description".to_vec(),
+                voting_results: VotingResults {
+                    abstentions: 0,
+                    approvals: 4,
+                    rejections: 0,
+                    slashes: 0,
+                },
+                exactly_execute_at: None,
+                activated_at: 1,
+                council_approvals: 1,
+                amendments: Vec::new(),
+            }
+        );
     });
 }
 
 #[test]
-fn create_proposal_fais_with_invalid_stake_parameters() {
+fn proposal_execution_vetoed_successfully_during_the_grace_period() {
     initial_test_ext().execute_with(|| {
-        let parameters_fixture = ProposalParametersFixture::default();
+        let parameters_fixture = ProposalParametersFixture::default().with_grace_period(2);
+        let dummy_proposal =
+            DummyProposalFixture::default().with_parameters(parameters_fixture.params());
 
-        let mut dummy_proposal = DummyProposalFixture::default()
-            .with_parameters(parameters_fixture.params())
-            .with_stake(200);
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
-        dummy_proposal.create_proposal_and_assert(Err(Error::StakeShouldBeEmpty.into()));
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
 
-        let parameters_fixture_stake_200 = parameters_fixture.with_required_stake(200);
-        dummy_proposal =
-            DummyProposalFixture::default().with_parameters(parameters_fixture_stake_200.params());
+        run_to_block_and_finalize(1);
+        run_to_block_and_finalize(2);
 
-        dummy_proposal.create_proposal_and_assert(Err(Error::EmptyStake.into()));
+        // check internal cache for proposal_id presense
+        assert!(<PendingExecutionProposalIds<Test>>::enumerate()
+            .find(|(x, _)| *x == proposal_id)
+            .is_some());
 
-        let parameters_fixture_stake_300 = parameters_fixture.with_required_stake(300);
-        dummy_proposal = DummyProposalFixture::default()
-            .with_parameters(parameters_fixture_stake_300.params())
-            .with_stake(200);
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+
+        assert_eq!(
+            proposal,
+            Proposal {
+                parameters: parameters_fixture.params(),
+                proposer_id: 1,
+                created_at: 1,
+                status: ProposalStatus::approved(ApprovedProposalStatus::PendingExecution, 1),
+                title: b"title".to_vec(),
+                description: b"description".to_vec(),
+                voting_results: VotingResults {
+                    abstentions: 0,
+                    approvals: 4,
+                    rejections: 0,
+                    slashes: 0,
+                },
+                exactly_execute_at: None,
+                activated_at: 1,
+                council_approvals: 1,
+                amendments: Vec::new(),
+            }
+        );
+
+        let veto_proposal = VetoProposalFixture::new(proposal_id);
+        veto_proposal.veto_and_assert(Ok(()));
+
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+
+        assert_eq!(
+            proposal,
+            Proposal {
+                parameters: parameters_fixture.params(),
+                proposer_id: 1,
+                created_at: 1,
+                status: ProposalStatus::finalized_successfully(ProposalDecisionStatus::Vetoed, 2),
+                title: b"title".to_vec(),
+                description: b"description".to_vec(),
+                voting_results: VotingResults {
+                    abstentions: 0,
+                    approvals: 4,
+                    rejections: 0,
+                    slashes: 0,
+                },
+                exactly_execute_at: None,
+                activated_at: 1,
+                council_approvals: 1,
+                amendments: Vec::new(),
+            }
+        );
+
+        // check internal cache for proposal_id presense
+        assert!(<PendingExecutionProposalIds<Test>>::enumerate()
+            .find(|(x, _)| *x == proposal_id)
+            .is_none());
+    });
+}
+
+#[test]
+fn proposal_execution_succeeds_after_the_grace_period() {
+    initial_test_ext().execute_with(|| {
+        let parameters_fixture = ProposalParametersFixture::default().with_grace_period(1);
+        let dummy_proposal =
+            DummyProposalFixture::default().with_parameters(parameters_fixture.params());
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(1);
+
+        // check internal cache for proposal_id presence
+        assert!(<PendingExecutionProposalIds<Test>>::enumerate()
+            .find(|(x, _)| *x == proposal_id)
+            .is_some());
+
+        let mut proposal = <crate::Proposals<Test>>::get(proposal_id);
+
+        let mut expected_proposal = Proposal {
+            parameters: parameters_fixture.params(),
+            proposer_id: 1,
+            created_at: 1,
+            status: ProposalStatus::approved(ApprovedProposalStatus::PendingExecution, 1),
+            title: b"title".to_vec(),
+            description: b"description".to_vec(),
+            voting_results: VotingResults {
+                abstentions: 0,
+                approvals: 4,
+                rejections: 0,
+                slashes: 0,
+            },
+            exactly_execute_at: None,
+            activated_at: 1,
+            council_approvals: 1,
+            amendments: Vec::new(),
+        };
+
+        assert_eq!(proposal, expected_proposal);
+
+        run_to_block_and_finalize(2);
+
+        proposal = <crate::Proposals<Test>>::get(proposal_id);
+
+        expected_proposal.status = ProposalStatus::approved(ApprovedProposalStatus::Executed, 1);
+
+        assert_eq!(proposal, expected_proposal);
+
+        // check internal cache for proposal_id absense
+        assert!(<PendingExecutionProposalIds<Test>>::enumerate()
+            .find(|(x, _)| *x == proposal_id)
+            .is_none());
+    });
+}
+
+#[test]
+fn proposal_execution_postponed_until_the_exact_execution_block() {
+    initial_test_ext().execute_with(|| {
+        let parameters_fixture = ProposalParametersFixture::default().with_grace_period(1);
+        let dummy_proposal = DummyProposalFixture::default()
+            .with_parameters(parameters_fixture.params())
+            .with_exactly_execute_at(6);
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(5);
+
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::approved(ApprovedProposalStatus::PendingExecution, 1)
+        );
+        assert!(<PendingExecutionProposalIds<Test>>::enumerate()
+            .find(|(x, _)| *x == proposal_id)
+            .is_some());
+
+        run_to_block_and_finalize(6);
+
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::approved(ApprovedProposalStatus::Executed, 1)
+        );
+        assert!(<PendingExecutionProposalIds<Test>>::enumerate()
+            .find(|(x, _)| *x == proposal_id)
+            .is_none());
+    });
+}
+
+#[test]
+fn create_proposal_fails_with_invalid_exact_execution_block() {
+    initial_test_ext().execute_with(|| {
+        let parameters_fixture = ProposalParametersFixture::default().with_grace_period(1);
+
+        // voting period (3) and grace period (1) end at the block 5
+        DummyProposalFixture::default()
+            .with_parameters(parameters_fixture.params())
+            .with_exactly_execute_at(4)
+            .create_proposal_and_assert(Err(Error::InvalidExactExecutionBlock));
+
+        DummyProposalFixture::default()
+            .with_parameters(parameters_fixture.params())
+            .with_exactly_execute_at(5)
+            .create_proposal_and_assert(Ok(1));
+    });
+}
+#[test]
+fn create_proposal_fails_on_exceeding_max_active_proposals_count() {
+    initial_test_ext().execute_with(|| {
+        for idx in 1..101 {
+            let dummy_proposal = DummyProposalFixture::default();
+            dummy_proposal.create_proposal_and_assert(Ok(idx));
+            // internal active proposal counter check
+            assert_eq!(<ActiveProposalCount>::get(), idx);
+        }
+
+        let dummy_proposal = DummyProposalFixture::default();
+        dummy_proposal
+            .create_proposal_and_assert(Err(Error::MaxActiveProposalNumberExceeded.into()));
+        // internal active proposal counter check
+        assert_eq!(<ActiveProposalCount>::get(), 100);
+    });
+}
+
+#[test]
+fn voting_internal_cache_exists_after_proposal_finalization() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        dummy_proposal.create_proposal_and_assert(Ok(1));
+
+        // last created proposal id equals current proposal count
+        let proposal_id = <ProposalCount>::get();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Abstain);
+        vote_generator.vote_and_assert_ok(VoteKind::Abstain);
+
+        // cache exists
+        assert!(<crate::VoteExistsByProposalByVoter<Test>>::exists(
+            proposal_id,
+            1
+        ));
+
+        run_to_block_and_finalize(2);
+
+        // cache still exists and is not cleared
+        assert!(<crate::VoteExistsByProposalByVoter<Test>>::exists(
+            proposal_id,
+            1
+        ));
+    });
+}
+
+#[test]
+fn vote_records_exist_after_proposal_finalization() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Slash);
+
+        run_to_block_and_finalize(6);
+
+        assert_eq!(
+            ProposalsEngine::votes_by_proposal(proposal_id),
+            vec![
+                VoteRecord {
+                    voter_id: 1,
+                    vote_kind: VoteKind::Reject,
+                    voted_at: 1,
+                },
+                VoteRecord {
+                    voter_id: 2,
+                    vote_kind: VoteKind::Slash,
+                    voted_at: 1,
+                },
+            ]
+        );
+    });
+}
+
+#[test]
+fn vote_records_are_updated_on_vote_change_and_withdrawal() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Abstain);
+
+        run_to_block(2);
+
+        assert_eq!(
+            ProposalsEngine::change_vote(
+                system::RawOrigin::Signed(1).into(),
+                1,
+                proposal_id,
+                VoteKind::Approve
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            ProposalsEngine::withdraw_vote(system::RawOrigin::Signed(2).into(), 2, proposal_id),
+            Ok(())
+        );
+
+        assert_eq!(
+            ProposalsEngine::votes_by_proposal(proposal_id),
+            vec![VoteRecord {
+                voter_id: 1,
+                vote_kind: VoteKind::Approve,
+                voted_at: 2,
+            }]
+        );
+    });
+}
+
+#[test]
+fn vote_records_are_pruned_after_retention_period() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        let cancel_proposal_fixture = CancelProposalFixture::new(proposal_id);
+        cancel_proposal_fixture.cancel_and_assert(Ok(()));
+
+        // mock pruning policy: records are removed after 10 blocks
+        let prune_at = 1 + 10;
+        assert_eq!(
+            ProposalsEngine::vote_records_pruning_schedule(prune_at),
+            vec![proposal_id]
+        );
+
+        run_to_block_and_finalize(prune_at - 1);
+        assert_eq!(ProposalsEngine::votes_by_proposal(proposal_id).len(), 1);
+
+        run_to_block_and_finalize(prune_at);
+        assert!(ProposalsEngine::votes_by_proposal(proposal_id).is_empty());
+        assert!(ProposalsEngine::vote_records_pruning_schedule(prune_at).is_empty());
+    });
+}
+
+#[test]
+fn votes_by_voter_returns_voting_record_across_proposals() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let first_proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let dummy_proposal = DummyProposalFixture::default();
+        let second_proposal_id = dummy_proposal.create_proposal_and_assert(Ok(2)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(first_proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+
+        let mut vote_generator = VoteGenerator::new(second_proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        assert_eq!(
+            ProposalsEngine::votes_by_voter(1),
+            vec![
+                (
+                    first_proposal_id,
+                    VoteRecord {
+                        voter_id: 1,
+                        vote_kind: VoteKind::Reject,
+                        voted_at: 1,
+                    }
+                ),
+                (
+                    second_proposal_id,
+                    VoteRecord {
+                        voter_id: 1,
+                        vote_kind: VoteKind::Approve,
+                        voted_at: 1,
+                    }
+                ),
+            ]
+        );
+
+        assert_eq!(
+            ProposalsEngine::withdraw_vote(
+                system::RawOrigin::Signed(1).into(),
+                1,
+                first_proposal_id
+            ),
+            Ok(())
+        );
+
+        assert_eq!(
+            ProposalsEngine::proposal_ids_by_voter(1),
+            vec![second_proposal_id]
+        );
+
+        let cancel_proposal_fixture = CancelProposalFixture::new(second_proposal_id);
+        cancel_proposal_fixture.cancel_and_assert(Ok(()));
+
+        // mock pruning policy: records are removed after 10 blocks
+        run_to_block_and_finalize(11);
+
+        assert!(ProposalsEngine::votes_by_voter(1).is_empty());
+        assert!(!<crate::ProposalIdsByVoter<Test>>::exists(1));
+    });
+}
+
+#[test]
+fn stake_weighted_voting_approves_proposal_by_stake_majority() {
+    initial_test_ext().execute_with(|| {
+        let parameters_fixture =
+            ProposalParametersFixture::default().with_voting_mode(VotingMode::StakeWeighted);
+        let dummy_proposal =
+            DummyProposalFixture::default().with_parameters(parameters_fixture.params());
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        // mock voter stakes: 10, 20, 30, 40
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(1);
+
+        let proposal = <Proposals<Test>>::get(proposal_id);
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::approved(ApprovedProposalStatus::Executed, 1)
+        );
+    });
+}
+
+#[test]
+fn headcount_voting_rejects_proposal_with_the_same_votes() {
+    initial_test_ext().execute_with(|| {
+        let parameters_fixture =
+            ProposalParametersFixture::default().with_voting_mode(VotingMode::Headcount);
+        let dummy_proposal =
+            DummyProposalFixture::default().with_parameters(parameters_fixture.params());
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(1);
+
+        let proposal = <Proposals<Test>>::get(proposal_id);
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::finalized_successfully(ProposalDecisionStatus::Rejected, 1)
+        );
+    });
+}
+
+#[test]
+fn constitutional_proposal_requires_approvals_of_consecutive_councils() {
+    initial_test_ext().execute_with(|| {
+        let parameters_fixture = ProposalParametersFixture::default().with_constitutionality(2);
+        let dummy_proposal =
+            DummyProposalFixture::default().with_parameters(parameters_fixture.params());
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(1);
+
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::PendingConstitutionality(None)
+        );
+        assert_eq!(proposal.council_approvals, 1);
+        assert!(<PendingExecutionProposalIds<Test>>::enumerate()
+            .find(|(x, _)| *x == proposal_id)
+            .is_none());
+
+        // the same council cannot approve the proposal again
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert(VoteKind::Approve, Err(Error::ProposalFinalized));
+
+        // the original voting period is expired by now
+        run_to_block(5);
+        ProposalsEngine::reset_active_proposals();
+
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+        assert_eq!(proposal.status, ProposalStatus::Active(None));
+        assert_eq!(proposal.activated_at, 5);
+        assert_eq!(proposal.voting_results, VotingResults::default());
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(5);
+
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::approved(ApprovedProposalStatus::Executed, 5)
+        );
+        assert_eq!(proposal.council_approvals, 2);
+    });
+}
+
+#[test]
+fn constitutional_proposal_vetoed_while_waiting_for_the_next_council() {
+    initial_test_ext().execute_with(|| {
+        let parameters_fixture = ProposalParametersFixture::default().with_constitutionality(2);
+        let dummy_proposal =
+            DummyProposalFixture::default().with_parameters(parameters_fixture.params());
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(1);
+
+        let veto_proposal = VetoProposalFixture::new(proposal_id);
+        veto_proposal.veto_and_assert(Ok(()));
+
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::finalized_successfully(ProposalDecisionStatus::Vetoed, 1)
+        );
+        assert!(<PendingConstitutionalityProposalIds<Test>>::enumerate()
+            .find(|(x, _)| *x == proposal_id)
+            .is_none());
+        assert_eq!(ProposalsEngine::active_proposal_count(), 0);
+    });
+}
+
+#[test]
+fn constitutional_proposal_canceled_while_waiting_for_the_next_council() {
+    initial_test_ext().execute_with(|| {
+        let parameters_fixture = ProposalParametersFixture::default().with_constitutionality(2);
+        let dummy_proposal =
+            DummyProposalFixture::default().with_parameters(parameters_fixture.params());
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(1);
+
+        let cancel_proposal = CancelProposalFixture::new(proposal_id);
+        cancel_proposal.cancel_and_assert(Ok(()));
+
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::finalized_successfully(ProposalDecisionStatus::Canceled, 1)
+        );
+        assert!(!<PendingConstitutionalityProposalIds<Test>>::exists(
+            proposal_id
+        ));
+        assert_eq!(ProposalsEngine::active_proposal_count(), 0);
+    });
+}
+
+#[test]
+fn constitutional_proposal_expires_without_the_next_council_approval() {
+    initial_test_ext().execute_with(|| {
+        let parameters_fixture = ProposalParametersFixture::default().with_constitutionality(2);
+        let dummy_proposal =
+            DummyProposalFixture::default().with_parameters(parameters_fixture.params());
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(1);
+
+        assert_eq!(
+            ProposalsEngine::pending_constitutionality_proposal_ids(proposal_id),
+            1
+        );
+
+        let expiration_block = 1 + MaxPendingConstitutionalityPeriod::get();
+
+        run_to_block(expiration_block);
+
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::PendingConstitutionality(None)
+        );
+
+        run_to_block_and_finalize(expiration_block);
+
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::finalized_successfully(
+                ProposalDecisionStatus::Expired,
+                expiration_block
+            )
+        );
+        assert!(!<PendingConstitutionalityProposalIds<Test>>::exists(
+            proposal_id
+        ));
+        assert_eq!(ProposalsEngine::active_proposal_count(), 0);
+    });
+}
+
+#[test]
+fn create_dummy_proposal_succeeds_with_stake() {
+    initial_test_ext().execute_with(|| {
+        let account_id = 1;
+
+        let required_stake = 200;
+        let parameters_fixture =
+            ProposalParametersFixture::default().with_required_stake(required_stake);
+
+        let dummy_proposal = DummyProposalFixture::default()
+            .with_parameters(parameters_fixture.params())
+            .with_account_id(account_id)
+            .with_stake(200);
+
+        let _imbalance = <Test as stake::Trait>::Currency::deposit_creating(&account_id, 500);
+
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+
+        assert_eq!(
+            proposal,
+            Proposal {
+                parameters: parameters_fixture.params(),
+                proposer_id: 1,
+                created_at: 1,
+                status: ProposalStatus::Active(Some(ActiveStake {
+                    stake_id: 0, // valid stake_id
+                    source_account_id: 1
+                })),
+                title: b"title".to_vec(),
+                description: b"description".to_vec(),
+                voting_results: VotingResults::default(),
+                exactly_execute_at: None,
+                activated_at: 1,
+                council_approvals: 0,
+                amendments: Vec::new(),
+            }
+        )
+    });
+}
+
+#[test]
+fn create_dummy_proposal_fail_with_stake_on_empty_account() {
+    initial_test_ext().execute_with(|| {
+        let account_id = 1;
+
+        let required_stake = 200;
+        let parameters_fixture =
+            ProposalParametersFixture::default().with_required_stake(required_stake);
+        let dummy_proposal = DummyProposalFixture::default()
+            .with_parameters(parameters_fixture.params())
+            .with_account_id(account_id)
+            .with_stake(required_stake);
+
+        dummy_proposal
+            .create_proposal_and_assert(Err(Error::Other("too few free funds in account")));
+    });
+}
+
+#[test]
+fn create_proposal_fais_with_invalid_stake_parameters() {
+    initial_test_ext().execute_with(|| {
+        let parameters_fixture = ProposalParametersFixture::default();
+
+        let mut dummy_proposal = DummyProposalFixture::default()
+            .with_parameters(parameters_fixture.params())
+            .with_stake(200);
+
+        dummy_proposal.create_proposal_and_assert(Err(Error::StakeShouldBeEmpty.into()));
+
+        let parameters_fixture_stake_200 = parameters_fixture.with_required_stake(200);
+        dummy_proposal =
+            DummyProposalFixture::default().with_parameters(parameters_fixture_stake_200.params());
+
+        dummy_proposal.create_proposal_and_assert(Err(Error::EmptyStake.into()));
+
+        let parameters_fixture_stake_300 = parameters_fixture.with_required_stake(300);
+        dummy_proposal = DummyProposalFixture::default()
+            .with_parameters(parameters_fixture_stake_300.params())
+            .with_stake(200);
+
+        dummy_proposal.create_proposal_and_assert(Err(Error::StakeIsBelowMinimum.into()));
+    });
+}
+
+#[test]
+fn finalize_expired_proposal_and_check_stake_removing_with_balance_checks_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let account_id = 1;
+
+        let stake_amount = 200;
+        let parameters = ProposalParameters {
+            voting_period: 3,
+            approval_quorum_percentage: 50,
+            approval_threshold_percentage: 60,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 60,
+            grace_period: 5,
+            required_stake: Some(stake_amount),
+            voting_mode: VotingMode::Headcount,
+            constitutionality: 1,
+            execution_retry_policy: None,
+        };
+        let dummy_proposal = DummyProposalFixture::default()
+            .with_parameters(parameters)
+            .with_account_id(account_id)
+            .with_stake(stake_amount);
+
+        let account_balance = 500;
+        let _imbalance =
+            <Test as stake::Trait>::Currency::deposit_creating(&account_id, account_balance);
+
+        assert_eq!(
+            <Test as stake::Trait>::Currency::total_balance(&account_id),
+            account_balance
+        );
+
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+        assert_eq!(
+            <Test as stake::Trait>::Currency::total_balance(&account_id),
+            account_balance - stake_amount
+        );
+
+        let mut proposal = <crate::Proposals<Test>>::get(proposal_id);
+
+        let mut expected_proposal = Proposal {
+            parameters,
+            proposer_id: 1,
+            created_at: 1,
+            status: ProposalStatus::Active(Some(ActiveStake {
+                stake_id: 0,
+                source_account_id: 1,
+            })),
+            title: b"title".to_vec(),
+            description: b"description".to_vec(),
+            voting_results: VotingResults::default(),
+            exactly_execute_at: None,
+            activated_at: 1,
+            council_approvals: 0,
+            amendments: Vec::new(),
+        };
+
+        assert_eq!(proposal, expected_proposal);
+
+        run_to_block_and_finalize(5);
+
+        proposal = <crate::Proposals<Test>>::get(proposal_id);
+
+        expected_proposal.status = ProposalStatus::Finalized(FinalizationData {
+            proposal_status: ProposalDecisionStatus::Expired,
+            finalized_at: 4,
+            encoded_unstaking_error_due_to_broken_runtime: None,
+            stake_data_after_unstaking_error: None,
+        });
+
+        assert_eq!(proposal, expected_proposal);
+
+        let rejection_fee = RejectionFee::get();
+        assert_eq!(
+            <Test as stake::Trait>::Currency::total_balance(&account_id),
+            account_balance - rejection_fee
+        );
+    });
+}
+
+#[test]
+fn proposal_cancellation_with_slashes_with_balance_checks_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let account_id = 1;
+
+        let stake_amount = 200;
+        let parameters = ProposalParameters {
+            voting_period: 3,
+            approval_quorum_percentage: 50,
+            approval_threshold_percentage: 60,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 60,
+            grace_period: 5,
+            required_stake: Some(stake_amount),
+            voting_mode: VotingMode::Headcount,
+            constitutionality: 1,
+            execution_retry_policy: None,
+        };
+        let dummy_proposal = DummyProposalFixture::default()
+            .with_parameters(parameters)
+            .with_account_id(account_id.clone())
+            .with_stake(stake_amount);
+
+        let account_balance = 500;
+        let _imbalance =
+            <Test as stake::Trait>::Currency::deposit_creating(&account_id, account_balance);
+
+        assert_eq!(
+            <Test as stake::Trait>::Currency::total_balance(&account_id),
+            account_balance
+        );
+
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+        assert_eq!(
+            <Test as stake::Trait>::Currency::total_balance(&account_id),
+            account_balance - stake_amount
+        );
+
+        let mut proposal = <crate::Proposals<Test>>::get(proposal_id);
+
+        let mut expected_proposal = Proposal {
+            parameters,
+            proposer_id: 1,
+            created_at: 1,
+            status: ProposalStatus::Active(Some(ActiveStake {
+                stake_id: 0,
+                source_account_id: 1,
+            })),
+            title: b"title".to_vec(),
+            description: b"description".to_vec(),
+            voting_results: VotingResults::default(),
+            exactly_execute_at: None,
+            activated_at: 1,
+            council_approvals: 0,
+            amendments: Vec::new(),
+        };
+
+        assert_eq!(proposal, expected_proposal);
+
+        let cancel_proposal_fixture = CancelProposalFixture::new(proposal_id);
+
+        cancel_proposal_fixture.cancel_and_assert(Ok(()));
+
+        proposal = <crate::Proposals<Test>>::get(proposal_id);
+
+        expected_proposal.status = ProposalStatus::Finalized(FinalizationData {
+            proposal_status: ProposalDecisionStatus::Canceled,
+            finalized_at: 1,
+            encoded_unstaking_error_due_to_broken_runtime: None,
+            stake_data_after_unstaking_error: None,
+        });
+
+        assert_eq!(proposal, expected_proposal);
+
+        let cancellation_fee = CancellationFee::get();
+        assert_eq!(
+            <Test as stake::Trait>::Currency::total_balance(&account_id),
+            account_balance - cancellation_fee
+        );
+    });
+}
+
+#[test]
+fn finalize_proposal_using_stake_mocks_succeeds() {
+    handle_mock(|| {
+        initial_test_ext().execute_with(|| {
+            let mock = {
+                let mut mock = crate::types::MockStakeHandler::<Test>::new();
+                mock.expect_create_stake().times(1).returning(|| Ok(1));
+
+                mock.expect_make_stake_imbalance()
+                    .times(1)
+                    .returning(|_, _| Ok(crate::types::NegativeImbalance::<Test>::new(200)));
+
+                mock.expect_stake().times(1).returning(|_, _| Ok(()));
+
+                mock.expect_remove_stake().times(1).returning(|_| Ok(()));
+
+                mock.expect_unstake().times(1).returning(|_| Ok(()));
+
+                mock.expect_slash().times(1).returning(|_, _| Ok(()));
+
+                Rc::new(mock)
+            };
+            set_stake_handler_impl(mock.clone());
+
+            let account_id = 1;
+
+            let stake_amount = 200;
+            let parameters_fixture =
+                ProposalParametersFixture::default().with_required_stake(stake_amount);
+            let dummy_proposal = DummyProposalFixture::default()
+                .with_parameters(parameters_fixture.params())
+                .with_account_id(account_id)
+                .with_stake(stake_amount);
+
+            let _proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+            run_to_block_and_finalize(5);
+        });
+    });
+}
+
+#[test]
+fn proposal_slashing_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Slash);
+        vote_generator.vote_and_assert_ok(VoteKind::Slash);
+        vote_generator.vote_and_assert_ok(VoteKind::Slash);
+
+        assert!(<ActiveProposalIds<Test>>::exists(proposal_id));
+
+        run_to_block_and_finalize(2);
+
+        let proposal = <Proposals<Test>>::get(proposal_id);
+
+        assert_eq!(
+            proposal.voting_results,
+            VotingResults {
+                abstentions: 0,
+                approvals: 0,
+                rejections: 1,
+                slashes: 3,
+            }
+        );
+
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::Finalized(FinalizationData {
+                proposal_status: ProposalDecisionStatus::Slashed,
+                encoded_unstaking_error_due_to_broken_runtime: None,
+                finalized_at: 1,
+                stake_data_after_unstaking_error: None,
+            }),
+        );
+        assert!(!<ActiveProposalIds<Test>>::exists(proposal_id));
+    });
+}
+
+#[test]
+fn finalize_proposal_using_stake_mocks_failed() {
+    handle_mock(|| {
+        initial_test_ext().execute_with(|| {
+            let mock = {
+                let mut mock = crate::types::MockStakeHandler::<Test>::new();
+                mock.expect_create_stake().times(1).returning(|| Ok(1));
+
+                mock.expect_remove_stake()
+                    .times(1)
+                    .returning(|_| Err("Cannot remove stake"));
+
+                mock.expect_make_stake_imbalance()
+                    .times(1)
+                    .returning(|_, _| Ok(crate::types::NegativeImbalance::<Test>::new(200)));
+
+                mock.expect_stake().times(1).returning(|_, _| Ok(()));
+
+                mock.expect_unstake().times(1).returning(|_| Ok(()));
+
+                mock.expect_slash().times(1).returning(|_, _| Ok(()));
+
+                Rc::new(mock)
+            };
+            set_stake_handler_impl(mock.clone());
+
+            let account_id = 1;
+
+            let stake_amount = 200;
+            let parameters_fixture =
+                ProposalParametersFixture::default().with_required_stake(stake_amount);
+            let dummy_proposal = DummyProposalFixture::default()
+                .with_parameters(parameters_fixture.params())
+                .with_account_id(account_id)
+                .with_stake(stake_amount);
+
+            let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+            run_to_block_and_finalize(5);
+
+            let proposal = <Proposals<Test>>::get(proposal_id);
+            assert_eq!(
+                proposal,
+                Proposal {
+                    parameters: parameters_fixture.params(),
+                    proposer_id: 1,
+                    created_at: 1,
+                    status: ProposalStatus::finalized(
+                        ProposalDecisionStatus::Expired,
+                        Some("Cannot remove stake"),
+                        Some(ActiveStake {
+                            stake_id: 1,
+                            source_account_id: 1
+                        }),
+                        4,
+                    ),
+                    title: b"title".to_vec(),
+                    description: b"description".to_vec(),
+                    voting_results: VotingResults::default(),
+                    exactly_execute_at: None,
+                    activated_at: 1,
+                    council_approvals: 0,
+                    amendments: Vec::new(),
+                }
+            );
+        });
+    });
+}
+
+#[test]
+fn create_proposal_fails_with_invalid_threshold_parameters() {
+    initial_test_ext().execute_with(|| {
+        let mut parameters = ProposalParameters {
+            voting_period: 3,
+            approval_quorum_percentage: 50,
+            approval_threshold_percentage: 0,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 60,
+            grace_period: 5,
+            required_stake: None,
+            voting_mode: VotingMode::Headcount,
+            constitutionality: 1,
+            execution_retry_policy: None,
+        };
+
+        let mut dummy_proposal = DummyProposalFixture::default().with_parameters(parameters);
+
+        dummy_proposal
+            .create_proposal_and_assert(Err(Error::InvalidParameterApprovalThreshold.into()));
+
+        parameters.approval_threshold_percentage = 60;
+        parameters.slashing_threshold_percentage = 0;
+        dummy_proposal = DummyProposalFixture::default().with_parameters(parameters);
+
+        dummy_proposal
+            .create_proposal_and_assert(Err(Error::InvalidParameterSlashingThreshold.into()));
+    });
+}
+
+#[test]
+fn proposal_reset_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Abstain);
+        vote_generator.vote_and_assert_ok(VoteKind::Slash);
+
+        assert!(<ActiveProposalIds<Test>>::exists(proposal_id));
+        assert_eq!(
+            <VoteExistsByProposalByVoter<Test>>::get(&proposal_id, &2),
+            VoteKind::Abstain
+        );
+
+        run_to_block_and_finalize(2);
+
+        let proposal = <Proposals<Test>>::get(proposal_id);
+
+        assert_eq!(
+            proposal.voting_results,
+            VotingResults {
+                abstentions: 1,
+                approvals: 0,
+                rejections: 1,
+                slashes: 1,
+            }
+        );
+
+        ProposalsEngine::reset_active_proposals();
+
+        let updated_proposal = <Proposals<Test>>::get(proposal_id);
+
+        assert_eq!(
+            updated_proposal.voting_results,
+            VotingResults {
+                abstentions: 0,
+                approvals: 0,
+                rejections: 0,
+                slashes: 0,
+            }
+        );
+
+        // whole double map prefix was removed (should return default value)
+        assert_eq!(
+            <VoteExistsByProposalByVoter<Test>>::get(&proposal_id, &2),
+            VoteKind::default()
+        );
+
+        // vote records were removed
+        assert!(ProposalsEngine::votes_by_proposal(proposal_id).is_empty());
+    });
+}
+
+#[test]
+fn proposal_counters_are_valid() {
+    initial_test_ext().execute_with(|| {
+        let mut dummy_proposal = DummyProposalFixture::default();
+        let _ = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        dummy_proposal = DummyProposalFixture::default();
+        let _ = dummy_proposal.create_proposal_and_assert(Ok(2)).unwrap();
+
+        dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(3)).unwrap();
+
+        assert_eq!(ActiveProposalCount::get(), 3);
+        assert_eq!(ProposalCount::get(), 3);
+
+        let cancel_proposal_fixture = CancelProposalFixture::new(proposal_id);
+        cancel_proposal_fixture.cancel_and_assert(Ok(()));
+
+        assert_eq!(ActiveProposalCount::get(), 2);
+        assert_eq!(ProposalCount::get(), 3);
+    });
+}
+
+#[test]
+fn proposal_stake_cache_is_valid() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 50000);
+
+        let stake = 250u32;
+        let parameters = ProposalParametersFixture::default().with_required_stake(stake.into());
+        let dummy_proposal = DummyProposalFixture::default()
+            .with_parameters(parameters.params())
+            .with_stake(stake as u64);
+
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+        let expected_stake_id = 0;
+        assert_eq!(
+            <StakesProposals<Test>>::get(&expected_stake_id),
+            proposal_id
+        );
+    });
+}
+
+#[test]
+fn slash_balance_is_calculated_correctly() {
+    initial_test_ext().execute_with(|| {
+        let vetoed_slash_balance = ProposalsEngine::calculate_slash_balance(
+            &ProposalDecisionStatus::Vetoed,
+            &ProposalParametersFixture::default().params(),
+        );
+
+        assert_eq!(vetoed_slash_balance, 0);
+
+        let approved_slash_balance = ProposalsEngine::calculate_slash_balance(
+            &ProposalDecisionStatus::Approved(ApprovedProposalStatus::Executed),
+            &ProposalParametersFixture::default().params(),
+        );
+
+        assert_eq!(approved_slash_balance, 0);
+
+        let rejection_fee = <Test as crate::Trait>::RejectionFee::get();
+
+        let rejected_slash_balance = ProposalsEngine::calculate_slash_balance(
+            &ProposalDecisionStatus::Rejected,
+            &ProposalParametersFixture::default().params(),
+        );
+
+        assert_eq!(rejected_slash_balance, rejection_fee);
+
+        let expired_slash_balance = ProposalsEngine::calculate_slash_balance(
+            &ProposalDecisionStatus::Expired,
+            &ProposalParametersFixture::default().params(),
+        );
+
+        assert_eq!(expired_slash_balance, rejection_fee);
+
+        let cancellation_fee = <Test as crate::Trait>::CancellationFee::get();
+
+        let cancellation_slash_balance = ProposalsEngine::calculate_slash_balance(
+            &ProposalDecisionStatus::Canceled,
+            &ProposalParametersFixture::default().params(),
+        );
+
+        assert_eq!(cancellation_slash_balance, cancellation_fee);
+
+        let slash_balance_with_no_stake = ProposalsEngine::calculate_slash_balance(
+            &ProposalDecisionStatus::Slashed,
+            &ProposalParametersFixture::default().params(),
+        );
+
+        assert_eq!(slash_balance_with_no_stake, 0);
+
+        let stake = 256;
+        let slash_balance_with_stake = ProposalsEngine::calculate_slash_balance(
+            &ProposalDecisionStatus::Slashed,
+            &ProposalParametersFixture::default()
+                .with_required_stake(stake)
+                .params(),
+        );
+
+        assert_eq!(slash_balance_with_stake, stake);
+    });
+}
+
+#[test]
+fn amend_proposal_resets_votes_and_restarts_voting_period() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block(2);
+
+        let amended_code = mock::proposals::Call::<Test>::dummy_proposal(
+            b"amended title".to_vec(),
+            b"amended description".to_vec(),
+        )
+        .encode();
+
+        assert_eq!(
+            ProposalsEngine::amend_proposal(1, proposal_id, amended_code.clone()),
+            Ok(())
+        );
+
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+        assert_eq!(proposal.voting_results, VotingResults::default());
+        assert_eq!(proposal.activated_at, 2);
+        assert_eq!(
+            proposal.amendments,
+            vec![ProposalAmendment {
+                amended_at: 2,
+                discarded_voting_results: VotingResults {
+                    abstentions: 0,
+                    approvals: 2,
+                    rejections: 0,
+                    slashes: 0,
+                },
+            }]
+        );
+        assert_eq!(ProposalsEngine::proposal_codes(proposal_id), amended_code);
+        assert!(!<VoteExistsByProposalByVoter<Test>>::exists(proposal_id, 1));
+        assert!(ProposalsEngine::votes_by_proposal(proposal_id).is_empty());
+
+        // voting period of the created proposal would expire at the block 4
+        run_to_block_and_finalize(4);
+
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+        assert_eq!(proposal.status, ProposalStatus::Active(None));
+
+        // previous voters can vote again
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+    });
+}
+
+#[test]
+fn amend_proposal_fails_with_invalid_proposal() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        assert_eq!(
+            ProposalsEngine::amend_proposal(1, 2, Vec::new()),
+            Err(Error::ProposalNotFound)
+        );
+
+        assert_eq!(
+            ProposalsEngine::amend_proposal(2, proposal_id, Vec::new()),
+            Err(Error::NotAuthor)
+        );
+
+        let cancel_proposal = CancelProposalFixture::new(proposal_id);
+        cancel_proposal.cancel_and_assert(Ok(()));
+
+        assert_eq!(
+            ProposalsEngine::amend_proposal(1, proposal_id, Vec::new()),
+            Err(Error::ProposalFinalized)
+        );
+    });
+}
+
+#[test]
+fn amend_proposal_fails_with_exceeded_amendments_limit() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        for _ in 0..MaxProposalAmendments::get() {
+            assert_eq!(
+                ProposalsEngine::amend_proposal(1, proposal_id, Vec::new()),
+                Ok(())
+            );
+        }
+
+        assert_eq!(
+            ProposalsEngine::amend_proposal(1, proposal_id, Vec::new()),
+            Err(Error::MaxProposalAmendmentsExceeded)
+        );
+    });
+}
+
+#[test]
+fn amend_constitutional_proposal_resets_council_approvals() {
+    initial_test_ext().execute_with(|| {
+        let parameters_fixture = ProposalParametersFixture::default().with_constitutionality(2);
+        let dummy_proposal =
+            DummyProposalFixture::default().with_parameters(parameters_fixture.params());
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(1);
+
+        ProposalsEngine::reset_active_proposals();
+
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+        assert_eq!(proposal.status, ProposalStatus::Active(None));
+        assert_eq!(proposal.council_approvals, 1);
+
+        assert_eq!(
+            ProposalsEngine::amend_proposal(1, proposal_id, Vec::new()),
+            Ok(())
+        );
+
+        // the amended proposal requires approvals of all councils again
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+        assert_eq!(proposal.council_approvals, 0);
+    });
+}
+
+#[test]
+fn amend_batch_proposal_fails_with_empty_calls() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let dummy_call = mock::proposals::Call::<Test>::dummy_proposal(
+            b"title".to_vec(),
+            b"description".to_vec(),
+        );
+        let proposal_id = dummy_proposal
+            .create_batch_proposal_and_assert(vec![dummy_call.encode()], Ok(1))
+            .unwrap();
 
-        dummy_proposal.create_proposal_and_assert(Err(Error::StakeDiffersFromRequired.into()));
+        assert_eq!(
+            ProposalsEngine::amend_batch_proposal(1, proposal_id, Vec::new()),
+            Err(Error::EmptyBatchProposal)
+        );
     });
 }
 
 #[test]
-fn finalize_expired_proposal_and_check_stake_removing_with_balance_checks_succeeds() {
+fn create_proposal_with_partial_stake_waits_for_sponsorship() {
     initial_test_ext().execute_with(|| {
-        let account_id = 1;
+        increase_total_balance_issuance_using_account_id(1, 500);
 
-        let stake_amount = 200;
-        let parameters = ProposalParameters {
-            voting_period: 3,
-            approval_quorum_percentage: 50,
-            approval_threshold_percentage: 60,
-            slashing_quorum_percentage: 60,
-            slashing_threshold_percentage: 60,
-            grace_period: 5,
-            required_stake: Some(stake_amount),
-        };
+        let parameters_fixture = ProposalParametersFixture::default().with_required_stake(300);
         let dummy_proposal = DummyProposalFixture::default()
-            .with_parameters(parameters)
-            .with_account_id(account_id)
-            .with_stake(stake_amount);
+            .with_parameters(parameters_fixture.params())
+            .with_stake(210);
 
-        let account_balance = 500;
-        let _imbalance =
-            <Test as stake::Trait>::Currency::deposit_creating(&account_id, account_balance);
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
         assert_eq!(
-            <Test as stake::Trait>::Currency::total_balance(&account_id),
-            account_balance
+            proposal.status,
+            ProposalStatus::PendingSponsorship(Some(ActiveStake {
+                stake_id: 0,
+                source_account_id: 1
+            }))
         );
-
-        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+        assert!(<PendingSponsorshipProposalIds<Test>>::exists(proposal_id));
+        assert!(!<ActiveProposalIds<Test>>::exists(proposal_id));
+        assert_eq!(<ActiveProposalCount>::get(), 1);
         assert_eq!(
-            <Test as stake::Trait>::Currency::total_balance(&account_id),
-            account_balance - stake_amount
+            ProposalsEngine::stake_contributions(proposal_id),
+            vec![StakeContribution {
+                member_id: 1,
+                account_id: 1,
+                amount: 210,
+            }]
         );
 
-        let mut proposal = <crate::Proposals<Test>>::get(proposal_id);
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert(VoteKind::Approve, Err(Error::ProposalFinalized));
+    });
+}
 
-        let mut expected_proposal = Proposal {
-            parameters,
-            proposer_id: 1,
-            created_at: 1,
-            status: ProposalStatus::Active(Some(ActiveStake {
-                stake_id: 0,
-                source_account_id: 1,
-            })),
-            title: b"title".to_vec(),
-            description: b"description".to_vec(),
-            voting_results: VotingResults::default(),
-        };
+#[test]
+fn sponsor_proposal_activates_fully_staked_proposal() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500);
+        increase_total_balance_issuance_using_account_id(2, 500);
 
-        assert_eq!(proposal, expected_proposal);
+        let parameters_fixture = ProposalParametersFixture::default().with_required_stake(300);
+        let dummy_proposal = DummyProposalFixture::default()
+            .with_parameters(parameters_fixture.params())
+            .with_stake(210);
 
-        run_to_block_and_finalize(5);
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
-        proposal = <crate::Proposals<Test>>::get(proposal_id);
+        run_to_block(2);
 
-        expected_proposal.status = ProposalStatus::Finalized(FinalizationData {
-            proposal_status: ProposalDecisionStatus::Expired,
-            finalized_at: 4,
-            encoded_unstaking_error_due_to_broken_runtime: None,
-            stake_data_after_unstaking_error: None,
-        });
+        SponsorProposalFixture::new(proposal_id, 40).sponsor_and_assert(Ok(()));
+        assert!(<PendingSponsorshipProposalIds<Test>>::exists(proposal_id));
 
-        assert_eq!(proposal, expected_proposal);
+        SponsorProposalFixture::new(proposal_id, 50).sponsor_and_assert(Ok(()));
 
-        let rejection_fee = RejectionFee::get();
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+        let active_status = ProposalStatus::Active(Some(ActiveStake {
+            stake_id: 0,
+            source_account_id: 1,
+        }));
+        assert_eq!(proposal.status, active_status);
+        assert_eq!(proposal.activated_at, 2);
+        assert!(!<PendingSponsorshipProposalIds<Test>>::exists(proposal_id));
+        assert!(<ActiveProposalIds<Test>>::exists(proposal_id));
         assert_eq!(
-            <Test as stake::Trait>::Currency::total_balance(&account_id),
-            account_balance - rejection_fee
+            <Test as stake::Trait>::Currency::total_balance(&2),
+            500 - 40 - 50
         );
+
+        EventFixture::assert_events(vec![
+            RawEvent::ProposalCreated(1, 1),
+            RawEvent::ProposalSponsored(2, 1, 40),
+            RawEvent::ProposalSponsored(2, 1, 50),
+            RawEvent::ProposalStatusUpdated(1, active_status),
+        ]);
     });
 }
 
 #[test]
-fn proposal_cancellation_with_slashes_with_balance_checks_succeeds() {
+fn sponsor_proposal_fails_with_invalid_parameters() {
     initial_test_ext().execute_with(|| {
-        let account_id = 1;
-
-        let stake_amount = 200;
-        let parameters = ProposalParameters {
-            voting_period: 3,
-            approval_quorum_percentage: 50,
-            approval_threshold_percentage: 60,
-            slashing_quorum_percentage: 60,
-            slashing_threshold_percentage: 60,
-            grace_period: 5,
-            required_stake: Some(stake_amount),
-        };
-        let dummy_proposal = DummyProposalFixture::default()
-            .with_parameters(parameters)
-            .with_account_id(account_id.clone())
-            .with_stake(stake_amount);
+        increase_total_balance_issuance_using_account_id(1, 500);
+        increase_total_balance_issuance_using_account_id(2, 500);
 
-        let account_balance = 500;
-        let _imbalance =
-            <Test as stake::Trait>::Currency::deposit_creating(&account_id, account_balance);
+        SponsorProposalFixture::new(1, 50).sponsor_and_assert(Err(Error::ProposalNotFound));
 
-        assert_eq!(
-            <Test as stake::Trait>::Currency::total_balance(&account_id),
-            account_balance
-        );
+        let fully_staked_parameters = ProposalParametersFixture::default().with_required_stake(100);
+        let fully_staked_proposal_id = DummyProposalFixture::default()
+            .with_parameters(fully_staked_parameters.params())
+            .with_stake(100)
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
 
-        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
-        assert_eq!(
-            <Test as stake::Trait>::Currency::total_balance(&account_id),
-            account_balance - stake_amount
-        );
+        SponsorProposalFixture::new(fully_staked_proposal_id, 50)
+            .sponsor_and_assert(Err(Error::ProposalIsNotPendingSponsorship));
 
-        let mut proposal = <crate::Proposals<Test>>::get(proposal_id);
+        let parameters_fixture = ProposalParametersFixture::default().with_required_stake(300);
+        let proposal_id = DummyProposalFixture::default()
+            .with_parameters(parameters_fixture.params())
+            .with_stake(210)
+            .create_proposal_and_assert(Ok(2))
+            .unwrap();
 
-        let mut expected_proposal = Proposal {
-            parameters,
-            proposer_id: 1,
-            created_at: 1,
-            status: ProposalStatus::Active(Some(ActiveStake {
-                stake_id: 0,
-                source_account_id: 1,
-            })),
-            title: b"title".to_vec(),
-            description: b"description".to_vec(),
-            voting_results: VotingResults::default(),
-        };
+        SponsorProposalFixture::new(proposal_id, 0).sponsor_and_assert(Err(Error::EmptyStake));
+        SponsorProposalFixture::new(proposal_id, 91)
+            .sponsor_and_assert(Err(Error::SponsorStakeExceedsRequired));
+    });
+}
 
-        assert_eq!(proposal, expected_proposal);
+#[test]
+fn create_proposal_fails_with_stake_less_than_min_proposer_stake() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500);
 
-        let cancel_proposal_fixture = CancelProposalFixture::new(proposal_id);
+        let parameters_fixture = ProposalParametersFixture::default().with_required_stake(300);
+        DummyProposalFixture::default()
+            .with_parameters(parameters_fixture.params())
+            .with_stake(209)
+            .create_proposal_and_assert(Err(Error::StakeIsBelowMinimum));
 
-        cancel_proposal_fixture.cancel_and_assert(Ok(()));
+        DummyProposalFixture::default()
+            .with_parameters(parameters_fixture.params())
+            .with_stake(301)
+            .create_proposal_and_assert(Err(Error::StakeDiffersFromRequired));
+    });
+}
 
-        proposal = <crate::Proposals<Test>>::get(proposal_id);
+#[test]
+fn pending_sponsorship_proposal_expires_after_voting_period() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500);
 
-        expected_proposal.status = ProposalStatus::Finalized(FinalizationData {
-            proposal_status: ProposalDecisionStatus::Canceled,
-            finalized_at: 1,
-            encoded_unstaking_error_due_to_broken_runtime: None,
-            stake_data_after_unstaking_error: None,
-        });
+        let parameters_fixture = ProposalParametersFixture::default().with_required_stake(300);
+        let proposal_id = DummyProposalFixture::default()
+            .with_parameters(parameters_fixture.params())
+            .with_stake(210)
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
 
-        assert_eq!(proposal, expected_proposal);
+        run_to_block_and_finalize(5);
 
-        let cancellation_fee = CancellationFee::get();
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
         assert_eq!(
-            <Test as stake::Trait>::Currency::total_balance(&account_id),
-            account_balance - cancellation_fee
+            proposal.status,
+            ProposalStatus::finalized_successfully(ProposalDecisionStatus::Expired, 4)
         );
+        assert!(!<PendingSponsorshipProposalIds<Test>>::exists(proposal_id));
+        assert!(!<StakeContributions<Test>>::exists(proposal_id));
+        assert_eq!(<ActiveProposalCount>::get(), 0);
     });
 }
 
 #[test]
-fn finalize_proposal_using_stake_mocks_succeeds() {
-    handle_mock(|| {
-        initial_test_ext().execute_with(|| {
-            let mock = {
-                let mut mock = crate::types::MockStakeHandler::<Test>::new();
-                mock.expect_create_stake().times(1).returning(|| Ok(1));
+fn refund_proposal_stake_splits_refund_pro_rata() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500);
+        increase_total_balance_issuance_using_account_id(2, 500);
 
-                mock.expect_make_stake_imbalance()
-                    .times(1)
-                    .returning(|_, _| Ok(crate::types::NegativeImbalance::<Test>::new(200)));
+        let parameters_fixture = ProposalParametersFixture::default().with_required_stake(300);
+        let proposal_id = DummyProposalFixture::default()
+            .with_parameters(parameters_fixture.params())
+            .with_stake(210)
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
 
-                mock.expect_stake().times(1).returning(|_, _| Ok(()));
+        SponsorProposalFixture::new(proposal_id, 90).sponsor_and_assert(Ok(()));
 
-                mock.expect_remove_stake().times(1).returning(|_| Ok(()));
+        let stake_id = 0;
+        ProposalsEngine::refund_proposal_stake(stake_id, NegativeImbalance::<Test>::new(100));
 
-                mock.expect_unstake().times(1).returning(|_| Ok(()));
+        assert_eq!(
+            <Test as stake::Trait>::Currency::total_balance(&1),
+            500 - 210 + 70
+        );
+        assert_eq!(
+            <Test as stake::Trait>::Currency::total_balance(&2),
+            500 - 90 + 30
+        );
+    });
+}
 
-                mock.expect_slash().times(1).returning(|_, _| Ok(()));
+#[test]
+fn finalized_proposal_is_replaced_with_outcome_after_retention_period() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
-                Rc::new(mock)
-            };
-            set_stake_handler_impl(mock.clone());
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
 
-            let account_id = 1;
+        let cancel_proposal = CancelProposalFixture::new(proposal_id);
+        cancel_proposal.cancel_and_assert(Ok(()));
 
-            let stake_amount = 200;
-            let parameters_fixture =
-                ProposalParametersFixture::default().with_required_stake(stake_amount);
-            let dummy_proposal = DummyProposalFixture::default()
-                .with_parameters(parameters_fixture.params())
-                .with_account_id(account_id)
-                .with_stake(stake_amount);
+        assert!(!<DispatchableCallCode<Test>>::exists(proposal_id));
+        assert_eq!(ProposalsEngine::finalized_proposal_ids(), vec![proposal_id]);
+        assert!(<crate::Proposals<Test>>::exists(proposal_id));
 
-            let _proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+        let retention_period = FinalizedProposalRetentionPeriod::get();
+        run_to_block_and_finalize(1 + retention_period);
 
-            run_to_block_and_finalize(5);
-        });
+        assert!(!<crate::Proposals<Test>>::exists(proposal_id));
+        assert!(!<VoteExistsByProposalByVoter<Test>>::exists(
+            &proposal_id,
+            &1
+        ));
+        assert_eq!(
+            ProposalsEngine::proposal_outcomes(proposal_id),
+            Some(ProposalOutcome {
+                proposer_id: 1,
+                created_at: 1,
+                finalized_at: 1,
+                decision_status: ProposalDecisionStatus::Canceled,
+            })
+        );
+        assert_eq!(ProposalsEngine::finalized_proposal_ids(), vec![proposal_id]);
     });
 }
 
 #[test]
-fn proposal_slashing_succeeds() {
+fn proposal_code_is_removed_after_execution() {
     initial_test_ext().execute_with(|| {
-        let dummy_proposal = DummyProposalFixture::default();
+        let parameters_fixture = ProposalParametersFixture::default().with_grace_period(2);
+        let dummy_proposal =
+            DummyProposalFixture::default().with_parameters(parameters_fixture.params());
         let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
         let mut vote_generator = VoteGenerator::new(proposal_id);
-        vote_generator.vote_and_assert_ok(VoteKind::Reject);
-        vote_generator.vote_and_assert_ok(VoteKind::Slash);
-        vote_generator.vote_and_assert_ok(VoteKind::Slash);
-        vote_generator.vote_and_assert_ok(VoteKind::Slash);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
 
-        assert!(<ActiveProposalIds<Test>>::exists(proposal_id));
+        run_to_block_and_finalize(1);
 
-        run_to_block_and_finalize(2);
+        // approved proposal waits for the execution
+        assert!(<DispatchableCallCode<Test>>::exists(proposal_id));
+        assert_eq!(ProposalsEngine::finalized_proposal_ids(), vec![proposal_id]);
 
-        let proposal = <Proposals<Test>>::get(proposal_id);
+        run_to_block_and_finalize(3);
 
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
         assert_eq!(
-            proposal.voting_results,
-            VotingResults {
-                abstentions: 0,
-                approvals: 0,
-                rejections: 1,
-                slashes: 3,
-            }
+            proposal.status,
+            ProposalStatus::approved(ApprovedProposalStatus::Executed, 1)
         );
+        assert!(!<DispatchableCallCode<Test>>::exists(proposal_id));
+
+        let retention_period = FinalizedProposalRetentionPeriod::get();
+        run_to_block_and_finalize(3 + retention_period);
 
+        assert!(!<crate::Proposals<Test>>::exists(proposal_id));
         assert_eq!(
-            proposal.status,
-            ProposalStatus::Finalized(FinalizationData {
-                proposal_status: ProposalDecisionStatus::Slashed,
-                encoded_unstaking_error_due_to_broken_runtime: None,
+            ProposalsEngine::proposal_outcomes(proposal_id),
+            Some(ProposalOutcome {
+                proposer_id: 1,
+                created_at: 1,
                 finalized_at: 1,
-                stake_data_after_unstaking_error: None,
-            }),
+                decision_status: ProposalDecisionStatus::Approved(ApprovedProposalStatus::Executed),
+            })
         );
-        assert!(!<ActiveProposalIds<Test>>::exists(proposal_id));
     });
 }
 
 #[test]
-fn finalize_proposal_using_stake_mocks_failed() {
-    handle_mock(|| {
-        initial_test_ext().execute_with(|| {
-            let mock = {
-                let mut mock = crate::types::MockStakeHandler::<Test>::new();
-                mock.expect_create_stake().times(1).returning(|| Ok(1));
-
-                mock.expect_remove_stake()
-                    .times(1)
-                    .returning(|_| Err("Cannot remove stake"));
-
-                mock.expect_make_stake_imbalance()
-                    .times(1)
-                    .returning(|_, _| Ok(crate::types::NegativeImbalance::<Test>::new(200)));
-
-                mock.expect_stake().times(1).returning(|_, _| Ok(()));
-
-                mock.expect_unstake().times(1).returning(|_| Ok(()));
-
-                mock.expect_slash().times(1).returning(|_, _| Ok(()));
+fn voting_participation_counts_ended_voting_rounds() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let voted_proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
-                Rc::new(mock)
-            };
-            set_stake_handler_impl(mock.clone());
+        let mut vote_generator = VoteGenerator::new(voted_proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
 
-            let account_id = 1;
+        // canceled and vetoed proposals end without the voting round
+        let dummy_proposal = DummyProposalFixture::default();
+        let canceled_proposal_id = dummy_proposal.create_proposal_and_assert(Ok(2)).unwrap();
+        let mut vote_generator = VoteGenerator::new(canceled_proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        CancelProposalFixture::new(canceled_proposal_id).cancel_and_assert(Ok(()));
 
-            let stake_amount = 200;
-            let parameters_fixture =
-                ProposalParametersFixture::default().with_required_stake(stake_amount);
-            let dummy_proposal = DummyProposalFixture::default()
-                .with_parameters(parameters_fixture.params())
-                .with_account_id(account_id)
-                .with_stake(stake_amount);
+        let dummy_proposal = DummyProposalFixture::default();
+        let vetoed_proposal_id = dummy_proposal.create_proposal_and_assert(Ok(3)).unwrap();
+        let mut vote_generator = VoteGenerator::new(vetoed_proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        VetoProposalFixture::new(vetoed_proposal_id).veto_and_assert(Ok(()));
 
-            let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+        assert_eq!(ProposalsEngine::voting_participation(&[1]), (0, 0));
 
-            run_to_block_and_finalize(5);
+        run_to_block_and_finalize(2);
 
-            let proposal = <Proposals<Test>>::get(proposal_id);
-            assert_eq!(
-                proposal,
-                Proposal {
-                    parameters: parameters_fixture.params(),
-                    proposer_id: 1,
-                    created_at: 1,
-                    status: ProposalStatus::finalized(
-                        ProposalDecisionStatus::Expired,
-                        Some("Cannot remove stake"),
-                        Some(ActiveStake {
-                            stake_id: 1,
-                            source_account_id: 1
-                        }),
-                        4,
-                    ),
-                    title: b"title".to_vec(),
-                    description: b"description".to_vec(),
-                    voting_results: VotingResults::default(),
-                }
-            );
-        });
+        assert_eq!(ProposalsEngine::voting_participation(&[1]), (1, 1));
+        assert_eq!(ProposalsEngine::voting_participation(&[5]), (0, 1));
+        assert_eq!(ProposalsEngine::voting_participation(&[1, 2]), (2, 1));
     });
 }
 
 #[test]
-fn create_proposal_fails_with_invalid_threshold_parameters() {
+fn finalized_proposal_ids_index_is_bounded() {
     initial_test_ext().execute_with(|| {
-        let mut parameters = ProposalParameters {
-            voting_period: 3,
-            approval_quorum_percentage: 50,
-            approval_threshold_percentage: 0,
-            slashing_quorum_percentage: 60,
-            slashing_threshold_percentage: 60,
-            grace_period: 5,
-            required_stake: None,
-        };
+        let max_finalized_proposal_ids = MaxFinalizedProposalIds::get();
 
-        let mut dummy_proposal = DummyProposalFixture::default().with_parameters(parameters);
+        for _ in 0..=max_finalized_proposal_ids {
+            let dummy_proposal = DummyProposalFixture::default();
+            let proposal_id = dummy_proposal
+                .create_proposal_and_assert(Ok(ProposalsEngine::proposal_count() + 1))
+                .unwrap();
 
-        dummy_proposal
-            .create_proposal_and_assert(Err(Error::InvalidParameterApprovalThreshold.into()));
+            let cancel_proposal = CancelProposalFixture::new(proposal_id);
+            cancel_proposal.cancel_and_assert(Ok(()));
+        }
 
-        parameters.approval_threshold_percentage = 60;
-        parameters.slashing_threshold_percentage = 0;
-        dummy_proposal = DummyProposalFixture::default().with_parameters(parameters);
+        let expected_ids: Vec<u32> = (2..=max_finalized_proposal_ids + 1).collect();
+        assert_eq!(ProposalsEngine::finalized_proposal_ids(), expected_ids);
 
-        dummy_proposal
-            .create_proposal_and_assert(Err(Error::InvalidParameterSlashingThreshold.into()));
+        let retention_period = FinalizedProposalRetentionPeriod::get();
+        run_to_block_and_finalize(1 + retention_period);
+
+        assert!(!<crate::Proposals<Test>>::exists(1));
+        assert_eq!(ProposalsEngine::proposal_outcomes(1), None);
+
+        for proposal_id in expected_ids {
+            assert!(ProposalsEngine::proposal_outcomes(proposal_id).is_some());
+        }
     });
 }
 
 #[test]
-fn proposal_reset_succeeds() {
+fn deferred_code_proposal_is_executed_after_providing_the_code() {
     initial_test_ext().execute_with(|| {
         let dummy_proposal = DummyProposalFixture::default();
-        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+        let proposal_code = dummy_proposal.proposal_code.clone();
+        let proposal_id = dummy_proposal
+            .create_deferred_code_proposal_and_assert(Ok(1))
+            .unwrap();
 
-        let mut vote_generator = VoteGenerator::new(proposal_id);
-        vote_generator.vote_and_assert_ok(VoteKind::Reject);
-        vote_generator.vote_and_assert_ok(VoteKind::Abstain);
-        vote_generator.vote_and_assert_ok(VoteKind::Slash);
+        assert!(ProposalsEngine::proposal_codes(proposal_id).is_empty());
 
-        assert!(<ActiveProposalIds<Test>>::exists(proposal_id));
-        assert_eq!(
-            <VoteExistsByProposalByVoter<Test>>::get(&proposal_id, &2),
-            VoteKind::Abstain
-        );
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
 
         run_to_block_and_finalize(2);
 
-        let proposal = <Proposals<Test>>::get(proposal_id);
-
+        // approved proposal waits for the code
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
         assert_eq!(
-            proposal.voting_results,
-            VotingResults {
-                abstentions: 1,
-                approvals: 0,
-                rejections: 1,
-                slashes: 1,
-            }
+            proposal.status,
+            ProposalStatus::approved(ApprovedProposalStatus::PendingExecution, 1)
         );
 
-        ProposalsEngine::reset_active_proposals();
-
-        let updated_proposal = <Proposals<Test>>::get(proposal_id);
-
         assert_eq!(
-            updated_proposal.voting_results,
-            VotingResults {
-                abstentions: 0,
-                approvals: 0,
-                rejections: 0,
-                slashes: 0,
-            }
+            ProposalsEngine::provide_proposal_code(proposal_id, proposal_code),
+            Ok(())
         );
 
-        // whole double map prefix was removed (should return default value)
+        run_to_block_and_finalize(3);
+
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
         assert_eq!(
-            <VoteExistsByProposalByVoter<Test>>::get(&proposal_id, &2),
-            VoteKind::default()
+            proposal.status,
+            ProposalStatus::approved(ApprovedProposalStatus::Executed, 1)
         );
+        assert!(!<PendingExecutionProposalIds<Test>>::exists(proposal_id));
     });
 }
 
 #[test]
-fn proposal_counters_are_valid() {
+fn provide_proposal_code_fails_with_invalid_proposal() {
     initial_test_ext().execute_with(|| {
-        let mut dummy_proposal = DummyProposalFixture::default();
-        let _ = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_code = dummy_proposal.proposal_code.clone();
 
-        dummy_proposal = DummyProposalFixture::default();
-        let _ = dummy_proposal.create_proposal_and_assert(Ok(2)).unwrap();
+        assert_eq!(
+            ProposalsEngine::provide_proposal_code(1, proposal_code.clone()),
+            Err(Error::ProposalNotFound)
+        );
 
-        dummy_proposal = DummyProposalFixture::default();
-        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(3)).unwrap();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+        assert_eq!(
+            ProposalsEngine::provide_proposal_code(proposal_id, proposal_code.clone()),
+            Err(Error::ProposalCodeIsNotDeferred)
+        );
 
-        assert_eq!(ActiveProposalCount::get(), 3);
-        assert_eq!(ProposalCount::get(), 3);
+        let deferred_proposal_id = DummyProposalFixture::default()
+            .create_deferred_code_proposal_and_assert(Ok(2))
+            .unwrap();
+        assert_eq!(
+            ProposalsEngine::provide_proposal_code(deferred_proposal_id, proposal_code.clone()),
+            Err(Error::ProposalIsNotPendingExecution)
+        );
 
-        let cancel_proposal_fixture = CancelProposalFixture::new(proposal_id);
-        cancel_proposal_fixture.cancel_and_assert(Ok(()));
+        let mut vote_generator = VoteGenerator::new(deferred_proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
 
-        assert_eq!(ActiveProposalCount::get(), 2);
-        assert_eq!(ProposalCount::get(), 3);
+        run_to_block_and_finalize(1);
+
+        assert_eq!(
+            ProposalsEngine::provide_proposal_code(deferred_proposal_id, proposal_code.clone()),
+            Ok(())
+        );
+        assert_eq!(
+            ProposalsEngine::provide_proposal_code(deferred_proposal_id, proposal_code),
+            Err(Error::ProposalCodeAlreadyProvided)
+        );
     });
 }
 
 #[test]
-fn proposal_stake_cache_is_valid() {
+fn deferred_code_proposal_fails_on_code_timeout() {
     initial_test_ext().execute_with(|| {
-        increase_total_balance_issuance_using_account_id(1, 50000);
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal
+            .create_deferred_code_proposal_and_assert(Ok(1))
+            .unwrap();
 
-        let stake = 250u32;
-        let parameters = ProposalParametersFixture::default().with_required_stake(stake.into());
-        let dummy_proposal = DummyProposalFixture::default()
-            .with_parameters(parameters.params())
-            .with_stake(stake as u64);
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
 
-        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
-        let expected_stake_id = 0;
+        let code_timeout = DeferredCodeTimeout::get();
+        run_to_block_and_finalize(code_timeout);
+
+        assert!(<PendingExecutionProposalIds<Test>>::exists(proposal_id));
+
+        run_to_block_and_finalize(1 + code_timeout);
+
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
         assert_eq!(
-            <StakesProposals<Test>>::get(&expected_stake_id),
-            proposal_id
+            proposal.status,
+            ProposalStatus::approved(
+                ApprovedProposalStatus::failed_execution("ProposalCodeTimeout"),
+                1
+            )
         );
+        assert!(!<PendingExecutionProposalIds<Test>>::exists(proposal_id));
+        assert!(!<DeferredCodeProposals<Test>>::exists(proposal_id));
     });
 }
 
 #[test]
-fn slash_balance_is_calculated_correctly() {
+fn deferred_code_timeout_counts_from_the_exact_execution_block() {
     initial_test_ext().execute_with(|| {
-        let vetoed_slash_balance = ProposalsEngine::calculate_slash_balance(
-            &ProposalDecisionStatus::Vetoed,
-            &ProposalParametersFixture::default().params(),
-        );
+        let exactly_execute_at = 20;
+        let dummy_proposal =
+            DummyProposalFixture::default().with_exactly_execute_at(exactly_execute_at);
+        let proposal_id = dummy_proposal
+            .create_deferred_code_proposal_and_assert(Ok(1))
+            .unwrap();
 
-        assert_eq!(vetoed_slash_balance, 0);
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
 
-        let approved_slash_balance = ProposalsEngine::calculate_slash_balance(
-            &ProposalDecisionStatus::Approved(ApprovedProposalStatus::Executed),
-            &ProposalParametersFixture::default().params(),
-        );
+        let timeout_expires_at = exactly_execute_at + DeferredCodeTimeout::get();
+        run_to_block_and_finalize(timeout_expires_at - 1);
 
-        assert_eq!(approved_slash_balance, 0);
+        assert!(<PendingExecutionProposalIds<Test>>::exists(proposal_id));
 
-        let rejection_fee = <Test as crate::Trait>::RejectionFee::get();
+        run_to_block_and_finalize(timeout_expires_at);
 
-        let rejected_slash_balance = ProposalsEngine::calculate_slash_balance(
-            &ProposalDecisionStatus::Rejected,
-            &ProposalParametersFixture::default().params(),
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::approved(
+                ApprovedProposalStatus::failed_execution("ProposalCodeTimeout"),
+                1
+            )
         );
+        assert!(!<PendingExecutionProposalIds<Test>>::exists(proposal_id));
+    });
+}
 
-        assert_eq!(rejected_slash_balance, rejection_fee);
+#[test]
+fn migrate_proposals_converts_legacy_proposals() {
+    initial_test_ext().execute_with(|| {
+        use crate::migration::*;
 
-        let expired_slash_balance = ProposalsEngine::calculate_slash_balance(
-            &ProposalDecisionStatus::Expired,
-            &ProposalParametersFixture::default().params(),
-        );
+        let legacy_parameters = LegacyProposalParameters {
+            voting_period: 3,
+            grace_period: 5,
+            approval_quorum_percentage: 60,
+            approval_threshold_percentage: 60,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 60,
+            required_stake: None,
+        };
 
-        assert_eq!(expired_slash_balance, rejection_fee);
+        let active_proposal: LegacyProposal<u64, u64, u64, u64, u64> = LegacyProposal {
+            parameters: legacy_parameters,
+            proposer_id: 1,
+            title: b"title".to_vec(),
+            description: b"description".to_vec(),
+            created_at: 1,
+            status: LegacyProposalStatus::Active(None),
+            voting_results: VotingResults::default(),
+        };
 
-        let cancellation_fee = <Test as crate::Trait>::CancellationFee::get();
+        let failed_proposal: LegacyProposal<u64, u64, u64, u64, u64> = LegacyProposal {
+            status: LegacyProposalStatus::Finalized(LegacyFinalizationData {
+                proposal_status: LegacyProposalDecisionStatus::Approved(
+                    LegacyApprovedProposalStatus::ExecutionFailed {
+                        error: b"error".to_vec(),
+                    },
+                ),
+                finalized_at: 1,
+                encoded_unstaking_error_due_to_broken_runtime: None,
+                stake_data_after_unstaking_error: None,
+            }),
+            ..active_proposal.clone()
+        };
 
-        let cancellation_slash_balance = ProposalsEngine::calculate_slash_balance(
-            &ProposalDecisionStatus::Canceled,
-            &ProposalParametersFixture::default().params(),
+        srml_support::storage::unhashed::put(
+            &<crate::Proposals<Test>>::hashed_key_for(1),
+            &active_proposal,
+        );
+        srml_support::storage::unhashed::put(
+            &<crate::Proposals<Test>>::hashed_key_for(2),
+            &failed_proposal,
         );
+        <DispatchableCallCode<Test>>::insert(2, b"code".to_vec());
+        ProposalCount::put(2);
 
-        assert_eq!(cancellation_slash_balance, cancellation_fee);
+        ProposalsEngine::migrate_proposals();
 
-        let slash_balance_with_no_stake = ProposalsEngine::calculate_slash_balance(
-            &ProposalDecisionStatus::Slashed,
-            &ProposalParametersFixture::default().params(),
-        );
+        let parameters = ProposalParameters {
+            voting_period: 3,
+            grace_period: 5,
+            approval_quorum_percentage: 60,
+            approval_threshold_percentage: 60,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 60,
+            required_stake: None,
+            voting_mode: VotingMode::Headcount,
+            constitutionality: 1,
+            execution_retry_policy: None,
+        };
 
-        assert_eq!(slash_balance_with_no_stake, 0);
+        assert_eq!(
+            ProposalsEngine::proposals(1),
+            Proposal {
+                parameters,
+                proposer_id: 1,
+                title: b"title".to_vec(),
+                description: b"description".to_vec(),
+                created_at: 1,
+                activated_at: 1,
+                status: ProposalStatus::Active(None),
+                voting_results: VotingResults::default(),
+                exactly_execute_at: None,
+                council_approvals: 0,
+                amendments: Vec::new(),
+            }
+        );
 
-        let stake = 256;
-        let slash_balance_with_stake = ProposalsEngine::calculate_slash_balance(
-            &ProposalDecisionStatus::Slashed,
-            &ProposalParametersFixture::default()
-                .with_required_stake(stake)
-                .params(),
+        let proposal = ProposalsEngine::proposals(2);
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::approved(ApprovedProposalStatus::failed_execution("error"), 1)
         );
+        assert_eq!(proposal.council_approvals, 1);
 
-        assert_eq!(slash_balance_with_stake, stake);
+        assert_eq!(ProposalsEngine::finalized_proposal_ids(), vec![2]);
+        assert!(!<DispatchableCallCode<Test>>::exists(2));
     });
 }
@@ -10,6 +10,7 @@ use rstd::prelude::*;
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
+use sr_primitives::traits::UniqueSaturatedInto;
 use sr_primitives::Perbill;
 use srml_support::dispatch;
 use srml_support::traits::Currency;
@@ -53,6 +54,24 @@ impl Default for VoteKind {
     }
 }
 
+/// Defines how votes are counted for the proposal.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VotingMode {
+    /// Every vote has the same weight. Quorum and threshold are calculated using votes number.
+    Headcount,
+
+    /// Every vote is weighted by the voter stake. Quorum and threshold are calculated using
+    /// the voters stake.
+    StakeWeighted,
+}
+
+impl Default for VotingMode {
+    fn default() -> Self {
+        VotingMode::Headcount
+    }
+}
+
 /// Proposal parameters required to manage proposal risk.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, Copy, PartialEq, Eq, Debug)]
@@ -78,6 +97,49 @@ pub struct ProposalParameters<BlockNumber, Balance> {
 
     /// Proposal stake
     pub required_stake: Option<Balance>,
+
+    /// Defines how votes are counted for the proposal
+    pub voting_mode: VotingMode,
+
+    /// Number of consecutive council approvals required before the proposal execution.
+    /// Zero and one mean a single approval.
+    pub constitutionality: u32,
+
+    /// Defines whether the failed proposal execution should be retried
+    pub execution_retry_policy: Option<ExecutionRetryPolicy<BlockNumber>>,
+}
+
+/// Retry policy for the failed proposal execution
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ExecutionRetryPolicy<BlockNumber> {
+    /// Pause between the execution attempts
+    pub retry_interval: BlockNumber,
+
+    /// Period after the first execution attempt during which the execution can be retried
+    pub retry_period: BlockNumber,
+}
+
+/// Record of the proposal execution attempt
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct ExecutionAttempt<BlockNumber> {
+    /// When the execution was attempted
+    pub attempted_at: BlockNumber,
+
+    /// Execution result: executed or failed with an error
+    pub result: ApprovedProposalStatus,
+}
+
+/// Record of the proposal amendment
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct ProposalAmendment<BlockNumber> {
+    /// When the proposal was amended
+    pub amended_at: BlockNumber,
+
+    /// Voting results discarded by the amendment
+    pub discarded_voting_results: VotingResults,
 }
 
 /// Contains current voting results
@@ -108,12 +170,87 @@ impl VotingResults {
         }
     }
 
+    /// Remove previously added vote from the related counter. Counters cannot go below zero.
+    pub fn remove_vote(&mut self, vote: VoteKind) {
+        match vote {
+            VoteKind::Abstain => self.abstentions = self.abstentions.saturating_sub(1),
+            VoteKind::Approve => self.approvals = self.approvals.saturating_sub(1),
+            VoteKind::Reject => self.rejections = self.rejections.saturating_sub(1),
+            VoteKind::Slash => self.slashes = self.slashes.saturating_sub(1),
+        }
+    }
+
     /// Calculates number of votes so far
     pub fn votes_number(&self) -> u32 {
         self.abstentions + self.approvals + self.rejections + self.slashes
     }
 }
 
+/// Contains current voting results weighted by the voters stake
+#[derive(Default, Clone, PartialEq, Eq, Debug)]
+pub struct WeightedVotingResults<Balance> {
+    /// 'Abstain' votes stake
+    pub abstentions: Balance,
+
+    /// 'Approve' votes stake
+    pub approvals: Balance,
+
+    /// 'Reject' votes stake
+    pub rejections: Balance,
+
+    /// 'Slash' votes stake
+    pub slashes: Balance,
+}
+
+impl<Balance: Add<Output = Balance> + Copy> WeightedVotingResults<Balance> {
+    /// Add vote stake to the related counter
+    pub fn add_vote(&mut self, vote: VoteKind, stake: Balance) {
+        match vote {
+            VoteKind::Abstain => self.abstentions = self.abstentions + stake,
+            VoteKind::Approve => self.approvals = self.approvals + stake,
+            VoteKind::Reject => self.rejections = self.rejections + stake,
+            VoteKind::Slash => self.slashes = self.slashes + stake,
+        }
+    }
+
+    /// Calculates stake of all votes so far
+    pub fn votes_stake(&self) -> Balance {
+        self.abstentions + self.approvals + self.rejections + self.slashes
+    }
+}
+
+/// Persistent record of the vote for the proposal
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct VoteRecord<MemberId, BlockNumber> {
+    /// Member id of a voter
+    pub voter_id: MemberId,
+
+    /// Kind of the vote
+    pub vote_kind: VoteKind,
+
+    /// When the vote was cast or changed last time
+    pub voted_at: BlockNumber,
+}
+
+/// Defines how long vote records are kept after the proposal finalization
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VoteRecordsPruningPolicy<BlockNumber> {
+    /// Vote records are never removed
+    KeepForever,
+
+    /// Vote records are removed when provided number of blocks passes after the proposal
+    /// finalization
+    PruneAfter(BlockNumber),
+}
+
+impl<BlockNumber> Default for VoteRecordsPruningPolicy<BlockNumber> {
+    fn default() -> Self {
+        VoteRecordsPruningPolicy::KeepForever
+    }
+}
+
 /// Contains created stake id and source account for the stake balance
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, Copy, PartialEq, Eq, Debug)]
@@ -125,6 +262,51 @@ pub struct ActiveStake<StakeId, AccountId> {
     pub source_account_id: AccountId,
 }
 
+/// Stake balance added to the proposal stake by the proposer or a sponsor
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct StakeContribution<MemberId, AccountId, Balance> {
+    /// Member id of the proposer or the sponsor
+    pub member_id: MemberId,
+
+    /// Source account of the stake balance. Refund if any will be provided using this account
+    pub account_id: AccountId,
+
+    /// Staked balance
+    pub amount: Balance,
+}
+
+/// Compact record of the finalized proposal. Replaces the proposal after its retention period.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct ProposalOutcome<BlockNumber, ProposerId> {
+    /// Identifier of member proposing.
+    pub proposer_id: ProposerId,
+
+    /// When it was created.
+    pub created_at: BlockNumber,
+
+    /// When the proposal decision was made.
+    pub finalized_at: BlockNumber,
+
+    /// Final proposal decision. Contains the execution result for approved proposals.
+    pub decision_status: ProposalDecisionStatus,
+}
+
+/// Veto record of the proposal. Keeps who vetoed the proposal and why.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct VetoRecord<MemberId, BlockNumber> {
+    /// Member id of the vetoer.
+    pub vetoer_id: MemberId,
+
+    /// When the proposal was vetoed.
+    pub vetoed_at: BlockNumber,
+
+    /// Veto rationale.
+    pub rationale: Vec<u8>,
+}
+
 /// 'Proposal' contains information necessary for the proposal system functioning.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
@@ -144,11 +326,24 @@ pub struct Proposal<BlockNumber, ProposerId, Balance, StakeId, AccountId> {
     /// When it was created.
     pub created_at: BlockNumber,
 
+    /// When the voting for the current council started. Equals the creation block unless the
+    /// proposal was approved by the previous council and waited for the next one.
+    pub activated_at: BlockNumber,
+
     /// Current proposal status
     pub status: ProposalStatus<BlockNumber, StakeId, AccountId>,
 
     /// Curring voting result for the proposal
     pub voting_results: VotingResults,
+
+    /// Exact block for the approved proposal execution. Grace period is used if not set.
+    pub exactly_execute_at: Option<BlockNumber>,
+
+    /// Number of the council approvals received by the proposal.
+    pub council_approvals: u32,
+
+    /// History of the proposal amendments.
+    pub amendments: Vec<ProposalAmendment<BlockNumber>>,
 }
 
 impl<BlockNumber, ProposerId, Balance, StakeId, AccountId>
@@ -160,7 +355,7 @@ where
 {
     /// Returns whether voting period expired by now
     pub fn is_voting_period_expired(&self, now: BlockNumber) -> bool {
-        now >= self.created_at + self.parameters.voting_period
+        now >= self.activated_at + self.parameters.voting_period
     }
 
     /// Returns whether grace period expired by now.
@@ -176,6 +371,33 @@ where
         false
     }
 
+    /// Returns whether the approved proposal can be executed by now.
+    /// Grace period must be expired and the exact execution block (if any) must be reached.
+    pub fn is_ready_for_execution(&self, now: BlockNumber) -> bool {
+        let exact_execution_block_reached = self
+            .exactly_execute_at
+            .map_or(true, |execute_at| now >= execute_at);
+
+        self.is_grace_period_expired(now) && exact_execution_block_reached
+    }
+
+    /// Returns the earliest execution block of the approved proposal: the end of the grace period
+    /// or the exact execution block whichever is later. Returns None for other proposals.
+    pub fn execution_block(&self) -> Option<BlockNumber> {
+        if let ProposalStatus::Finalized(finalized_status) = self.status.clone() {
+            if let ProposalDecisionStatus::Approved(_) = finalized_status.proposal_status {
+                let grace_period_end = finalized_status.finalized_at + self.parameters.grace_period;
+
+                return match self.exactly_execute_at {
+                    Some(execute_at) if execute_at > grace_period_end => Some(execute_at),
+                    _ => Some(grace_period_end),
+                };
+            }
+        }
+
+        None
+    }
+
     /// Determines the finalized proposal status using voting results tally for current proposal.
     /// Calculates votes, takes in account voting period expiration.
     /// If voting process is in progress, then decision status is None.
@@ -188,30 +410,51 @@ where
     ) -> Option<ProposalDecisionStatus> {
         let proposal_status_resolution = ProposalStatusResolution {
             proposal: self,
-            approvals: self.voting_results.approvals,
-            slashes: self.voting_results.slashes,
+            approvals: self.voting_results.approvals.into(),
+            slashes: self.voting_results.slashes.into(),
             now,
-            votes_count: self.voting_results.votes_number(),
-            total_voters_count,
+            votes_count: self.voting_results.votes_number().into(),
+            total_voters_count: total_voters_count.into(),
+            all_voters_voted: self.voting_results.votes_number() == total_voters_count,
         };
 
-        if proposal_status_resolution.is_approval_quorum_reached()
-            && proposal_status_resolution.is_approval_threshold_reached()
-        {
-            Some(ProposalDecisionStatus::Approved(
-                ApprovedProposalStatus::PendingExecution,
-            ))
-        } else if proposal_status_resolution.is_slashing_quorum_reached()
-            && proposal_status_resolution.is_slashing_threshold_reached()
-        {
-            Some(ProposalDecisionStatus::Slashed)
-        } else if proposal_status_resolution.is_expired() {
-            Some(ProposalDecisionStatus::Expired)
-        } else if proposal_status_resolution.is_voting_completed() {
-            Some(ProposalDecisionStatus::Rejected)
-        } else {
-            None
-        }
+        proposal_status_resolution.define_proposal_decision_status()
+    }
+
+    /// Determines the finalized proposal status using the stake weighted voting results.
+    /// Quorum and threshold are calculated using the voters stake, voting completion is defined
+    /// by the votes number.
+    /// Parameters: weighted voting results, total voters stake, total voters number involved
+    /// (council size), current time.
+    /// Returns the proposal finalized status if any.
+    pub fn define_weighted_proposal_decision_status(
+        &self,
+        weighted_voting_results: &WeightedVotingResults<Balance>,
+        total_voters_stake: Balance,
+        total_voters_count: u32,
+        now: BlockNumber,
+    ) -> Option<ProposalDecisionStatus>
+    where
+        Balance: Add<Output = Balance> + UniqueSaturatedInto<u128> + Copy,
+    {
+        let proposal_status_resolution = ProposalStatusResolution {
+            proposal: self,
+            approvals: weighted_voting_results.approvals.unique_saturated_into(),
+            slashes: weighted_voting_results.slashes.unique_saturated_into(),
+            now,
+            votes_count: weighted_voting_results
+                .votes_stake()
+                .unique_saturated_into(),
+            total_voters_count: total_voters_stake.unique_saturated_into(),
+            all_voters_voted: self.voting_results.votes_number() == total_voters_count,
+        };
+
+        proposal_status_resolution.define_proposal_decision_status()
+    }
+
+    /// Returns whether the proposal requires more council approvals before the execution.
+    pub fn requires_council_approval(&self) -> bool {
+        self.council_approvals < self.parameters.constitutionality
     }
 
     /// Reset the proposal in Active status. Proposal with other status won't be changed.
@@ -223,20 +466,68 @@ where
     }
 }
 
+impl<BlockNumber, ProposerId, Balance, StakeId, AccountId>
+    Proposal<BlockNumber, ProposerId, Balance, StakeId, AccountId>
+where
+    BlockNumber: Copy,
+    ProposerId: Clone,
+{
+    /// Creates the compact proposal outcome record. Returns None for the non-finalized proposal.
+    pub fn outcome(&self) -> Option<ProposalOutcome<BlockNumber, ProposerId>> {
+        if let ProposalStatus::Finalized(finalization_data) = &self.status {
+            Some(ProposalOutcome {
+                proposer_id: self.proposer_id.clone(),
+                created_at: self.created_at,
+                finalized_at: finalization_data.finalized_at,
+                decision_status: finalization_data.proposal_status.clone(),
+            })
+        } else {
+            None
+        }
+    }
+}
+
 /// Provides data for the voting.
 pub trait VotersParameters {
     /// Defines maximum voters count for the proposal
     fn total_voters_count() -> u32;
 }
 
-// Calculates quorum, votes threshold, expiration status
+/// Provides voters stake for the stake weighted voting.
+pub trait VoterStakesProvider<MemberId, Balance> {
+    /// Returns the stake backing the voter
+    fn voter_stake(voter_id: &MemberId) -> Balance;
+
+    /// Returns the stake backing all voters
+    fn total_voters_stake() -> Balance;
+}
+
+/// Allows other modules to react on the proposal finalization and pruning.
+pub trait ProposalFinalizationHandler<ProposalId> {
+    /// Handles the proposal finalization: approval, rejection, cancellation, veto, etc.
+    fn proposal_finalized(proposal_id: ProposalId);
+
+    /// Handles the proposal pruning: the finalized proposal was removed after its retention
+    /// period. Other modules can remove theirs proposal data.
+    fn proposal_pruned(proposal_id: ProposalId);
+}
+
+impl<ProposalId> ProposalFinalizationHandler<ProposalId> for () {
+    fn proposal_finalized(_proposal_id: ProposalId) {}
+
+    fn proposal_pruned(_proposal_id: ProposalId) {}
+}
+
+// Calculates quorum, votes threshold, expiration status.
+// Votes are represented either by votes number or by votes stake depending on the voting mode.
 struct ProposalStatusResolution<'a, BlockNumber, ProposerId, Balance, StakeId, AccountId> {
     proposal: &'a Proposal<BlockNumber, ProposerId, Balance, StakeId, AccountId>,
     now: BlockNumber,
-    votes_count: u32,
-    total_voters_count: u32,
-    approvals: u32,
-    slashes: u32,
+    votes_count: u128,
+    total_voters_count: u128,
+    approvals: u128,
+    slashes: u128,
+    all_voters_voted: bool,
 }
 
 impl<'a, BlockNumber, ProposerId, Balance, StakeId, AccountId>
@@ -246,6 +537,23 @@ where
     StakeId: Clone,
     AccountId: Clone,
 {
+    // Defines the proposal decision status using the votes tally.
+    pub fn define_proposal_decision_status(&self) -> Option<ProposalDecisionStatus> {
+        if self.is_approval_quorum_reached() && self.is_approval_threshold_reached() {
+            Some(ProposalDecisionStatus::Approved(
+                ApprovedProposalStatus::PendingExecution,
+            ))
+        } else if self.is_slashing_quorum_reached() && self.is_slashing_threshold_reached() {
+            Some(ProposalDecisionStatus::Slashed)
+        } else if self.is_expired() {
+            Some(ProposalDecisionStatus::Expired)
+        } else if self.is_voting_completed() {
+            Some(ProposalDecisionStatus::Rejected)
+        } else {
+            None
+        }
+    }
+
     // Proposal has been expired and quorum not reached.
     pub fn is_expired(&self) -> bool {
         self.proposal.is_voting_period_expired(self.now)
@@ -297,7 +605,7 @@ where
 
     // All voters had voted
     pub fn is_voting_completed(&self) -> bool {
-        self.votes_count == self.total_voters_count
+        self.all_voters_voted
     }
 }
 
@@ -381,6 +689,7 @@ mod tests {
         let mut proposal = ProposalObject::default();
 
         proposal.created_at = 1;
+        proposal.activated_at = 1;
         proposal.parameters.voting_period = 3;
 
         assert!(proposal.is_voting_period_expired(4));
@@ -391,6 +700,7 @@ mod tests {
         let mut proposal = ProposalObject::default();
 
         proposal.created_at = 1;
+        proposal.activated_at = 1;
         proposal.parameters.voting_period = 3;
 
         assert!(!proposal.is_voting_period_expired(3));
@@ -440,11 +750,45 @@ mod tests {
         assert!(!proposal.is_grace_period_expired(3));
     }
 
+    #[test]
+    fn proposal_is_ready_for_execution_at_exact_execution_block() {
+        let mut proposal = ProposalObject::default();
+
+        proposal.parameters.grace_period = 3;
+        proposal.exactly_execute_at = Some(10);
+        proposal.status = ProposalStatus::finalized_successfully(
+            ProposalDecisionStatus::Approved(ApprovedProposalStatus::PendingExecution),
+            0,
+        );
+
+        assert!(proposal.is_grace_period_expired(4));
+        assert!(!proposal.is_ready_for_execution(4));
+        assert!(proposal.is_ready_for_execution(10));
+    }
+
+    #[test]
+    fn proposal_execution_block_succeeds() {
+        let mut proposal = ProposalObject::default();
+
+        proposal.parameters.grace_period = 3;
+        assert_eq!(proposal.execution_block(), None);
+
+        proposal.status = ProposalStatus::finalized_successfully(
+            ProposalDecisionStatus::Approved(ApprovedProposalStatus::PendingExecution),
+            2,
+        );
+        assert_eq!(proposal.execution_block(), Some(5));
+
+        proposal.exactly_execute_at = Some(10);
+        assert_eq!(proposal.execution_block(), Some(10));
+    }
+
     #[test]
     fn define_proposal_decision_status_returns_expired() {
         let mut proposal = ProposalObject::default();
         let now = 5;
         proposal.created_at = 1;
+        proposal.activated_at = 1;
         proposal.parameters.voting_period = 3;
         proposal.parameters.approval_quorum_percentage = 80;
         proposal.parameters.approval_threshold_percentage = 40;
@@ -477,6 +821,7 @@ mod tests {
         let now = 2;
         let mut proposal = ProposalObject::default();
         proposal.created_at = 1;
+        proposal.activated_at = 1;
         proposal.parameters.voting_period = 3;
         proposal.parameters.approval_quorum_percentage = 60;
         proposal.parameters.slashing_quorum_percentage = 50;
@@ -512,6 +857,7 @@ mod tests {
         let now = 2;
 
         proposal.created_at = 1;
+        proposal.activated_at = 1;
         proposal.parameters.voting_period = 3;
         proposal.parameters.approval_quorum_percentage = 50;
         proposal.parameters.approval_threshold_percentage = 51;
@@ -546,6 +892,7 @@ mod tests {
         let now = 2;
 
         proposal.created_at = 1;
+        proposal.activated_at = 1;
         proposal.parameters.voting_period = 3;
         proposal.parameters.approval_quorum_percentage = 50;
         proposal.parameters.approval_threshold_percentage = 50;
@@ -580,6 +927,7 @@ mod tests {
         let now = 2;
 
         proposal.created_at = 1;
+        proposal.activated_at = 1;
         proposal.parameters.voting_period = 3;
         proposal.parameters.approval_quorum_percentage = 60;
         proposal.parameters.slashing_quorum_percentage = 50;
@@ -605,6 +953,7 @@ mod tests {
         let now = 2;
 
         proposal.created_at = 1;
+        proposal.activated_at = 1;
         proposal.parameters.voting_period = 3;
         proposal.parameters.approval_quorum_percentage = 50;
         proposal.parameters.approval_threshold_percentage = 30;
@@ -644,6 +993,7 @@ mod tests {
         let now = 2;
 
         proposal.created_at = 1;
+        proposal.activated_at = 1;
         proposal.parameters.voting_period = 3;
         proposal.parameters.approval_quorum_percentage = 50;
         proposal.parameters.approval_threshold_percentage = 30;
@@ -675,6 +1025,101 @@ mod tests {
         );
     }
 
+    #[test]
+    fn define_weighted_proposal_decision_status_returns_approved() {
+        let mut proposal = ProposalObject::default();
+        let now = 2;
+
+        proposal.created_at = 1;
+        proposal.activated_at = 1;
+        proposal.parameters.voting_period = 3;
+        proposal.parameters.approval_quorum_percentage = 60;
+        proposal.parameters.approval_threshold_percentage = 60;
+        proposal.parameters.slashing_quorum_percentage = 60;
+        proposal.parameters.slashing_threshold_percentage = 60;
+        proposal.parameters.voting_mode = VotingMode::StakeWeighted;
+
+        proposal.voting_results.add_vote(VoteKind::Approve);
+        proposal.voting_results.add_vote(VoteKind::Reject);
+
+        // not enough voters for the head count quorum
+        assert_eq!(proposal.define_proposal_decision_status(4, now), None);
+
+        let mut weighted_voting_results = WeightedVotingResults::default();
+        weighted_voting_results.add_vote(VoteKind::Approve, 70);
+        weighted_voting_results.add_vote(VoteKind::Reject, 10);
+
+        let expected_proposal_status = proposal.define_weighted_proposal_decision_status(
+            &weighted_voting_results,
+            100,
+            4,
+            now,
+        );
+
+        assert_eq!(
+            expected_proposal_status,
+            Some(ProposalDecisionStatus::Approved(
+                ApprovedProposalStatus::PendingExecution
+            ))
+        );
+    }
+
+    #[test]
+    fn define_weighted_proposal_decision_status_returns_rejected_on_completed_voting() {
+        let mut proposal = ProposalObject::default();
+        let now = 2;
+
+        proposal.created_at = 1;
+        proposal.activated_at = 1;
+        proposal.parameters.voting_period = 3;
+        proposal.parameters.approval_quorum_percentage = 60;
+        proposal.parameters.approval_threshold_percentage = 60;
+        proposal.parameters.slashing_quorum_percentage = 60;
+        proposal.parameters.slashing_threshold_percentage = 60;
+        proposal.parameters.voting_mode = VotingMode::StakeWeighted;
+
+        proposal.voting_results.add_vote(VoteKind::Approve);
+        proposal.voting_results.add_vote(VoteKind::Approve);
+        proposal.voting_results.add_vote(VoteKind::Reject);
+
+        let mut weighted_voting_results = WeightedVotingResults::default();
+        weighted_voting_results.add_vote(VoteKind::Approve, 10);
+        weighted_voting_results.add_vote(VoteKind::Approve, 10);
+        weighted_voting_results.add_vote(VoteKind::Reject, 80);
+
+        let expected_proposal_status = proposal.define_weighted_proposal_decision_status(
+            &weighted_voting_results,
+            100,
+            3,
+            now,
+        );
+
+        assert_eq!(
+            expected_proposal_status,
+            Some(ProposalDecisionStatus::Rejected)
+        );
+    }
+
+    #[test]
+    fn voting_results_remove_vote_works_correctly() {
+        let mut voting_results = VotingResults::default();
+
+        voting_results.add_vote(VoteKind::Approve);
+        voting_results.add_vote(VoteKind::Slash);
+        voting_results.remove_vote(VoteKind::Approve);
+        voting_results.remove_vote(VoteKind::Reject);
+
+        assert_eq!(
+            voting_results,
+            VotingResults {
+                abstentions: 0,
+                approvals: 0,
+                rejections: 0,
+                slashes: 1,
+            }
+        );
+    }
+
     #[test]
     fn proposal_status_resolution_approval_quorum_works_correctly() {
         let no_approval_quorum_proposal: Proposal<u64, u64, u64, u64, u64> = Proposal {
@@ -692,6 +1137,7 @@ mod tests {
             total_voters_count: 500,
             approvals: 3,
             slashes: 3,
+            all_voters_voted: false,
         };
 
         assert!(!no_approval_proposal_status_resolution.is_approval_quorum_reached());
@@ -721,6 +1167,7 @@ mod tests {
             total_voters_count: 500,
             approvals: 3,
             slashes: 3,
+            all_voters_voted: false,
         };
 
         assert!(!no_slashing_proposal_status_resolution.is_slashing_quorum_reached());
@@ -750,6 +1197,7 @@ mod tests {
             total_voters_count: 600,
             approvals: 314,
             slashes: 3,
+            all_voters_voted: false,
         };
 
         assert!(!no_approval_proposal_status_resolution.is_approval_threshold_reached());
@@ -779,6 +1227,7 @@ mod tests {
             total_voters_count: 600,
             approvals: 3,
             slashes: 314,
+            all_voters_voted: false,
         };
 
         assert!(!no_slashing_proposal_status_resolution.is_slashing_threshold_reached());
@@ -790,4 +1239,27 @@ mod tests {
 
         assert!(slashing_threshold_proposal_status_resolution.is_slashing_threshold_reached());
     }
+
+    #[test]
+    fn proposal_outcome_is_created_for_finalized_proposal() {
+        let mut proposal = ProposalObject::default();
+
+        proposal.proposer_id = 1;
+        proposal.created_at = 2;
+
+        assert_eq!(proposal.outcome(), None);
+
+        let decision_status = ProposalDecisionStatus::Approved(ApprovedProposalStatus::Executed);
+        proposal.status = ProposalStatus::finalized_successfully(decision_status.clone(), 5);
+
+        assert_eq!(
+            proposal.outcome(),
+            Some(ProposalOutcome {
+                proposer_id: 1,
+                created_at: 2,
+                finalized_at: 5,
+                decision_status,
+            })
+        );
+    }
 }
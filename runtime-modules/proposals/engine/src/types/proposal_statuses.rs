@@ -14,8 +14,16 @@ pub enum ProposalStatus<BlockNumber, StakeId, AccountId> {
     /// A new proposal status that is available for voting (with optional stake data).
     Active(Option<ActiveStake<StakeId, AccountId>>),
 
+    /// The proposal was approved by the current council and waits for the next council voting
+    /// because of its constitutionality (with optional stake data).
+    PendingConstitutionality(Option<ActiveStake<StakeId, AccountId>>),
+
     /// The proposal decision was made.
     Finalized(FinalizationData<BlockNumber, StakeId, AccountId>),
+
+    /// The proposal waits for sponsors to cover its required stake (with optional stake data).
+    /// It becomes active when the required stake is fully covered.
+    PendingSponsorship(Option<ActiveStake<StakeId, AccountId>>),
 }
 
 impl<BlockNumber, StakeId, AccountId> Default for ProposalStatus<BlockNumber, StakeId, AccountId> {
@@ -109,10 +117,13 @@ pub enum ApprovedProposalStatus {
     /// Proposal was successfully executed
     Executed,
 
-    /// Proposal was executed and failed with an error
+    /// Proposal was executed and failed with an error. The proposal made no changes.
     ExecutionFailed {
         /// Error message
         error: Vec<u8>,
+
+        /// Index of the failed item for the batch proposal
+        failed_batch_item: Option<u32>,
     },
 }
 
@@ -121,6 +132,16 @@ impl ApprovedProposalStatus {
     pub fn failed_execution(err: &str) -> ApprovedProposalStatus {
         ApprovedProposalStatus::ExecutionFailed {
             error: err.as_bytes().to_vec(),
+            failed_batch_item: None,
+        }
+    }
+
+    /// ApprovedProposalStatus helper, creates ExecutionFailed approved proposal status
+    /// for the failed batch proposal item
+    pub fn failed_batch_item_execution(item_index: u32, err: &str) -> ApprovedProposalStatus {
+        ApprovedProposalStatus::ExecutionFailed {
+            error: err.as_bytes().to_vec(),
+            failed_batch_item: Some(item_index),
         }
     }
 }
@@ -163,7 +184,16 @@ mod tests {
         assert_eq!(
             ApprovedProposalStatus::failed_execution(&msg),
             ApprovedProposalStatus::ExecutionFailed {
-                error: msg.as_bytes().to_vec()
+                error: msg.as_bytes().to_vec(),
+                failed_batch_item: None,
+            }
+        );
+
+        assert_eq!(
+            ApprovedProposalStatus::failed_batch_item_execution(2, &msg),
+            ApprovedProposalStatus::ExecutionFailed {
+                error: msg.as_bytes().to_vec(),
+                failed_batch_item: Some(2),
             }
         );
     }
@@ -44,6 +44,13 @@ pub trait StakeHandler<T: Trait> {
         stake_imbalance: NegativeImbalance<T>,
     ) -> Result<(), &'static str>;
 
+    /// Add the imbalance to the existing stake
+    fn increase_stake(
+        &self,
+        stake_id: &T::StakeId,
+        stake_imbalance: NegativeImbalance<T>,
+    ) -> Result<(), &'static str>;
+
     /// Removes stake
     fn remove_stake(&self, stake_id: T::StakeId) -> Result<(), &'static str>;
 
@@ -84,6 +91,17 @@ impl<T: Trait> StakeHandler<T> for DefaultStakeHandler<T> {
         Ok(())
     }
 
+    /// Add the imbalance to the existing stake
+    fn increase_stake(
+        &self,
+        stake_id: &<T as stake::Trait>::StakeId,
+        stake_imbalance: NegativeImbalance<T>,
+    ) -> Result<(), &'static str> {
+        stake::Module::<T>::increase_stake(&stake_id, stake_imbalance).map_err(WrappedError)?;
+
+        Ok(())
+    }
+
     /// Removes stake
     fn remove_stake(&self, stake_id: <T as stake::Trait>::StakeId) -> Result<(), &'static str> {
         stake::Module::<T>::remove_stake(&stake_id).map_err(WrappedError)?;
@@ -149,6 +167,18 @@ impl<T: Trait> ProposalStakeManager<T> {
         Ok(stake_id)
     }
 
+    /// Adds stake balance from the source account to the existing stake.
+    pub fn increase_stake(
+        stake_id: T::StakeId,
+        stake_balance: BalanceOf<T>,
+        source_account_id: T::AccountId,
+    ) -> Result<(), &'static str> {
+        let stake_imbalance = T::StakeHandlerProvider::stakes()
+            .make_stake_imbalance(stake_balance, &source_account_id)?;
+
+        T::StakeHandlerProvider::stakes().increase_stake(&stake_id, stake_imbalance)
+    }
+
     /// Execute unstaking and removes the stake
     pub fn remove_stake(stake_id: T::StakeId) -> Result<(), &'static str> {
         T::StakeHandlerProvider::stakes().unstake(stake_id)?;
@@ -229,6 +259,26 @@ impl From<WrappedError<stake::StakeActionError<stake::InitiateSlashingError>>> f
     }
 }
 
+// error conversion for the Wrapped StakeActionError with the inner IncreasingStakeError
+impl From<WrappedError<stake::StakeActionError<stake::IncreasingStakeError>>> for &str {
+    fn from(wrapper: WrappedError<stake::StakeActionError<stake::IncreasingStakeError>>) -> Self {
+        {
+            match wrapper.0 {
+                stake::StakeActionError::StakeNotFound => "StakeNotFound",
+                stake::StakeActionError::Error(err) => match err {
+                    stake::IncreasingStakeError::NotStaked => "NotStaked",
+                    stake::IncreasingStakeError::CannotChangeStakeByZero => {
+                        "CannotChangeStakeByZero"
+                    }
+                    stake::IncreasingStakeError::CannotIncreaseStakeWhileUnstaking => {
+                        "CannotIncreaseStakeWhileUnstaking"
+                    }
+                },
+            }
+        }
+    }
+}
+
 // error conversion for the Wrapped StakeActionError with the inner ImmediateSlashingError
 impl From<WrappedError<stake::StakeActionError<stake::ImmediateSlashingError>>> for &str {
     fn from(wrapper: WrappedError<stake::StakeActionError<stake::ImmediateSlashingError>>) -> Self {
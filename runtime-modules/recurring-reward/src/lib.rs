@@ -15,12 +15,16 @@ use srml_support::{decl_module, decl_storage, ensure, Parameter};
 
 use minting::{self, BalanceOf};
 
+mod migration;
 mod mock;
 mod tests;
 
 pub trait Trait: system::Trait + minting::Trait {
     type PayoutStatusHandler: PayoutStatusHandler<Self>;
 
+    /// Defines the penalties withheld from the scheduled payouts.
+    type PayoutPenaltyPolicy: PayoutPenaltyPolicy<Self>;
+
     /// Type of identifier for recipients.
     type RecipientId: Parameter
         + Member
@@ -74,6 +78,26 @@ impl<T: Trait> PayoutStatusHandler<T> for () {
     }
 }
 
+/// Penalty withheld from the scheduled payout. Called once for every payout attempt.
+pub trait PayoutPenaltyPolicy<T: Trait> {
+    fn payout_penalty(
+        id: T::RewardRelationshipId,
+        destination_account: &T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> BalanceOf<T>;
+}
+
+/// Makes `()` empty tuple, a PayoutPenaltyPolicy that withholds nothing.
+impl<T: Trait> PayoutPenaltyPolicy<T> for () {
+    fn payout_penalty(
+        _id: T::RewardRelationshipId,
+        _destination_account: &T::AccountId,
+        _amount: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        Zero::zero()
+    }
+}
+
 /// A recipient of recurring rewards
 #[derive(Encode, Decode, Copy, Clone, Debug, Default)]
 pub struct Recipient<Balance> {
@@ -113,6 +137,9 @@ pub struct RewardRelationship<AccountId, Balance, BlockNumber, MintId, Recipient
 
     /// Total payout failed in this relationship
     total_reward_missed: Balance,
+
+    /// Total payout withheld by the penalties in this relationship
+    total_reward_penalized: Balance,
 }
 
 impl<AccountId: Clone, Balance: Clone, BlockNumber: Clone, MintId: Clone, RecipientId: Clone>
@@ -123,6 +150,21 @@ impl<AccountId: Clone, Balance: Clone, BlockNumber: Clone, MintId: Clone, Recipi
         self.next_payment_at_block.is_some()
     }
 
+    /// Total payout received in this relationship
+    pub fn total_reward_received(&self) -> Balance {
+        self.total_reward_received.clone()
+    }
+
+    /// Total payout failed in this relationship
+    pub fn total_reward_missed(&self) -> Balance {
+        self.total_reward_missed.clone()
+    }
+
+    /// Total payout withheld by the penalties in this relationship
+    pub fn total_reward_penalized(&self) -> Balance {
+        self.total_reward_penalized.clone()
+    }
+
     /// Make clone which is activated.
     pub fn clone_activated(&self, start_at: &BlockNumber) -> Self {
         Self {
@@ -213,6 +255,7 @@ impl<T: Trait> Module<T> {
                 payout_interval,
                 total_reward_received: Zero::zero(),
                 total_reward_missed: Zero::zero(),
+                total_reward_penalized: Zero::zero(),
             },
         );
         Ok(relationship_id)
@@ -314,6 +357,8 @@ impl<T: Trait> Module<T> {
     /*
     For all relationships where next_payment_at_block is set and matches current block height,
     a call to pay_reward is made for the suitable amount, recipient and source.
+    The penalty defined by T::PayoutPenaltyPolicy is withheld from the amount and added to
+    total_reward_penalized of the relationship.
     The next_payment_in_block is updated based on payout_interval.
     If the call succeeds, total_reward_received is incremented on both
     recipient and dependency with amount_per_payout, and a call to T::PayoutStatusHandler is made.
@@ -330,9 +375,20 @@ impl<T: Trait> Module<T> {
                     continue;
                 }
 
+                let penalty = rstd::cmp::min(
+                    T::PayoutPenaltyPolicy::payout_penalty(
+                        relationship_id,
+                        &relationship.account,
+                        relationship.amount_per_payout,
+                    ),
+                    relationship.amount_per_payout,
+                );
+
+                relationship.total_reward_penalized += penalty;
+
                 // Add the missed payout and try to pay those in addition to scheduled payout?
                 // let payout = relationship.total_reward_missed + relationship.amount_per_payout;
-                let payout = relationship.amount_per_payout;
+                let payout = relationship.amount_per_payout - penalty;
 
                 // try to make payment
                 if <minting::Module<T>>::transfer_tokens(
@@ -343,10 +399,10 @@ impl<T: Trait> Module<T> {
                 .is_err()
                 {
                     // add only newly scheduled payout to total missed payout
-                    relationship.total_reward_missed += relationship.amount_per_payout;
+                    relationship.total_reward_missed += payout;
 
                     // update recipient stats
-                    recipient.total_reward_missed += relationship.amount_per_payout;
+                    recipient.total_reward_missed += payout;
 
                     T::PayoutStatusHandler::payout_failed(
                         relationship_id,
@@ -0,0 +1,70 @@
+//! Migration of the reward relationships saved by the previous runtime version.
+//! The previous reward relationship layout lacks the total payout withheld by the penalties.
+
+use codec::{Decode, Encode};
+use runtime_primitives::traits::Zero;
+use srml_support::{print, StorageLinkedMap};
+
+use crate::{BalanceOf, Module, RewardRelationship, RewardRelationships, Trait};
+
+// Reward relationship of the previous runtime version.
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub(crate) struct LegacyRewardRelationship<AccountId, Balance, BlockNumber, MintId, RecipientId> {
+    pub recipient: RecipientId,
+    pub mint_id: MintId,
+    pub account: AccountId,
+    pub amount_per_payout: Balance,
+    pub next_payment_at_block: Option<BlockNumber>,
+    pub payout_interval: Option<BlockNumber>,
+    pub total_reward_received: Balance,
+    pub total_reward_missed: Balance,
+}
+
+impl<AccountId, Balance: Zero, BlockNumber, MintId, RecipientId>
+    From<LegacyRewardRelationship<AccountId, Balance, BlockNumber, MintId, RecipientId>>
+    for RewardRelationship<AccountId, Balance, BlockNumber, MintId, RecipientId>
+{
+    fn from(
+        relationship: LegacyRewardRelationship<
+            AccountId,
+            Balance,
+            BlockNumber,
+            MintId,
+            RecipientId,
+        >,
+    ) -> Self {
+        RewardRelationship {
+            recipient: relationship.recipient,
+            mint_id: relationship.mint_id,
+            account: relationship.account,
+            amount_per_payout: relationship.amount_per_payout,
+            next_payment_at_block: relationship.next_payment_at_block,
+            payout_interval: relationship.payout_interval,
+            total_reward_received: relationship.total_reward_received,
+            total_reward_missed: relationship.total_reward_missed,
+            total_reward_penalized: Zero::zero(),
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// Migrates the reward relationships saved by the previous runtime version to the current
+    /// layout. Existing relationships have no penalized payouts.
+    /// Should be called on the migration to the new runtime version only once.
+    pub fn migrate_reward_relationships() {
+        let translation_result = <RewardRelationships<T>>::translate(
+            |relationship_id| relationship_id,
+            |relationship: LegacyRewardRelationship<
+                T::AccountId,
+                BalanceOf<T>,
+                T::BlockNumber,
+                T::MintId,
+                T::RecipientId,
+            >| relationship.into(),
+        );
+
+        if translation_result.is_err() {
+            print("Broken invariant: cannot migrate reward relationships");
+        }
+    }
+}
@@ -14,7 +14,9 @@ use runtime_primitives::{
 };
 use srml_support::{impl_outer_origin, parameter_types};
 
+mod penalty_policy;
 mod status_handler;
+pub use penalty_policy::MockPenaltyPolicy;
 pub use status_handler::MockStatusHandler;
 
 impl_outer_origin! {
@@ -78,6 +80,7 @@ impl balances::Trait for Test {
 
 impl Trait for Test {
     type PayoutStatusHandler = MockStatusHandler;
+    type PayoutPenaltyPolicy = MockPenaltyPolicy;
     type RecipientId = u64;
     type RewardRelationshipId = u64;
 }
@@ -89,6 +92,7 @@ impl minting::Trait for Test {
 
 pub fn build_test_externalities() -> runtime_io::TestExternalities {
     MockStatusHandler::reset();
+    MockPenaltyPolicy::set_penalty(0);
 
     let t = system::GenesisConfig::default()
         .build_storage::<Test>()
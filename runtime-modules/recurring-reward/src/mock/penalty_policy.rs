@@ -0,0 +1,25 @@
+#![cfg(test)]
+
+use super::Test;
+use crate::PayoutPenaltyPolicy;
+use std::cell::RefCell;
+
+thread_local!(static PAYOUT_PENALTY: RefCell<u64> = RefCell::new(0));
+
+pub struct MockPenaltyPolicy {}
+impl MockPenaltyPolicy {
+    pub fn set_penalty(penalty: u64) {
+        PAYOUT_PENALTY.with(|cell| {
+            *cell.borrow_mut() = penalty;
+        });
+    }
+}
+impl PayoutPenaltyPolicy<Test> for MockPenaltyPolicy {
+    fn payout_penalty(_id: u64, _destination_account: &u64, _amount: u64) -> u64 {
+        let mut value = 0;
+        PAYOUT_PENALTY.with(|cell| {
+            value = *cell.borrow();
+        });
+        value
+    }
+}
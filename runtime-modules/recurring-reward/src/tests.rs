@@ -213,6 +213,44 @@ fn track_missed_payouts() {
     });
 }
 
+#[test]
+fn track_penalized_payouts() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let recipient_account: u64 = 1;
+        let _ = Balances::deposit_creating(&recipient_account, 400);
+        let mint_id = create_new_mint_with_capacity(1000000);
+        let recipient_id = Rewards::add_recipient();
+        let payout: u64 = 1000;
+        let penalty: u64 = 300;
+        let next_payout_at: u64 = 12222;
+        let relationship_id = Rewards::add_reward_relationship(
+            mint_id,
+            recipient_id,
+            recipient_account,
+            payout,
+            next_payout_at,
+            None,
+        )
+        .unwrap();
+
+        MockPenaltyPolicy::set_penalty(penalty);
+
+        let starting_balance = Balances::free_balance(&recipient_account);
+
+        Rewards::do_payouts(next_payout_at);
+        assert_eq!(
+            Balances::free_balance(&recipient_account),
+            starting_balance + payout - penalty
+        );
+
+        let relationship = Rewards::reward_relationships(&relationship_id);
+        assert_eq!(relationship.total_reward_received, payout - penalty);
+        assert_eq!(relationship.total_reward_penalized, penalty);
+        assert_eq!(relationship.total_reward_missed, 0);
+    });
+}
+
 #[test]
 fn activate_and_deactivate_relationship() {
     build_test_externalities().execute_with(|| {
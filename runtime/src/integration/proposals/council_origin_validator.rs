@@ -3,12 +3,15 @@
 use rstd::marker::PhantomData;
 
 use common::origin_validator::ActorOriginValidator;
-use proposals_engine::VotersParameters;
+use governance::council::{BalanceOf, VotingParticipation};
+use proposals_engine::{VoterStakesProvider, VotersParameters};
+use sr_primitives::traits::Zero;
 
 use super::{MemberId, MembershipOriginValidator};
 
 /// Handles work with the council.
-/// Provides implementations for ActorOriginValidator and VotersParameters.
+/// Provides implementations for ActorOriginValidator, VotersParameters, VoterStakesProvider
+/// and VotingParticipation.
 pub struct CouncilManager<T> {
     marker: PhantomData<T>,
 }
@@ -40,14 +43,54 @@ impl<T: governance::council::Trait> VotersParameters for CouncilManager<T> {
     }
 }
 
+impl<T: governance::council::Trait + membership::members::Trait>
+    VoterStakesProvider<MemberId<T>, BalanceOf<T>> for CouncilManager<T>
+{
+    /// Implement voter_stake() as total stake of the council seat (including backing stake)
+    /// occupied by the member controller account. Returns zero for non-council members.
+    fn voter_stake(voter_id: &MemberId<T>) -> BalanceOf<T> {
+        <membership::members::Module<T>>::member_profile(voter_id)
+            .and_then(|profile| {
+                <governance::council::Module<T>>::active_council()
+                    .into_iter()
+                    .find(|seat| seat.member == profile.controller_account)
+            })
+            .map_or_else(BalanceOf::<T>::zero, |seat| seat.calc_total_stake())
+    }
+
+    /// Implement total_voters_stake() as total stake of all council seats.
+    fn total_voters_stake() -> BalanceOf<T> {
+        <governance::council::Module<T>>::active_council()
+            .iter()
+            .fold(BalanceOf::<T>::zero(), |acc, seat| {
+                acc + seat.calc_total_stake()
+            })
+    }
+}
+
+impl<T: governance::council::Trait + proposals_engine::Trait>
+    VotingParticipation<<T as system::Trait>::AccountId> for CouncilManager<T>
+{
+    /// Implement voting_participation() as the proposals engine voting participation of
+    /// the members controlled by the councilor account.
+    fn voting_participation(councilor: &<T as system::Trait>::AccountId) -> (u32, u32) {
+        let member_ids =
+            <membership::members::Module<T>>::member_ids_by_controller_account_id(councilor);
+
+        <proposals_engine::Module<T>>::voting_participation(&member_ids)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::CouncilManager;
     use crate::Runtime;
     use common::origin_validator::ActorOriginValidator;
+    use governance::election::{Backer, Seat};
     use membership::members::UserInfo;
-    use proposals_engine::VotersParameters;
+    use proposals_engine::{VoterStakesProvider, VotersParameters};
     use sr_primitives::AccountId32;
+    use srml_support::StorageValue;
     use system::RawOrigin;
 
     type Council = governance::council::Module<Runtime>;
@@ -205,4 +248,53 @@ mod tests {
             assert_eq!(CouncilManager::<Runtime>::total_voters_count(), 4)
         });
     }
+
+    #[test]
+    fn council_stakes_calculation_aka_voters_stakes_succeeds() {
+        initial_test_ext().execute_with(|| {
+            let account_id = AccountId32::default();
+            let authority_account_id = AccountId32::default();
+            Membership::set_screening_authority(
+                RawOrigin::Root.into(),
+                authority_account_id.clone(),
+            )
+            .unwrap();
+
+            Membership::add_screened_member(
+                RawOrigin::Signed(authority_account_id).into(),
+                account_id.clone(),
+                UserInfo {
+                    handle: Some(b"handle".to_vec()),
+                    avatar_uri: None,
+                    about: None,
+                },
+            )
+            .unwrap();
+            let member_id = 0; // newly created member_id
+
+            let backer: [u8; 32] = [2; 32];
+            let councilor2: [u8; 32] = [3; 32];
+            <governance::council::ActiveCouncil<Runtime>>::put(vec![
+                Seat {
+                    member: account_id,
+                    stake: 100,
+                    backers: vec![Backer {
+                        member: backer.into(),
+                        stake: 50,
+                    }],
+                    term_ends_at: 0,
+                },
+                Seat {
+                    member: councilor2.into(),
+                    stake: 200,
+                    backers: vec![],
+                    term_ends_at: 0,
+                },
+            ]);
+
+            assert_eq!(CouncilManager::<Runtime>::voter_stake(&member_id), 150);
+            assert_eq!(CouncilManager::<Runtime>::voter_stake(&(member_id + 1)), 0);
+            assert_eq!(CouncilManager::<Runtime>::total_voters_stake(), 350);
+        });
+    }
 }
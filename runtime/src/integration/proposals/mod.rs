@@ -5,9 +5,11 @@ mod council_origin_validator;
 mod membership_origin_validator;
 mod proposal_encoder;
 mod staking_events_handler;
+mod sudo_member_origin_validator;
 
 pub use council_elected_handler::CouncilElectedHandler;
 pub use council_origin_validator::CouncilManager;
 pub use membership_origin_validator::{MemberId, MembershipOriginValidator};
 pub use proposal_encoder::ExtrinsicProposalEncoder;
 pub use staking_events_handler::StakingEventsHandler;
+pub use sudo_member_origin_validator::SudoMemberOriginValidator;
@@ -6,7 +6,7 @@ use codec::Encode;
 use rstd::vec::Vec;
 
 /// _ProposalEncoder_ implementation. It encodes extrinsics with proposal details parameters
-/// using Runtime Call and parity codec.
+/// using Runtime Call and parity codec. Batch proposal is encoded as a list of encoded calls.
 pub struct ExtrinsicProposalEncoder;
 impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
     fn encode_proposal(proposal_details: ProposalDetailsOf<Runtime>) -> Vec<u8> {
@@ -46,6 +46,57 @@ impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
                 proposals_codex::Call::execute_runtime_upgrade_proposal(wasm_code),
             )
             .encode(),
+            // the wasm code is provided after the proposal approval
+            ProposalDetails::RuntimeUpgradeCodeHash(_) => Vec::new(),
+            ProposalDetails::SetProposalParameters(proposal_type, proposal_parameters) => {
+                Call::ProposalsCodex(proposals_codex::Call::set_proposal_parameters(
+                    proposal_type,
+                    proposal_parameters,
+                ))
+                .encode()
+            }
+            ProposalDetails::AddContentWorkingGroupLeaderOpening(
+                commitment,
+                human_readable_text,
+            ) => Call::ContentWorkingGroup(content_working_group::Call::add_lead_opening(
+                hiring::ActivateOpeningAt::CurrentBlock,
+                commitment,
+                human_readable_text,
+            ))
+            .encode(),
+            ProposalDetails::BeginReviewContentWorkingGroupLeaderApplications(opening_id) => {
+                Call::ContentWorkingGroup(content_working_group::Call::begin_lead_applicant_review(
+                    opening_id,
+                ))
+                .encode()
+            }
+            ProposalDetails::FillContentWorkingGroupLeaderOpening(
+                opening_id,
+                successful_application_id,
+                reward_policy,
+            ) => Call::ContentWorkingGroup(content_working_group::Call::fill_lead_opening(
+                opening_id,
+                successful_application_id,
+                reward_policy,
+            ))
+            .encode(),
+            ProposalDetails::SlashContentWorkingGroupLeaderStake(slashing_amount) => {
+                Call::ContentWorkingGroup(content_working_group::Call::slash_lead_stake(
+                    slashing_amount,
+                ))
+                .encode()
+            }
+            ProposalDetails::TerminateContentWorkingGroupLeaderRole(rationale_text) => {
+                Call::ContentWorkingGroup(content_working_group::Call::terminate_lead_role(
+                    rationale_text,
+                ))
+                .encode()
+            }
+            ProposalDetails::Batch(batch_items) => batch_items
+                .into_iter()
+                .map(Self::encode_proposal)
+                .collect::<Vec<_>>()
+                .encode(),
         }
     }
 }
@@ -0,0 +1,176 @@
+#![warn(missing_docs)]
+
+use rstd::marker::PhantomData;
+
+use common::origin_validator::ActorOriginValidator;
+use system::ensure_root;
+
+use super::{MemberId, MembershipOriginValidator};
+
+/// Veto actor origin validator. Allows vetoes for the member controlled by the sudo key holder,
+/// signed either by the sudo key holder or by the root origin.
+pub struct SudoMemberOriginValidator<T> {
+    marker: PhantomData<T>,
+}
+
+impl<T: sudo::Trait + membership::members::Trait>
+    ActorOriginValidator<<T as system::Trait>::Origin, MemberId<T>, <T as system::Trait>::AccountId>
+    for SudoMemberOriginValidator<T>
+{
+    /// Check for valid combination of origin and actor_id. Actor_id should be valid member_id of
+    /// the membership module controlled by the sudo key holder. The root origin acts on behalf of
+    /// the sudo key holder.
+    fn ensure_actor_origin(
+        origin: <T as system::Trait>::Origin,
+        actor_id: MemberId<T>,
+    ) -> Result<<T as system::Trait>::AccountId, &'static str> {
+        let account_id = if ensure_root(origin.clone()).is_ok() {
+            <membership::members::Module<T>>::ensure_profile(actor_id)
+                .map_err(|_| "Sudo validation failed: cannot find a profile for a member")?
+                .controller_account
+        } else {
+            <MembershipOriginValidator<T>>::ensure_actor_origin(origin, actor_id)?
+        };
+
+        if <sudo::Module<T>>::key() == account_id {
+            return Ok(account_id);
+        }
+
+        Err("Sudo validation failed: account id doesn't belong to the sudo key holder")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SudoMemberOriginValidator;
+    use crate::Runtime;
+    use common::origin_validator::ActorOriginValidator;
+    use membership::members::UserInfo;
+    use sr_primitives::AccountId32;
+    use system::RawOrigin;
+
+    type Membership = membership::members::Module<Runtime>;
+
+    fn initial_test_ext() -> runtime_io::TestExternalities {
+        let mut t = system::GenesisConfig::default()
+            .build_storage::<Runtime>()
+            .unwrap();
+
+        sudo::GenesisConfig::<Runtime> {
+            key: AccountId32::default(),
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+
+        t.into()
+    }
+
+    fn add_member(account_id: AccountId32) {
+        let authority_account_id = AccountId32::default();
+        Membership::set_screening_authority(RawOrigin::Root.into(), authority_account_id.clone())
+            .unwrap();
+
+        Membership::add_screened_member(
+            RawOrigin::Signed(authority_account_id).into(),
+            account_id,
+            UserInfo {
+                handle: Some(b"handle".to_vec()),
+                avatar_uri: None,
+                about: None,
+            },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn sudo_member_origin_validator_succeeds() {
+        initial_test_ext().execute_with(|| {
+            let account_id = AccountId32::default();
+            add_member(account_id.clone());
+            let member_id = 0; // newly created member_id
+
+            let validation_result = SudoMemberOriginValidator::<Runtime>::ensure_actor_origin(
+                RawOrigin::Signed(account_id.clone()).into(),
+                member_id,
+            );
+
+            assert_eq!(validation_result, Ok(account_id));
+        });
+    }
+
+    #[test]
+    fn sudo_member_origin_validator_fails_with_not_sudo_account_id() {
+        initial_test_ext().execute_with(|| {
+            let account_id: AccountId32 = [2; 32].into();
+            add_member(account_id.clone());
+            let member_id = 0; // newly created member_id
+            let error = "Sudo validation failed: account id doesn't belong to the sudo key holder";
+
+            let validation_result = SudoMemberOriginValidator::<Runtime>::ensure_actor_origin(
+                RawOrigin::Signed(account_id).into(),
+                member_id,
+            );
+
+            assert_eq!(validation_result, Err(error));
+        });
+    }
+
+    #[test]
+    fn sudo_member_origin_validator_succeeds_with_root_origin() {
+        initial_test_ext().execute_with(|| {
+            let account_id = AccountId32::default();
+            add_member(account_id.clone());
+            let member_id = 0; // newly created member_id
+
+            let validation_result = SudoMemberOriginValidator::<Runtime>::ensure_actor_origin(
+                RawOrigin::Root.into(),
+                member_id,
+            );
+
+            assert_eq!(validation_result, Ok(account_id));
+        });
+    }
+
+    #[test]
+    fn sudo_member_origin_validator_fails_with_root_origin_and_not_sudo_member() {
+        initial_test_ext().execute_with(|| {
+            let account_id: AccountId32 = [2; 32].into();
+            add_member(account_id);
+            let member_id = 0; // newly created member_id
+            let error = "Sudo validation failed: account id doesn't belong to the sudo key holder";
+
+            let validation_result = SudoMemberOriginValidator::<Runtime>::ensure_actor_origin(
+                RawOrigin::Root.into(),
+                member_id,
+            );
+
+            assert_eq!(validation_result, Err(error));
+
+            let validation_result = SudoMemberOriginValidator::<Runtime>::ensure_actor_origin(
+                RawOrigin::Root.into(),
+                1,
+            );
+
+            assert_eq!(
+                validation_result,
+                Err("Sudo validation failed: cannot find a profile for a member")
+            );
+        });
+    }
+
+    #[test]
+    fn sudo_member_origin_validator_fails_with_none_origin() {
+        initial_test_ext().execute_with(|| {
+            let account_id = AccountId32::default();
+            add_member(account_id);
+            let member_id = 0; // newly created member_id
+
+            let validation_result = SudoMemberOriginValidator::<Runtime>::ensure_actor_origin(
+                RawOrigin::None.into(),
+                member_id,
+            );
+
+            assert_eq!(validation_result, Err("RequireSignedOrigin"));
+        });
+    }
+}
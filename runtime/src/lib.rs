@@ -17,6 +17,7 @@ mod test;
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
 mod integration;
+pub mod runtime_api;
 
 use authority_discovery_primitives::{
     AuthorityId as EncodedAuthorityId, Signature as EncodedSignature,
@@ -30,12 +31,12 @@ use primitives::OpaqueMetadata;
 use rstd::prelude::*;
 use sr_primitives::curve::PiecewiseLinear;
 use sr_primitives::traits::{
-    BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, StaticLookup, Verify,
+    BlakeTwo256, Block as BlockT, Dispatchable, IdentifyAccount, NumberFor, StaticLookup, Verify,
 };
 use sr_primitives::weights::Weight;
 use sr_primitives::{
     create_runtime_str, generic, impl_opaque_keys, transaction_validity::TransactionValidity,
-    ApplyResult, MultiSignature,
+    ApplyResult, DispatchError, MultiSignature,
 };
 use substrate_client::{
     block_builder::api::{self as block_builder_api, CheckInherentsResult, InherentData},
@@ -59,7 +60,9 @@ pub use srml_support::{
 pub use staking::StakerStatus;
 pub use timestamp::Call as TimestampCall;
 
-use integration::proposals::{CouncilManager, ExtrinsicProposalEncoder, MembershipOriginValidator};
+use integration::proposals::{
+    CouncilManager, ExtrinsicProposalEncoder, MembershipOriginValidator, SudoMemberOriginValidator,
+};
 pub use proposals_codex::ProposalsConfigParameters;
 
 /// An index to a block.
@@ -142,7 +145,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("joystream-node"),
     impl_name: create_runtime_str!("joystream-node"),
     authoring_version: 6,
-    spec_version: 15,
+    spec_version: 16,
     impl_version: 0,
     apis: RUNTIME_API_VERSIONS,
 };
@@ -423,7 +426,7 @@ impl finality_tracker::Trait for Runtime {
 }
 
 pub use forum;
-pub use governance::election_params::ElectionParameters;
+pub use governance::election_params::{ElectionParameters, TallyMethod};
 use governance::{council, election};
 use membership::members;
 use storage::{data_directory, data_object_storage_registry, data_object_type_registry};
@@ -589,6 +592,7 @@ impl minting::Trait for Runtime {
 
 impl recurringrewards::Trait for Runtime {
     type PayoutStatusHandler = (); // TODO - deal with successful and failed payouts
+    type PayoutPenaltyPolicy = Council;
     type RecipientId = u64;
     type RewardRelationshipId = u64;
 }
@@ -683,14 +687,30 @@ impl common::currency::GovernanceCurrency for Runtime {
     type Currency = balances::Module<Self>;
 }
 
+parameter_types! {
+    pub const CouncilElectionMaxArchivedElections: u32 = 100;
+    pub const CouncilElectionMaxDelegatorsPerDelegate: u32 = 1000;
+    pub const CouncilElectionMaxEscrowedVotes: u32 = 500;
+}
+
 impl governance::election::Trait for Runtime {
     type Event = Event;
     type CouncilElected = (Council, integration::proposals::CouncilElectedHandler);
+    type MaxArchivedElections = CouncilElectionMaxArchivedElections;
+    type MaxDelegatorsPerDelegate = CouncilElectionMaxDelegatorsPerDelegate;
+    type MaxEscrowedVotes = CouncilElectionMaxEscrowedVotes;
+}
+
+parameter_types! {
+    pub const CouncilMinRecallMotionStake: Balance = 1000;
 }
 
 impl governance::council::Trait for Runtime {
     type Event = Event;
     type CouncilTermEnded = (CouncilElection,);
+    type CouncilSeatVacated = (CouncilElection,);
+    type VotingParticipation = CouncilManager<Self>;
+    type MinRecallMotionStake = CouncilMinRecallMotionStake;
 }
 
 impl memo::Trait for Runtime {
@@ -830,22 +850,42 @@ parameter_types! {
     pub const ProposalRejectionFee: u64 = 5000;
     pub const ProposalTitleMaxLength: u32 = 40;
     pub const ProposalDescriptionMaxLength: u32 = 3000;
+    pub const ProposalVetoRationaleMaxLength: u32 = 3000;
     pub const ProposalMaxActiveProposalLimit: u32 = 5;
+    pub const ProposalVotesPruningPolicy: proposals_engine::VoteRecordsPruningPolicy<BlockNumber> =
+        proposals_engine::VoteRecordsPruningPolicy::PruneAfter(30 * DAYS);
+    pub const ProposalMinProposerStakePercentage: u32 = 50;
+    pub const ProposalFinalizedProposalRetentionPeriod: BlockNumber = 30 * DAYS;
+    pub const ProposalMaxFinalizedProposalIds: u32 = 1000;
+    pub const ProposalDeferredCodeTimeout: BlockNumber = 7 * DAYS;
+    pub const ProposalMaxPendingConstitutionalityPeriod: BlockNumber = 30 * DAYS;
+    pub const ProposalMaxProposalAmendments: u32 = 5;
 }
 
 impl proposals_engine::Trait for Runtime {
     type Event = Event;
     type ProposerOriginValidator = MembershipOriginValidator<Self>;
     type VoterOriginValidator = CouncilManager<Self>;
+    type VetoOriginValidator = SudoMemberOriginValidator<Self>;
     type TotalVotersCounter = CouncilManager<Self>;
+    type VoterStakesProvider = CouncilManager<Self>;
     type ProposalId = u32;
     type StakeHandlerProvider = proposals_engine::DefaultStakeHandlerProvider;
     type CancellationFee = ProposalCancellationFee;
     type RejectionFee = ProposalRejectionFee;
     type TitleMaxLength = ProposalTitleMaxLength;
     type DescriptionMaxLength = ProposalDescriptionMaxLength;
+    type VetoRationaleMaxLength = ProposalVetoRationaleMaxLength;
     type MaxActiveProposalLimit = ProposalMaxActiveProposalLimit;
     type DispatchableCallCode = Call;
+    type VotesPruningPolicy = ProposalVotesPruningPolicy;
+    type ProposalFinalizationHandler = ProposalsCodex;
+    type MinProposerStakePercentage = ProposalMinProposerStakePercentage;
+    type FinalizedProposalRetentionPeriod = ProposalFinalizedProposalRetentionPeriod;
+    type MaxFinalizedProposalIds = ProposalMaxFinalizedProposalIds;
+    type DeferredCodeTimeout = ProposalDeferredCodeTimeout;
+    type MaxPendingConstitutionalityPeriod = ProposalMaxPendingConstitutionalityPeriod;
+    type MaxProposalAmendments = ProposalMaxProposalAmendments;
 }
 impl Default for Call {
     fn default() -> Self {
@@ -858,17 +898,22 @@ parameter_types! {
     pub const ProposalMaxThreadInARowNumber: u32 = 100_000; // will not be used
     pub const ProposalThreadTitleLengthLimit: u32 = 40;
     pub const ProposalPostLengthLimit: u32 = 1000;
+    pub const ProposalMaxWhiteListSize: u32 = 20;
+    pub const ProposalMaxPostDepth: u32 = 5;
 }
 
 impl proposals_discussion::Trait for Runtime {
     type Event = Event;
     type PostAuthorOriginValidator = MembershipOriginValidator<Self>;
+    type CouncilOriginValidator = CouncilManager<Self>;
     type ThreadId = ThreadId;
     type PostId = PostId;
     type MaxPostEditionNumber = ProposalMaxPostEditionNumber;
     type ThreadTitleLengthLimit = ProposalThreadTitleLengthLimit;
     type PostLengthLimit = ProposalPostLengthLimit;
     type MaxThreadInARowNumber = ProposalMaxThreadInARowNumber;
+    type MaxWhiteListSize = ProposalMaxWhiteListSize;
+    type MaxPostDepth = ProposalMaxPostDepth;
 }
 
 parameter_types! {
@@ -1074,6 +1119,93 @@ impl_runtime_apis! {
         }
     }
 
+    impl runtime_api::ProposalsApi<Block> for Runtime {
+        fn active_proposals() -> Vec<runtime_api::ProposalInfo> {
+            let active_proposal_ids = <proposals_engine::ActiveProposalIds<Runtime>>::enumerate()
+                .map(|(proposal_id, _)| proposal_id);
+            let pending_sponsorship_proposal_ids =
+                <proposals_engine::PendingSponsorshipProposalIds<Runtime>>::enumerate()
+                    .map(|(proposal_id, _)| proposal_id);
+            let pending_constitutionality_proposal_ids =
+                <proposals_engine::PendingConstitutionalityProposalIds<Runtime>>::enumerate()
+                    .map(|(proposal_id, _)| proposal_id);
+
+            active_proposal_ids
+                .chain(pending_sponsorship_proposal_ids)
+                .chain(pending_constitutionality_proposal_ids)
+                .map(|proposal_id| runtime_api::ProposalInfo {
+                    proposal_id,
+                    proposal: ProposalsEngine::proposals(proposal_id),
+                    details: ProposalsCodex::proposal_details_by_proposal_id(proposal_id),
+                })
+                .collect()
+        }
+
+        fn pending_executions() -> Vec<runtime_api::PendingExecution> {
+            <proposals_engine::PendingExecutionProposalIds<Runtime>>::enumerate()
+                .filter_map(|(proposal_id, _)| {
+                    ProposalsEngine::next_execution_block(proposal_id).map(|execute_at| {
+                        runtime_api::PendingExecution {
+                            proposal_id,
+                            proposal: ProposalsEngine::proposals(proposal_id),
+                            execute_at,
+                        }
+                    })
+                })
+                .collect()
+        }
+
+        fn member_vote_statuses(
+            member_id: runtime_api::MemberId,
+        ) -> Vec<runtime_api::MemberVoteStatus> {
+            <proposals_engine::ActiveProposalIds<Runtime>>::enumerate()
+                .map(|(proposal_id, _)| runtime_api::MemberVoteStatus {
+                    proposal_id,
+                    vote: ProposalsEngine::vote_status(proposal_id, member_id),
+                })
+                .collect()
+        }
+
+        fn preview_execution(
+            proposal_id: runtime_api::ProposalId,
+        ) -> Option<proposals_engine::ApprovedProposalStatus> {
+            if !<proposals_engine::Proposals<Runtime>>::exists(proposal_id) {
+                return None;
+            }
+
+            let calls = match ProposalsEngine::decode_proposal_calls(proposal_id) {
+                Ok(calls) => calls,
+                Err(approved_proposal_status) => return Some(approved_proposal_status),
+            };
+
+            // Storage changes of the runtime API call are never committed, so the calls can be
+            // dispatched with the root origin here only.
+            for (index, call) in calls.into_iter().enumerate() {
+                if let Err(error) = call.dispatch(system::RawOrigin::Root.into()) {
+                    let error: DispatchError = error.into();
+
+                    return Some(ProposalsEngine::failed_call_execution_status(
+                        proposal_id,
+                        index as u32,
+                        error.message.unwrap_or("Dispatch error"),
+                    ));
+                }
+            }
+
+            Some(proposals_engine::ApprovedProposalStatus::Executed)
+        }
+    }
+
+    impl runtime_api::ElectionsApi<Block> for Runtime {
+        fn archived_election_rounds() -> Vec<u32> {
+            CouncilElection::archived_rounds()
+        }
+
+        fn archived_election(round: u32) -> Option<runtime_api::ElectionRecordOf> {
+            CouncilElection::election_archive(round)
+        }
+    }
+
     impl substrate_session::SessionKeys<Block> for Runtime {
         fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
             let seed = seed.as_ref().map(|s| rstd::str::from_utf8(&s).expect("Seed is an utf8 string"));
@@ -6,22 +6,57 @@ use rstd::prelude::*;
 // use sr_primitives::{print, traits::Zero};
 use srml_support::{debug, decl_event, decl_module, decl_storage};
 
+// Spec version of the runtime which storage is saved in the legacy layouts migrated on
+// the upgrade to the current runtime version.
+const LEGACY_LAYOUTS_SPEC_VERSION: u32 = 15;
+
 impl<T: Trait> Module<T> {
     /// This method is called from on_initialize() when a runtime upgrade is detected. This
     /// happens when the runtime spec version is found to be higher than the stored value.
     /// Important to note this method should be carefully maintained, because it runs on every runtime
-    /// upgrade.
-    fn runtime_upgraded() {
+    /// upgrade. The legacy layouts migrations run only on the upgrade from the spec version
+    /// they were written for: the newer storage could be decoded in the legacy layouts.
+    fn runtime_upgraded(previous_spec_version: Option<u32>) {
         debug::print!("Running runtime upgraded handler");
 
         // Add initialization of modules introduced in new runtime release. Typically this
         // would be any new storage values that need an initial value which would not
         // have been initialized with config() or build() chainspec construction mechanism.
         // Other tasks like resetting values, migrating values etc.
+
+        if previous_spec_version == Some(LEGACY_LAYOUTS_SPEC_VERSION) {
+            // Migrate proposals, theirs discussions and details to the current layout
+            proposals_engine::Module::<T>::migrate_proposals();
+            proposals_discussion::Module::<T>::migrate_threads_and_posts();
+            proposals_codex::Module::<T>::migrate_proposal_details();
+
+            // Close discussions of the finalized proposals
+            proposals_codex::Module::<T>::close_finalized_proposal_threads();
+
+            // Initialize config values of the new proposals
+            proposals_codex::Module::<T>::set_missing_config_values();
+
+            // Migrate content working group leads and curator openings to the current layout
+            content_working_group::Module::<T>::migrate_leads_and_openings();
+
+            // Migrate council seats to the current layout
+            governance::council::Module::<T>::migrate_active_council();
+
+            // Migrate reward relationships to the current layout
+            recurringrewards::Module::<T>::migrate_reward_relationships();
+        }
     }
 }
 
-pub trait Trait: system::Trait {
+pub trait Trait:
+    system::Trait
+    + proposals_engine::Trait
+    + proposals_discussion::Trait
+    + proposals_codex::Trait
+    + content_working_group::Trait
+    + governance::council::Trait
+    + recurringrewards::Trait
+{
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 }
 
@@ -48,12 +83,14 @@ decl_module! {
         fn deposit_event() = default;
 
         fn on_initialize(_now: T::BlockNumber) {
-            if Self::spec_version().map_or(true, |spec_version| VERSION.spec_version > spec_version) {
+            let previous_spec_version = Self::spec_version();
+
+            if previous_spec_version.map_or(true, |spec_version| VERSION.spec_version > spec_version) {
                 // Mark store version with current version of the runtime
                 SpecVersion::put(VERSION.spec_version);
 
                 // Run migrations and store initializers
-                Self::runtime_upgraded();
+                Self::runtime_upgraded(previous_spec_version);
 
                 Self::deposit_event(RawEvent::Migrated(
                     <system::Module<T>>::block_number(),
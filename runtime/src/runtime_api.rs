@@ -0,0 +1,95 @@
+//! Joystream runtime APIs.
+
+use codec::{Decode, Encode};
+use rstd::prelude::*;
+use substrate_client::decl_runtime_apis;
+
+use governance::election::ElectionRecord;
+use proposals_codex::ProposalDetailsOf;
+use proposals_engine::{ApprovedProposalStatus, Proposal, VoteKind};
+
+use crate::{AccountId, Balance, BlockNumber, Hash, Runtime};
+
+/// Proposal id of the runtime.
+pub type ProposalId = <Runtime as proposals_engine::Trait>::ProposalId;
+
+/// Member id of the runtime.
+pub type MemberId = <Runtime as membership::members::Trait>::MemberId;
+
+/// Proposal of the runtime.
+pub type ProposalOf = Proposal<
+    BlockNumber,
+    MemberId,
+    proposals_engine::BalanceOf<Runtime>,
+    <Runtime as stake::Trait>::StakeId,
+    AccountId,
+>;
+
+/// Proposal with its decoded details.
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub struct ProposalInfo {
+    /// Proposal id.
+    pub proposal_id: ProposalId,
+
+    /// Proposal data.
+    pub proposal: ProposalOf,
+
+    /// Decoded proposal details.
+    pub details: ProposalDetailsOf<Runtime>,
+}
+
+/// Approved proposal pending execution with its next execution block.
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub struct PendingExecution {
+    /// Proposal id.
+    pub proposal_id: ProposalId,
+
+    /// Proposal data.
+    pub proposal: ProposalOf,
+
+    /// The earliest block of the next execution attempt.
+    pub execute_at: BlockNumber,
+}
+
+/// Vote status of the member for the active proposal.
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub struct MemberVoteStatus {
+    /// Proposal id.
+    pub proposal_id: ProposalId,
+
+    /// Vote of the member. None if the member didn't vote.
+    pub vote: Option<VoteKind>,
+}
+
+/// Archived council election of the runtime.
+pub type ElectionRecordOf = ElectionRecord<AccountId, Balance, Hash, BlockNumber>;
+
+decl_runtime_apis! {
+    /// Provides proposals data for the clients without decoding the raw storage.
+    pub trait ProposalsApi {
+        /// Returns not finalized proposals with theirs decoded details: active proposals and
+        /// proposals pending sponsorship or the next council approval.
+        fn active_proposals() -> Vec<ProposalInfo>;
+
+        /// Returns approved proposals pending execution with theirs next execution blocks.
+        fn pending_executions() -> Vec<PendingExecution>;
+
+        /// Returns vote statuses of the member for the active proposals.
+        fn member_vote_statuses(member_id: MemberId) -> Vec<MemberVoteStatus>;
+
+        /// Dispatches the proposal code and returns the would-be execution status. Returns None
+        /// for the non-existing proposal. Storage changes of the runtime API call are never
+        /// committed: changes made by the dispatched calls are rolled back after the call.
+        fn preview_execution(proposal_id: ProposalId) -> Option<ApprovedProposalStatus>;
+    }
+
+    /// Provides archived council elections for the clients without replaying the events.
+    pub trait ElectionsApi {
+        /// Returns rounds of the archived elections, the oldest first.
+        fn archived_election_rounds() -> Vec<u32>;
+
+        /// Returns the archived election of the round. Returns None if the election
+        /// of the round is not archived.
+        fn archived_election(round: u32) -> Option<ElectionRecordOf>;
+    }
+}
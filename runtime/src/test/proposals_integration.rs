@@ -2,15 +2,16 @@
 
 #![cfg(test)]
 
-use crate::{BlockNumber, ElectionParameters, ProposalCancellationFee, Runtime};
+use crate::{BlockNumber, ElectionParameters, ProposalCancellationFee, Runtime, TallyMethod};
 use codec::Encode;
 use governance::election::CouncilElected;
 use membership::members;
 use membership::role_types::Role;
+use proposals_discussion::ThreadMode;
 use proposals_engine::{
     ActiveStake, ApprovedProposalStatus, BalanceOf, Error, FinalizationData, Proposal,
     ProposalDecisionStatus, ProposalParameters, ProposalStatus, VoteKind, VotersParameters,
-    VotingResults,
+    VotingMode, VotingResults,
 };
 use roles::actors::RoleParameters;
 
@@ -156,6 +157,7 @@ struct DummyProposalFixture {
     title: Vec<u8>,
     description: Vec<u8>,
     stake_balance: Option<BalanceOf<Runtime>>,
+    exactly_execute_at: Option<u32>,
 }
 
 impl Default for DummyProposalFixture {
@@ -174,6 +176,9 @@ impl Default for DummyProposalFixture {
                 slashing_threshold_percentage: 60,
                 grace_period: 0,
                 required_stake: None,
+                voting_mode: VotingMode::Headcount,
+                constitutionality: 1,
+                execution_retry_policy: None,
             },
             account_id: <Runtime as system::Trait>::AccountId::default(),
             proposer_id: 0,
@@ -181,6 +186,7 @@ impl Default for DummyProposalFixture {
             title,
             description,
             stake_balance: None,
+            exactly_execute_at: None,
         }
     }
 }
@@ -217,6 +223,7 @@ impl DummyProposalFixture {
             self.description,
             self.stake_balance,
             self.proposal_code,
+            self.exactly_execute_at,
         );
         assert_eq!(proposal_id_result, result);
 
@@ -278,6 +285,9 @@ fn proposal_cancellation_with_slashes_with_balance_checks_succeeds() {
             slashing_threshold_percentage: 60,
             grace_period: 5,
             required_stake: Some(stake_amount),
+            voting_mode: VotingMode::Headcount,
+            constitutionality: 1,
+            execution_retry_policy: None,
         };
         let dummy_proposal = DummyProposalFixture::default()
             .with_parameters(parameters)
@@ -313,6 +323,10 @@ fn proposal_cancellation_with_slashes_with_balance_checks_succeeds() {
             title: b"title".to_vec(),
             description: b"description".to_vec(),
             voting_results: VotingResults::default(),
+            exactly_execute_at: None,
+            activated_at: 1,
+            council_approvals: 0,
+            amendments: Vec::new(),
         };
 
         assert_eq!(proposal, expected_proposal);
@@ -396,6 +410,62 @@ fn proposal_reset_succeeds() {
     });
 }
 
+#[test]
+fn proposal_veto_is_allowed_for_the_sudo_member_and_root_only() {
+    initial_test_ext().execute_with(|| {
+        // the sudo key holder is the default account id of the member 0
+        setup_members(2);
+        setup_council();
+
+        let first_proposal_id = DummyProposalFixture::default()
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+        let second_proposal_id = DummyProposalFixture::default()
+            .create_proposal_and_assert(Ok(2))
+            .unwrap();
+
+        let not_sudo_account_id: [u8; 32] = [1; 32];
+        assert_eq!(
+            ProposalsEngine::veto_proposal(
+                RawOrigin::Signed(not_sudo_account_id.into()).into(),
+                1,
+                first_proposal_id,
+                b"rationale".to_vec(),
+            ),
+            Err(Error::Other(
+                "Sudo validation failed: account id doesn't belong to the sudo key holder"
+            ))
+        );
+
+        assert_eq!(
+            ProposalsEngine::veto_proposal(
+                RawOrigin::Signed(AccountId32::default()).into(),
+                0,
+                first_proposal_id,
+                b"rationale".to_vec(),
+            ),
+            Ok(())
+        );
+
+        assert_eq!(
+            ProposalsEngine::veto_proposal(
+                RawOrigin::Root.into(),
+                0,
+                second_proposal_id,
+                b"rationale".to_vec(),
+            ),
+            Ok(())
+        );
+
+        for proposal_id in vec![first_proposal_id, second_proposal_id] {
+            assert_eq!(
+                ProposalsEngine::proposal_vetoes(proposal_id).map(|veto| veto.vetoer_id),
+                Some(0)
+            );
+        }
+    });
+}
+
 struct CodexProposalTestFixture<SuccessfulCall>
 where
     SuccessfulCall: Fn() -> DispatchResult<proposals_codex::Error>,
@@ -463,6 +533,7 @@ fn text_proposal_execution_succeeds() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Runtime>>::from(25000u32)),
+                    ThreadMode::Open,
                     b"text".to_vec(),
                 )
             },
@@ -487,6 +558,7 @@ fn set_lead_proposal_execution_succeeds() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Runtime>>::from(50000u32)),
+                    ThreadMode::Open,
                     Some((member_id as u64, account_id.into())),
                 )
             },
@@ -520,6 +592,7 @@ fn spending_proposal_execution_succeeds() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Runtime>>::from(25_000_u32)),
+                    ThreadMode::Open,
                     new_balance,
                     target_account_id.clone().into(),
                 )
@@ -562,6 +635,7 @@ fn set_content_working_group_mint_capacity_execution_succeeds() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Runtime>>::from(50000u32)),
+                    ThreadMode::Open,
                     new_balance,
                 )
             },
@@ -588,6 +662,8 @@ fn set_election_parameters_proposal_execution_succeeds() {
             new_term_duration: 14400,
             min_council_stake: 1,
             min_voting_stake: 1,
+            tally_method: TallyMethod::FirstPastThePost,
+            max_ranked_candidates: 0,
         };
         assert_eq!(Election::announcing_period(), 0);
 
@@ -600,6 +676,7 @@ fn set_election_parameters_proposal_execution_succeeds() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Runtime>>::from(200_000_u32)),
+                    ThreadMode::Open,
                     election_parameters,
                 )
             },
@@ -649,6 +726,7 @@ fn evict_storage_provider_proposal_execution_succeeds() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Runtime>>::from(25000u32)),
+                    ThreadMode::Open,
                     target_account.into(),
                 )
             },
@@ -692,6 +770,7 @@ fn set_storage_role_parameters_proposal_execution_succeeds() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Runtime>>::from(100_000_u32)),
+                    ThreadMode::Open,
                     target_role_parameters.clone(),
                 )
             },
@@ -723,7 +802,9 @@ fn set_validator_count_proposal_execution_succeeds() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Runtime>>::from(100_000_u32)),
+                    ThreadMode::Open,
                     new_validator_count,
+                    None,
                 )
             },
         };